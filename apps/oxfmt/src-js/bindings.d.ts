@@ -26,12 +26,58 @@ export declare const enum Severity {
   Warning = 'Warning',
   Advice = 'Advice'
 }
+/**
+ * Check whether each of the given `[filename, source_text]` pairs is already formatted.
+ *
+ * The external formatter is set up only once for all files, which is a lot cheaper than calling [`format`] for each file.
+ * Returns one boolean per file in the same order, files which could not be formatted (e.g. because of parse errors)
+ * are reported as not formatted.
+ */
+export declare function checkMany(files: Array<[string, string]>, options: any | undefined | null, initExternalFormatterCb: (numThreads: number) => Promise<string[]>, formatEmbeddedCb: (options: Record<string, any>, parserName: string, code: string) => Promise<string>, formatFileCb: (options: Record<string, any>, parserName: string, fileName: string, code: string) => Promise<string>, sortTailwindClassesCb: (filepath: string, options: Record<string, any>, classes: string[]) => Promise<string[]>): Promise<Array<boolean>>
+
+/**
+ * Clear the in-process cache of formatted code.
+ *
+ * Clear it when something else affecting the result has changed, e.g. the installed Prettier plugins.
+ */
+export declare function clearFormatCache(): void
+
 /**
  * NAPI based format API entry point.
  *
  * Since it internally uses `await prettier.format()` in JS side, `formatSync()` cannot be provided.
+ *
+ * # Errors
+ * By default, errors are returned in `errors` with the original source text as `code`.
+ * With `throw_on_error`, the promise is rejected with an error containing the diagnostics instead.
  */
-export declare function format(filename: string, sourceText: string, options: any | undefined | null, initExternalFormatterCb: (numThreads: number) => Promise<string[]>, formatEmbeddedCb: (options: Record<string, any>, parserName: string, code: string) => Promise<string>, formatFileCb: (options: Record<string, any>, parserName: string, fileName: string, code: string) => Promise<string>, sortTailwindClassesCb: (filepath: string, options: Record<string, any>, classes: string[]) => Promise<string[]>): Promise<FormatResult>
+export declare function format(filename: string, sourceText: string, options: any | undefined | null, throwOnError: boolean | undefined | null, initExternalFormatterCb: (numThreads: number) => Promise<string[]>, formatEmbeddedCb: (options: Record<string, any>, parserName: string, code: string) => Promise<string>, formatFileCb: (options: Record<string, any>, parserName: string, fileName: string, code: string) => Promise<string>, sortTailwindClassesCb: (filepath: string, options: Record<string, any>, classes: string[]) => Promise<string[]>): Promise<FormatResult>
+
+/** Count the lookups of the in-process cache of formatted code since it was turned on or last cleared. */
+export declare function formatCacheStats(): FormatCacheStats
+
+export interface FormatCacheStats {
+  /** Number of files served from the cache. */
+  hits: number
+  /** Number of files which were not found in the cache. */
+  misses: number
+}
+
+/**
+ * Same as [`format`], but returns the minimal edits to transform the source text into the formatted code,
+ * instead of the whole formatted code.
+ *
+ * Editors can apply these edits without replacing the whole buffer,
+ * which avoids flickering and preserves folds.
+ */
+export declare function formatEdits(filename: string, sourceText: string, options: any | undefined | null, initExternalFormatterCb: (numThreads: number) => Promise<string[]>, formatEmbeddedCb: (options: Record<string, any>, parserName: string, code: string) => Promise<string>, formatFileCb: (options: Record<string, any>, parserName: string, fileName: string, code: string) => Promise<string>, sortTailwindClassesCb: (filepath: string, options: Record<string, any>, classes: string[]) => Promise<string[]>): Promise<FormatEditsResult>
+
+export interface FormatEditsResult {
+  /** The minimal edits to apply to the source text, sorted by their position. */
+  edits: Array<TextEdit>
+  /** Parse and format errors. */
+  errors: Array<OxcError>
+}
 
 export interface FormatResult {
   /** The formatted code. */
@@ -40,6 +86,15 @@ export interface FormatResult {
   errors: Array<OxcError>
 }
 
+/**
+ * Parse `source_text` as if it was the content of `filename`, without formatting it.
+ *
+ * The source type is inferred from `filename` the same way as [`format`] does.
+ * Returns the parse errors, or an empty vector when the source text is valid.
+ * Files which are not JS/TS files are reported with a single error.
+ */
+export declare function parseOnly(filename: string, sourceText: string): Array<OxcError>
+
 /**
  * NAPI based JS CLI entry point.
  * For pure Rust CLI entry point, see `main.rs`.
@@ -56,3 +111,34 @@ export interface FormatResult {
  * - `exitCode`: If main logic already ran in Rust side, return the exit code
  */
 export declare function runCli(args: Array<string>, initExternalFormatterCb: (numThreads: number) => Promise<string[]>, formatEmbeddedCb: (options: Record<string, any>, parserName: string, code: string) => Promise<string>, formatFileCb: (options: Record<string, any>, parserName: string, fileName: string, code: string) => Promise<string>, sortTailwindcssClassesCb: (filepath: string, options: Record<string, any>, classes: string[]) => Promise<string[]>): Promise<[string, number | undefined | null]>
+
+/**
+ * Turn the in-process cache of formatted code on or off, it is off by default.
+ *
+ * When it is on, repeated calls with the same file name, source text and options are served from this cache.
+ * Turning it off also drops the cached code.
+ */
+export declare function setFormatCacheEnabled(enabled: boolean): void
+
+/**
+ * Returns the Prettier option keys which Oxfmt understands.
+ *
+ * Other options are passed through to Prettier as they are, but do not affect the files formatted by Oxfmt itself.
+ */
+export declare function supportedOptions(): Array<string>
+
+export interface TextEdit {
+  range: TextRange
+  newText: string
+}
+
+/** Zero-based position, `character` is counted in UTF-16 code units (same as LSP). */
+export interface TextPosition {
+  line: number
+  character: number
+}
+
+export interface TextRange {
+  start: TextPosition
+  end: TextPosition
+}
@@ -579,7 +579,14 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { Severity, format, runCli } = nativeBinding
+const { Severity, checkMany, clearFormatCache, format, formatCacheStats, formatEdits, parseOnly, runCli, setFormatCacheEnabled, supportedOptions } = nativeBinding
 export { Severity }
+export { checkMany }
+export { clearFormatCache }
 export { format }
+export { formatCacheStats }
+export { formatEdits }
+export { parseOnly }
 export { runCli }
+export { setFormatCacheEnabled }
+export { supportedOptions }
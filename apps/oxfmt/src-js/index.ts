@@ -1,4 +1,13 @@
-import { format as napiFormat } from "./bindings";
+import {
+  checkMany as napiCheckMany,
+  clearFormatCache as napiClearFormatCache,
+  format as napiFormat,
+  formatCacheStats as napiFormatCacheStats,
+  formatEdits as napiFormatEdits,
+  parseOnly as napiParseOnly,
+  setFormatCacheEnabled as napiSetFormatCacheEnabled,
+  supportedOptions as napiSupportedOptions,
+} from "./bindings";
 import {
   resolvePlugins,
   formatEmbeddedCode,
@@ -12,8 +21,16 @@ import type { Options } from "prettier";
 
 /**
  * Format the given source text according to the specified options.
+ *
+ * Parse and format errors are returned in `errors` with the original source text as `code`.
+ * If `throwOnError` is `true`, the returned promise is rejected with these errors instead.
  */
-export async function format(fileName: string, sourceText: string, options?: FormatOptions) {
+export async function format(
+  fileName: string,
+  sourceText: string,
+  options?: FormatOptions,
+  throwOnError?: boolean,
+) {
   if (typeof fileName !== "string") throw new TypeError("`fileName` must be a string");
   if (typeof sourceText !== "string") throw new TypeError("`sourceText` must be a string");
 
@@ -21,6 +38,53 @@ export async function format(fileName: string, sourceText: string, options?: For
     fileName,
     sourceText,
     options ?? {},
+    throwOnError ?? false,
+    resolvePlugins,
+    (options, parserName, code) => formatEmbeddedCode({ options, parserName, code }),
+    (options, parserName, fileName, code) => formatFile({ options, parserName, fileName, code }),
+    (filepath, options, classes) => sortTailwindClasses({ filepath, classes, options }),
+  );
+}
+
+/**
+ * Format the given source text according to the specified options,
+ * and return the minimal edits to apply instead of the whole formatted code.
+ */
+export async function formatEdits(fileName: string, sourceText: string, options?: FormatOptions) {
+  if (typeof fileName !== "string") throw new TypeError("`fileName` must be a string");
+  if (typeof sourceText !== "string") throw new TypeError("`sourceText` must be a string");
+
+  return napiFormatEdits(
+    fileName,
+    sourceText,
+    options ?? {},
+    resolvePlugins,
+    (options, parserName, code) => formatEmbeddedCode({ options, parserName, code }),
+    (options, parserName, fileName, code) => formatFile({ options, parserName, fileName, code }),
+    (filepath, options, classes) => sortTailwindClasses({ filepath, classes, options }),
+  );
+}
+
+/**
+ * Check whether each of the given files is already formatted according to the specified options.
+ *
+ * Returns one boolean per file in the same order.
+ * Files which cannot be formatted, e.g. because of parse errors, are reported as not formatted.
+ */
+export async function checkMany(
+  files: [fileName: string, sourceText: string][],
+  options?: FormatOptions,
+): Promise<boolean[]> {
+  if (!Array.isArray(files)) throw new TypeError("`files` must be an array");
+  for (const file of files) {
+    if (!Array.isArray(file) || typeof file[0] !== "string" || typeof file[1] !== "string") {
+      throw new TypeError("`files` must contain `[fileName, sourceText]` string pairs");
+    }
+  }
+
+  return napiCheckMany(
+    files,
+    options ?? {},
     resolvePlugins,
     (options, parserName, code) => formatEmbeddedCode({ options, parserName, code }),
     (options, parserName, fileName, code) => formatFile({ options, parserName, fileName, code }),
@@ -28,6 +92,55 @@ export async function format(fileName: string, sourceText: string, options?: For
   );
 }
 
+/**
+ * Turn the cache of formatted code on or off, it is off by default.
+ *
+ * When it is on, formatting the same source text of the same file with the same options again returns the cached result.
+ * Turning it off also drops the cached results.
+ */
+export function setFormatCacheEnabled(enabled: boolean) {
+  napiSetFormatCacheEnabled(enabled);
+}
+
+/**
+ * Clear the cache of formatted code.
+ *
+ * Call this when something else affecting the result has changed, e.g. the installed Prettier plugins.
+ */
+export function clearFormatCache() {
+  napiClearFormatCache();
+}
+
+/**
+ * Count the files which were served from the cache of formatted code (`hits`) and which were not (`misses`),
+ * since the cache was turned on or last cleared.
+ */
+export function formatCacheStats(): { hits: number; misses: number } {
+  return napiFormatCacheStats();
+}
+
+/**
+ * Returns the Prettier option keys which are understood by Oxfmt itself.
+ *
+ * Other options are still passed to Prettier, e.g. for plugins or non-JS files.
+ */
+export function supportedOptions(): string[] {
+  return napiSupportedOptions();
+}
+
+/**
+ * Parse the given source text without formatting it, and return the parse errors.
+ *
+ * The source type is inferred from the file name, like `format()` does.
+ * Returns an empty array when the source text is valid.
+ */
+export function parseOnly(fileName: string, sourceText: string) {
+  if (typeof fileName !== "string") throw new TypeError("`fileName` must be a string");
+  if (typeof sourceText !== "string") throw new TypeError("`sourceText` must be a string");
+
+  return napiParseOnly(fileName, sourceText);
+}
+
 // NOTE: Regarding the handwritten TypeScript types.
 //
 // Initially, I tried to use the `FormatConfig` struct to automatically generate types with `napi(object)`,
@@ -75,6 +188,58 @@ export type FormatOptions = Pick<
   printWidth?: number;
   /** Whether to insert a final newline at the end of the file. (Default: `true`) */
   insertFinalNewline?: boolean;
+  /** Tags of template literals to format as SQL, needs a Prettier plugin for SQL. (Default: `[]`) */
+  embeddedSqlTags?: string[];
+  /** How far the statements of a `switch` case are indented. (Default: `"single"`) */
+  switchCaseBodyIndent?: "single" | "double";
+  /** The number of calls of a member chain before it breaks, if any call has a complex argument. (Default: `2`) */
+  methodChainBreakThreshold?: number;
+  /** Print a space inside empty objects and arrays: `{ }` and `[ ]`. (Default: `false`) */
+  emptyBracketSpacing?: boolean;
+  /** Align the values of properties in multi-line objects. (Default: `false`) */
+  alignObjectProperties?: boolean;
+  /** Re-indent the content of embedded language templates. (Default: `false`) */
+  reindentEmbeddedTemplates?: boolean;
+  /** Always print a blank line between class members. (Default: `false`) */
+  blankLineBetweenClassMembers?: boolean;
+  /** Whether arrays of objects or arrays are printed with one element per line. (Default: `"auto"`) */
+  expandArraysOfObjects?: "auto" | "always" | "never";
+  /** Sort the properties of object literals by their keys. (Default: `"none"`) */
+  sortObjectKeys?: "none" | "asc" | "desc";
+  /** Where the operators of broken union and intersection types are printed. (Default: `"auto"`) */
+  typeOperatorPosition?: "auto" | "start" | "end";
+  /** Indent the members of a broken union type. (Default: `true`) */
+  indentUnionTypes?: boolean;
+  /** Hug the sole object or array argument of a call. (Default: `true`) */
+  hugSoleArgument?: boolean;
+  /** Align the `=` of enum members with an initializer. (Default: `false`) */
+  alignEnumMembers?: boolean;
+  /** Print an `else` block which only contains an `if` statement as `else if`. (Default: `false`) */
+  collapseElseIf?: boolean;
+  /** Experimental: Parse and format the pipeline operator `x |> f`. (Default: `false`) */
+  experimentalPipelineOperator?: boolean;
+  /** Print conditionals with single-line JSX branches without parentheses. (Default: `false`) */
+  compactJsxConditionals?: boolean;
+  /** How the quotes of JSX attribute values are chosen. (Default: `"as-needed"`) */
+  jsxAttributeQuotes?: "as-needed" | "consistent";
+  /** Whether the braces of empty function and class bodies stay on the same line. (Default: `"collapse"`) */
+  emptyBody?: "collapse" | "expand";
+  /** How the continuation operands of a broken string concatenation are indented. (Default: `"indent"`) */
+  stringConcatenationIndent?: "indent" | "align";
+  /** How JSX text is wrapped. (Default: `"reflow"`) */
+  jsxTextWrap?: "reflow" | "preserve";
+  /** Keep the first comment of the file as written. (Default: `false`) */
+  preserveFirstComment?: boolean;
+  /** Whether numeric literals are normalized. (Default: `"normalize"`) */
+  numericLiterals?: "normalize" | "preserve";
+  /** Keep a blank line at the start and the end of blocks. (Default: `false`) */
+  preserveBlockBlankLines?: boolean;
+  /** How the conditionals of a ternary chain are indented. (Default: `"indent"`) */
+  ternaryChainIndent?: "indent" | "flat";
+  /** Print a space after the `function` keyword of anonymous functions. (Default: `true`) */
+  anonymousFunctionSpace?: boolean;
+  /** Lowercase the hex digits of `\x` and `\u` escape sequences in strings. (Default: `false`) */
+  normalizeEscapeSequences?: boolean;
   /** Experimental: Sort import statements. Disabled by default. */
   experimentalSortImports?: SortImportsOptions;
   /** Experimental: Sort `package.json` keys. (Default: `true`) */
@@ -96,12 +261,16 @@ export type SortImportsOptions = {
   partitionByComment?: boolean;
   /** Sort side-effect imports. (Default: `false`) */
   sortSideEffects?: boolean;
+  /** Keep side-effect imports in place and never move imports across them. (Default: `false`) */
+  partitionBySideEffect?: boolean;
   /** Sort order. (Default: `"asc"`) */
   order?: "asc" | "desc";
   /** Ignore case when sorting. (Default: `true`) */
   ignoreCase?: boolean;
   /** Add newlines between import groups. (Default: `true`) */
   newlinesBetween?: boolean;
+  /** Keep existing blank lines between import groups when `newlinesBetween` is `false`. (Default: `false`) */
+  preserveNewlinesBetween?: boolean;
   /** Prefixes to identify internal imports. (Default: `["~/", "@/"]`) */
   internalPattern?: string[];
   /**
@@ -28,6 +28,8 @@ pub struct FormatCommand {
     pub ignore_options: IgnoreOptions,
     #[bpaf(external)]
     pub runtime_options: RuntimeOptions,
+    #[bpaf(external)]
+    pub diff_target: Option<DiffTarget>,
     /// Single file, single path or list of paths.
     /// If not provided, current working directory is used.
     /// Glob is supported only for exclude patterns like `'!**/fixtures/*.js'`.
@@ -126,6 +128,29 @@ fn output_mode() -> impl bpaf::Parser<OutputMode> {
     bpaf::construct!([write, check, list_different]).group_help("Output Options:")
 }
 
+/// Git diff target, only the lines changed against it are formatted
+#[derive(Debug, Clone)]
+pub enum DiffTarget {
+    /// The staged changes, compared with `HEAD`
+    Staged,
+    /// The changes of the working tree, compared with the given ref
+    Ref(String),
+}
+
+fn diff_target() -> impl bpaf::Parser<Option<DiffTarget>> {
+    let staged = bpaf::long("staged")
+        .help("Format only the lines changed in the staged files")
+        .req_flag(DiffTarget::Staged)
+        .hide_usage();
+    let diff_against = bpaf::long("diff-against")
+        .help("Format only the lines changed against the given git ref")
+        .argument::<String>("REF")
+        .map(DiffTarget::Ref)
+        .hide_usage();
+
+    bpaf::construct!([staged, diff_against]).optional().group_help("Diff Options:")
+}
+
 /// Migration Source
 #[cfg(feature = "napi")]
 #[derive(Debug, Clone)]
@@ -167,4 +192,11 @@ pub struct RuntimeOptions {
     /// Number of threads to use. Set to 1 for using only 1 CPU core.
     #[bpaf(argument("INT"), hide_usage)]
     pub threads: Option<usize>,
+    /// Do not print the summary of formatted files in write mode
+    #[bpaf(switch, hide_usage)]
+    pub quiet: bool,
+    /// Write the resolved options of each file next to it as `<FILE>.oxfmt-debug.json`,
+    /// to be attached to bug reports
+    #[bpaf(switch, hide_usage)]
+    pub debug_dump: bool,
 }
@@ -3,7 +3,8 @@ use std::{env, io::BufWriter, path::PathBuf, sync::mpsc, time::Instant};
 use oxc_diagnostics::DiagnosticService;
 
 use super::{
-    command::{FormatCommand, Mode, OutputMode},
+    command::{DiffTarget, FormatCommand, Mode, OutputMode},
+    git_diff::collect_changed_lines,
     reporter::DefaultReporter,
     result::CliRunResult,
     service::{FormatService, SuccessResult},
@@ -56,8 +57,14 @@ impl FormatRunner {
         let start_time = Instant::now();
 
         let cwd = self.cwd;
-        let FormatCommand { paths, mode, config_options, ignore_options, runtime_options } =
-            self.options;
+        let FormatCommand {
+            paths,
+            mode,
+            config_options,
+            ignore_options,
+            runtime_options,
+            diff_target,
+        } = self.options;
         // If `napi` feature is disabled, there is no other mode.
         #[cfg_attr(not(feature = "napi"), expect(irrefutable_let_patterns))]
         let Mode::Cli(format_mode) = mode else {
@@ -69,6 +76,7 @@ impl FormatRunner {
         // NOTE: Currently, we only load single config file.
         // - from `--config` if specified
         // - else, search nearest for the nearest `.oxfmtrc.json` from cwd upwards
+        //   - `package.json` with `oxfmt` key is also used, if no `.oxfmtrc` exists in the same directory
         let oxfmtrc_path = resolve_oxfmtrc_path(&cwd, config_options.config.as_deref());
         let editorconfig_path = resolve_editorconfig_path(&cwd);
         let mut config_resolver = match ConfigResolver::from_config_paths(
@@ -93,6 +101,17 @@ impl FormatRunner {
             }
         };
 
+        // Only the lines changed in `git diff` are formatted, other files are skipped
+        let staged = matches!(diff_target, Some(DiffTarget::Staged));
+        let changed_lines = match diff_target.map(|target| collect_changed_lines(&cwd, &target)) {
+            Some(Ok(changed_lines)) => Some(changed_lines),
+            Some(Err(err)) => {
+                utils::print_and_flush(stderr, &format!("Failed to get git diff.\n{err}\n"));
+                return CliRunResult::InvalidOptionConfig;
+            }
+            None => None,
+        };
+
         // Use `block_in_place()` to avoid nested async runtime access
         #[cfg(feature = "napi")]
         match tokio::task::block_in_place(|| {
@@ -165,16 +184,21 @@ impl FormatRunner {
         // Spawn a thread to run formatting service with streaming entries
         rayon::spawn(move || {
             let format_service =
-                FormatService::new(cwd, format_mode_clone, source_formatter, config_resolver);
+                FormatService::new(cwd, format_mode_clone, source_formatter, config_resolver)
+                    .with_changed_lines(changed_lines)
+                    .with_staged(staged)
+                    .with_debug_dump(runtime_options.debug_dump);
             format_service.run_streaming(rx_entry, &tx_error, &tx_success);
         });
 
         // Collect results and separate changed paths from unchanged count
         let mut changed_paths: Vec<String> = vec![];
+        let mut written_count: usize = 0;
         let mut unchanged_count: usize = 0;
         for result in rx_success {
             match result {
                 SuccessResult::Changed(path) => changed_paths.push(path),
+                SuccessResult::Written => written_count += 1,
                 SuccessResult::Unchanged => unchanged_count += 1,
             }
         }
@@ -192,7 +216,8 @@ impl FormatRunner {
         let error_count = diagnostics.errors_count();
 
         // Count the processed files
-        let total_target_files_count = changed_paths.len() + unchanged_count + error_count;
+        let total_target_files_count =
+            changed_paths.len() + written_count + unchanged_count + error_count;
         let print_stats = |stdout| {
             let elapsed_ms = start_time.elapsed().as_millis();
             utils::print_and_flush(
@@ -202,6 +227,17 @@ impl FormatRunner {
                 ),
             );
         };
+        // Write mode outputs a summary like Prettier, unless `--quiet` is specified
+        let print_write_summary = |stdout| {
+            if matches!(format_mode, OutputMode::Write) && !runtime_options.quiet {
+                utils::print_and_flush(
+                    stdout,
+                    &format!(
+                        "{written_count} files formatted, {unchanged_count} unchanged, {error_count} errored.\n",
+                    ),
+                );
+            }
+        };
 
         // Check if no files were found
         if total_target_files_count == 0 {
@@ -216,6 +252,7 @@ impl FormatRunner {
         }
 
         if 0 < error_count {
+            print_write_summary(stdout);
             // Each error is already printed in reporter
             utils::print_and_flush(
                 stderr,
@@ -245,13 +282,14 @@ impl FormatRunner {
                 print_stats(stdout);
                 CliRunResult::FormatMismatch
             }
-            // Default (write) does not output anything
+            // Default (write) outputs only the summary
             (OutputMode::Write, changed_count) => {
-                // Each changed file is also NOT printed
+                // Each changed file is NOT printed
                 debug_assert_eq!(
                     changed_count, 0,
                     "In write mode, changed_count should not be counted"
                 );
+                print_write_summary(stdout);
                 CliRunResult::FormatSucceeded
             }
         }
@@ -0,0 +1,146 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use rustc_hash::FxHashMap;
+
+use super::command::DiffTarget;
+
+/// An inclusive range of 1-based line numbers.
+pub type LineRange = (u32, u32);
+
+/// Changed lines of each file, keyed by the absolute path of the file.
+pub type ChangedLines = FxHashMap<PathBuf, Vec<LineRange>>;
+
+/// Run `git diff` inside `cwd`, and collect the changed lines of each file.
+///
+/// - [`DiffTarget::Staged`] compares the index with `HEAD`
+/// - [`DiffTarget::Ref`] compares the working tree with the given ref
+///
+/// Deleted files are not included.
+pub fn collect_changed_lines(cwd: &Path, target: &DiffTarget) -> Result<ChangedLines, String> {
+    let root = run_git(cwd, &["rev-parse", "--show-toplevel"])?;
+    let root = PathBuf::from(root.trim_end());
+
+    let mut args = vec!["diff", "--no-color", "--no-ext-diff", "--no-prefix", "-U0"];
+    match target {
+        DiffTarget::Staged => args.push("--cached"),
+        DiffTarget::Ref(reference) => args.extend([reference.as_str(), "--"]),
+    }
+    let diff = run_git(cwd, &args)?;
+
+    Ok(parse_diff(&diff)
+        .into_iter()
+        .map(|(path, ranges)| (normalize_path(&root.join(path)), ranges))
+        .collect())
+}
+
+fn run_git(cwd: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .map_err(|err| format!("Failed to run `git {}`: {err}", args.join(" ")))?;
+    if !output.status.success() {
+        return Err(format!(
+            "`git {}` failed:\n{}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim_end()
+        ));
+    }
+    String::from_utf8(output.stdout).map_err(|err| format!("Invalid `git` output: {err}"))
+}
+
+/// On Windows, `git rev-parse` returns `/`-separated paths, while the walker returns `\`-separated ones.
+fn normalize_path(path: &Path) -> PathBuf {
+    path.components().collect()
+}
+
+/// Parse the output of `git diff --no-prefix -U0` into the changed lines of each file.
+///
+/// Paths are relative to the root of the repository.
+/// A hunk which only deletes lines marks the lines around the deletion as changed.
+fn parse_diff(diff: &str) -> FxHashMap<PathBuf, Vec<LineRange>> {
+    let mut changed_lines = FxHashMap::<PathBuf, Vec<LineRange>>::default();
+    let mut current_file: Option<PathBuf> = None;
+
+    for line in diff.lines() {
+        if let Some(path) = line.strip_prefix("+++ ") {
+            current_file = (path != "/dev/null").then(|| PathBuf::from(path));
+            continue;
+        }
+        let (Some(hunk), Some(file)) = (line.strip_prefix("@@ "), &current_file) else {
+            continue;
+        };
+        // `@@ -old_start[,old_count] +new_start[,new_count] @@ context`
+        let Some(new_range) = hunk.split(' ').find_map(|part| part.strip_prefix('+')) else {
+            continue;
+        };
+        let (start, count) = match new_range.split_once(',') {
+            Some((start, count)) => (start.parse::<u32>(), count.parse::<u32>()),
+            None => (new_range.parse::<u32>(), Ok(1)),
+        };
+        let (Ok(start), Ok(count)) = (start, count) else {
+            continue;
+        };
+
+        let range = if count == 0 { (start.max(1), start + 1) } else { (start, start + count - 1) };
+        changed_lines.entry(file.clone()).or_default().push(range);
+    }
+
+    changed_lines
+}
+
+/// Read the staged content of the file at `path` from the index.
+pub fn read_staged_file(path: &Path) -> Result<String, String> {
+    let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+        return Err(format!("Invalid path: {}", path.display()));
+    };
+    run_git(dir, &["show", &format!(":./{}", file_name.to_string_lossy())])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::parse_diff;
+
+    #[test]
+    fn test_parse_diff() {
+        let diff = "\
+diff --git src/a.js src/a.js
+index 1111111..2222222 100644
+--- src/a.js
++++ src/a.js
+@@ -1 +1 @@
+-const a=1
++const a =1
+@@ -5,0 +6,2 @@ function foo() {
++  bar()
++  baz()
+@@ -10,3 +11,0 @@ function foo() {
+-removed()
+-removed()
+-removed()
+diff --git old.js old.js
+deleted file mode 100644
+--- old.js
++++ /dev/null
+@@ -1 +0,0 @@
+-gone()
+diff --git new.ts new.ts
+new file mode 100644
+--- /dev/null
++++ new.ts
+@@ -0,0 +1,3 @@
++let x
++let y
++let z
+";
+        let changed_lines = parse_diff(diff);
+        assert_eq!(changed_lines.len(), 2);
+        assert_eq!(changed_lines[&PathBuf::from("src/a.js")], vec![(1, 1), (6, 7), (11, 12)]);
+        assert_eq!(changed_lines[&PathBuf::from("new.ts")], vec![(1, 3)]);
+    }
+}
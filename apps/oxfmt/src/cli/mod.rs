@@ -1,5 +1,6 @@
 pub(crate) mod command;
 mod format;
+mod git_diff;
 mod init;
 mod reporter;
 mod result;
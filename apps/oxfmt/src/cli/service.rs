@@ -5,11 +5,18 @@ use rayon::prelude::*;
 
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService};
 
-use super::command::OutputMode;
-use crate::core::{ConfigResolver, FormatFileStrategy, FormatResult, SourceFormatter, utils};
+use super::{
+    command::OutputMode,
+    git_diff::{ChangedLines, read_staged_file},
+};
+use crate::core::{
+    ConfigResolver, DirectoryOptionsCache, FormatFileStrategy, FormatResult, SourceFormatter, utils,
+};
 
 pub enum SuccessResult {
     Changed(String),
+    /// The file was changed and written back in write mode
+    Written,
     Unchanged,
 }
 
@@ -18,6 +25,13 @@ pub struct FormatService {
     format_mode: OutputMode,
     formatter: SourceFormatter,
     config_resolver: ConfigResolver,
+    options_cache: DirectoryOptionsCache,
+    /// If set, only these lines are formatted, files without changed lines are skipped
+    changed_lines: Option<ChangedLines>,
+    /// If set, the staged content of each file is formatted instead of the working tree
+    staged: bool,
+    /// If set, the resolved options of each file are written next to it
+    debug_dump: bool,
 }
 
 impl FormatService {
@@ -30,7 +44,34 @@ impl FormatService {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), format_mode, formatter, config_resolver }
+        Self {
+            cwd: cwd.into(),
+            format_mode,
+            formatter,
+            config_resolver,
+            options_cache: DirectoryOptionsCache::default(),
+            changed_lines: None,
+            staged: false,
+            debug_dump: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_changed_lines(mut self, changed_lines: Option<ChangedLines>) -> Self {
+        self.changed_lines = changed_lines;
+        self
+    }
+
+    #[must_use]
+    pub fn with_staged(mut self, staged: bool) -> Self {
+        self.staged = staged;
+        self
+    }
+
+    #[must_use]
+    pub fn with_debug_dump(mut self, debug_dump: bool) -> Self {
+        self.debug_dump = debug_dump;
+        self
     }
 
     /// Process entries as they are received from the channel
@@ -44,6 +85,14 @@ impl FormatService {
             let start_time = matches!(self.format_mode, OutputMode::Check).then(Instant::now);
 
             let path = entry.path();
+            let changed_lines = self.changed_lines.as_ref().map(|lines| lines.get(path));
+            // Files without changed lines are kept as is
+            if changed_lines == Some(None) {
+                tx_success.send(SuccessResult::Unchanged).unwrap();
+                return;
+            }
+            let changed_lines = changed_lines.flatten().map(Vec::as_slice);
+
             let Ok(source_text) = utils::read_to_string(path) else {
                 // This happens if binary file is attempted to be formatted
                 // e.g. `.ts` for MPEG-TS video file
@@ -63,23 +112,81 @@ impl FormatService {
                 return;
             };
 
-            // Resolve options for this specific file entry
-            let resolved_options = self.config_resolver.resolve(&entry);
-
-            let (code, is_changed) =
-                match self.formatter.format(&entry, &source_text, resolved_options) {
-                    FormatResult::Success { code, is_changed } => (code, is_changed),
-                    FormatResult::Error(diagnostics) => {
-                        let errors = DiagnosticService::wrap_diagnostics(
+            // The changed lines of the index are formatted, not the ones of the working tree.
+            // Writing the result back would drop the unstaged changes, so partially staged files are skipped.
+            let source_text = if self.staged {
+                let staged_text = match read_staged_file(path) {
+                    Ok(staged_text) => staged_text,
+                    Err(err) => {
+                        let diagnostics = DiagnosticService::wrap_diagnostics(
                             self.cwd.clone(),
                             path,
-                            &source_text,
-                            diagnostics,
+                            "",
+                            vec![oxc_diagnostics::OxcDiagnostic::error(format!(
+                                "Failed to read the staged file: {}\n{err}",
+                                path.display()
+                            ))],
                         );
-                        tx_error.send(errors).unwrap();
+                        tx_error.send(diagnostics).unwrap();
                         return;
                     }
                 };
+                if matches!(self.format_mode, OutputMode::Write) && staged_text != source_text {
+                    let diagnostics = DiagnosticService::wrap_diagnostics(
+                        self.cwd.clone(),
+                        path,
+                        "",
+                        vec![
+                            oxc_diagnostics::OxcDiagnostic::error(format!(
+                                "Skipped partially staged file: {}",
+                                path.display()
+                            ))
+                            .with_help("Stage or stash the unstaged changes of the file first."),
+                        ],
+                    );
+                    tx_error.send(diagnostics).unwrap();
+                    return;
+                }
+                staged_text
+            } else {
+                source_text
+            };
+
+            // Resolve options for this specific file entry
+            let resolved_options = self.config_resolver.resolve_cached(&entry, &self.options_cache);
+
+            if self.debug_dump {
+                let mut dump_path = path.as_os_str().to_owned();
+                dump_path.push(".oxfmt-debug.json");
+                let config = self.config_resolver.resolve_format_config(entry.path());
+                let dump = resolved_options.to_debug_dump(&entry, &config);
+                fs::write(&dump_path, format!("{dump:#}\n"))
+                    .map_err(|_| format!("Failed to write to '{}'", dump_path.to_string_lossy()))
+                    .unwrap();
+            }
+
+            let result = match changed_lines {
+                Some(lines) => self.formatter.format_changed_lines(
+                    &entry,
+                    &source_text,
+                    resolved_options,
+                    lines,
+                ),
+                None => self.formatter.format(&entry, &source_text, resolved_options),
+            };
+            let (code, is_changed) = match result {
+                FormatResult::Success { code, is_changed } => (code, is_changed),
+                FormatResult::Error(diagnostics) => {
+                    let errors = DiagnosticService::wrap_diagnostics(
+                        self.cwd.clone(),
+                        path,
+                        &source_text,
+                        diagnostics,
+                    );
+                    tx_error.send(errors).unwrap();
+                    return;
+                }
+            };
 
             // Write back if needed
             if matches!(self.format_mode, OutputMode::Write) && is_changed {
@@ -107,6 +214,7 @@ impl FormatService {
                         SuccessResult::Changed(display_path)
                     }
                 }
+                (OutputMode::Write, true) => SuccessResult::Written,
                 _ => SuccessResult::Unchanged,
             };
             tx_success.send(result).unwrap();
@@ -1,14 +1,18 @@
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
 
 use editorconfig_parser::{
     EditorConfig, EditorConfigProperties, EditorConfigProperty, EndOfLine, IndentStyle,
     MaxLineLength,
 };
 use fast_glob::glob_match;
+use rustc_hash::FxHashMap;
 use serde_json::Value;
 use tracing::instrument;
 
-use oxc_formatter::FormatOptions;
+use oxc_formatter::{FormatOptions, LineEnding};
 use oxc_toml::Options as TomlFormatterOptions;
 
 use super::{
@@ -36,10 +40,30 @@ pub fn resolve_oxfmtrc_path(cwd: &Path, config_path: Option<&Path>) -> Option<Pa
                 return Some(config_path);
             }
         }
+        // Like Prettier, fall back to the `oxfmt` key of `package.json` in the same directory
+        let package_json_path = dir.join("package.json");
+        if has_package_json_config(&package_json_path) {
+            return Some(package_json_path);
+        }
         None
     })
 }
 
+/// The key in `package.json` which holds the config.
+const PACKAGE_JSON_CONFIG_KEY: &str = "oxfmt";
+
+fn is_package_json(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == "package.json")
+}
+
+fn has_package_json_config(path: &Path) -> bool {
+    let Ok(json_string) = utils::read_to_string(path) else {
+        return false;
+    };
+    serde_json::from_str::<Value>(&json_string)
+        .is_ok_and(|package_json| package_json.get(PACKAGE_JSON_CONFIG_KEY).is_some())
+}
+
 pub fn resolve_editorconfig_path(cwd: &Path) -> Option<PathBuf> {
     // Search the nearest `.editorconfig` from cwd upwards
     cwd.ancestors().map(|dir| dir.join(".editorconfig")).find(|p| p.exists())
@@ -84,6 +108,8 @@ pub enum ResolvedOptions {
     },
     /// For TOML files.
     OxfmtToml { toml_options: TomlFormatterOptions, insert_final_newline: bool },
+    /// For JSON Lines files.
+    Jsonl { line_ending: LineEnding, insert_final_newline: bool },
     /// For non-JS files formatted by external formatter (Prettier).
     #[cfg(feature = "napi")]
     ExternalFormatter { external_options: Value, insert_final_newline: bool },
@@ -118,6 +144,10 @@ impl ResolvedOptions {
             FormatFileStrategy::OxfmtToml { .. } => {
                 ResolvedOptions::OxfmtToml { toml_options, insert_final_newline }
             }
+            FormatFileStrategy::Jsonl { .. } => ResolvedOptions::Jsonl {
+                line_ending: format_options.line_ending,
+                insert_final_newline,
+            },
             #[cfg(feature = "napi")]
             FormatFileStrategy::ExternalFormatter { .. } => {
                 ResolvedOptions::ExternalFormatter { external_options, insert_final_newline }
@@ -138,9 +168,61 @@ impl ResolvedOptions {
     }
 }
 
+impl ResolvedOptions {
+    /// Dump the options resolved for `entry` as JSON, to be attached to bug reports.
+    ///
+    /// The dump contains the `oxfmt` version, the path and source type of the file,
+    /// the `config` of the file (see [`ConfigResolver::resolve_format_config`])
+    /// which can be pasted back as a `.oxfmtrc.json`, and the options passed to the formatters.
+    pub fn to_debug_dump(&self, entry: &FormatFileStrategy, config: &FormatConfig) -> Value {
+        let mut dump = serde_json::json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "path": entry.path().to_string_lossy(),
+            "config": config,
+        });
+
+        if let FormatFileStrategy::OxcFormatter { source_type, .. } = entry {
+            dump["sourceType"] = Value::from(format!("{source_type:?}"));
+        }
+
+        match self {
+            Self::OxcFormatter { external_options, insert_final_newline, .. } => {
+                dump["externalOptions"] = external_options.clone();
+                dump["insertFinalNewline"] = Value::from(*insert_final_newline);
+            }
+            Self::OxfmtToml { toml_options, insert_final_newline } => {
+                dump["tomlOptions"] = Value::from(format!("{toml_options:?}"));
+                dump["insertFinalNewline"] = Value::from(*insert_final_newline);
+            }
+            Self::Jsonl { line_ending, insert_final_newline } => {
+                dump["lineEnding"] = Value::from(line_ending.to_string());
+                dump["insertFinalNewline"] = Value::from(*insert_final_newline);
+            }
+            #[cfg(feature = "napi")]
+            Self::ExternalFormatter { external_options, insert_final_newline } => {
+                dump["externalOptions"] = external_options.clone();
+                dump["insertFinalNewline"] = Value::from(*insert_final_newline);
+            }
+            #[cfg(feature = "napi")]
+            Self::ExternalFormatterPackageJson {
+                external_options,
+                sort_package_json,
+                insert_final_newline,
+            } => {
+                dump["externalOptions"] = external_options.clone();
+                dump["sortPackageJson"] = Value::from(format!("{sort_package_json:?}"));
+                dump["insertFinalNewline"] = Value::from(*insert_final_newline);
+            }
+        }
+
+        dump
+    }
+}
+
 // ---
 
 /// Configuration resolver to handle `.oxfmtrc` and `.editorconfig` files.
+/// The `oxfmt` key of a `package.json` is handled the same as a `.oxfmtrc` file.
 ///
 /// Priority order: `Oxfmtrc::default()` → user's `.oxfmtrc` base → `.oxfmtrc` overrides
 /// `.editorconfig` is applied as fallback for unset fields only.
@@ -161,6 +243,9 @@ pub struct ConfigResolver {
     oxfmtrc_overrides: Option<OxfmtrcOverrides>,
     /// Parsed `.editorconfig`, if any.
     editorconfig: Option<EditorConfig>,
+    /// Whether per-file overrides may differ between files in the same directory.
+    /// If so, [`DirectoryOptionsCache`] cannot be used.
+    file_name_dependent: bool,
 }
 
 impl ConfigResolver {
@@ -192,8 +277,26 @@ impl ConfigResolver {
         };
 
         // Parse as raw JSON value
-        let raw_config: Value = serde_json::from_str(&json_string)
+        let mut raw_config: Value = serde_json::from_str(&json_string)
             .map_err(|err| format!("Failed to parse config: {err}"))?;
+        // `package.json` holds the config under its `oxfmt` key
+        if let Some(path) = oxfmtrc_path.filter(|path| is_package_json(path)) {
+            raw_config = match raw_config.get_mut(PACKAGE_JSON_CONFIG_KEY).map(Value::take) {
+                Some(config @ Value::Object(_)) => config,
+                Some(_) => {
+                    return Err(format!(
+                        "Failed to parse config: `{PACKAGE_JSON_CONFIG_KEY}` in {} must be an object",
+                        path.display()
+                    ));
+                }
+                None => {
+                    return Err(format!(
+                        "Failed to parse config: No `{PACKAGE_JSON_CONFIG_KEY}` key found in {}",
+                        path.display()
+                    ));
+                }
+            };
+        }
         // Store the config directory for override path resolution
         let config_dir = oxfmtrc_path.and_then(|p| p.parent().map(Path::to_path_buf));
 
@@ -215,6 +318,7 @@ impl ConfigResolver {
             cached_options: None,
             oxfmtrc_overrides: None,
             editorconfig,
+            file_name_dependent: true,
         })
     }
 
@@ -264,6 +368,12 @@ impl ConfigResolver {
         // Save cache for fast path: no per-file overrides
         self.cached_options = Some((oxfmt_options, external_options));
 
+        self.file_name_dependent =
+            self.oxfmtrc_overrides.as_ref().is_some_and(OxfmtrcOverrides::is_file_name_dependent)
+                || self.editorconfig.as_ref().is_some_and(|ec| {
+                    ec.sections().iter().any(|section| !is_directory_glob(&section.name))
+                });
+
         let ignore_patterns = oxfmtrc.ignore_patterns.unwrap_or_default();
         Ok(ignore_patterns)
     }
@@ -275,6 +385,26 @@ impl ConfigResolver {
         ResolvedOptions::from_oxfmt_options(oxfmt_options, external_options, strategy)
     }
 
+    /// Resolve format options for a specific file,
+    /// reusing the options resolved for another file in the same directory if possible.
+    ///
+    /// When an override pattern depends on the file name (e.g. `*.test.ts`),
+    /// the cache is bypassed and this behaves like [`ConfigResolver::resolve`].
+    pub fn resolve_cached(
+        &self,
+        strategy: &FormatFileStrategy,
+        cache: &DirectoryOptionsCache,
+    ) -> ResolvedOptions {
+        let path = strategy.path();
+        let Some(dir) = path.parent().filter(|_| !self.file_name_dependent) else {
+            return self.resolve(strategy);
+        };
+
+        let (oxfmt_options, external_options) =
+            cache.get_or_insert_with(dir, || self.resolve_options(path));
+        ResolvedOptions::from_oxfmt_options(oxfmt_options, external_options, strategy)
+    }
+
     /// Resolve options for a specific file path.
     /// Priority: oxfmtrc base → oxfmtrc overrides → editorconfig (fallback for unset fields) -> defaults
     fn resolve_options(&self, path: &Path) -> (OxfmtOptions, Value) {
@@ -293,6 +423,24 @@ impl ConfigResolver {
         }
 
         // Slow path: reconstruct `FormatConfig` to apply overrides
+        let format_config = self.resolve_format_config(path);
+
+        // NOTE: See `build_and_validate()` for details about `external_options` handling
+        let mut external_options = serde_json::to_value(&format_config)
+            .expect("FormatConfig serialization should not fail");
+
+        let oxfmt_options = format_config
+            .into_oxfmt_options()
+            .expect("If this fails, there is an issue with override values");
+
+        populate_prettier_config(&oxfmt_options.format_options, &mut external_options);
+
+        (oxfmt_options, external_options)
+    }
+
+    /// Resolve the `FormatConfig` for a specific file path, before defaults are applied.
+    /// Priority: oxfmtrc base → oxfmtrc overrides → editorconfig (fallback for unset fields)
+    pub fn resolve_format_config(&self, path: &Path) -> FormatConfig {
         // Overrides are merged at `FormatConfig` level, not `OxfmtOptions` level
         let mut format_config: FormatConfig = serde_json::from_value(self.raw_config.clone())
             .expect("`build_and_validate()` should catch this before");
@@ -309,17 +457,38 @@ impl ConfigResolver {
             apply_editorconfig(&mut format_config, &props);
         }
 
-        // NOTE: See `build_and_validate()` for details about `external_options` handling
-        let mut external_options = serde_json::to_value(&format_config)
-            .expect("FormatConfig serialization should not fail");
+        format_config
+    }
+}
 
-        let oxfmt_options = format_config
-            .into_oxfmt_options()
-            .expect("If this fails, there is an issue with override values");
+// ---
 
-        populate_prettier_config(&oxfmt_options.format_options, &mut external_options);
+/// Directory-keyed cache of the options resolved by [`ConfigResolver::resolve_cached`].
+///
+/// Formatting many files resolves the same per-directory overrides over and over,
+/// this cache makes every directory resolved only once.
+#[derive(Debug, Default)]
+pub struct DirectoryOptionsCache {
+    entries: Mutex<FxHashMap<PathBuf, (OxfmtOptions, Value)>>,
+}
 
-        (oxfmt_options, external_options)
+impl DirectoryOptionsCache {
+    /// Get the cached options of `dir`, or insert the result of `resolve`.
+    ///
+    /// The lock is not held during `resolve`,
+    /// so concurrent calls for the same uncached directory may resolve more than once.
+    fn get_or_insert_with(
+        &self,
+        dir: &Path,
+        resolve: impl FnOnce() -> (OxfmtOptions, Value),
+    ) -> (OxfmtOptions, Value) {
+        if let Some(options) = self.entries.lock().unwrap().get(dir) {
+            return options.clone();
+        }
+
+        let options = resolve();
+        self.entries.lock().unwrap().insert(dir.to_path_buf(), options.clone());
+        options
     }
 }
 
@@ -384,6 +553,14 @@ impl OxfmtrcOverrides {
             .into_owned()
     }
 
+    /// Check if any pattern can match only some of the files in a directory.
+    fn is_file_name_dependent(&self) -> bool {
+        self.entries
+            .iter()
+            .flat_map(|e| e.files.iter().chain(&e.exclude_files))
+            .any(|glob| !is_directory_glob(glob))
+    }
+
     fn is_entry_match(entry: &OxfmtrcOverrideEntry, relative: &str) -> bool {
         entry.files.iter().any(|glob| glob_match(glob, relative))
             && !entry.exclude_files.iter().any(|glob| glob_match(glob, relative))
@@ -399,6 +576,12 @@ struct OxfmtrcOverrideEntry {
     options: FormatConfig,
 }
 
+/// Check if a glob pattern matches either all or none of the files in a directory,
+/// i.e. its last segment is `*` or `**`.
+fn is_directory_glob(glob: &str) -> bool {
+    matches!(glob.rsplit('/').next(), Some("*" | "**"))
+}
+
 // ---
 
 /// Check if `.editorconfig` has per-file overrides for this path.
@@ -488,3 +671,198 @@ fn apply_editorconfig(config: &mut FormatConfig, props: &EditorConfigProperties)
         config.insert_final_newline = Some(v);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use oxc_formatter::QuoteStyle;
+    use serde_json::json;
+
+    use super::{
+        ConfigResolver, DirectoryOptionsCache, ResolvedOptions, is_directory_glob,
+        resolve_oxfmtrc_path,
+    };
+    use crate::core::FormatFileStrategy;
+
+    fn create_resolver(raw_config: serde_json::Value) -> ConfigResolver {
+        let mut resolver = ConfigResolver {
+            raw_config,
+            config_dir: Some(PathBuf::from("/project")),
+            cached_options: None,
+            oxfmtrc_overrides: None,
+            editorconfig: None,
+            file_name_dependent: true,
+        };
+        resolver.build_and_validate().unwrap();
+        resolver
+    }
+
+    fn strategy(path: &str) -> FormatFileStrategy {
+        FormatFileStrategy::try_from(PathBuf::from(path)).unwrap()
+    }
+
+    #[test]
+    fn test_is_directory_glob() {
+        assert!(is_directory_glob("*"));
+        assert!(is_directory_glob("**/*"));
+        assert!(is_directory_glob("src/**"));
+        assert!(!is_directory_glob("**/*.ts"));
+        assert!(!is_directory_glob("src/index.js"));
+    }
+
+    #[test]
+    fn test_resolve_cached_once_per_directory() {
+        let resolver = create_resolver(json!({
+            "overrides": [{ "files": ["src/legacy/**"], "options": { "semi": false } }]
+        }));
+        assert!(!resolver.file_name_dependent);
+
+        let cache = DirectoryOptionsCache::default();
+        for path in [
+            "/project/src/legacy/a.js",
+            "/project/src/legacy/b.ts",
+            "/project/src/main.js",
+            "/project/src/legacy/c.js",
+        ] {
+            resolver.resolve_cached(&strategy(path), &cache);
+        }
+        assert_eq!(cache.entries.lock().unwrap().len(), 2);
+
+        let ResolvedOptions::OxcFormatter { format_options, .. } =
+            resolver.resolve_cached(&strategy("/project/src/legacy/d.js"), &cache)
+        else {
+            unreachable!()
+        };
+        assert!(format_options.semicolons.is_as_needed());
+    }
+
+    #[test]
+    fn test_directory_options_cache() {
+        let resolver = create_resolver(json!({}));
+        let cache = DirectoryOptionsCache::default();
+
+        let mut resolve_count = 0;
+        for dir in ["/project/a", "/project/b", "/project/a", "/project/a"] {
+            cache.get_or_insert_with(Path::new(dir), || {
+                resolve_count += 1;
+                resolver.resolve_options(&Path::new(dir).join("index.js"))
+            });
+        }
+        assert_eq!(resolve_count, 2);
+    }
+
+    #[test]
+    fn test_resolve_cached_file_name_dependent() {
+        let resolver = create_resolver(json!({
+            "overrides": [{ "files": ["*.test.js"], "options": { "semi": false } }]
+        }));
+        assert!(resolver.file_name_dependent);
+
+        let cache = DirectoryOptionsCache::default();
+        resolver.resolve_cached(&strategy("/project/src/a.test.js"), &cache);
+        resolver.resolve_cached(&strategy("/project/src/a.js"), &cache);
+        assert!(cache.entries.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_overrides_exclude_files() {
+        let resolver = create_resolver(json!({
+            "semi": true,
+            "overrides": [{
+                "files": ["*.ts"],
+                "excludeFiles": ["*.test.ts"],
+                "options": { "semi": false }
+            }]
+        }));
+
+        let resolve = |path| {
+            let ResolvedOptions::OxcFormatter { format_options, .. } =
+                resolver.resolve(&strategy(path))
+            else {
+                unreachable!()
+            };
+            format_options.semicolons
+        };
+        assert!(resolve("/project/src/index.ts").is_as_needed());
+        // Excluded files fall back to the base options
+        assert!(resolve("/project/src/index.test.ts").is_always());
+        assert!(resolve("/project/index.test.ts").is_always());
+    }
+
+    fn package_json_fixtures_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("test/cli/config_package_json/fixtures")
+    }
+
+    #[test]
+    fn test_resolve_package_json_config() {
+        let dir = package_json_fixtures_dir();
+        // `without_key/package.json` has no `oxfmt` key, so the parent one is used
+        for cwd in [dir.clone(), dir.join("without_key")] {
+            let config_path = resolve_oxfmtrc_path(&cwd, None);
+            assert_eq!(config_path, Some(dir.join("package.json")));
+
+            let mut resolver =
+                ConfigResolver::from_config_paths(&cwd, config_path.as_deref(), None).unwrap();
+            resolver.build_and_validate().unwrap();
+
+            let ResolvedOptions::OxcFormatter { format_options, .. } =
+                resolver.resolve(&strategy(cwd.join("test.js").to_str().unwrap()))
+            else {
+                unreachable!()
+            };
+            assert!(format_options.semicolons.is_as_needed());
+            assert_eq!(format_options.quote_style, QuoteStyle::Single);
+        }
+    }
+
+    #[test]
+    fn test_resolve_oxfmtrc_before_package_json() {
+        let cwd = package_json_fixtures_dir().join("with_oxfmtrc");
+        assert_eq!(resolve_oxfmtrc_path(&cwd, None), Some(cwd.join(".oxfmtrc.json")));
+    }
+
+    #[test]
+    fn test_package_json_without_config_key() {
+        let path = package_json_fixtures_dir().join("without_key/package.json");
+        let err = ConfigResolver::from_config_paths(Path::new("."), Some(&path), None).unwrap_err();
+        assert!(err.contains("No `oxfmt` key found"));
+    }
+
+    #[test]
+    fn test_debug_dump() {
+        let resolver = create_resolver(json!({ "semi": false, "printWidth": 120 }));
+        let entry = strategy("/project/src/index.ts");
+
+        let config = resolver.resolve_format_config(entry.path());
+        let dump = resolver.resolve(&entry).to_debug_dump(&entry, &config);
+        assert_eq!(dump["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(dump["path"], "/project/src/index.ts");
+        assert!(dump["sourceType"].as_str().unwrap().contains("TypeScript"));
+        assert_eq!(dump["config"], json!({ "semi": false, "printWidth": 120 }));
+        assert_eq!(dump["externalOptions"]["printWidth"], 120);
+        assert_eq!(dump["insertFinalNewline"], true);
+
+        // The config of the dump can be used as a config again
+        let pasted = create_resolver(dump["config"].clone());
+        let ResolvedOptions::OxcFormatter { format_options, .. } = pasted.resolve(&entry) else {
+            unreachable!()
+        };
+        assert!(format_options.semicolons.is_as_needed());
+        assert_eq!(format_options.line_width.value(), 120);
+    }
+
+    #[test]
+    fn test_debug_dump_of_override() {
+        let resolver = create_resolver(json!({
+            "semi": false,
+            "overrides": [{ "files": ["*.ts"], "options": { "singleQuote": true } }]
+        }));
+        let entry = strategy("/project/src/index.ts");
+
+        // The config of the file includes the options of the matching overrides
+        let config = resolver.resolve_format_config(entry.path());
+        let dump = resolver.resolve(&entry).to_debug_dump(&entry, &config);
+        assert_eq!(dump["config"], json!({ "semi": false, "singleQuote": true }));
+    }
+}
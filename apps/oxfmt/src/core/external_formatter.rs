@@ -263,13 +263,20 @@ impl ExternalFormatter {
 
 /// Mapping from `oxc_formatter` language identifiers to Prettier `parser` names.
 /// This is the single source of truth for supported embedded languages.
-fn language_to_prettier_parser(language: &str) -> Option<&'static str> {
+///
+/// Parser names are passed through as-is,
+/// they are set by `ExternalCallbacks::with_embedded_language_mapping()` in place of the built-in mapping.
+fn language_to_prettier_parser(language: &str) -> Option<&str> {
     match language {
+        "css" | "scss" | "less" | "graphql" | "html" | "markdown" | "mdx" | "sql" | "angular" => {
+            Some(language)
+        }
         // TODO: "tagged-css" should use `scss` parser to support quasis
         "tagged-css" | "styled-jsx" => Some("css"),
         "tagged-graphql" => Some("graphql"),
         "tagged-html" => Some("html"),
         "tagged-markdown" => Some("markdown"),
+        "tagged-sql" => Some("sql"),
         "angular-template" => Some("angular"),
         "angular-styles" => Some("scss"),
         _ => None,
@@ -434,3 +441,16 @@ fn wrap_sort_tailwind_classes(
         )
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::language_to_prettier_parser;
+
+    #[test]
+    fn test_mapped_parser_names_are_passed_through() {
+        assert_eq!(language_to_prettier_parser("tagged-css"), Some("css"));
+        assert_eq!(language_to_prettier_parser("scss"), Some("scss"));
+        assert_eq!(language_to_prettier_parser("less"), Some("less"));
+        assert_eq!(language_to_prettier_parser("unknown"), None);
+    }
+}
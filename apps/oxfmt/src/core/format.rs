@@ -1,15 +1,18 @@
 #[cfg(feature = "napi")]
 use std::borrow::Cow;
-use std::path::Path;
+use std::{ops::Range, path::Path};
 
+use serde::de::IgnoredAny;
 use serde_json::Value;
 use tracing::instrument;
 
 use oxc_allocator::AllocatorPool;
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_formatter::{FormatOptions, Formatter, enable_jsx_source_type, get_parse_options};
+use oxc_formatter::{
+    FormatOptions, Formatter, LineEnding, enable_jsx_source_type, get_parse_options_with,
+};
 use oxc_parser::Parser;
-use oxc_span::SourceType;
+use oxc_span::{GetSpan, SourceType, Span};
 
 use super::{FormatFileStrategy, ResolvedOptions};
 
@@ -44,6 +47,11 @@ impl SourceFormatter {
     }
 
     /// Format a file based on its entry type and resolved options.
+    ///
+    /// Existing line breaks are normalized to the configured line ending as well,
+    /// except the ones which are part of a string value (e.g. TOML multiline strings).
+    ///
+    /// Empty or whitespace-only files are formatted to an empty string, regardless of `insert_final_newline`.
     #[instrument(level = "debug", name = "oxfmt::format", skip_all, fields(path = %entry.path().display()))]
     pub fn format(
         &self,
@@ -51,6 +59,15 @@ impl SourceFormatter {
         source_text: &str,
         resolved_options: ResolvedOptions,
     ) -> FormatResult {
+        // Every formatter prints nothing for empty or whitespace-only files,
+        // there is no need to parse them (or call the external formatter).
+        if source_text.trim().is_empty() {
+            return FormatResult::Success {
+                is_changed: !source_text.is_empty(),
+                code: String::new(),
+            };
+        }
+
         let (result, insert_final_newline) = match (entry, resolved_options) {
             (
                 FormatFileStrategy::OxcFormatter { path, source_type },
@@ -73,6 +90,10 @@ impl SourceFormatter {
                 FormatFileStrategy::OxfmtToml { .. },
                 ResolvedOptions::OxfmtToml { toml_options, insert_final_newline },
             ) => (Ok(Self::format_by_toml(source_text, toml_options)), insert_final_newline),
+            (
+                FormatFileStrategy::Jsonl { path },
+                ResolvedOptions::Jsonl { line_ending, insert_final_newline },
+            ) => (Self::format_jsonl(source_text, path, line_ending), insert_final_newline),
             #[cfg(feature = "napi")]
             (
                 FormatFileStrategy::ExternalFormatter { path, parser_name },
@@ -119,6 +140,63 @@ impl SourceFormatter {
         }
     }
 
+    /// Format only the top-level statements of a JS/TS file touching one of `changed_lines`,
+    /// which are 1-based inclusive line ranges. The rest of the file is kept as is.
+    ///
+    /// Other files can not be formatted partially, so they are formatted as a whole.
+    #[instrument(level = "debug", name = "oxfmt::format_changed_lines", skip_all, fields(path = %entry.path().display()))]
+    pub fn format_changed_lines(
+        &self,
+        entry: &FormatFileStrategy,
+        source_text: &str,
+        resolved_options: ResolvedOptions,
+        changed_lines: &[(u32, u32)],
+    ) -> FormatResult {
+        let (
+            FormatFileStrategy::OxcFormatter { path, source_type },
+            ResolvedOptions::OxcFormatter { format_options, external_options, .. },
+        ) = (entry, &resolved_options)
+        else {
+            return self.format(entry, source_text, resolved_options);
+        };
+
+        let ranges = {
+            let allocator = self.allocator_pool.get();
+            let ret = Parser::new(&allocator, source_text, enable_jsx_source_type(*source_type))
+                .with_options(get_parse_options_with(format_options))
+                .parse();
+            if !ret.errors.is_empty() {
+                // Report the errors in the same way as formatting the whole file
+                return self.format(entry, source_text, resolved_options);
+            }
+            let statements = ret.program.body.iter().map(GetSpan::span).collect::<Vec<_>>();
+            let comments =
+                ret.program.comments.iter().map(|comment| comment.span).collect::<Vec<_>>();
+            changed_statement_ranges(source_text, &statements, &comments, changed_lines)
+        };
+
+        let mut code = String::with_capacity(source_text.len());
+        let mut last_end = 0;
+        for range in ranges {
+            let formatted = match self.format_by_oxc_formatter(
+                &source_text[range.clone()],
+                path,
+                *source_type,
+                (**format_options).clone(),
+                external_options.clone(),
+            ) {
+                Ok(formatted) => formatted,
+                Err(err) => return FormatResult::Error(vec![err]),
+            };
+            code.push_str(&source_text[last_end..range.start]);
+            code.push_str(formatted.trim_end());
+            last_end = range.end;
+        }
+        code.push_str(&source_text[last_end..]);
+
+        FormatResult::Success { is_changed: source_text != code, code }
+    }
+
     /// Format JS/TS source code using oxc_formatter.
     #[instrument(level = "debug", name = "oxfmt::format::oxc_formatter", skip_all)]
     fn format_by_oxc_formatter(
@@ -133,7 +211,7 @@ impl SourceFormatter {
         let allocator = self.allocator_pool.get();
 
         let ret = Parser::new(&allocator, source_text, source_type)
-            .with_options(get_parse_options())
+            .with_options(get_parse_options_with(&format_options))
             .parse();
         if !ret.errors.is_empty() {
             // Return the first error for simplicity
@@ -185,6 +263,42 @@ impl SourceFormatter {
         oxc_toml::format(source_text, options)
     }
 
+    /// Format JSON Lines file by compacting each line into a single-line JSON value.
+    /// Only whitespace outside of strings is removed, so numbers and duplicate keys are kept as written.
+    /// Blank lines are removed.
+    #[instrument(level = "debug", name = "oxfmt::format::jsonl", skip_all)]
+    fn format_jsonl(
+        source_text: &str,
+        path: &Path,
+        line_ending: LineEnding,
+    ) -> Result<String, OxcDiagnostic> {
+        let line_ending = match line_ending {
+            LineEnding::Lf => "\n",
+            LineEnding::Crlf => "\r\n",
+            LineEnding::Cr => "\r",
+        };
+
+        let mut code = String::with_capacity(source_text.len());
+        for (index, line) in source_text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            serde_json::from_str::<IgnoredAny>(line).map_err(|err| {
+                OxcDiagnostic::error(format!(
+                    "Failed to parse line {} of JSON Lines file: {}\n{err}",
+                    index + 1,
+                    path.display()
+                ))
+            })?;
+            push_compact_json(&mut code, line);
+            code.push_str(line_ending);
+        }
+
+        Ok(code)
+    }
+
     /// Format non-JS/TS file using external formatter (Prettier).
     #[cfg(feature = "napi")]
     #[expect(clippy::needless_pass_by_value)]
@@ -252,3 +366,309 @@ impl SourceFormatter {
         self.format_by_external_formatter(&source_text, path, parser_name, external_options)
     }
 }
+
+/// Returns the byte ranges of the top-level `statements` touching one of `changed_lines`,
+/// each of them can be formatted as a program on its own.
+///
+/// A range starts at the first non-blank line after the previous statement, to include the leading comments.
+/// Statements which can not be separated from the previous one, because they are on the same line
+/// or a comment starts on that line, are always in the same range.
+fn changed_statement_ranges(
+    source_text: &str,
+    statements: &[Span],
+    comments: &[Span],
+    changed_lines: &[(u32, u32)],
+) -> Vec<Range<usize>> {
+    let line_starts = std::iter::once(0)
+        .chain(source_text.match_indices('\n').map(|(index, _)| index + 1))
+        .collect::<Vec<_>>();
+    // 1-based line of the offset
+    #[expect(clippy::cast_possible_truncation)]
+    let line_of = |offset: usize| line_starts.partition_point(|&start| start <= offset) as u32;
+    let is_changed = |span: Span| {
+        let (first, last) = (line_of(span.start as usize), line_of(span.end as usize - 1));
+        changed_lines.iter().any(|&(start, end)| start <= last && first <= end)
+    };
+
+    // The start of the range of each statement, `None` if it is joined with the previous statement
+    let mut starts = Vec::with_capacity(statements.len());
+    let mut previous_end = 0;
+    for (index, span) in statements.iter().enumerate() {
+        let gap = &source_text[previous_end..span.start as usize];
+        // The rest of the line of the previous statement, e.g. a trailing comment, is kept with it
+        let gap_start = if index == 0 {
+            Some(0)
+        } else {
+            gap.find('\n').map(|newline| previous_end + newline + 1)
+        };
+        let start = gap_start.map(|gap_start| {
+            let content_start = source_text[gap_start..span.start as usize]
+                .find(|c: char| !c.is_whitespace())
+                .map_or(span.start as usize, |offset| gap_start + offset);
+            source_text[gap_start..content_start]
+                .rfind('\n')
+                .map_or(gap_start, |newline| gap_start + newline + 1)
+        });
+        let start = start.filter(|&start| {
+            !comments
+                .iter()
+                .any(|comment| (comment.start as usize) < start && start < comment.end as usize)
+        });
+        starts.push(start);
+        previous_end = span.end as usize;
+    }
+
+    // Joined statements are changed together
+    let mut changed = statements.iter().map(|span| is_changed(*span)).collect::<Vec<_>>();
+    for index in (1..statements.len()).rev() {
+        if starts[index].is_none() && changed[index] {
+            changed[index - 1] = true;
+        }
+    }
+    for index in 1..statements.len() {
+        if starts[index].is_none() && changed[index - 1] {
+            changed[index] = true;
+        }
+    }
+
+    let mut ranges: Vec<Range<usize>> = vec![];
+    for (index, span) in statements.iter().enumerate() {
+        if !changed[index] {
+            continue;
+        }
+        if index > 0 && changed[index - 1] {
+            let range = ranges.last_mut().expect("the previous statement is in a range");
+            range.end = span.end as usize;
+        } else {
+            let start = starts[index].expect("only joined statements have no start");
+            ranges.push(start..span.end as usize);
+        }
+    }
+    ranges
+}
+
+/// Push `json` to `code` without the whitespace outside of strings.
+/// `json` must be a valid JSON value.
+fn push_compact_json(code: &mut String, json: &str) {
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in json.chars() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if matches!(c, ' ' | '\t' | '\n' | '\r') {
+            continue;
+        }
+        code.push(c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use serde_json::Value;
+
+    use oxc_formatter::{FormatOptions, LineEnding};
+    use oxc_span::SourceType;
+
+    use super::{FormatResult, SourceFormatter};
+    use crate::core::{FormatFileStrategy, ResolvedOptions};
+
+    fn create_formatter() -> SourceFormatter {
+        let formatter = SourceFormatter::new(1);
+        #[cfg(feature = "napi")]
+        let formatter =
+            formatter.with_external_formatter(Some(crate::core::ExternalFormatter::dummy()));
+        formatter
+    }
+
+    fn format_code(
+        entry: &FormatFileStrategy,
+        source_text: &str,
+        options: ResolvedOptions,
+    ) -> String {
+        match create_formatter().format(entry, source_text, options) {
+            FormatResult::Success { code, .. } => code,
+            FormatResult::Error(errors) => panic!("Failed to format: {errors:?}"),
+        }
+    }
+
+    #[test]
+    fn test_normalize_mixed_line_endings_js() {
+        let entry = FormatFileStrategy::OxcFormatter {
+            path: PathBuf::from("test.js"),
+            source_type: SourceType::mjs(),
+        };
+        let options = ResolvedOptions::OxcFormatter {
+            format_options: Box::new(FormatOptions {
+                line_ending: LineEnding::Lf,
+                ..FormatOptions::default()
+            }),
+            external_options: Value::Null,
+            insert_final_newline: true,
+        };
+
+        let source_text = "const a = 1;\r\nconst b = \"\\r\\n\";\nconst c = `\\r\\n`;\r\n";
+        assert_eq!(
+            format_code(&entry, source_text, options),
+            "const a = 1;\nconst b = \"\\r\\n\";\nconst c = `\\r\\n`;\n"
+        );
+    }
+
+    #[test]
+    fn test_format_empty_and_whitespace_only() {
+        let entry = FormatFileStrategy::OxcFormatter {
+            path: PathBuf::from("test.js"),
+            source_type: SourceType::mjs(),
+        };
+        let options = || ResolvedOptions::OxcFormatter {
+            format_options: Box::new(FormatOptions::default()),
+            external_options: Value::Null,
+            insert_final_newline: true,
+        };
+
+        let FormatResult::Success { is_changed, code } =
+            create_formatter().format(&entry, "", options())
+        else {
+            panic!("Expected success");
+        };
+        assert!(!is_changed);
+        assert_eq!(code, "");
+
+        let FormatResult::Success { is_changed, code } =
+            create_formatter().format(&entry, " \n\t\r\n\n", options())
+        else {
+            panic!("Expected success");
+        };
+        assert!(is_changed);
+        assert_eq!(code, "");
+
+        // Comments are not whitespace, the file is still parsed and printed
+        assert_eq!(format_code(&entry, "\n  // comment\n\n", options()), "// comment\n");
+        let result = create_formatter().format(&entry, "  /* unterminated ", options());
+        assert!(matches!(result, FormatResult::Error(_)));
+    }
+
+    #[test]
+    fn test_format_changed_lines() {
+        let entry = FormatFileStrategy::OxcFormatter {
+            path: PathBuf::from("test.js"),
+            source_type: SourceType::mjs(),
+        };
+        let format_changed_lines = |source_text: &str, changed_lines: &[(u32, u32)]| {
+            let options = ResolvedOptions::OxcFormatter {
+                format_options: Box::new(FormatOptions::default()),
+                external_options: Value::Null,
+                insert_final_newline: true,
+            };
+            match create_formatter().format_changed_lines(
+                &entry,
+                source_text,
+                options,
+                changed_lines,
+            ) {
+                FormatResult::Success { code, .. } => code,
+                FormatResult::Error(errors) => panic!("Failed to format: {errors:?}"),
+            }
+        };
+
+        let source_text = "const a=1\nconst b=2\n\n// c\nconst c=3 // d\n";
+        assert_eq!(
+            format_changed_lines(source_text, &[(2, 2)]),
+            "const a=1\nconst b = 2;\n\n// c\nconst c=3 // d\n"
+        );
+        // Leading comments are formatted with the statement, trailing ones are kept as is
+        assert_eq!(
+            format_changed_lines(source_text, &[(5, 5)]),
+            "const a=1\nconst b=2\n\n// c\nconst c = 3; // d\n"
+        );
+        assert_eq!(format_changed_lines(source_text, &[]), source_text);
+
+        // The whole enclosing statement is formatted
+        let source_text = "function f(){\nif(a){\nreturn 1\n}\n}\nconst x=1\n";
+        assert_eq!(
+            format_changed_lines(source_text, &[(3, 3)]),
+            "function f() {\n  if (a) {\n    return 1;\n  }\n}\nconst x=1\n"
+        );
+
+        // Statements on the same line, or separated by a comment starting on the line, are formatted together
+        let source_text = "a();b()\nc() /* x\ny */ d()\ne()\n";
+        assert_eq!(
+            format_changed_lines(source_text, &[(1, 1)]),
+            "a();\nb();\nc() /* x\ny */ d()\ne()\n"
+        );
+        assert_eq!(
+            format_changed_lines(source_text, &[(4, 4)]),
+            "a();b()\nc() /* x\ny */ d()\ne();\n"
+        );
+        assert_eq!(
+            format_changed_lines(source_text, &[(3, 3)]),
+            "a();b()\nc();\n/* x\ny */ d();\ne()\n"
+        );
+    }
+
+    #[test]
+    fn test_format_jsonl() {
+        let entry = FormatFileStrategy::Jsonl { path: PathBuf::from("test.jsonl") };
+        let options =
+            ResolvedOptions::Jsonl { line_ending: LineEnding::Lf, insert_final_newline: true };
+
+        // Each line is normalized on its own, blank lines are skipped
+        let source_text =
+            "{ \"a\": 1,  \"b\": [1, 2] }\r\n\n  [ true, null ]\n{\"z\":\"x y\", \"a\" : {} }";
+        assert_eq!(
+            format_code(&entry, source_text, options),
+            "{\"a\":1,\"b\":[1,2]}\n[true,null]\n{\"z\":\"x y\",\"a\":{}}\n"
+        );
+    }
+
+    #[test]
+    fn test_format_jsonl_invalid_line() {
+        let entry = FormatFileStrategy::Jsonl { path: PathBuf::from("test.jsonl") };
+        let options =
+            ResolvedOptions::Jsonl { line_ending: LineEnding::Lf, insert_final_newline: true };
+
+        let result = create_formatter().format(&entry, "{}\n{\"a\": 1,\n", options);
+        let FormatResult::Error(errors) = result else { panic!("Expected an error") };
+        assert!(errors[0].to_string().contains("Failed to parse line 2"));
+    }
+
+    #[test]
+    fn test_format_jsonl_keeps_values() {
+        let entry = FormatFileStrategy::Jsonl { path: PathBuf::from("test.jsonl") };
+        let options =
+            ResolvedOptions::Jsonl { line_ending: LineEnding::Lf, insert_final_newline: true };
+
+        // Numbers are kept as written, and duplicate keys are not collapsed
+        let source_text =
+            "{ \"a\": 1e2, \"b\": 12345678901234567890 }\n{ \"a\": 1, \"a\": \"x \\\" y\" }\n";
+        assert_eq!(
+            format_code(&entry, source_text, options),
+            "{\"a\":1e2,\"b\":12345678901234567890}\n{\"a\":1,\"a\":\"x \\\" y\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_normalize_mixed_line_endings_toml() {
+        let entry = FormatFileStrategy::OxfmtToml { path: PathBuf::from("test.toml") };
+        let options = ResolvedOptions::OxfmtToml {
+            toml_options: oxc_toml::Options { crlf: false, ..oxc_toml::Options::default() },
+            insert_final_newline: true,
+        };
+
+        // Line breaks inside multiline strings are part of the value and must be preserved
+        let source_text = "a = 1\r\nb = \"\"\"x\r\ny\"\"\"\n[c]\r\nd = 2\n";
+        assert_eq!(
+            format_code(&entry, source_text, options),
+            "a = 1\nb = \"\"\"x\r\ny\"\"\"\n[c]\nd = 2\n"
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use rustc_hash::FxHashMap;
+use sha1::{Digest, Sha1};
+
+/// Key of a [`FormatCache`] entry, a SHA-1 digest of everything which affects the formatted code.
+pub type FormatCacheKey = [u8; 20];
+
+/// In-process LRU cache of formatted code, used by the NAPI API.
+///
+/// JS build tools tend to re-format the same unchanged files on every watch cycle,
+/// so the formatted code is kept keyed by a hash of everything which affects the result.
+/// The cache is disabled until it is turned on with [`FormatCache::set_enabled`].
+#[derive(Debug)]
+pub struct FormatCache {
+    enabled: bool,
+    capacity: usize,
+    /// Formatted code and the tick it was last used at, by key.
+    entries: FxHashMap<FormatCacheKey, (String, u64)>,
+    tick: u64,
+    hits: u32,
+    misses: u32,
+}
+
+impl FormatCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            enabled: false,
+            capacity,
+            entries: FxHashMap::default(),
+            tick: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Turn the cache on or off, turning it off also drops the cached code.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.clear();
+        }
+    }
+
+    /// Compute the cache key for formatting `source_text` as the content of `filename` with `options`.
+    ///
+    /// The file name determines the format strategy (and the source type for JS/TS files),
+    /// `options` is the serialized user options before they are resolved.
+    /// The version of oxfmt is part of the key, so the formatted code of another version is never reused.
+    pub fn key(filename: &str, source_text: &str, options: &str) -> FormatCacheKey {
+        let mut hasher = Sha1::new();
+        for part in [env!("CARGO_PKG_VERSION"), filename, source_text, options] {
+            // Prefix each part with its length, so that the boundaries between them are unambiguous
+            hasher.update((part.len() as u64).to_le_bytes());
+            hasher.update(part.as_bytes());
+        }
+        hasher.finalize().into()
+    }
+
+    pub fn get(&mut self, key: &FormatCacheKey) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+        self.tick += 1;
+        let Some((code, last_used)) = self.entries.get_mut(key) else {
+            self.misses += 1;
+            return None;
+        };
+        *last_used = self.tick;
+        self.hits += 1;
+        Some(code.clone())
+    }
+
+    pub fn insert(&mut self, key: FormatCacheKey, code: String) {
+        if !self.enabled || self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            // Evict the least recently used entry
+            if let Some(oldest) =
+                self.entries.iter().min_by_key(|(_, (_, last_used))| *last_used).map(|(k, _)| *k)
+            {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.tick += 1;
+        self.entries.insert(key, (code, self.tick));
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// Number of lookups served from the cache since it was created or last cleared.
+    pub fn hits(&self) -> u32 {
+        self.hits
+    }
+
+    /// Number of lookups not found in the cache since it was created or last cleared.
+    pub fn misses(&self) -> u32 {
+        self.misses
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FormatCache;
+
+    #[test]
+    fn test_repeated_lookup_is_a_hit() {
+        let mut cache = FormatCache::new(8);
+        cache.set_enabled(true);
+        let key = FormatCache::key("a.ts", "const x=1", "{}");
+
+        assert_eq!(cache.get(&key), None);
+        cache.insert(key, "const x = 1;\n".to_string());
+        assert_eq!(cache.get(&key).as_deref(), Some("const x = 1;\n"));
+        assert_eq!(cache.get(&key).as_deref(), Some("const x = 1;\n"));
+        assert_eq!((cache.hits(), cache.misses()), (2, 1));
+
+        // Any change of the inputs is a different entry
+        assert_eq!(cache.get(&FormatCache::key("a.js", "const x=1", "{}")), None);
+        assert_eq!(cache.get(&FormatCache::key("a.ts", "const x=2", "{}")), None);
+        assert_eq!(cache.get(&FormatCache::key("a.ts", "const x=1", r#"{"semi":false}"#)), None);
+        // The boundaries between the inputs are part of the key
+        assert_eq!(cache.get(&FormatCache::key("a.t", "sconst x=1", "{}")), None);
+        assert_eq!((cache.hits(), cache.misses()), (2, 5));
+
+        cache.clear();
+        assert_eq!(cache.get(&key), None);
+        assert_eq!((cache.hits(), cache.misses()), (0, 1));
+    }
+
+    #[test]
+    fn test_key_is_stable() {
+        assert_eq!(
+            FormatCache::key("a.ts", "const x=1", "{}"),
+            FormatCache::key("a.ts", "const x=1", "{}")
+        );
+        assert_ne!(
+            FormatCache::key("a.ts", "const x=1", "{}"),
+            FormatCache::key("a.ts", "const x=1", "{ }")
+        );
+    }
+
+    #[test]
+    fn test_disabled_cache() {
+        let mut cache = FormatCache::new(8);
+        let key = FormatCache::key("a.ts", "const x=1", "{}");
+
+        cache.insert(key, "const x = 1;\n".to_string());
+        assert_eq!(cache.get(&key), None);
+        assert_eq!((cache.hits(), cache.misses()), (0, 0));
+
+        cache.set_enabled(true);
+        cache.insert(key, "const x = 1;\n".to_string());
+        assert!(cache.get(&key).is_some());
+
+        // Turning the cache off drops the cached code
+        cache.set_enabled(false);
+        cache.set_enabled(true);
+        assert_eq!(cache.get(&key), None);
+    }
+
+    #[test]
+    fn test_evict_least_recently_used() {
+        let mut cache = FormatCache::new(2);
+        cache.set_enabled(true);
+        let [a, b, c] = ["a", "b", "c"].map(|code| FormatCache::key("a.ts", code, "{}"));
+        cache.insert(a, "a".to_string());
+        cache.insert(b, "b".to_string());
+        // `a` is now more recently used than `b`
+        assert!(cache.get(&a).is_some());
+        cache.insert(c, "c".to_string());
+
+        assert!(cache.get(&b).is_none());
+        assert!(cache.get(&a).is_some());
+        assert!(cache.get(&c).is_some());
+    }
+}
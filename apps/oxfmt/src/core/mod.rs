@@ -6,11 +6,16 @@ pub mod utils;
 
 #[cfg(feature = "napi")]
 mod external_formatter;
+#[cfg(feature = "napi")]
+mod format_cache;
+#[cfg(feature = "napi")]
+mod text_edit;
 
 #[cfg(feature = "napi")]
 pub use config::resolve_options_from_value;
 pub use config::{
-    ConfigResolver, ResolvedOptions, resolve_editorconfig_path, resolve_oxfmtrc_path,
+    ConfigResolver, DirectoryOptionsCache, ResolvedOptions, resolve_editorconfig_path,
+    resolve_oxfmtrc_path,
 };
 pub use format::{FormatResult, SourceFormatter};
 pub use support::FormatFileStrategy;
@@ -20,3 +25,7 @@ pub use external_formatter::{
     ExternalFormatter, JsFormatEmbeddedCb, JsFormatFileCb, JsInitExternalFormatterCb,
     JsSortTailwindClassesCb,
 };
+#[cfg(feature = "napi")]
+pub use format_cache::{FormatCache, FormatCacheKey};
+#[cfg(feature = "napi")]
+pub use text_edit::compute_text_edits;
@@ -3,10 +3,13 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use oxc_formatter::{
-    ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing, CustomGroupDefinition,
-    EmbeddedLanguageFormatting, Expand, FormatOptions, IndentStyle, IndentWidth, LineEnding,
-    LineWidth, QuoteProperties, QuoteStyle, Semicolons, SortImportsOptions, SortOrder,
-    TailwindcssOptions, TrailingCommas,
+    AnonymousFunctionSpace, ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing,
+    CustomGroupDefinition, EmbeddedLanguageFormatting, EmptyBody, EmptyBracketSpacing, Expand,
+    ExpandArraysOfObjects, FormatOptions, HugSoleArgument, IndentStyle, IndentUnionTypes,
+    IndentWidth, JsxAttributeQuotes, JsxTextWrap, LineEnding, LineWidth, MethodChainBreakThreshold,
+    NumericLiterals, QuoteProperties, QuoteStyle, Semicolons, SortImportsOptions, SortObjectKeys,
+    SortOrder, StringConcatenationIndent, SwitchCaseBodyIndent, TailwindcssOptions,
+    TernaryChainIndent, TrailingCommas, TypeOperatorPosition,
 };
 use oxc_toml::Options as TomlFormatterOptions;
 
@@ -51,6 +54,30 @@ pub struct OxfmtOverrideConfig {
 
 // ---
 
+/// Prettier options understood by Oxfmt, as they are written in the config.
+///
+/// These are the fields of [`FormatConfig`], except for our own extensions.
+pub const PRETTIER_OPTIONS: &[&str] = &[
+    "useTabs",
+    "tabWidth",
+    "endOfLine",
+    "printWidth",
+    "singleQuote",
+    "jsxSingleQuote",
+    "quoteProps",
+    "trailingComma",
+    "semi",
+    "arrowParens",
+    "bracketSpacing",
+    "bracketSameLine",
+    "objectWrap",
+    "singleAttributePerLine",
+    "embeddedLanguageFormatting",
+    "proseWrap",
+    "htmlWhitespaceSensitivity",
+    "vueIndentScriptAndStyle",
+];
+
 // NOTE: All fields are typed as `Option` to distinguish between user-specified values and defaults.
 #[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase", default)]
@@ -113,9 +140,11 @@ pub struct FormatConfig {
     pub trailing_comma: Option<TrailingCommaConfig>,
     /// Print semicolons at the ends of statements.
     ///
+    /// `"hybrid"` omits them like `false`, but keeps them after class properties.
+    ///
     /// - Default: `true`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub semi: Option<bool>,
+    pub semi: Option<SemiConfig>,
     /// Include parentheses around a sole arrow function parameter.
     ///
     /// - Default: `"always"`
@@ -197,6 +226,234 @@ pub struct FormatConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub insert_final_newline: Option<bool>,
 
+    /// Tags of template literals to format as SQL, e.g. `["sql"]`.
+    ///
+    /// SQL is formatted by Prettier, so a plugin providing the `sql` parser must be configured,
+    /// e.g. [prettier-plugin-sql](https://github.com/un-ts/prettier/tree/master/packages/sql).
+    /// Interpolations are kept as written.
+    ///
+    /// - Default: `[]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedded_sql_tags: Option<Vec<String>>,
+
+    /// How far the statements of a `switch` case are indented relative to the case.
+    ///
+    /// - `"single"`: One level, same as Prettier.
+    /// - `"double"`: Two levels. A block statement hugging the case is not affected.
+    ///
+    /// - Default: `"single"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_case_body_indent: Option<SwitchCaseBodyIndentConfig>,
+
+    /// The number of calls a member chain can have before it is always printed on multiple lines,
+    /// if any of the calls has a complex argument, like a function.
+    ///
+    /// - Default: `2`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method_chain_break_threshold: Option<u8>,
+
+    /// Print a space inside the brackets of empty object literals, object types and arrays: `{ }` and `[ ]`.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub empty_bracket_spacing: Option<bool>,
+
+    /// Align the values of object literal properties in multi-line objects.
+    ///
+    /// Keys are padded to the longest key of their group, where groups are separated by blank lines.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align_object_properties: Option<bool>,
+
+    /// Re-indent the content of embedded language templates (e.g. `` css`...` ``, `` graphql`...` ``)
+    /// relative to the indentation of the template, if the content starts on a new line
+    /// and isn't formatted by the embedded formatter.
+    ///
+    /// NOTE: This changes the content of the string.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reindent_embedded_templates: Option<bool>,
+
+    /// Always print a blank line between consecutive class members.
+    ///
+    /// An existing blank line is kept as is, the first member of a class body isn't affected.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blank_line_between_class_members: Option<bool>,
+
+    /// Whether arrays of objects (or arrays of arrays) are printed with one element per line,
+    /// regardless of whether they fit on a single line.
+    ///
+    /// - `"auto"`: Same as Prettier, only if all elements are objects with at least two properties,
+    ///   or arrays with at least two elements.
+    /// - `"always"`: If all elements are objects, or all elements are arrays.
+    /// - `"never"`: Only if the array doesn't fit on a single line.
+    ///
+    /// - Default: `"auto"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expand_arrays_of_objects: Option<ExpandArraysOfObjectsConfig>,
+
+    /// Sort the properties of object literals by their keys.
+    ///
+    /// Only objects whose keys are all statically known are sorted, objects with spread elements,
+    /// computed keys or comments are left as is.
+    /// NOTE: Sorting changes the evaluation order of the property values.
+    ///
+    /// - Default: `"none"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_object_keys: Option<SortObjectKeysConfig>,
+
+    /// Where the `|` and `&` operators of broken union and intersection types are printed.
+    ///
+    /// - `"auto"`: Same as Prettier, `|` at the start and `&` at the end of lines.
+    /// - `"start"`: At the start of lines.
+    /// - `"end"`: At the end of lines.
+    ///
+    /// - Default: `"auto"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_operator_position: Option<TypeOperatorPositionConfig>,
+
+    /// Indent the members of a broken union type.
+    ///
+    /// Unions which are already indented by their parent, e.g. in tuples or type arguments, aren't affected.
+    ///
+    /// - Default: `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_union_types: Option<bool>,
+
+    /// Hug the sole object or array argument of a call, e.g. `foo({` with the properties on the next lines.
+    ///
+    /// When `false`, the argument is moved to its own line, if the call doesn't fit on a single line.
+    /// Function arguments are always hugged.
+    ///
+    /// - Default: `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hug_sole_argument: Option<bool>,
+
+    /// Align the `=` of enum members with an initializer.
+    ///
+    /// Members are padded to the longest name of their group, where groups are separated by blank lines.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align_enum_members: Option<bool>,
+
+    /// Print an `else` block which only contains an `if` statement as `else if`.
+    ///
+    /// `} else { if (a) {} }` becomes `} else if (a) {}`, blocks containing comments are kept.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collapse_else_if: Option<bool>,
+
+    /// Experimental: Parse and format the [pipeline operator](https://github.com/tc39/proposal-pipeline-operator) `x |> f`.
+    ///
+    /// Only the minimal proposal is supported, files using it fail to parse when disabled.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experimental_pipeline_operator: Option<bool>,
+
+    /// Print a conditional whose JSX branches are single-line elements, like `<A />` or `<b>text</b>`,
+    /// like any other conditional, instead of wrapping the branches in parentheses.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compact_jsx_conditionals: Option<bool>,
+
+    /// How the quotes of JSX attribute values are chosen.
+    ///
+    /// - `"as-needed"`: Use the quote of `jsxSingleQuote`, unless the value contains more of it than of the other quote,
+    ///   so that the fewest quotes need to be escaped. Same as Prettier.
+    /// - `"consistent"`: Always use the quote of `jsxSingleQuote`, escaping quotes in the value as HTML entities.
+    ///
+    /// - Default: `"as-needed"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jsx_attribute_quotes: Option<JsxAttributeQuotesConfig>,
+
+    /// Whether the braces of empty function and class bodies stay on the same line.
+    ///
+    /// - `"collapse"`: `function f() {}`, same as Prettier.
+    /// - `"expand"`: The closing brace is printed on its own line. Bodies containing comments are not affected.
+    ///
+    /// - Default: `"collapse"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub empty_body: Option<EmptyBodyConfig>,
+
+    /// How the continuation operands of a broken string concatenation are indented.
+    ///
+    /// - `"indent"`: Indent them, unless the parent already does. Same as Prettier.
+    /// - `"align"`: Align them under the first operand.
+    ///
+    /// - Default: `"indent"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub string_concatenation_indent: Option<StringConcatenationIndentConfig>,
+
+    /// How JSX text is wrapped.
+    ///
+    /// - `"reflow"`: Fill the words up to the print width, ignoring the line breaks of the input. Same as Prettier.
+    /// - `"preserve"`: Keep the line breaks between the words, and don't insert new ones.
+    ///
+    /// - Default: `"reflow"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jsx_text_wrap: Option<JsxTextWrapConfig>,
+
+    /// Keep the first comment of the file as written, e.g. for license headers.
+    ///
+    /// A leading `/**` or `/*!` comment which mentions a copyright or license is always kept as written.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_first_comment: Option<bool>,
+
+    /// Whether numeric literals are normalized.
+    ///
+    /// - `"normalize"`: Print them in lowercase, with a leading zero before the decimal point
+    ///   and without redundant zeros, dots and exponent signs (`0XFF` to `0xff`, `.5` to `0.5`). Same as Prettier.
+    /// - `"preserve"`: Print them as written.
+    ///
+    /// - Default: `"normalize"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numeric_literals: Option<NumericLiteralsConfig>,
+
+    /// Keep a blank line directly after the `{` and before the `}` of blocks and function bodies.
+    ///
+    /// Multiple blank lines are collapsed into one. When `false`, they are removed like Prettier.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_block_blank_lines: Option<bool>,
+
+    /// How the branches of a conditional in the alternate of another conditional are indented.
+    ///
+    /// - `"indent"`: Indent each conditional of a chain one level more than its parent. Same as Prettier.
+    /// - `"flat"`: Align all conditionals of a chain with the root conditional.
+    ///
+    /// Independent of `experimentalTernaries`.
+    ///
+    /// - Default: `"indent"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ternary_chain_indent: Option<TernaryChainIndentConfig>,
+
+    /// Print a space after the `function` keyword (or the `*` of generators) of anonymous functions,
+    /// e.g. `function () {}` or `function* () {}`.
+    ///
+    /// Named functions are always printed like `function name() {}`.
+    ///
+    /// - Default: `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anonymous_function_space: Option<bool>,
+
+    /// Lowercase the hex digits of `\x` and `\u` escape sequences in strings (`"\xFF"` to `"\xff"`),
+    /// like the hex digits of numeric literals.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalize_escape_sequences: Option<bool>,
+
     /// Experimental: Sort import statements.
     ///
     /// Using the similar algorithm as [eslint-plugin-perfectionist/sort-imports](https://perfectionist.dev/rules/sort-imports).
@@ -317,9 +574,13 @@ impl FormatConfig {
         }
 
         // [Prettier] semi: boolean
+        // [Oxfmt] semi: "hybrid"
         if let Some(semi) = self.semi {
-            format_options.semicolons =
-                if semi { Semicolons::Always } else { Semicolons::AsNeeded };
+            format_options.semicolons = match semi {
+                SemiConfig::Bool(true) => Semicolons::Always,
+                SemiConfig::Bool(false) => Semicolons::AsNeeded,
+                SemiConfig::Mode(SemiModeConfig::Hybrid) => Semicolons::Hybrid,
+            };
         }
 
         // [Prettier] arrowParens: "avoid" | "always"
@@ -367,6 +628,144 @@ impl FormatConfig {
 
         // Below are our own extensions
 
+        if let Some(tags) = self.embedded_sql_tags {
+            format_options.embedded_sql_tags = tags;
+        }
+
+        if let Some(indent) = self.switch_case_body_indent {
+            format_options.switch_case_body_indent = match indent {
+                SwitchCaseBodyIndentConfig::Single => SwitchCaseBodyIndent::Single,
+                SwitchCaseBodyIndentConfig::Double => SwitchCaseBodyIndent::Double,
+            };
+        }
+
+        if let Some(threshold) = self.method_chain_break_threshold {
+            format_options.method_chain_break_threshold =
+                MethodChainBreakThreshold::from(threshold);
+        }
+
+        if let Some(spacing) = self.empty_bracket_spacing {
+            format_options.empty_bracket_spacing = EmptyBracketSpacing::from(spacing);
+        }
+
+        if let Some(align) = self.align_object_properties {
+            format_options.align_object_properties = align;
+        }
+
+        if let Some(reindent) = self.reindent_embedded_templates {
+            format_options.reindent_embedded_templates = reindent;
+        }
+
+        if let Some(blank_line) = self.blank_line_between_class_members {
+            format_options.blank_line_between_class_members = blank_line;
+        }
+
+        if let Some(expand) = self.expand_arrays_of_objects {
+            format_options.expand_arrays_of_objects = match expand {
+                ExpandArraysOfObjectsConfig::Auto => ExpandArraysOfObjects::Auto,
+                ExpandArraysOfObjectsConfig::Always => ExpandArraysOfObjects::Always,
+                ExpandArraysOfObjectsConfig::Never => ExpandArraysOfObjects::Never,
+            };
+        }
+
+        if let Some(sort) = self.sort_object_keys {
+            format_options.sort_object_keys = match sort {
+                SortObjectKeysConfig::None => SortObjectKeys::None,
+                SortObjectKeysConfig::Asc => SortObjectKeys::Asc,
+                SortObjectKeysConfig::Desc => SortObjectKeys::Desc,
+            };
+        }
+
+        if let Some(position) = self.type_operator_position {
+            format_options.type_operator_position = match position {
+                TypeOperatorPositionConfig::Auto => TypeOperatorPosition::Auto,
+                TypeOperatorPositionConfig::Start => TypeOperatorPosition::Start,
+                TypeOperatorPositionConfig::End => TypeOperatorPosition::End,
+            };
+        }
+
+        if let Some(indent) = self.indent_union_types {
+            format_options.indent_union_types = IndentUnionTypes::from(indent);
+        }
+
+        if let Some(hug) = self.hug_sole_argument {
+            format_options.hug_sole_argument = HugSoleArgument::from(hug);
+        }
+
+        if let Some(align) = self.align_enum_members {
+            format_options.align_enum_members = align;
+        }
+
+        if let Some(collapse) = self.collapse_else_if {
+            format_options.collapse_else_if = collapse;
+        }
+
+        if let Some(pipeline) = self.experimental_pipeline_operator {
+            format_options.experimental_pipeline_operator = pipeline;
+        }
+
+        if let Some(compact) = self.compact_jsx_conditionals {
+            format_options.compact_jsx_conditionals = compact;
+        }
+
+        if let Some(quotes) = self.jsx_attribute_quotes {
+            format_options.jsx_attribute_quotes = match quotes {
+                JsxAttributeQuotesConfig::AsNeeded => JsxAttributeQuotes::AsNeeded,
+                JsxAttributeQuotesConfig::Consistent => JsxAttributeQuotes::Consistent,
+            };
+        }
+
+        if let Some(body) = self.empty_body {
+            format_options.empty_body = match body {
+                EmptyBodyConfig::Collapse => EmptyBody::Collapse,
+                EmptyBodyConfig::Expand => EmptyBody::Expand,
+            };
+        }
+
+        if let Some(indent) = self.string_concatenation_indent {
+            format_options.string_concatenation_indent = match indent {
+                StringConcatenationIndentConfig::Indent => StringConcatenationIndent::Indent,
+                StringConcatenationIndentConfig::Align => StringConcatenationIndent::Align,
+            };
+        }
+
+        if let Some(wrap) = self.jsx_text_wrap {
+            format_options.jsx_text_wrap = match wrap {
+                JsxTextWrapConfig::Reflow => JsxTextWrap::Reflow,
+                JsxTextWrapConfig::Preserve => JsxTextWrap::Preserve,
+            };
+        }
+
+        if let Some(preserve) = self.preserve_first_comment {
+            format_options.preserve_first_comment = preserve;
+        }
+
+        if let Some(literals) = self.numeric_literals {
+            format_options.numeric_literals = match literals {
+                NumericLiteralsConfig::Normalize => NumericLiterals::Normalize,
+                NumericLiteralsConfig::Preserve => NumericLiterals::Preserve,
+            };
+        }
+
+        if let Some(preserve) = self.preserve_block_blank_lines {
+            format_options.preserve_block_blank_lines = preserve;
+        }
+
+        if let Some(indent) = self.ternary_chain_indent {
+            format_options.ternary_chain_indent = match indent {
+                TernaryChainIndentConfig::Indent => TernaryChainIndent::Indent,
+                TernaryChainIndentConfig::Flat => TernaryChainIndent::Flat,
+            };
+        }
+
+        if let Some(space) = self.anonymous_function_space {
+            format_options.anonymous_function_space = AnonymousFunctionSpace::from(space);
+        }
+
+        if let Some(normalize) = self.normalize_escape_sequences {
+            format_options.normalize_escape_sequences = normalize;
+        }
+
         if let Some(config) = self.experimental_sort_imports {
             let mut sort_imports = SortImportsOptions::default();
 
@@ -379,6 +778,9 @@ impl FormatConfig {
             if let Some(v) = config.sort_side_effects {
                 sort_imports.sort_side_effects = v;
             }
+            if let Some(v) = config.partition_by_side_effect {
+                sort_imports.partition_by_side_effect = v;
+            }
             if let Some(v) = config.order {
                 sort_imports.order = match v {
                     SortOrderConfig::Asc => SortOrder::Asc,
@@ -391,6 +793,9 @@ impl FormatConfig {
             if let Some(v) = config.newlines_between {
                 sort_imports.newlines_between = v;
             }
+            if let Some(v) = config.preserve_newlines_between {
+                sort_imports.preserve_newlines_between = v;
+            }
             if let Some(v) = config.internal_pattern {
                 sort_imports.internal_pattern = v;
             }
@@ -486,6 +891,19 @@ pub enum TrailingCommaConfig {
     None,
 }
 
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SemiConfig {
+    Bool(bool),
+    Mode(SemiModeConfig),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SemiModeConfig {
+    Hybrid,
+}
+
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum ArrowParensConfig {
@@ -507,6 +925,79 @@ pub enum EmbeddedLanguageFormattingConfig {
     Off,
 }
 
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SwitchCaseBodyIndentConfig {
+    Single,
+    Double,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExpandArraysOfObjectsConfig {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortObjectKeysConfig {
+    None,
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TypeOperatorPositionConfig {
+    Auto,
+    Start,
+    End,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum JsxAttributeQuotesConfig {
+    AsNeeded,
+    Consistent,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum EmptyBodyConfig {
+    Collapse,
+    Expand,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum StringConcatenationIndentConfig {
+    Indent,
+    Align,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum JsxTextWrapConfig {
+    Reflow,
+    Preserve,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum NumericLiteralsConfig {
+    Normalize,
+    Preserve,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TernaryChainIndentConfig {
+    Indent,
+    Flat,
+}
+
 // ---
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
@@ -566,6 +1057,23 @@ pub struct SortImportsConfig {
     /// - Default: `false`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_side_effects: Option<bool>,
+    /// Enables the use of side-effect imports to separate imports into logical groups.
+    ///
+    /// When `true`, side-effect imports always stay where they are,
+    /// and no other import is moved across them.
+    /// This matters when a side-effect import must run before or after specific imports.
+    ///
+    /// ```js
+    /// import { b } from 'b'
+    /// import './polyfill'
+    /// import { a } from 'a'
+    /// ```
+    ///
+    /// Has no effect when `sortSideEffects` is `true`.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition_by_side_effect: Option<bool>,
     /// Specifies whether to sort items in ascending or descending order.
     ///
     /// - Default: `"asc"`
@@ -583,6 +1091,22 @@ pub struct SortImportsConfig {
     /// - Default: `true`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub newlines_between: Option<bool>,
+    /// Specifies whether to keep existing newlines between groups.
+    ///
+    /// When `true`, an empty line is kept before a group after sorting
+    /// if one of its imports was separated from another group by an empty line in the source.
+    ///
+    /// ```js
+    /// import { a } from 'a'
+    ///
+    /// import { b } from '~/b'
+    /// ```
+    ///
+    /// Has no effect when `newlinesBetween` is `true`.
+    ///
+    /// - Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_newlines_between: Option<bool>,
     /// Specifies a prefix for identifying internal imports.
     ///
     /// This is useful for distinguishing your own modules from external dependencies.
@@ -836,12 +1360,43 @@ pub fn populate_prettier_config(options: &FormatOptions, config: &mut Value) {
         }),
     );
 
+    // Prettier only accepts a boolean, `"hybrid"` is printed by Oxfmt
+    if obj.get("semi").is_some_and(Value::is_string) {
+        obj.insert("semi".to_string(), Value::Bool(true));
+    }
+
     // Already handled by Oxfmt
     obj.remove("overrides");
 
     // Below are our own extensions, just remove them
     obj.remove("ignorePatterns");
     obj.remove("insertFinalNewline");
+    obj.remove("embeddedSqlTags");
+    obj.remove("switchCaseBodyIndent");
+    obj.remove("methodChainBreakThreshold");
+    obj.remove("emptyBracketSpacing");
+    obj.remove("alignObjectProperties");
+    obj.remove("reindentEmbeddedTemplates");
+    obj.remove("blankLineBetweenClassMembers");
+    obj.remove("expandArraysOfObjects");
+    obj.remove("sortObjectKeys");
+    obj.remove("typeOperatorPosition");
+    obj.remove("indentUnionTypes");
+    obj.remove("hugSoleArgument");
+    obj.remove("alignEnumMembers");
+    obj.remove("collapseElseIf");
+    obj.remove("experimentalPipelineOperator");
+    obj.remove("compactJsxConditionals");
+    obj.remove("jsxAttributeQuotes");
+    obj.remove("emptyBody");
+    obj.remove("stringConcatenationIndent");
+    obj.remove("jsxTextWrap");
+    obj.remove("preserveFirstComment");
+    obj.remove("numericLiterals");
+    obj.remove("preserveBlockBlankLines");
+    obj.remove("ternaryChainIndent");
+    obj.remove("anonymousFunctionSpace");
+    obj.remove("normalizeEscapeSequences");
     obj.remove("experimentalSortImports");
     obj.remove("experimentalSortPackageJson");
 
@@ -995,6 +1550,326 @@ mod tests {
         assert_eq!(oxfmt_options.format_options.expand, Expand::Never);
     }
 
+    #[test]
+    fn test_embedded_sql_tags() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.embedded_sql_tags.is_empty());
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"embeddedSqlTags": ["sql", "query"]}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.embedded_sql_tags, ["sql", "query"]);
+    }
+
+    #[test]
+    fn test_switch_case_body_indent() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(
+            oxfmt_options.format_options.switch_case_body_indent,
+            SwitchCaseBodyIndent::Single
+        );
+
+        let json = r#"{"switchCaseBodyIndent":"double"}"#;
+        let config: FormatConfig = serde_json::from_str(json).unwrap();
+        // Our own extensions survive a round-trip, e.g. when merging overrides
+        assert_eq!(serde_json::to_string(&config).unwrap(), json);
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(
+            oxfmt_options.format_options.switch_case_body_indent,
+            SwitchCaseBodyIndent::Double
+        );
+    }
+
+    #[test]
+    fn test_method_chain_break_threshold() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.method_chain_break_threshold.value(), 2);
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"methodChainBreakThreshold": 4}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.method_chain_break_threshold.value(), 4);
+    }
+
+    #[test]
+    fn test_empty_bracket_spacing() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.empty_bracket_spacing.value());
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"emptyBracketSpacing": true}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.empty_bracket_spacing.value());
+    }
+
+    #[test]
+    fn test_align_object_properties() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.align_object_properties);
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"alignObjectProperties": true}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.align_object_properties);
+    }
+
+    #[test]
+    fn test_reindent_embedded_templates() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.reindent_embedded_templates);
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"reindentEmbeddedTemplates": true}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.reindent_embedded_templates);
+    }
+
+    #[test]
+    fn test_blank_line_between_class_members() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.blank_line_between_class_members);
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"blankLineBetweenClassMembers": true}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.blank_line_between_class_members);
+    }
+
+    #[test]
+    fn test_expand_arrays_of_objects() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(
+            oxfmt_options.format_options.expand_arrays_of_objects,
+            ExpandArraysOfObjects::Auto
+        );
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"expandArraysOfObjects": "never"}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(
+            oxfmt_options.format_options.expand_arrays_of_objects,
+            ExpandArraysOfObjects::Never
+        );
+    }
+
+    #[test]
+    fn test_sort_object_keys() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.sort_object_keys, SortObjectKeys::None);
+
+        let config: FormatConfig = serde_json::from_str(r#"{"sortObjectKeys": "desc"}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.sort_object_keys, SortObjectKeys::Desc);
+    }
+
+    #[test]
+    fn test_type_operator_options() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.type_operator_position, TypeOperatorPosition::Auto);
+        assert!(oxfmt_options.format_options.indent_union_types.value());
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"typeOperatorPosition": "end", "indentUnionTypes": false}"#)
+                .unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.type_operator_position, TypeOperatorPosition::End);
+        assert!(!oxfmt_options.format_options.indent_union_types.value());
+    }
+
+    #[test]
+    fn test_hug_sole_argument() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.hug_sole_argument.value());
+
+        let config: FormatConfig = serde_json::from_str(r#"{"hugSoleArgument": false}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.hug_sole_argument.value());
+    }
+
+    #[test]
+    fn test_align_enum_members() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.align_enum_members);
+
+        let config: FormatConfig = serde_json::from_str(r#"{"alignEnumMembers": true}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.align_enum_members);
+    }
+
+    #[test]
+    fn test_collapse_else_if() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.collapse_else_if);
+
+        let config: FormatConfig = serde_json::from_str(r#"{"collapseElseIf": true}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.collapse_else_if);
+    }
+
+    #[test]
+    fn test_experimental_pipeline_operator() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.experimental_pipeline_operator);
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"experimentalPipelineOperator": true}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.experimental_pipeline_operator);
+    }
+
+    #[test]
+    fn test_compact_jsx_conditionals() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.compact_jsx_conditionals);
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"compactJsxConditionals": true}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.compact_jsx_conditionals);
+    }
+
+    #[test]
+    fn test_jsx_attribute_quotes() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.jsx_attribute_quotes, JsxAttributeQuotes::AsNeeded);
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"jsxAttributeQuotes": "consistent"}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(
+            oxfmt_options.format_options.jsx_attribute_quotes,
+            JsxAttributeQuotes::Consistent
+        );
+    }
+
+    #[test]
+    fn test_empty_body() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.empty_body, EmptyBody::Collapse);
+
+        let config: FormatConfig = serde_json::from_str(r#"{"emptyBody": "expand"}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.empty_body, EmptyBody::Expand);
+    }
+
+    #[test]
+    fn test_string_concatenation_indent() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(
+            oxfmt_options.format_options.string_concatenation_indent,
+            StringConcatenationIndent::Indent
+        );
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"stringConcatenationIndent": "align"}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(
+            oxfmt_options.format_options.string_concatenation_indent,
+            StringConcatenationIndent::Align
+        );
+    }
+
+    #[test]
+    fn test_jsx_text_wrap() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.jsx_text_wrap, JsxTextWrap::Reflow);
+
+        let config: FormatConfig = serde_json::from_str(r#"{"jsxTextWrap": "preserve"}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.jsx_text_wrap, JsxTextWrap::Preserve);
+    }
+
+    #[test]
+    fn test_preserve_first_comment() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.preserve_first_comment);
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"preserveFirstComment": true}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.preserve_first_comment);
+    }
+
+    #[test]
+    fn test_numeric_literals() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.numeric_literals, NumericLiterals::Normalize);
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"numericLiterals": "preserve"}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.numeric_literals, NumericLiterals::Preserve);
+    }
+
+    #[test]
+    fn test_preserve_block_blank_lines() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.preserve_block_blank_lines);
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"preserveBlockBlankLines": true}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.preserve_block_blank_lines);
+    }
+
+    #[test]
+    fn test_ternary_chain_indent() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.ternary_chain_indent, TernaryChainIndent::Indent);
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"ternaryChainIndent": "flat"}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert_eq!(oxfmt_options.format_options.ternary_chain_indent, TernaryChainIndent::Flat);
+    }
+
+    #[test]
+    fn test_anonymous_function_space() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.anonymous_function_space.value());
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"anonymousFunctionSpace": false}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.anonymous_function_space.value());
+    }
+
+    #[test]
+    fn test_normalize_escape_sequences() {
+        let config: FormatConfig = serde_json::from_str("{}").unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(!oxfmt_options.format_options.normalize_escape_sequences);
+
+        let config: FormatConfig =
+            serde_json::from_str(r#"{"normalizeEscapeSequences": true}"#).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.normalize_escape_sequences);
+    }
+
     #[test]
     fn test_sort_imports_config() {
         let config: FormatConfig = serde_json::from_str(
@@ -1118,6 +1993,76 @@ mod tests_populate_prettier_config {
         assert!(!obj.contains_key("experimentalSortImports"));
     }
 
+    #[test]
+    fn test_prettier_options_in_sync() {
+        let schema = schemars::schema_for!(FormatConfig);
+        let mut keys: Vec<&str> = schema
+            .schema
+            .object
+            .as_ref()
+            .unwrap()
+            .properties
+            .keys()
+            .map(String::as_str)
+            // Our own extensions
+            .filter(|key| {
+                !matches!(
+                    *key,
+                    "insertFinalNewline"
+                        | "embeddedSqlTags"
+                        | "switchCaseBodyIndent"
+                        | "methodChainBreakThreshold"
+                        | "emptyBracketSpacing"
+                        | "alignObjectProperties"
+                        | "reindentEmbeddedTemplates"
+                        | "blankLineBetweenClassMembers"
+                        | "expandArraysOfObjects"
+                        | "sortObjectKeys"
+                        | "typeOperatorPosition"
+                        | "indentUnionTypes"
+                        | "hugSoleArgument"
+                        | "alignEnumMembers"
+                        | "collapseElseIf"
+                        | "experimentalPipelineOperator"
+                        | "compactJsxConditionals"
+                        | "jsxAttributeQuotes"
+                        | "emptyBody"
+                        | "stringConcatenationIndent"
+                        | "jsxTextWrap"
+                        | "preserveFirstComment"
+                        | "numericLiterals"
+                        | "preserveBlockBlankLines"
+                        | "ternaryChainIndent"
+                        | "anonymousFunctionSpace"
+                        | "normalizeEscapeSequences"
+                        | "experimentalSortImports"
+                        | "experimentalSortPackageJson"
+                        | "experimentalTailwindcss"
+                )
+            })
+            .collect();
+        keys.sort_unstable();
+
+        let mut expected = PRETTIER_OPTIONS.to_vec();
+        expected.sort_unstable();
+        assert_eq!(keys, expected);
+    }
+
+    #[test]
+    fn test_semi_hybrid() {
+        let json_string = r#"{ "semi": "hybrid" }"#;
+        let mut raw_config: Value = serde_json::from_str(json_string).unwrap();
+        let config: FormatConfig = serde_json::from_str(json_string).unwrap();
+        let oxfmt_options = config.into_oxfmt_options().unwrap();
+        assert!(oxfmt_options.format_options.semicolons.is_hybrid());
+
+        // Prettier does not know `"hybrid"`, it keeps semicolons for the languages it formats
+        populate_prettier_config(&oxfmt_options.format_options, &mut raw_config);
+        assert_eq!(raw_config.get("semi").unwrap(), true);
+
+        assert!(serde_json::from_str::<FormatConfig>(r#"{ "semi": "never" }"#).is_err());
+    }
+
     #[test]
     fn test_overrides_parsing() {
         let json = r#"{
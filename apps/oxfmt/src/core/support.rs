@@ -14,6 +14,10 @@ pub enum FormatFileStrategy {
     OxfmtToml {
         path: PathBuf,
     },
+    /// JSON Lines files, each line formatted as a single-line JSON value (Pure Rust).
+    Jsonl {
+        path: PathBuf,
+    },
     ExternalFormatter {
         path: PathBuf,
         #[cfg_attr(not(feature = "napi"), expect(dead_code))]
@@ -52,13 +56,18 @@ impl TryFrom<PathBuf> for FormatFileStrategy {
             return Ok(Self::OxfmtToml { path });
         }
 
+        // Then JSON Lines files, which Prettier cannot format line by line
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        if extension == Some("jsonl") {
+            return Ok(Self::Jsonl { path });
+        }
+
         // Then external formatter files
         // `package.json` is special: sorted then formatted
         if file_name == "package.json" {
             return Ok(Self::ExternalFormatterPackageJson { path, parser_name: "json-stringify" });
         }
 
-        let extension = path.extension().and_then(|ext| ext.to_str());
         if let Some(parser_name) = get_external_parser_name(file_name, extension) {
             return Ok(Self::ExternalFormatter { path, parser_name });
         }
@@ -68,15 +77,39 @@ impl TryFrom<PathBuf> for FormatFileStrategy {
 }
 
 impl FormatFileStrategy {
+    /// Same as [`FormatFileStrategy::try_from`], but falls back to detecting JS/TS from `source_text`,
+    /// if the file name has no extension, like dotfiles or extensionless scripts.
+    ///
+    /// # Errors
+    /// Returns an error message if the file type is not supported or cannot be detected.
+    pub fn try_from_content(path: PathBuf, source_text: &str) -> Result<Self, String> {
+        if let Ok(strategy) = Self::try_from(path.clone()) {
+            return Ok(strategy);
+        }
+        if path.extension().is_some() {
+            return Err(format!("Unsupported file type: {}", path.display()));
+        }
+
+        match detect_source_type(source_text) {
+            Some(source_type) => Ok(Self::OxcFormatter { path, source_type }),
+            None => Err(format!(
+                "Could not detect the file type of `{}` from its content.\nSpecify the parser by using a file name with an extension, like `{}.ts`.",
+                path.display(),
+                path.file_name().unwrap_or_default().to_string_lossy()
+            )),
+        }
+    }
+
     #[cfg(not(feature = "napi"))]
     pub fn can_format_without_external(&self) -> bool {
-        matches!(self, Self::OxcFormatter { .. } | Self::OxfmtToml { .. })
+        matches!(self, Self::OxcFormatter { .. } | Self::OxfmtToml { .. } | Self::Jsonl { .. })
     }
 
     pub fn path(&self) -> &Path {
         match self {
             Self::OxcFormatter { path, .. }
             | Self::OxfmtToml { path }
+            | Self::Jsonl { path }
             | Self::ExternalFormatter { path, .. }
             | Self::ExternalFormatterPackageJson { path, .. } => path,
         }
@@ -106,6 +139,119 @@ static EXCLUDE_FILENAMES: phf::Set<&'static str> = phf_set! {
 
 // ---
 
+/// Detect JS/TS from the content of an extensionless file, by looking for a few syntax markers.
+///
+/// - A shebang decides by its interpreter, e.g. `node` for JS and `ts-node` for TS
+/// - Otherwise, lines starting with JS or TS only statements are looked for
+/// - JSX is detected by closing tags like `</div>` or `/>`
+///
+/// Returns `None` if the content does not look like JS/TS.
+fn detect_source_type(source_text: &str) -> Option<SourceType> {
+    let mut is_js = false;
+    let mut is_ts = false;
+
+    if let Some(shebang) = source_text.lines().next().and_then(|line| line.strip_prefix("#!")) {
+        // e.g. `#!/usr/bin/env -S node --flag`
+        let interpreter = shebang
+            .split_whitespace()
+            .filter_map(|token| token.rsplit('/').next())
+            .find(|token| *token != "env" && !token.starts_with('-'))
+            .unwrap_or_default();
+        if TS_INTERPRETERS.contains(interpreter) {
+            is_ts = true;
+        } else if JS_INTERPRETERS.contains(interpreter) {
+            is_js = true;
+        } else {
+            // e.g. `#!/bin/sh`, `#!/usr/bin/env python3`
+            return None;
+        }
+    }
+
+    for line in source_text.lines().map(str::trim_start) {
+        if TS_LINE_PREFIXES.iter().any(|prefix| line.starts_with(prefix))
+            || TS_MARKERS.iter().any(|marker| line.contains(marker))
+        {
+            is_ts = true;
+            break;
+        }
+        if JS_LINE_PREFIXES.iter().any(|prefix| line.starts_with(prefix))
+            // `import os` in Python has no module specifier
+            || (line.starts_with("import ") && (line.contains('"') || line.contains('\'')))
+            || line.contains("require(")
+        {
+            is_js = true;
+        }
+    }
+
+    let is_jsx = source_text.contains("</") || source_text.contains("/>");
+    match (is_ts, is_js, is_jsx) {
+        (true, _, true) => Some(SourceType::tsx()),
+        (true, _, false) => Some(SourceType::ts()),
+        (false, true, _) => Some(SourceType::jsx()),
+        (false, false, _) => None,
+    }
+}
+
+static JS_INTERPRETERS: phf::Set<&'static str> = phf_set! {
+    "node",
+    "nodejs",
+    "bun",
+    "deno",
+    "zx",
+};
+
+static TS_INTERPRETERS: phf::Set<&'static str> = phf_set! {
+    "ts-node",
+    "tsx",
+};
+
+const JS_LINE_PREFIXES: &[&str] = &[
+    "const ",
+    "let ",
+    "var ",
+    "function ",
+    "async function ",
+    "class ",
+    "export default ",
+    "export {",
+    "export * ",
+    "export const ",
+    "export let ",
+    "export function ",
+    "export async ",
+    "export class ",
+    "module.exports",
+    "\"use strict\"",
+    "'use strict'",
+];
+
+const TS_LINE_PREFIXES: &[&str] = &[
+    "interface ",
+    "export interface ",
+    "type ",
+    "export type ",
+    "import type ",
+    "enum ",
+    "export enum ",
+    "declare ",
+    "export declare ",
+    "namespace ",
+    "abstract class ",
+    "export abstract class ",
+];
+
+const TS_MARKERS: &[&str] = &[
+    ": string",
+    ": number",
+    ": boolean",
+    ": unknown",
+    ": any",
+    ": void",
+    ": never",
+    " as const",
+    " satisfies ",
+];
+
 /// Returns `true` if this is a TOML file.
 fn is_toml_file(file_name: &str) -> bool {
     if TOML_FILENAMES.contains(file_name) {
@@ -357,6 +503,8 @@ static YAML_EXTENSIONS: phf::Set<&'static str> = phf_set! {
 
 #[cfg(test)]
 mod tests {
+    use oxc_span::ModuleKind;
+
     use super::*;
 
     fn get_parser_name(file_name: &str) -> Option<&'static str> {
@@ -460,4 +608,91 @@ mod tests {
             assert!(result.is_err(), "`{file_name}` should be excluded (lock file)");
         }
     }
+
+    #[test]
+    fn test_detect_by_content() {
+        let ts_source =
+            "#!/usr/bin/env node\ninterface Foo {\n  bar: string;\n}\nconst foo = {} as Foo;\n";
+        let source = FormatFileStrategy::try_from_content(PathBuf::from("script"), ts_source);
+        assert!(matches!(
+            source,
+            Ok(FormatFileStrategy::OxcFormatter { source_type, .. }) if source_type == SourceType::ts()
+        ));
+
+        let js_source = "const fs = require('fs');\n\nmodule.exports = function () {};\n";
+        let source = FormatFileStrategy::try_from_content(PathBuf::from("run"), js_source);
+        assert!(matches!(
+            source,
+            Ok(FormatFileStrategy::OxcFormatter { source_type, .. }) if source_type == SourceType::jsx()
+        ));
+
+        let tsx_source = "type Props = { name: string };\nexport const App = (props: Props) => <div>{props.name}</div>;\n";
+        let source = FormatFileStrategy::try_from_content(PathBuf::from("app"), tsx_source);
+        assert!(matches!(
+            source,
+            Ok(FormatFileStrategy::OxcFormatter { source_type, .. }) if source_type == SourceType::tsx()
+        ));
+
+        // Known file names are still detected by name
+        let source = FormatFileStrategy::try_from_content(PathBuf::from("Pipfile"), "const a = 1;");
+        assert!(matches!(source, Ok(FormatFileStrategy::OxfmtToml { .. })));
+
+        // Other languages, or unsupported extensions are not detected
+        let sources = [
+            ("script", "#!/bin/sh\nexport FOO=1\n"),
+            ("script", "import os\nprint(os.getcwd())\n"),
+            ("notes", "Some plain text\n"),
+            ("notes.txt", "const a = 1;\n"),
+        ];
+        for (file_name, source_text) in sources {
+            let source =
+                FormatFileStrategy::try_from_content(PathBuf::from(file_name), source_text);
+            assert!(source.is_err(), "`{file_name}` should not be detected as JS/TS");
+        }
+    }
+
+    #[test]
+    fn test_jsonl_files() {
+        let source = FormatFileStrategy::try_from(PathBuf::from("events.jsonl")).unwrap();
+        assert!(matches!(source, FormatFileStrategy::Jsonl { .. }));
+
+        let source = FormatFileStrategy::try_from(PathBuf::from("events.json")).unwrap();
+        assert!(matches!(source, FormatFileStrategy::ExternalFormatter { .. }));
+    }
+
+    #[test]
+    fn test_declaration_files() {
+        let test_cases = [
+            ("index.d.ts", ModuleKind::Unambiguous),
+            ("index.d.mts", ModuleKind::Module),
+            ("index.d.cts", ModuleKind::CommonJS),
+            ("styles.d.css.ts", ModuleKind::Unambiguous),
+        ];
+
+        for (file_name, module_kind) in test_cases {
+            let source = FormatFileStrategy::try_from(PathBuf::from(file_name));
+            assert!(
+                matches!(
+                    source,
+                    Ok(FormatFileStrategy::OxcFormatter { source_type, .. })
+                        if source_type.is_typescript_definition()
+                            && source_type.module_kind() == module_kind
+                ),
+                "`{file_name}` should be a declaration file with {module_kind:?}"
+            );
+        }
+
+        // Not declaration files
+        for file_name in ["index.mts", "index.cts", "index.d.css.mts"] {
+            let source = FormatFileStrategy::try_from(PathBuf::from(file_name));
+            assert!(
+                matches!(
+                    source,
+                    Ok(FormatFileStrategy::OxcFormatter { source_type, .. })
+                        if !source_type.is_typescript_definition()
+                ),
+                "`{file_name}` should not be a declaration file"
+            );
+        }
+    }
 }
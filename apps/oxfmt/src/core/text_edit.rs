@@ -0,0 +1,140 @@
+use similar::{DiffOp, TextDiff};
+
+/// A single replacement of `source_text[start..end]` with `new_text`, using byte offsets.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TextEdit {
+    pub start: u32,
+    pub end: u32,
+    pub new_text: String,
+}
+
+/// Compute a minimal set of edits to transform `source_text` into `formatted_text`.
+///
+/// Lines are diffed first, then each changed hunk is narrowed down
+/// to the characters which actually differ.
+/// Edits are sorted by their position and never overlap.
+#[expect(clippy::cast_possible_truncation)]
+pub fn compute_text_edits(source_text: &str, formatted_text: &str) -> Vec<TextEdit> {
+    let diff = TextDiff::from_lines(source_text, formatted_text);
+    let old_offsets = line_offsets(diff.old_slices());
+    let new_offsets = line_offsets(diff.new_slices());
+
+    let mut edits = Vec::new();
+    // Consecutive non-equal ops are merged into a single hunk
+    let mut hunk: Option<(usize, usize, usize, usize)> = None;
+    for op in diff.ops() {
+        if let DiffOp::Equal { .. } = op {
+            if let Some(finished) = hunk.take() {
+                edits.push(finished);
+            }
+            continue;
+        }
+
+        let (old_range, new_range) = (op.old_range(), op.new_range());
+        let (old_start, old_end) = (old_offsets[old_range.start], old_offsets[old_range.end]);
+        let (new_start, new_end) = (new_offsets[new_range.start], new_offsets[new_range.end]);
+        hunk = Some(match hunk {
+            Some((prev_old_start, _, prev_new_start, _)) => {
+                (prev_old_start, old_end, prev_new_start, new_end)
+            }
+            None => (old_start, old_end, new_start, new_end),
+        });
+    }
+    if let Some(last) = hunk {
+        edits.push(last);
+    }
+
+    edits
+        .into_iter()
+        .map(|(old_start, old_end, new_start, new_end)| {
+            let old = &source_text[old_start..old_end];
+            let new = &formatted_text[new_start..new_end];
+            let (prefix, suffix) = common_prefix_suffix(old, new);
+            TextEdit {
+                start: (old_start + prefix) as u32,
+                end: (old_end - suffix) as u32,
+                new_text: new[prefix..new.len() - suffix].to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Byte offsets of the start of each line, plus the end of the text.
+fn line_offsets(lines: &[&str]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(lines.len() + 1);
+    let mut offset = 0;
+    offsets.push(offset);
+    for line in lines {
+        offset += line.len();
+        offsets.push(offset);
+    }
+    offsets
+}
+
+/// Returns the byte length of the common prefix and suffix of `a` and `b`, which never overlap.
+fn common_prefix_suffix(a: &str, b: &str) -> (usize, usize) {
+    let prefix: usize =
+        a.chars().zip(b.chars()).take_while(|(a, b)| a == b).map(|(c, _)| c.len_utf8()).sum();
+
+    let suffix: usize = a[prefix..]
+        .chars()
+        .rev()
+        .zip(b[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum();
+
+    (prefix, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TextEdit, compute_text_edits};
+
+    fn edit(start: u32, end: u32, new_text: &str) -> TextEdit {
+        TextEdit { start, end, new_text: new_text.to_string() }
+    }
+
+    #[test]
+    fn test_no_change() {
+        assert!(compute_text_edits("const a = 1;\n", "const a = 1;\n").is_empty());
+    }
+
+    #[test]
+    fn test_single_line_change() {
+        let source = "const a = 1;\nconst b = 2;\nconst c = 3;\n";
+        let formatted = "const a = 1;\nconst b = 22;\nconst c = 3;\n";
+        assert_eq!(compute_text_edits(source, formatted), vec![edit(24, 24, "2")]);
+    }
+
+    #[test]
+    fn test_multiple_hunks() {
+        let source = "let a=1;\nlet b = 2;\nlet c=3;\n";
+        let formatted = "let a = 1;\nlet b = 2;\nlet c = 3;\n";
+        assert_eq!(
+            compute_text_edits(source, formatted),
+            vec![edit(5, 6, " = "), edit(25, 26, " = ")]
+        );
+    }
+
+    #[test]
+    fn test_insert_and_delete_lines() {
+        let source = "a;\n\n\nb;\n";
+        let formatted = "a;\n\nb;\nc;\n";
+        let edits = compute_text_edits(source, formatted);
+
+        let mut result = source.to_string();
+        for edit in edits.iter().rev() {
+            result.replace_range(edit.start as usize..edit.end as usize, &edit.new_text);
+        }
+        assert_eq!(result, formatted);
+        assert_eq!(edits.len(), 2);
+    }
+
+    #[test]
+    fn test_unicode() {
+        let source = "const a = \"😀\";\n";
+        let formatted = "const a = \"😃\";\n";
+        assert_eq!(compute_text_edits(source, formatted), vec![edit(11, 15, "😃")]);
+    }
+}
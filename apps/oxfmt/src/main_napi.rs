@@ -1,17 +1,23 @@
 use std::ffi::OsString;
 use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex, MutexGuard, PoisonError};
 
 use napi_derive::napi;
 
+use oxc_allocator::Allocator;
+use oxc_data_structures::rope::{Rope, get_line_column};
+use oxc_formatter::{enable_jsx_source_type, get_parse_options};
 use oxc_napi::OxcError;
+use oxc_parser::Parser;
 use serde_json::Value;
 
 use crate::{
     cli::{FormatRunner, MigrateSource, Mode, format_command, init_miette, init_rayon},
     core::{
-        ExternalFormatter, FormatFileStrategy, FormatResult as CoreFormatResult,
-        JsFormatEmbeddedCb, JsFormatFileCb, JsInitExternalFormatterCb, JsSortTailwindClassesCb,
-        SourceFormatter, resolve_options_from_value, utils,
+        ExternalFormatter, FormatCache, FormatCacheKey, FormatFileStrategy,
+        FormatResult as CoreFormatResult, JsFormatEmbeddedCb, JsFormatFileCb,
+        JsInitExternalFormatterCb, JsSortTailwindClassesCb, SourceFormatter, compute_text_edits,
+        oxfmtrc::PRETTIER_OPTIONS, resolve_options_from_value, utils,
     },
     lsp::run_lsp,
     stdin::StdinRunner,
@@ -135,6 +141,10 @@ pub struct FormatResult {
 /// NAPI based format API entry point.
 ///
 /// Since it internally uses `await prettier.format()` in JS side, `formatSync()` cannot be provided.
+///
+/// # Errors
+/// By default, errors are returned in `errors` with the original source text as `code`.
+/// With `throw_on_error`, the promise is rejected with an error containing the diagnostics instead.
 #[expect(clippy::allow_attributes)]
 #[allow(clippy::trailing_empty_array, clippy::unused_async)] // https://github.com/napi-rs/napi-rs/issues/2758
 #[napi]
@@ -142,6 +152,7 @@ pub async fn format(
     filename: String,
     source_text: String,
     options: Option<Value>,
+    throw_on_error: Option<bool>,
     #[napi(ts_arg_type = "(numThreads: number) => Promise<string[]>")]
     init_external_formatter_cb: JsInitExternalFormatterCb,
     #[napi(
@@ -156,9 +167,159 @@ pub async fn format(
         ts_arg_type = "(filepath: string, options: Record<string, any>, classes: string[]) => Promise<string[]>"
     )]
     sort_tailwind_classes_cb: JsSortTailwindClassesCb,
-) -> FormatResult {
-    let num_of_threads = 1;
+) -> napi::Result<FormatResult> {
+    let external_formatter = ExternalFormatter::new(
+        init_external_formatter_cb,
+        format_embedded_cb,
+        format_file_cb,
+        sort_tailwind_classes_cb,
+    );
+
+    match format_source(&filename, &source_text, options, &external_formatter) {
+        Ok(code) => Ok(FormatResult { code, errors: vec![] }),
+        Err(errors) if throw_on_error == Some(true) => Err(to_napi_error(&errors)),
+        Err(errors) => Ok(FormatResult { code: source_text, errors }),
+    }
+}
+
+/// Returns the Prettier option keys which Oxfmt understands.
+///
+/// Other options are passed through to Prettier as they are, but do not affect the files formatted by Oxfmt itself.
+#[napi]
+pub fn supported_options() -> Vec<String> {
+    PRETTIER_OPTIONS.iter().map(ToString::to_string).collect()
+}
+
+/// Parse `source_text` as if it was the content of `filename`, without formatting it.
+///
+/// The source type is inferred from `filename` the same way as [`format`] does.
+/// Returns the parse errors, or an empty vector when the source text is valid.
+/// Files which are not JS/TS files are reported with a single error.
+#[napi]
+#[allow(clippy::needless_pass_by_value, clippy::allow_attributes)]
+pub fn parse_only(filename: String, source_text: String) -> Vec<OxcError> {
+    let source_type =
+        match FormatFileStrategy::try_from_content(PathBuf::from(&filename), &source_text) {
+            Ok(FormatFileStrategy::OxcFormatter { source_type, .. }) => source_type,
+            Ok(_) => return vec![OxcError::new(format!("Not a JS/TS file: {filename}"))],
+            Err(err) => return vec![OxcError::new(err)],
+        };
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, enable_jsx_source_type(source_type))
+        .with_options(get_parse_options())
+        .parse();
+    OxcError::from_diagnostics(&filename, &source_text, ret.errors)
+}
+
+/// Maximum number of formatted files kept by [`FORMAT_CACHE`].
+const FORMAT_CACHE_CAPACITY: usize = 1024;
+
+/// Formatted code of the files recently passed to the format APIs, shared by all calls in this process.
+static FORMAT_CACHE: LazyLock<Mutex<FormatCache>> =
+    LazyLock::new(|| Mutex::new(FormatCache::new(FORMAT_CACHE_CAPACITY)));
+
+/// Turn the in-process cache of formatted code on or off, it is off by default.
+///
+/// When it is on, repeated calls with the same file name, source text and options are served from this cache.
+/// Turning it off also drops the cached code.
+#[napi]
+pub fn set_format_cache_enabled(enabled: bool) {
+    format_cache().set_enabled(enabled);
+}
+
+/// Clear the in-process cache of formatted code.
+///
+/// Clear it when something else affecting the result has changed, e.g. the installed Prettier plugins.
+#[napi]
+pub fn clear_format_cache() {
+    format_cache().clear();
+}
+
+#[napi(object)]
+pub struct FormatCacheStats {
+    /// Number of files served from the cache.
+    pub hits: u32,
+    /// Number of files which were not found in the cache.
+    pub misses: u32,
+}
 
+/// Count the lookups of the in-process cache of formatted code since it was turned on or last cleared.
+#[napi]
+pub fn format_cache_stats() -> FormatCacheStats {
+    let cache = format_cache();
+    FormatCacheStats { hits: cache.hits(), misses: cache.misses() }
+}
+
+/// The cache only holds complete entries, so it is still usable after a panic while it was locked.
+fn format_cache() -> MutexGuard<'static, FormatCache> {
+    FORMAT_CACHE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Join the errors into a single error, preferring the code frame of each error over its message.
+fn to_napi_error(errors: &[OxcError]) -> napi::Error {
+    let reason = errors
+        .iter()
+        .map(|error| error.codeframe.as_deref().unwrap_or(&error.message))
+        .collect::<Vec<_>>()
+        .join("\n");
+    napi::Error::from_reason(reason)
+}
+
+#[napi(object)]
+pub struct FormatEditsResult {
+    /// The minimal edits to apply to the source text, sorted by their position.
+    pub edits: Vec<TextEdit>,
+    /// Parse and format errors.
+    pub errors: Vec<OxcError>,
+}
+
+#[napi(object)]
+pub struct TextEdit {
+    pub range: TextRange,
+    pub new_text: String,
+}
+
+#[napi(object)]
+pub struct TextRange {
+    pub start: TextPosition,
+    pub end: TextPosition,
+}
+
+/// Zero-based position, `character` is counted in UTF-16 code units (same as LSP).
+#[napi(object)]
+pub struct TextPosition {
+    pub line: u32,
+    pub character: u32,
+}
+
+/// Same as [`format`], but returns the minimal edits to transform the source text into the formatted code,
+/// instead of the whole formatted code.
+///
+/// Editors can apply these edits without replacing the whole buffer,
+/// which avoids flickering and preserves folds.
+#[expect(clippy::allow_attributes)]
+#[allow(clippy::trailing_empty_array, clippy::unused_async)] // https://github.com/napi-rs/napi-rs/issues/2758
+#[napi]
+pub async fn format_edits(
+    filename: String,
+    source_text: String,
+    options: Option<Value>,
+    #[napi(ts_arg_type = "(numThreads: number) => Promise<string[]>")]
+    init_external_formatter_cb: JsInitExternalFormatterCb,
+    #[napi(
+        ts_arg_type = "(options: Record<string, any>, parserName: string, code: string) => Promise<string>"
+    )]
+    format_embedded_cb: JsFormatEmbeddedCb,
+    #[napi(
+        ts_arg_type = "(options: Record<string, any>, parserName: string, fileName: string, code: string) => Promise<string>"
+    )]
+    format_file_cb: JsFormatFileCb,
+    #[napi(
+        ts_arg_type = "(filepath: string, options: Record<string, any>, classes: string[]) => Promise<string[]>"
+    )]
+    sort_tailwind_classes_cb: JsSortTailwindClassesCb,
+) -> FormatEditsResult {
     let external_formatter = ExternalFormatter::new(
         init_external_formatter_cb,
         format_embedded_cb,
@@ -166,59 +327,190 @@ pub async fn format(
         sort_tailwind_classes_cb,
     );
 
-    // Use `block_in_place()` to avoid nested async runtime access
-    match tokio::task::block_in_place(|| external_formatter.init(num_of_threads)) {
-        // TODO: Plugins support
-        Ok(_) => {}
-        Err(err) => {
-            external_formatter.cleanup();
-            return FormatResult {
-                code: source_text,
-                errors: vec![OxcError::new(format!("Failed to setup external formatter: {err}"))],
-            };
+    match format_source(&filename, &source_text, options, &external_formatter) {
+        Ok(code) => {
+            FormatEditsResult { edits: to_napi_text_edits(&source_text, &code), errors: vec![] }
         }
+        Err(errors) => FormatEditsResult { edits: vec![], errors },
     }
+}
 
-    // Determine format strategy from file path
-    let Ok(strategy) = FormatFileStrategy::try_from(PathBuf::from(&filename)) else {
-        external_formatter.cleanup();
-        return FormatResult {
-            code: source_text,
-            errors: vec![OxcError::new(format!("Unsupported file type: {filename}"))],
-        };
+/// Check whether each of the given `[filename, source_text]` pairs is already formatted.
+///
+/// The external formatter is set up only once for all files, which is a lot cheaper than calling [`format`] for each file.
+/// Returns one boolean per file in the same order, files which could not be formatted (e.g. because of parse errors)
+/// are reported as not formatted.
+#[expect(clippy::allow_attributes)]
+#[allow(clippy::trailing_empty_array, clippy::unused_async)] // https://github.com/napi-rs/napi-rs/issues/2758
+#[napi]
+pub async fn check_many(
+    files: Vec<(String, String)>,
+    options: Option<Value>,
+    #[napi(ts_arg_type = "(numThreads: number) => Promise<string[]>")]
+    init_external_formatter_cb: JsInitExternalFormatterCb,
+    #[napi(
+        ts_arg_type = "(options: Record<string, any>, parserName: string, code: string) => Promise<string>"
+    )]
+    format_embedded_cb: JsFormatEmbeddedCb,
+    #[napi(
+        ts_arg_type = "(options: Record<string, any>, parserName: string, fileName: string, code: string) => Promise<string>"
+    )]
+    format_file_cb: JsFormatFileCb,
+    #[napi(
+        ts_arg_type = "(filepath: string, options: Record<string, any>, classes: string[]) => Promise<string[]>"
+    )]
+    sort_tailwind_classes_cb: JsSortTailwindClassesCb,
+) -> Vec<bool> {
+    let external_formatter = ExternalFormatter::new(
+        init_external_formatter_cb,
+        format_embedded_cb,
+        format_file_cb,
+        sort_tailwind_classes_cb,
+    );
+
+    let files: Vec<(&str, &str)> = files
+        .iter()
+        .map(|(filename, source_text)| (filename.as_str(), source_text.as_str()))
+        .collect();
+
+    format_sources(&files, options, &external_formatter)
+        .into_iter()
+        .zip(&files)
+        .map(|(result, (_, source_text))| result.is_ok_and(|code| code == *source_text))
+        .collect()
+}
+
+/// Format `source_text` as if it was the content of `filename`.
+/// The `external_formatter` is always cleaned up before returning.
+fn format_source(
+    filename: &str,
+    source_text: &str,
+    options: Option<Value>,
+    external_formatter: &ExternalFormatter,
+) -> Result<String, Vec<OxcError>> {
+    format_sources(&[(filename, source_text)], options, external_formatter)
+        .pop()
+        .expect("one result per file")
+}
+
+/// Format each `(filename, source_text)` pair as if `source_text` was the content of `filename`,
+/// returning the results in the same order.
+/// The `external_formatter` is set up at most once, and always cleaned up before returning.
+fn format_sources(
+    files: &[(&str, &str)],
+    options: Option<Value>,
+    external_formatter: &ExternalFormatter,
+) -> Vec<Result<String, Vec<OxcError>>> {
+    let num_of_threads = 1;
+
+    let options = options.unwrap_or_default();
+
+    // Look up the files which were already formatted with the same options
+    let cache_keys: Vec<Option<FormatCacheKey>> = if format_cache().is_enabled() {
+        let options_key = options.to_string();
+        files
+            .iter()
+            .map(|(filename, source_text)| {
+                Some(FormatCache::key(filename, source_text, &options_key))
+            })
+            .collect()
+    } else {
+        vec![None; files.len()]
+    };
+    let cached: Vec<Option<String>> = {
+        let mut cache = format_cache();
+        cache_keys.iter().map(|key| key.as_ref().and_then(|key| cache.get(key))).collect()
     };
 
-    // Resolve format options directly from the provided options
-    let resolved_options = match resolve_options_from_value(options.unwrap_or_default(), &strategy)
-    {
-        Ok(options) => options,
-        Err(err) => {
-            external_formatter.cleanup();
-            return FormatResult {
-                code: source_text,
-                errors: vec![OxcError::new(format!("Failed to parse configuration: {err}"))],
-            };
-        }
+    // Determine format strategy from file path, or from the content if it has no extension
+    let strategies: Vec<_> = files
+        .iter()
+        .map(|(filename, source_text)| {
+            FormatFileStrategy::try_from_content(PathBuf::from(filename), source_text)
+        })
+        .collect();
+
+    // Skip the setup of the external formatter if there is nothing to format
+    let needs_external_formatter =
+        files.iter().zip(&strategies).zip(&cached).any(|(((_, source_text), strategy), cached)| {
+            cached.is_none() && strategy.is_ok() && !source_text.trim().is_empty()
+        });
+    let init_result = if needs_external_formatter {
+        // Use `block_in_place()` to avoid nested async runtime access
+        // TODO: Plugins support
+        tokio::task::block_in_place(|| external_formatter.init(num_of_threads)).map(|_| ())
+    } else {
+        Ok(())
     };
 
-    // Create formatter and format
     let formatter = SourceFormatter::new(num_of_threads)
         .with_external_formatter(Some(external_formatter.clone()));
 
-    // Use `block_in_place()` to avoid nested async runtime access
-    let result = match tokio::task::block_in_place(|| {
-        formatter.format(&strategy, &source_text, resolved_options)
-    }) {
-        CoreFormatResult::Success { code, .. } => FormatResult { code, errors: vec![] },
-        CoreFormatResult::Error(diagnostics) => {
-            let errors = OxcError::from_diagnostics(&filename, &source_text, diagnostics);
-            FormatResult { code: source_text, errors }
-        }
-    };
+    let results = files
+        .iter()
+        .zip(strategies)
+        .zip(cached.into_iter().zip(cache_keys))
+        .map(|((&(filename, source_text), strategy), (cached, cache_key))| {
+            if let Some(code) = cached {
+                return Ok(code);
+            }
+
+            let strategy = strategy.map_err(|err| vec![OxcError::new(err)])?;
+
+            // Nothing to format
+            if source_text.trim().is_empty() {
+                return Ok(String::new());
+            }
+
+            if let Err(err) = &init_result {
+                return Err(vec![OxcError::new(format!(
+                    "Failed to setup external formatter: {err}"
+                ))]);
+            }
+
+            // Resolve format options directly from the provided options
+            let resolved_options =
+                resolve_options_from_value(options.clone(), &strategy).map_err(|err| {
+                    vec![OxcError::new(format!("Failed to parse configuration: {err}"))]
+                })?;
+
+            // Use `block_in_place()` to avoid nested async runtime access
+            match tokio::task::block_in_place(|| {
+                formatter.format(&strategy, source_text, resolved_options)
+            }) {
+                CoreFormatResult::Success { code, .. } => {
+                    if let Some(cache_key) = cache_key {
+                        format_cache().insert(cache_key, code.clone());
+                    }
+                    Ok(code)
+                }
+                CoreFormatResult::Error(diagnostics) => {
+                    Err(OxcError::from_diagnostics(filename, source_text, diagnostics))
+                }
+            }
+        })
+        .collect();
 
     // Explicitly drop ThreadsafeFunctions before returning to prevent
     // use-after-free during V8 cleanup (Node.js issue with TSFN cleanup timing)
     external_formatter.cleanup();
 
-    result
+    results
+}
+
+/// Convert the byte offset based edits into line / character based ones.
+fn to_napi_text_edits(source_text: &str, formatted_text: &str) -> Vec<TextEdit> {
+    let rope = Rope::from_str(source_text);
+    let to_position = |offset| {
+        let (line, character) = get_line_column(&rope, offset, source_text);
+        TextPosition { line, character }
+    };
+
+    compute_text_edits(source_text, formatted_text)
+        .into_iter()
+        .map(|edit| TextEdit {
+            range: TextRange { start: to_position(edit.start), end: to_position(edit.end) },
+            new_text: edit.new_text,
+        })
+        .collect()
 }
@@ -88,10 +88,16 @@ impl StdinRunner {
             }
         }
 
-        // Determine format strategy from filepath
-        let Ok(strategy) = FormatFileStrategy::try_from(filepath) else {
-            utils::print_and_flush(stderr, "Unsupported file type for stdin-filepath\n");
-            return CliRunResult::InvalidOptionConfig;
+        // Determine format strategy from filepath, or from the content if it has no extension
+        let strategy = match FormatFileStrategy::try_from_content(filepath, &source_text) {
+            Ok(strategy) => strategy,
+            Err(err) => {
+                utils::print_and_flush(
+                    stderr,
+                    &format!("Unsupported file type for stdin-filepath.\n{err}\n"),
+                );
+                return CliRunResult::InvalidOptionConfig;
+            }
         };
 
         // Resolve options for the stdin file entry
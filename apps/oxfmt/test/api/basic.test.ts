@@ -1,5 +1,14 @@
 import { describe, expect, it } from "vitest";
-import { format } from "../../dist/index.js";
+import {
+  checkMany,
+  clearFormatCache,
+  format,
+  formatCacheStats,
+  formatEdits,
+  parseOnly,
+  setFormatCacheEnabled,
+  supportedOptions,
+} from "../../dist/index.js";
 import type { FormatOptions } from "../../dist/index.js";
 
 describe("Basic", () => {
@@ -81,4 +90,118 @@ describe("Basic", () => {
     );
     expect(result3.errors).toStrictEqual([]);
   });
+
+  it("should `formatEdits()` return minimal edits", async () => {
+    const sourceText = "const a = 1;\nconst b=2;\nconst c = 3;\n";
+    const result = await formatEdits("a.ts", sourceText);
+    expect(result.edits).toStrictEqual([
+      {
+        range: { start: { line: 1, character: 7 }, end: { line: 1, character: 8 } },
+        newText: " = ",
+      },
+    ]);
+    expect(result.errors).toStrictEqual([]);
+  });
+
+  it("should `format()` return errors for broken input", async () => {
+    const sourceText = "const x = ;";
+    const result = await format("a.ts", sourceText);
+    expect(result.code).toBe(sourceText);
+    expect(result.errors.length).toBe(1);
+    expect(result.errors[0].message).toContain("Unexpected token");
+  });
+
+  it("should `format()` return errors for deeply nested input", async () => {
+    const sourceText = `x = ${"[".repeat(1000)}1${"]".repeat(1000)};`;
+    const result = await format("a.js", sourceText);
+    expect(result.code).toBe(sourceText);
+    expect(result.errors.length).toBe(1);
+    expect(result.errors[0].message).toContain("nested too deeply");
+  });
+
+  it("should `format()` throw errors for broken input with `throwOnError`", async () => {
+    await expect(format("a.ts", "const x = ;", {}, true)).rejects.toThrow("Unexpected token");
+
+    const result = await format("a.ts", "const x=1", {}, true);
+    expect(result.code).toBe("const x = 1;\n");
+    expect(result.errors).toStrictEqual([]);
+  });
+
+  it("should `format()` empty and whitespace-only files to an empty string", async () => {
+    for (const [fileName, sourceText] of [
+      ["a.ts", ""],
+      ["a.ts", "  \n\t\n"],
+      ["a.json", "\n\n"],
+      ["a.css", " "],
+    ]) {
+      const result = await format(fileName, sourceText);
+      expect(result.code).toBe("");
+      expect(result.errors).toStrictEqual([]);
+    }
+
+    // Comment-only files are still formatted
+    const result = await format("a.ts", "\n  // comment\n");
+    expect(result.code).toBe("// comment\n");
+  });
+
+  it("should `checkMany()` report whether each file is formatted", async () => {
+    const result = await checkMany([
+      ["a.ts", "const x = 1;\n"],
+      ["b.ts", "const x=1"],
+      ["c.json", '{ "a": 1 }\n'],
+      ["d.css", "a{color:red}"],
+      ["e.ts", "const x = ;"],
+      ["f.ts", ""],
+    ]);
+    expect(result).toStrictEqual([true, false, true, false, false, true]);
+
+    expect(await checkMany([])).toStrictEqual([]);
+  });
+
+  it("should serve repeated `format()` calls from the cache only when it is enabled", async () => {
+    await format("cache.ts", "const x=1");
+    expect(formatCacheStats()).toStrictEqual({ hits: 0, misses: 0 });
+
+    setFormatCacheEnabled(true);
+    const result1 = await format("cache.ts", "const x=1");
+    expect(formatCacheStats()).toStrictEqual({ hits: 0, misses: 1 });
+    const result2 = await format("cache.ts", "const x=1");
+    expect(result2).toStrictEqual(result1);
+    expect(formatCacheStats()).toStrictEqual({ hits: 1, misses: 1 });
+
+    // Options are part of the cache key
+    const result3 = await format("cache.ts", "const x=1", { semi: false });
+    expect(result3.code).toBe("const x = 1\n");
+    expect(formatCacheStats()).toStrictEqual({ hits: 1, misses: 2 });
+
+    clearFormatCache();
+    const result4 = await format("cache.ts", "const x=1");
+    expect(result4.code).toBe("const x = 1;\n");
+    expect(formatCacheStats()).toStrictEqual({ hits: 0, misses: 1 });
+
+    setFormatCacheEnabled(false);
+    await format("cache.ts", "const x=1");
+    expect(formatCacheStats()).toStrictEqual({ hits: 0, misses: 0 });
+  });
+
+  it("should `supportedOptions()` list known Prettier options", () => {
+    const options = supportedOptions();
+    expect(options).toContain("singleQuote");
+    expect(options).toContain("tabWidth");
+    expect(options).toContain("printWidth");
+    // Our own extensions are not Prettier options
+    expect(options).not.toContain("experimentalSortImports");
+  });
+
+  it("should `parseOnly()` return parse errors without formatting", () => {
+    expect(parseOnly("a.ts", "const x: number = 42;\ntype A<T> = T[];\n")).toStrictEqual([]);
+
+    const errors = parseOnly("a.ts", "const x: number = ;\n");
+    expect(errors.length).toBe(1);
+    expect(errors[0].severity).toBe("Error");
+
+    // The source type is inferred from the file name
+    expect(parseOnly("a.js", "const x: number = 42;\n").length).toBeGreaterThan(0);
+    expect(parseOnly("a.json", "{}").length).toBe(1);
+  });
 });
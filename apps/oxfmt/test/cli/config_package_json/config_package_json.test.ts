@@ -0,0 +1,21 @@
+import { describe, expect, it } from "vitest";
+import { join } from "node:path";
+import { runCli } from "../utils";
+
+const fixturesDir = join(import.meta.dirname, "fixtures");
+
+describe("config_package_json", () => {
+  it("should apply options from `oxfmt` key in package.json", async () => {
+    // `semi: false` and `singleQuote: true` are only set in `package.json`
+    for (const cwd of [fixturesDir, join(fixturesDir, "without_key")]) {
+      // oxlint-disable-next-line no-await-in-loop
+      const result = await runCli(cwd, ["--check", "test.js"]);
+      expect(result.exitCode).toBe(0);
+    }
+  });
+
+  it("should prefer .oxfmtrc.json over package.json", async () => {
+    const result = await runCli(join(fixturesDir, "with_oxfmtrc"), ["--check", "test.js"]);
+    expect(result.exitCode).toBe(0);
+  });
+});
@@ -0,0 +1,105 @@
+import { describe, expect, it } from "vitest";
+import { join } from "node:path";
+import fs from "node:fs/promises";
+import { tmpdir } from "node:os";
+import { execa } from "execa";
+import { runCli } from "../utils";
+
+const UNFORMATTED = "const a=1\nconst b=2\nconst c=3\n";
+
+async function setupRepo() {
+  const cwd = await fs.mkdtemp(join(tmpdir(), "oxfmt-test-"));
+  const git = (...args: string[]) => execa("git", args, { cwd });
+
+  await git("init", "-q");
+  await git("config", "user.email", "test@example.com");
+  await git("config", "user.name", "test");
+  await fs.writeFile(join(cwd, "changed.js"), UNFORMATTED);
+  await fs.writeFile(join(cwd, "untouched.js"), UNFORMATTED);
+  await git("add", ".");
+  await git("commit", "-q", "-m", "init");
+
+  return { cwd, git };
+}
+
+describe("git_diff", () => {
+  it("--diff-against formats only the changed lines", async () => {
+    const { cwd } = await setupRepo();
+    try {
+      await fs.writeFile(join(cwd, "changed.js"), "const a=1\nconst b=22\nconst c=3\n");
+
+      await runCli(cwd, ["--diff-against", "HEAD"]);
+
+      expect(await fs.readFile(join(cwd, "changed.js"), "utf8")).toBe(
+        "const a=1\nconst b = 22;\nconst c=3\n",
+      );
+      expect(await fs.readFile(join(cwd, "untouched.js"), "utf8")).toBe(UNFORMATTED);
+    } finally {
+      await fs.rm(cwd, { recursive: true, force: true });
+    }
+  });
+
+  it("--staged checks only the staged lines", async () => {
+    const { cwd, git } = await setupRepo();
+    try {
+      await fs.writeFile(join(cwd, "changed.js"), "const a=1\nconst b=2\nconst c = 3;\n");
+      await git("add", "changed.js");
+
+      const formatted = await runCli(cwd, ["--staged", "--check"]);
+      expect(formatted.exitCode).toBe(0);
+
+      await fs.writeFile(join(cwd, "changed.js"), "const a=1\nconst b=2\nconst c=33\n");
+      await git("add", "changed.js");
+
+      const unformatted = await runCli(cwd, ["--staged", "--check"]);
+      expect(unformatted.exitCode).toBe(1);
+      expect(unformatted.stdout).toContain("changed.js");
+      expect(unformatted.stdout).not.toContain("untouched.js");
+    } finally {
+      await fs.rm(cwd, { recursive: true, force: true });
+    }
+  });
+
+  it("--staged uses the staged content of partially staged files", async () => {
+    const { cwd, git } = await setupRepo();
+    try {
+      await fs.writeFile(join(cwd, "changed.js"), "const a=1\nconst b=2\nconst c=33\n");
+      await git("add", "changed.js");
+      // Unstaged change, which is not formatted
+      await fs.writeFile(join(cwd, "changed.js"), "const a=11\nconst b=2\nconst c=33\n");
+
+      const checked = await runCli(cwd, ["--staged", "--check"]);
+      expect(checked.exitCode).toBe(1);
+      expect(checked.stdout).toContain("changed.js");
+
+      // Writing would drop the unstaged change
+      const written = await runCli(cwd, ["--staged"]);
+      expect(written.exitCode).toBe(2);
+      expect(written.stderr + written.stdout).toContain("Skipped partially staged file");
+      expect(await fs.readFile(join(cwd, "changed.js"), "utf8")).toBe(
+        "const a=11\nconst b=2\nconst c=33\n",
+      );
+
+      // Only the staged line is checked, the unstaged one is ignored
+      await fs.writeFile(join(cwd, "changed.js"), "const a=1\nconst b=2\nconst c = 33;\n");
+      await git("add", "changed.js");
+      await fs.writeFile(join(cwd, "changed.js"), "const a=11\nconst b=2\nconst c = 33;\n");
+
+      const formatted = await runCli(cwd, ["--staged", "--check"]);
+      expect(formatted.exitCode).toBe(0);
+    } finally {
+      await fs.rm(cwd, { recursive: true, force: true });
+    }
+  });
+
+  it("fails outside of a git repository", async () => {
+    const cwd = await fs.mkdtemp(join(tmpdir(), "oxfmt-test-"));
+    try {
+      const result = await runCli(cwd, ["--staged"]);
+      expect(result.exitCode).toBe(1);
+      expect(result.stderr).toContain("Failed to get git diff.");
+    } finally {
+      await fs.rm(cwd, { recursive: true, force: true });
+    }
+  });
+});
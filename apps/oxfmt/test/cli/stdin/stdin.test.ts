@@ -30,6 +30,30 @@ describe("--stdin-filepath", () => {
     }).toMatchSnapshot();
   });
 
+  it("should detect TS code from the content of an extensionless file", async () => {
+    const result = await runCliStdin("interface Foo {bar:string}", "script");
+    expect({
+      exitCode: result.exitCode,
+      stdout: result.stdout,
+    }).toMatchSnapshot();
+  });
+
+  it("should detect JS code from the content of an extensionless file", async () => {
+    const result = await runCliStdin("#!/usr/bin/env node\nconst   a=require('a')", "script");
+    expect({
+      exitCode: result.exitCode,
+      stdout: result.stdout,
+    }).toMatchSnapshot();
+  });
+
+  it("should fail for extensionless file with undetectable content", async () => {
+    const result = await runCliStdin("hello world", "notes");
+    expect({
+      exitCode: result.exitCode,
+      stdout: result.stdout,
+    }).toMatchSnapshot();
+  });
+
   // https://github.com/oxc-project/oxc/issues/17939
   it("should not report `WouldBlock` error on large file piped to wc", async () => {
     const largeFile = await readFile(join(fixturesDir, "parser.ts"), "utf-8");
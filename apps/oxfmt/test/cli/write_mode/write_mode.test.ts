@@ -1,6 +1,8 @@
 import { describe, expect, it } from "vitest";
 import { join } from "node:path";
-import { runWriteModeAndSnapshot } from "../utils";
+import fs from "node:fs/promises";
+import { tmpdir } from "node:os";
+import { runCli, runWriteModeAndSnapshot } from "../utils";
 
 const fixturesDir = join(import.meta.dirname, "fixtures");
 
@@ -25,4 +27,27 @@ describe("write_mode", () => {
     const snapshot = await runWriteModeAndSnapshot(fixturesDir, files);
     expect(snapshot).toMatchSnapshot();
   });
+
+  it("should print a summary of formatted, unchanged and errored files", async () => {
+    const tempDir = await fs.mkdtemp(join(tmpdir(), "oxfmt-test-"));
+
+    try {
+      await fs.cp(fixturesDir, tempDir, { recursive: true });
+      const files = ["unformatted.js", "complex.js", "already_formatted.js", "syntax_error.js"];
+
+      const result = await runCli(tempDir, files);
+      expect(result.exitCode).toBe(1);
+      expect(result.stdout).toContain("2 files formatted, 1 unchanged, 1 errored.");
+
+      // Formatted files are now unchanged
+      const quiet = await runCli(tempDir, ["--quiet", ...files]);
+      expect(quiet.exitCode).toBe(1);
+      expect(quiet.stdout).not.toContain("files formatted");
+
+      const rerun = await runCli(tempDir, files);
+      expect(rerun.stdout).toContain("0 files formatted, 3 unchanged, 1 errored.");
+    } finally {
+      await fs.rm(tempDir, { recursive: true, force: true });
+    }
+  });
 });
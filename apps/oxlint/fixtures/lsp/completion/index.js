@@ -0,0 +1,3 @@
+// eslint-disable-next-line no-deb
+debugger;
+const a = 1; // a comment
@@ -0,0 +1,7 @@
+import { defineConfig } from "#oxlint";
+
+export default defineConfig({
+  rules: {
+    "no-console": "off",
+  },
+});
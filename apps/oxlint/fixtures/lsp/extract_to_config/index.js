@@ -0,0 +1,9 @@
+// eslint-disable-next-line no-debugger
+debugger;
+
+function foo() {
+  // eslint-disable-next-line no-console, no-debugger
+  debugger;
+}
+
+debugger; // eslint-disable-line no-debugger
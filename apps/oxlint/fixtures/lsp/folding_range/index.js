@@ -0,0 +1,7 @@
+/*
+ * A block comment
+ * spanning multiple lines.
+ */
+function foo(a) {
+  return a + 1;
+}
@@ -0,0 +1,5 @@
+// oxlint-disable-next-line no-debugger
+debugger;
+
+// oxlint-disable-next-line no-console
+export const a = 1;
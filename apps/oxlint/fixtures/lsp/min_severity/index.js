@@ -0,0 +1,3 @@
+debugger;
+console.log("hello");
+console.log("world");
@@ -0,0 +1,2 @@
+import "./polyfill";
+const lib = require("lib");
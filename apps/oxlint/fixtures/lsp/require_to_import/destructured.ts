@@ -0,0 +1,2 @@
+export { a };
+const { a, b: c } = require("lib");
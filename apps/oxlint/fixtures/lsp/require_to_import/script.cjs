@@ -0,0 +1 @@
+const lib = require("lib");
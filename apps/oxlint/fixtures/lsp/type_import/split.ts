@@ -0,0 +1,3 @@
+import { Foo, createFoo } from "./foo";
+
+export const foo: Foo = createFoo();
@@ -0,0 +1,3 @@
+import { Foo } from "./foo";
+
+export const foo: Foo = { name: "foo" };
@@ -0,0 +1 @@
+console.warn("c");
@@ -0,0 +1,9 @@
+export class Counter {
+  increment() {
+    this.count += 1;
+  }
+
+  reset() {
+    this.count = 0;
+  }
+}
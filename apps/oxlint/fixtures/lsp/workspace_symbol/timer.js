@@ -0,0 +1,5 @@
+let timer;
+
+export function resetTimer() {
+  clearTimeout(timer);
+}
@@ -1717,7 +1717,8 @@ export type BinaryOperator =
   | "^"
   | "&"
   | "in"
-  | "instanceof";
+  | "instanceof"
+  | "|>";
 
 export type LogicalOperator = "||" | "&&" | "??";
 
@@ -150,6 +150,8 @@ fn to_discovered_config(entry: &DirEntry) -> Option<DiscoveredConfig> {
 pub struct LoadedConfig {
     /// The directory this config applies to
     pub dir: PathBuf,
+    /// The config file this config was loaded from
+    pub path: PathBuf,
     /// The built configuration
     pub config: Config,
     /// Ignore patterns from this config
@@ -380,6 +382,7 @@ impl<'a> ConfigLoader<'a> {
             {
                 Ok(config) => built_configs.push(LoadedConfig {
                     dir,
+                    path,
                     config,
                     ignore_patterns,
                     extended_paths,
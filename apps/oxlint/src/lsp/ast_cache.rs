@@ -0,0 +1,98 @@
+#[cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::FxHashMap;
+use self_cell::self_cell;
+use tower_lsp_server::ls_types::Uri;
+
+/// The source text of a document together with the allocator of its program.
+struct SourceWithAllocator {
+    allocator: Allocator,
+    source_text: String,
+    source_type: SourceType,
+}
+
+/// The parsed program of a document.
+pub struct ParsedProgram<'a> {
+    pub program: Program<'a>,
+    /// Whether the parser panicked, the program is empty or incomplete in this case.
+    pub panicked: bool,
+}
+
+self_cell! {
+    /// A parsed document, owning the allocator of its program.
+    pub struct ParsedSource {
+        owner: SourceWithAllocator,
+        #[covariant]
+        dependent: ParsedProgram,
+    }
+}
+
+// Safety: the program only borrows from the allocator and the source text of the owner.
+// They are moved together, and the program is only accessed while holding the lock of the cache.
+unsafe impl Send for ParsedSource {}
+
+impl ParsedSource {
+    pub fn parse(source_text: String, source_type: SourceType) -> Self {
+        let owner =
+            SourceWithAllocator { allocator: Allocator::default(), source_text, source_type };
+        Self::new(owner, |owner| {
+            let ret = Parser::new(&owner.allocator, &owner.source_text, owner.source_type).parse();
+            ParsedProgram { program: ret.program, panicked: ret.panicked }
+        })
+    }
+
+    fn is_parsed_from(&self, source_text: &str, source_type: SourceType) -> bool {
+        let owner = self.borrow_owner();
+        owner.source_type == source_type && owner.source_text == source_text
+    }
+}
+
+/// Caches the parsed program of each document, so the requests for the same version of a document share one parse.
+///
+/// The version of a document is identified by its source text, a cached program is parsed again when it changes.
+#[derive(Default)]
+pub struct AstCache {
+    documents: Mutex<FxHashMap<Uri, ParsedSource>>,
+    /// How many times a source text was parsed.
+    #[cfg(test)]
+    parse_count: AtomicUsize,
+}
+
+impl AstCache {
+    /// Run `f` with the parsed program of the document, parsing `source_text` if it is not cached yet.
+    pub fn with_program<R>(
+        &self,
+        uri: &Uri,
+        source_text: &str,
+        source_type: SourceType,
+        f: impl FnOnce(&ParsedProgram<'_>) -> R,
+    ) -> R {
+        let mut documents = self.documents.lock().unwrap_or_else(PoisonError::into_inner);
+        let is_cached = documents
+            .get(uri)
+            .is_some_and(|parsed| parsed.is_parsed_from(source_text, source_type));
+        if !is_cached {
+            #[cfg(test)]
+            self.parse_count.fetch_add(1, Ordering::Relaxed);
+            documents
+                .insert(uri.clone(), ParsedSource::parse(source_text.to_string(), source_type));
+        }
+        f(documents[uri].borrow_dependent())
+    }
+
+    pub fn remove(&self, uri: &Uri) {
+        self.documents.lock().unwrap_or_else(PoisonError::into_inner).remove(uri);
+    }
+
+    /// How many times a source text was parsed, used to test the cache.
+    #[cfg(test)]
+    pub fn parse_count(&self) -> usize {
+        self.parse_count.load(Ordering::Relaxed)
+    }
+}
@@ -1,8 +1,14 @@
-use oxc_linter::FixKind;
+use std::path::Path;
+
+use oxc_data_structures::rope::Rope;
+use oxc_linter::{AllowWarnDeny, FixKind};
 use tower_lsp_server::ls_types::{CodeAction, CodeActionKind, TextEdit, Uri, WorkspaceEdit};
 use tracing::debug;
 
-use crate::lsp::error_with_position::{FixedContent, LinterCodeAction};
+use crate::lsp::{
+    error_with_position::{FixedContent, LinterCodeAction},
+    extract_to_config::set_rule_severity_in_config,
+};
 
 pub const CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC: CodeActionKind =
     CodeActionKind::new("source.fixAll.oxc");
@@ -45,11 +51,49 @@ pub fn apply_fix_code_actions(action: LinterCodeAction, uri: &Uri) -> Vec<CodeAc
     code_actions
 }
 
+/// Get the code actions which set the severity of `rule` to `"off"`, `"warn"` or `"error"`
+/// in the config file at `config_path`, except for its `current` severity.
+pub fn rule_severity_code_actions(
+    rule: &str,
+    current: AllowWarnDeny,
+    config_path: &Path,
+    config_text: &str,
+) -> Vec<CodeAction> {
+    let Some(config_uri) = Uri::from_file_path(config_path) else {
+        return vec![];
+    };
+    let config_name = config_path
+        .file_name()
+        .map_or_else(|| config_path.to_string_lossy(), |file_name| file_name.to_string_lossy());
+    let rope = Rope::from_str(config_text);
+
+    [(AllowWarnDeny::Allow, "off"), (AllowWarnDeny::Warn, "warn"), (AllowWarnDeny::Deny, "error")]
+        .into_iter()
+        .filter(|(severity, _)| *severity != current)
+        .filter_map(|(_, severity)| {
+            let edit = set_rule_severity_in_config(config_text, &rope, rule, severity)?;
+            Some(CodeAction {
+                title: format!("Set `{rule}` to `{severity}` in `{config_name}`"),
+                kind: Some(CodeActionKind::REFACTOR),
+                edit: Some(WorkspaceEdit {
+                    #[expect(clippy::disallowed_types)]
+                    changes: Some(std::collections::HashMap::from([(
+                        config_uri.clone(),
+                        vec![edit],
+                    )])),
+                    ..WorkspaceEdit::default()
+                }),
+                ..CodeAction::default()
+            })
+        })
+        .collect()
+}
+
 pub fn apply_all_fix_code_action(
     actions: impl Iterator<Item = LinterCodeAction>,
     uri: Uri,
 ) -> Option<CodeAction> {
-    let quick_fixes: Vec<TextEdit> = fix_all_text_edit(actions);
+    let FixAllTextEdits { text_edits: quick_fixes, .. } = fix_all_text_edit(actions);
 
     if quick_fixes.is_empty() {
         return None;
@@ -71,9 +115,19 @@ pub fn apply_all_fix_code_action(
     })
 }
 
+/// The text edits of a "fix all" action / command.
+pub struct FixAllTextEdits {
+    pub text_edits: Vec<TextEdit>,
+    /// The number of fixes which were skipped, because they overlap with another fix.
+    /// They can be applied by running "fix all" again.
+    pub skipped_conflicts: usize,
+}
+
 /// Collect all text edits from the provided diagnostic reports, which can be applied at once.
 /// This is useful for implementing a "fix all" code action / command that applies multiple fixes in one go.
-pub fn fix_all_text_edit(actions: impl Iterator<Item = LinterCodeAction>) -> Vec<TextEdit> {
+///
+/// The edits of one workspace edit must not overlap, so fixes overlapping with a previous fix are skipped.
+pub fn fix_all_text_edit(actions: impl Iterator<Item = LinterCodeAction>) -> FixAllTextEdits {
     let mut text_edits: Vec<TextEdit> = vec![];
 
     for action in actions {
@@ -109,5 +163,19 @@ pub fn fix_all_text_edit(actions: impl Iterator<Item = LinterCodeAction>) -> Vec
         text_edits.push(TextEdit { range: fixed_content.range, new_text: fixed_content.code });
     }
 
-    text_edits
+    // Keep the first fix of overlapping fixes, like the CLI does
+    text_edits.sort_by_key(|edit| edit.range.start);
+    let mut skipped_conflicts = 0;
+    let mut last_end = None;
+    text_edits.retain(|edit| {
+        if last_end.is_some_and(|end| edit.range.start < end) {
+            debug!("Skipping conflicting fix for fix all action: {:?}", edit.range);
+            skipped_conflicts += 1;
+            return false;
+        }
+        last_end = Some(edit.range.end);
+        true
+    });
+
+    FixAllTextEdits { text_edits, skipped_conflicts }
 }
@@ -1,7 +1,18 @@
-use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
 
 pub const FIX_ALL_COMMAND_ID: &str = "oxc.fixAll";
 
+/// The id of the change annotation which groups the edits of [`FIX_ALL_COMMAND_ID`].
+pub const FIX_ALL_CHANGE_ANNOTATION_ID: &str = "oxc.fixAll";
+
+pub const SHOW_STATS_COMMAND_ID: &str = "oxc.showStats";
+
+pub const LINT_FILE_COMMAND_ID: &str = "oxc.lintFile";
+
+pub const WHICH_CONFIG_COMMAND_ID: &str = "oxc.whichConfig";
+
 #[derive(Deserialize)]
 pub struct FixAllCommandArgs {
     pub uri: String,
@@ -19,3 +30,60 @@ impl TryFrom<Vec<serde_json::Value>> for FixAllCommandArgs {
         serde_json::from_value(first_value).map_err(|_| "Failed to parse FixAllCommandArgs")
     }
 }
+
+#[derive(Deserialize)]
+pub struct LintFileCommandArgs {
+    pub uri: String,
+}
+
+impl TryFrom<Vec<serde_json::Value>> for LintFileCommandArgs {
+    type Error = &'static str;
+
+    fn try_from(value: Vec<serde_json::Value>) -> Result<Self, Self::Error> {
+        if value.len() != 1 {
+            return Err("Expected exactly one argument for LintFileCommandArgs");
+        }
+
+        let first_value = value.into_iter().next().ok_or("Missing argument")?;
+        serde_json::from_value(first_value).map_err(|_| "Failed to parse LintFileCommandArgs")
+    }
+}
+
+#[derive(Deserialize)]
+pub struct WhichConfigCommandArgs {
+    pub uri: String,
+}
+
+impl TryFrom<Vec<serde_json::Value>> for WhichConfigCommandArgs {
+    type Error = &'static str;
+
+    fn try_from(value: Vec<serde_json::Value>) -> Result<Self, Self::Error> {
+        if value.len() != 1 {
+            return Err("Expected exactly one argument for WhichConfigCommandArgs");
+        }
+
+        let first_value = value.into_iter().next().ok_or("Missing argument")?;
+        serde_json::from_value(first_value).map_err(|_| "Failed to parse WhichConfigCommandArgs")
+    }
+}
+
+/// The response of the `oxc.whichConfig` command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WhichConfig {
+    /// The config files which apply to the file, the one with the highest precedence first.
+    pub config_paths: Vec<String>,
+    /// Explains why no config file applies to the file.
+    pub message: Option<String>,
+}
+
+impl WhichConfig {
+    pub fn new(path: &Path, config_files: &[PathBuf]) -> Self {
+        let message = config_files.is_empty().then(|| {
+            format!("No config file applies to {}, the default config is used.", path.display())
+        });
+        let config_paths =
+            config_files.iter().map(|file| file.to_string_lossy().into_owned()).collect();
+        Self { config_paths, message }
+    }
+}
@@ -0,0 +1,155 @@
+use std::sync::LazyLock;
+
+use cow_utils::CowUtils;
+use oxc_data_structures::rope::Rope;
+use oxc_linter::rules::RULES;
+use tower_lsp_server::ls_types::{
+    CompletionItem, CompletionItemKind, CompletionTextEdit, Position, Range, TextEdit,
+};
+
+use crate::lsp::error_with_position::{offset_to_position, position_to_offset};
+
+/// The names of all known rules, as they are written in disable directives.
+/// Rules of the `eslint` plugin are written without a plugin prefix, e.g. `no-debugger` and `react/jsx-key`.
+static RULE_NAMES: LazyLock<Vec<(String, &'static str)>> = LazyLock::new(|| {
+    let mut names = RULES
+        .iter()
+        .map(|rule| {
+            let plugin_name = rule.plugin_name();
+            let name = if plugin_name == "eslint" {
+                rule.name().to_string()
+            } else {
+                format!("{}/{}", plugin_name.cow_replace('_', "-"), rule.name())
+            };
+            (name, plugin_name)
+        })
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    names
+});
+
+/// The directives which accept a list of rule names, longest first so `-next-line` wins over the plain directive.
+pub const DIRECTIVES: [&str; 4] = ["disable-next-line", "disable-line", "disable", "enable"];
+
+/// Get the rule names to complete at the position, if it is inside the rule list of a disable directive comment.
+///
+/// Only rule names starting with the partially typed name are returned, outside of a directive no completions are returned.
+#[expect(clippy::cast_possible_truncation)]
+pub fn get_rule_name_completions(source_text: &str, position: Position) -> Vec<CompletionItem> {
+    let rope = Rope::from_str(source_text);
+    let offset = position_to_offset(&rope, position, source_text) as usize;
+
+    let line_start = source_text[..offset].rfind(['\n', '\r']).map_or(0, |index| index + 1);
+    let Some(rule_list) = find_directive_rule_list(&source_text[..offset], line_start) else {
+        return Vec::new();
+    };
+
+    // The partially typed rule name, everything after the last separator
+    let prefix_start =
+        rule_list.rfind(|c: char| c == ',' || c.is_whitespace()).map_or(0, |i| i + 1);
+    let prefix = &rule_list[prefix_start..];
+    let range = Range::new(
+        offset_to_position(&rope, (offset - prefix.len()) as u32, source_text),
+        position,
+    );
+
+    RULE_NAMES
+        .iter()
+        .filter(|(name, _)| name.starts_with(prefix))
+        .map(|(name, plugin_name)| CompletionItem {
+            label: name.clone(),
+            kind: Some(CompletionItemKind::VALUE),
+            detail: Some(format!("{plugin_name} rule")),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(range, name.clone()))),
+            ..CompletionItem::default()
+        })
+        .collect()
+}
+
+/// Find the rule list of a disable directive comment, which ends at the end of `text`.
+/// The comment must start in the same line, after `line_start`.
+fn find_directive_rule_list(text: &str, line_start: usize) -> Option<&str> {
+    let line = &text[line_start..];
+    let (comment_start, is_block) = match (line.rfind("//"), line.rfind("/*")) {
+        (Some(line_comment), Some(block_comment)) if block_comment > line_comment => {
+            (block_comment, true)
+        }
+        (Some(line_comment), _) => (line_comment, false),
+        (None, Some(block_comment)) => (block_comment, true),
+        (None, None) => return None,
+    };
+
+    let comment = line[comment_start + 2..].trim_start();
+    let directive = comment.strip_prefix("eslint-").or_else(|| comment.strip_prefix("oxlint-"))?;
+    let rule_list = DIRECTIVES.iter().find_map(|name| directive.strip_prefix(name))?;
+
+    // Rule names must be separated from the directive, a description ends the rule list
+    if !rule_list.starts_with(char::is_whitespace)
+        || rule_list.contains("--")
+        || (is_block && rule_list.contains("*/"))
+    {
+        return None;
+    }
+
+    Some(rule_list)
+}
+
+#[cfg(test)]
+mod test {
+    use tower_lsp_server::ls_types::Position;
+
+    use super::get_rule_name_completions;
+
+    fn labels(source: &str, position: Position) -> Vec<String> {
+        get_rule_name_completions(source, position).into_iter().map(|item| item.label).collect()
+    }
+
+    #[test]
+    fn test_complete_rule_names() {
+        let source = "// eslint-disable-next-line no-debug";
+        assert_eq!(labels(source, Position::new(0, 36)), vec!["no-debugger"]);
+
+        // every rule is offered without a prefix
+        let source = "/* oxlint-disable no-console, ";
+        let all = labels(source, Position::new(0, 30));
+        assert!(all.contains(&"no-debugger".to_string()));
+        assert!(all.contains(&"typescript/no-explicit-any".to_string()));
+        assert!(all.contains(&"jsx-a11y/alt-text".to_string()));
+
+        let source = "foo(); // eslint-disable-line react/jsx-k";
+        assert_eq!(labels(source, Position::new(0, 41)), vec!["react/jsx-key"]);
+    }
+
+    #[test]
+    fn test_replace_partial_rule_name() {
+        let source = "let a;\n  // eslint-enable no-debug";
+        let completions = get_rule_name_completions(source, Position::new(1, 27));
+        assert_eq!(completions.len(), 1);
+        let Some(tower_lsp_server::ls_types::CompletionTextEdit::Edit(edit)) =
+            &completions[0].text_edit
+        else {
+            panic!("expected a text edit");
+        };
+        assert_eq!(edit.range.start, Position::new(1, 19));
+        assert_eq!(edit.range.end, Position::new(1, 27));
+        assert_eq!(edit.new_text, "no-debugger");
+    }
+
+    #[test]
+    fn test_no_completions_outside_directives() {
+        // not a comment
+        assert!(labels("const noDebug = 1;", Position::new(0, 13)).is_empty());
+        // not a directive
+        assert!(labels("// some comment ", Position::new(0, 16)).is_empty());
+        // directive without rule list yet
+        assert!(labels("// eslint-disable-next-line", Position::new(0, 27)).is_empty());
+        // inside the description
+        assert!(
+            labels("// eslint-disable no-debugger -- reason ", Position::new(0, 40)).is_empty()
+        );
+        // after the block comment
+        assert!(labels("/* eslint-disable */ no", Position::new(0, 23)).is_empty());
+        // before the comment
+        assert!(labels("foo(); // eslint-disable-line ", Position::new(0, 3)).is_empty());
+    }
+}
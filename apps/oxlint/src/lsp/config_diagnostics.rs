@@ -0,0 +1,255 @@
+use oxc_data_structures::rope::Rope;
+use rustc_hash::FxHashMap;
+use serde_json::{Map, Value, value::RawValue};
+use tower_lsp_server::ls_types::{Diagnostic, DiagnosticSeverity, Range};
+
+use oxc_linter::{AllowWarnDeny, OxlintRules};
+
+use crate::lsp::{error_with_position::offset_to_position, extract_to_config::offset_of};
+
+/// Report the problems of the `rules` of an oxlint JSON config file,
+/// which would otherwise only show up as rules silently not running:
+/// - unknown rules of built-in plugins
+/// - invalid severities
+///
+/// Every diagnostic points at the key of the offending rule.
+/// Syntax errors of the config file itself are not reported.
+pub fn get_config_diagnostics(source_text: &str) -> Vec<Diagnostic> {
+    let Some(json) = strip_comments(source_text) else {
+        return Vec::new();
+    };
+    let Some(config) = parse_object(&json) else {
+        return Vec::new();
+    };
+
+    let overrides = config
+        .get("overrides")
+        .and_then(|overrides| serde_json::from_str::<Vec<&RawValue>>(overrides.get()).ok())
+        .unwrap_or_default();
+    let rules_objects =
+        std::iter::once(config.get("rules").copied()).chain(overrides.iter().map(|config| {
+            parse_object(config.get()).and_then(|config| config.get("rules").copied())
+        }));
+
+    let rope = Rope::from_str(source_text);
+    let mut diagnostics = Vec::new();
+    for rules in rules_objects.flatten() {
+        let Ok(Value::Object(rule_configs)) = serde_json::from_str::<Value>(rules.get()) else {
+            continue;
+        };
+        for (key, message, severity) in collect_rule_problems(&rule_configs) {
+            let Some(start) = find_rule_key_offset(&json, rules, &key) else {
+                continue;
+            };
+            diagnostics.push(key_diagnostic(&rope, source_text, start, &key, &message, severity));
+        }
+    }
+
+    diagnostics.sort_by_key(|diagnostic| diagnostic.range.start);
+    diagnostics
+}
+
+/// Report the top level rules of a JSON config file,
+/// which are configured differently by another JSON config file of the same directory.
+///
+/// Only one of the files is used for the directory, `note` explains which one.
+/// Every diagnostic points at the key of the rule.
+pub fn get_rule_conflict_diagnostics(
+    source_text: &str,
+    other_source_text: &str,
+    other_name: &str,
+    note: &str,
+) -> Vec<Diagnostic> {
+    let Some(json) = strip_comments(source_text) else {
+        return Vec::new();
+    };
+    let Some(rules) = parse_object(&json).and_then(|config| config.get("rules").copied()) else {
+        return Vec::new();
+    };
+    let Some(other_json) = strip_comments(other_source_text) else {
+        return Vec::new();
+    };
+    let (Ok(Value::Object(rule_configs)), Ok(Value::Object(other_config))) =
+        (serde_json::from_str::<Value>(rules.get()), serde_json::from_str::<Value>(&other_json))
+    else {
+        return Vec::new();
+    };
+    let Some(other_rules) = other_config.get("rules").and_then(Value::as_object) else {
+        return Vec::new();
+    };
+
+    let rope = Rope::from_str(source_text);
+    let mut diagnostics = Vec::new();
+    for (key, value) in &rule_configs {
+        let Some(other_value) = other_rules.get(key) else {
+            continue;
+        };
+        if normalize_rule_config(value) == normalize_rule_config(other_value) {
+            continue;
+        }
+        let Some(start) = find_rule_key_offset(&json, rules, key) else {
+            continue;
+        };
+        let message = format!(
+            "Rule `{key}` is configured differently in `{other_name}` of the same directory. {note}"
+        );
+        diagnostics.push(key_diagnostic(
+            &rope,
+            source_text,
+            start,
+            key,
+            &message,
+            DiagnosticSeverity::INFORMATION,
+        ));
+    }
+
+    diagnostics.sort_by_key(|diagnostic| diagnostic.range.start);
+    diagnostics
+}
+
+/// Replace the comments of a JSON config file with whitespace, so offsets are kept.
+fn strip_comments(source_text: &str) -> Option<String> {
+    let mut json = source_text.to_string();
+    json_strip_comments::strip(&mut json).ok()?;
+    Some(json)
+}
+
+/// Parse a JSON object, the borrowed raw values point into `json`.
+fn parse_object(json: &str) -> Option<FxHashMap<String, &RawValue>> {
+    serde_json::from_str(json).ok()
+}
+
+/// Split a rule config into its severity and options, so `"error"` and `["deny"]` are the same.
+fn normalize_rule_config(value: &Value) -> (Option<AllowWarnDeny>, &[Value]) {
+    match value {
+        Value::Array(items) => match items.split_first() {
+            Some((severity, options)) => (AllowWarnDeny::try_from(severity).ok(), options),
+            None => (None, &[]),
+        },
+        severity => (AllowWarnDeny::try_from(severity).ok(), &[]),
+    }
+}
+
+/// Create a diagnostic for the quoted `key` starting at the `start` offset.
+fn key_diagnostic(
+    rope: &Rope,
+    source_text: &str,
+    start: usize,
+    key: &str,
+    message: &str,
+    severity: DiagnosticSeverity,
+) -> Diagnostic {
+    #[expect(clippy::cast_possible_truncation)]
+    let (start, end) = (start as u32, (start + key.len() + 2) as u32);
+    Diagnostic {
+        range: Range::new(
+            offset_to_position(rope, start, source_text),
+            offset_to_position(rope, end, source_text),
+        ),
+        severity: Some(severity),
+        message: message.to_string(),
+        source: Some("oxc".into()),
+        ..Default::default()
+    }
+}
+
+/// Validate each rule of a `rules` object, returning the key, message and severity of every problem.
+fn collect_rule_problems(rules: &Map<String, Value>) -> Vec<(String, String, DiagnosticSeverity)> {
+    let mut problems = Vec::new();
+    for (key, value) in rules {
+        // Validate each rule on its own, so one invalid rule does not hide the others
+        let single_rule = Value::Object(Map::from_iter([(key.clone(), value.clone())]));
+        let (message, severity) = match serde_json::from_value::<OxlintRules>(single_rule) {
+            Ok(rule) if rule.unknown_rules().next().is_some() => {
+                (format!("Unknown rule `{key}`, it will not be run."), DiagnosticSeverity::WARNING)
+            }
+            Ok(_) => continue,
+            Err(err) => (
+                format!("Invalid configuration for rule `{key}`: {err}"),
+                DiagnosticSeverity::ERROR,
+            ),
+        };
+        problems.push((key.clone(), message, severity));
+    }
+    problems
+}
+
+/// Find the byte offset of the quoted `key` of a member of the `rules` object in `json`.
+/// Keys of nested objects, e.g. of rule options or other `rules` objects, are never matched.
+fn find_rule_key_offset(json: &str, rules: &RawValue, key: &str) -> Option<usize> {
+    let rule_configs = parse_object(rules.get())?;
+    let rules_start = offset_of(json, rules.get()) as usize;
+    let value_start = offset_of(json, rule_configs.get(key)?.get()) as usize;
+    // Only whitespace and the `:` are between the key and its value
+    let key_start = json[rules_start..value_start].rfind(&format!("\"{key}\""))?;
+    Some(rules_start + key_start)
+}
+
+#[cfg(test)]
+mod test {
+    use tower_lsp_server::ls_types::{DiagnosticSeverity, Position};
+
+    use super::{get_config_diagnostics, get_rule_conflict_diagnostics};
+
+    #[test]
+    fn test_valid_config() {
+        let diagnostics = get_config_diagnostics(
+            r#"{ "rules": { "no-console": "error", "my-js-plugin/rule": "warn" } }"#,
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_severity() {
+        let diagnostics = get_config_diagnostics(
+            "{\n  // comment\n  \"overrides\": [{ \"files\": [\"*.ts\"], \"rules\": { \"no-debugger\": \"errr\" } }]\n}",
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(diagnostics[0].range.start, Position::new(2, 48));
+        assert_eq!(diagnostics[0].range.end, Position::new(2, 61));
+        assert!(diagnostics[0].message.starts_with("Invalid configuration for rule `no-debugger`"));
+    }
+
+    #[test]
+    fn test_invalid_severity_of_repeated_rule() {
+        // Only the key of the `rules` object with the invalid severity is reported
+        let diagnostics = get_config_diagnostics(
+            "{\n  \"rules\": { \"no-debugger\": \"error\" },\n  \"overrides\": [{ \"files\": [\"*.ts\"], \"rules\": { \"no-debugger\": \"errr\" } }]\n}",
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start, Position::new(2, 48));
+        assert_eq!(diagnostics[0].range.end, Position::new(2, 61));
+    }
+
+    #[test]
+    fn test_rule_conflicts() {
+        let source_text = "{\n  \"rules\": {\n    \"no-console\": \"error\",\n    \"eqeqeq\": [\"deny\", \"smart\"],\n    \"no-debugger\": \"warn\"\n  }\n}";
+        let other_source_text = r#"{ "rules": { "no-console": "warn", "eqeqeq": [2, "smart"], "no-debugger": "warn" } }"#;
+
+        let diagnostics =
+            get_rule_conflict_diagnostics(source_text, other_source_text, "other.json", "Note.");
+        // Same severities written differently are not reported
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::INFORMATION));
+        assert_eq!(diagnostics[0].range.start, Position::new(2, 4));
+        assert_eq!(diagnostics[0].range.end, Position::new(2, 16));
+        assert_eq!(
+            diagnostics[0].message,
+            "Rule `no-console` is configured differently in `other.json` of the same directory. Note."
+        );
+    }
+
+    #[test]
+    fn test_rule_conflicts_after_overrides() {
+        let source_text = "{\n  \"overrides\": [{ \"files\": [\"*.ts\"], \"rules\": { \"no-console\": \"warn\" } }],\n  \"rules\": { \"no-console\": \"error\" }\n}";
+        let other_source_text = r#"{ "rules": { "no-console": "warn" } }"#;
+
+        let diagnostics =
+            get_rule_conflict_diagnostics(source_text, other_source_text, "other.json", "Note.");
+        // The key of the top level `rules` is reported, not the one of the override
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start, Position::new(2, 13));
+        assert_eq!(diagnostics[0].range.end, Position::new(2, 25));
+    }
+}
@@ -0,0 +1,365 @@
+use std::borrow::Cow;
+
+use oxc_ast::ast::{
+    BindingPattern, Class, Declaration, ExportDefaultDeclaration, ExportDefaultDeclarationKind,
+    ExportNamedDeclaration, Expression, Function, MethodDefinition, MethodDefinitionKind, Program,
+    PropertyDefinition, PropertyKey, VariableDeclarationKind,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_data_structures::rope::Rope;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{GetSpan, Span};
+use tower_lsp_server::ls_types::{
+    DocumentSymbol, Location, Range, SymbolInformation, SymbolKind, Uri,
+};
+
+use crate::lsp::error_with_position::offset_to_position;
+
+/// Collect the hierarchical document symbols of a JavaScript / TypeScript source text.
+///
+/// Classes, methods, properties, function declarations and exported variables are reported.
+/// Default exports without a name are reported as `default`,
+/// anonymous functions and classes do not create a symbol, their members are reported to the enclosing symbol.
+pub fn get_document_symbols(program: &Program<'_>) -> Vec<DocumentSymbol> {
+    let source_text = program.source_text;
+    let mut collector = DocumentSymbolCollector {
+        rope: Rope::from_str(source_text),
+        source_text,
+        symbols: vec![Vec::new()],
+    };
+
+    collector.visit_program(program);
+
+    collector.symbols.pop().unwrap_or_default()
+}
+
+/// Collect the document symbols of a source text whose name matches the query of a `workspace/symbol` request.
+///
+/// The query matches fuzzily: its characters must appear in the name in the same order, ignoring case.
+/// The hierarchy is flattened, nested symbols report the name of their parent as container name.
+pub fn get_workspace_symbols(
+    uri: &Uri,
+    program: &Program<'_>,
+    query: &str,
+) -> Vec<SymbolInformation> {
+    let mut result = Vec::new();
+    collect_workspace_symbols(uri, get_document_symbols(program), None, query, &mut result);
+    result
+}
+
+fn collect_workspace_symbols(
+    uri: &Uri,
+    symbols: Vec<DocumentSymbol>,
+    container_name: Option<&str>,
+    query: &str,
+    result: &mut Vec<SymbolInformation>,
+) {
+    for symbol in symbols {
+        if matches_query(&symbol.name, query) {
+            #[expect(deprecated)]
+            // `SymbolInformation::deprecated` is deprecated in favor of `tags`
+            result.push(SymbolInformation {
+                name: symbol.name.clone(),
+                kind: symbol.kind,
+                tags: None,
+                deprecated: None,
+                location: Location::new(uri.clone(), symbol.range),
+                container_name: container_name.map(ToString::to_string),
+            });
+        }
+        let children = symbol.children.unwrap_or_default();
+        collect_workspace_symbols(uri, children, Some(&symbol.name), query, result);
+    }
+}
+
+/// Whether the characters of the query appear in the name in the same order, ignoring case and whitespace.
+fn matches_query(name: &str, query: &str) -> bool {
+    let mut name_chars = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .all(|query_char| name_chars.any(|name_char| name_char == query_char))
+}
+
+struct DocumentSymbolCollector<'s> {
+    rope: Rope,
+    source_text: &'s str,
+    /// A stack of the children of every open symbol, the first entry holds the top level symbols.
+    symbols: Vec<Vec<DocumentSymbol>>,
+}
+
+impl DocumentSymbolCollector<'_> {
+    /// Add a symbol to the current parent, `collect_children` visits the nodes whose symbols become its children.
+    fn add_symbol(
+        &mut self,
+        name: String,
+        kind: SymbolKind,
+        span: Span,
+        selection_span: Span,
+        collect_children: impl FnOnce(&mut Self),
+    ) {
+        self.symbols.push(Vec::new());
+        collect_children(self);
+        let children = self.symbols.pop().unwrap_or_default();
+
+        #[expect(deprecated)] // `DocumentSymbol::deprecated` is deprecated in favor of `tags`
+        let symbol = DocumentSymbol {
+            name,
+            detail: None,
+            kind,
+            tags: None,
+            deprecated: None,
+            range: self.range(span),
+            selection_range: self.range(selection_span),
+            children: Some(children),
+        };
+
+        if let Some(parent) = self.symbols.last_mut() {
+            parent.push(symbol);
+        }
+    }
+
+    fn range(&self, span: Span) -> Range {
+        Range::new(
+            offset_to_position(&self.rope, span.start, self.source_text),
+            offset_to_position(&self.rope, span.end, self.source_text),
+        )
+    }
+
+    fn key_name(&self, key: &PropertyKey, computed: bool) -> String {
+        let name = key
+            .static_name()
+            .map_or_else(|| key.span().source_text(self.source_text).to_string(), Cow::into_owned);
+        if computed { format!("[{name}]") } else { name }
+    }
+
+    fn visit_default_export_kind(&mut self, it: &ExportDefaultDeclarationKind<'_>) {
+        match it {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                walk::walk_function(self, func, ScopeFlags::Function);
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                walk::walk_class(self, class);
+            }
+            _ => {
+                if let Some(expression) = it.as_expression() {
+                    self.visit_expression(expression);
+                }
+            }
+        }
+    }
+}
+
+/// The symbol kind of a variable, depending on its initializer.
+fn variable_kind(init: Option<&Expression<'_>>, kind: VariableDeclarationKind) -> SymbolKind {
+    match init.map(Expression::get_inner_expression) {
+        Some(Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)) => {
+            SymbolKind::FUNCTION
+        }
+        Some(Expression::ClassExpression(_)) => SymbolKind::CLASS,
+        _ if kind == VariableDeclarationKind::Const => SymbolKind::CONSTANT,
+        _ => SymbolKind::VARIABLE,
+    }
+}
+
+impl<'a> Visit<'a> for DocumentSymbolCollector<'_> {
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        // Function expressions are named by the variable or property they are assigned to
+        match &it.id {
+            Some(id) if it.is_declaration() => {
+                self.add_symbol(
+                    id.name.to_string(),
+                    SymbolKind::FUNCTION,
+                    it.span,
+                    id.span,
+                    |collector| walk::walk_function(collector, it, flags),
+                );
+            }
+            _ => walk::walk_function(self, it, flags),
+        }
+    }
+
+    fn visit_class(&mut self, it: &Class<'a>) {
+        match &it.id {
+            Some(id) => {
+                self.add_symbol(id.name.to_string(), SymbolKind::CLASS, it.span, id.span, |c| {
+                    walk::walk_class(c, it);
+                });
+            }
+            None => walk::walk_class(self, it),
+        }
+    }
+
+    fn visit_method_definition(&mut self, it: &MethodDefinition<'a>) {
+        let kind = match it.kind {
+            MethodDefinitionKind::Constructor => SymbolKind::CONSTRUCTOR,
+            MethodDefinitionKind::Get | MethodDefinitionKind::Set => SymbolKind::PROPERTY,
+            MethodDefinitionKind::Method => SymbolKind::METHOD,
+        };
+        let name = self.key_name(&it.key, it.computed);
+        self.add_symbol(name, kind, it.span, it.key.span(), |collector| {
+            walk::walk_method_definition(collector, it);
+        });
+    }
+
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        let name = self.key_name(&it.key, it.computed);
+        self.add_symbol(name, SymbolKind::PROPERTY, it.span, it.key.span(), |collector| {
+            walk::walk_property_definition(collector, it);
+        });
+    }
+
+    fn visit_export_named_declaration(&mut self, it: &ExportNamedDeclaration<'a>) {
+        let Some(Declaration::VariableDeclaration(declaration)) = &it.declaration else {
+            walk::walk_export_named_declaration(self, it);
+            return;
+        };
+
+        for declarator in &declaration.declarations {
+            // Destructured exports have no single name, report their initializer only
+            let BindingPattern::BindingIdentifier(id) = &declarator.id else {
+                self.visit_variable_declarator(declarator);
+                continue;
+            };
+            let kind = variable_kind(declarator.init.as_ref(), declaration.kind);
+            self.add_symbol(id.name.to_string(), kind, declarator.span, id.span, |collector| {
+                if let Some(init) = &declarator.init {
+                    collector.visit_expression(init);
+                }
+            });
+        }
+    }
+
+    fn visit_export_default_declaration(&mut self, it: &ExportDefaultDeclaration<'a>) {
+        let (name, kind, selection_span) = match &it.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => (
+                func.id.as_ref().map(|id| (id.name.to_string(), id.span)),
+                SymbolKind::FUNCTION,
+                func.span,
+            ),
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => (
+                class.id.as_ref().map(|id| (id.name.to_string(), id.span)),
+                SymbolKind::CLASS,
+                class.span,
+            ),
+            // Interfaces are not reported
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {
+                walk::walk_export_default_declaration(self, it);
+                return;
+            }
+            declaration => {
+                let expression = declaration.to_expression();
+                let kind = match expression.get_inner_expression() {
+                    Expression::ObjectExpression(_) => SymbolKind::OBJECT,
+                    inner => variable_kind(Some(inner), VariableDeclarationKind::Const),
+                };
+                (None, kind, expression.span())
+            }
+        };
+
+        // `export default function () {}` and `export default {}` are named after the export
+        let (name, selection_span) =
+            name.unwrap_or_else(|| ("default".to_string(), selection_span));
+        self.add_symbol(name, kind, it.span, selection_span, |collector| {
+            collector.visit_default_export_kind(&it.declaration);
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+    use tower_lsp_server::ls_types::{DocumentSymbol, SymbolKind};
+
+    use crate::lsp::ast_cache::ParsedSource;
+
+    use super::get_workspace_symbols;
+
+    fn get_document_symbols(source_text: &str) -> Vec<DocumentSymbol> {
+        let parsed = ParsedSource::parse(source_text.to_string(), SourceType::mjs());
+        super::get_document_symbols(&parsed.borrow_dependent().program)
+    }
+
+    fn flatten(symbols: &[DocumentSymbol], depth: usize, result: &mut Vec<String>) {
+        for symbol in symbols {
+            result.push(format!("{}{} {:?}", "  ".repeat(depth), symbol.name, symbol.kind));
+            flatten(symbol.children.as_deref().unwrap_or_default(), depth + 1, result);
+        }
+    }
+
+    fn get_tree(source: &str) -> Vec<String> {
+        let mut result = Vec::new();
+        flatten(&get_document_symbols(source), 0, &mut result);
+        result
+    }
+
+    #[test]
+    fn test_exported_variables_only() {
+        let tree =
+            get_tree("const a = 1; export const b = 2; export let c = () => { function d() {} };");
+        assert_eq!(tree, vec!["b Constant", "c Function", "  d Function"]);
+    }
+
+    #[test]
+    fn test_anonymous_default_exports() {
+        let tree = get_tree("export default function () { class A { b() {} } }");
+        assert_eq!(tree, vec!["default Function", "  A Class", "    b Method"]);
+
+        let tree = get_tree("export default class { constructor() {} get a() {} #b = 1; }");
+        assert_eq!(
+            tree,
+            vec!["default Class", "  constructor Constructor", "  a Property", "  #b Property"]
+        );
+
+        let tree = get_tree("export default { a() {} }");
+        assert_eq!(tree, vec!["default Object"]);
+    }
+
+    #[test]
+    fn test_anonymous_functions_report_to_parent() {
+        let tree = get_tree("function a() { [].map(function () { function b() {} }); }");
+        assert_eq!(tree, vec!["a Function", "  b Function"]);
+    }
+
+    #[test]
+    fn test_workspace_symbols_match_fuzzily() {
+        let uri = "file:///path/to/file.js".parse().unwrap();
+        let parsed = ParsedSource::parse(
+            "class UserService { fetchUser() {} } function useState() {}".to_string(),
+            SourceType::mjs(),
+        );
+        let symbols = |query| {
+            get_workspace_symbols(&uri, &parsed.borrow_dependent().program, query)
+                .into_iter()
+                .map(|symbol| (symbol.name, symbol.container_name))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            symbols("us"),
+            vec![
+                ("UserService".to_string(), None),
+                ("fetchUser".to_string(), Some("UserService".to_string())),
+                ("useState".to_string(), None),
+            ]
+        );
+        assert_eq!(symbols("fU"), vec![("fetchUser".to_string(), Some("UserService".to_string()))]);
+        assert_eq!(symbols("usst"), vec![("useState".to_string(), None)]);
+        assert!(symbols("service user").is_empty());
+        assert_eq!(symbols("").len(), 3);
+    }
+
+    #[test]
+    fn test_selection_range_is_inside_range() {
+        let symbols = get_document_symbols("export function foo() {}");
+        assert_eq!(symbols.len(), 1);
+        let symbol = &symbols[0];
+        assert_eq!(symbol.kind, SymbolKind::FUNCTION);
+        assert_eq!((symbol.range.start.character, symbol.range.end.character), (7, 24));
+        assert_eq!(
+            (symbol.selection_range.start.character, symbol.selection_range.end.character),
+            (16, 19)
+        );
+    }
+}
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use tower_lsp_server::ls_types::{
     self, CodeDescription, Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity,
-    NumberOrString, Position, Range, Uri,
+    DiagnosticTag, NumberOrString, Position, Range, Uri,
 };
 
 use oxc_data_structures::rope::{Rope, get_line_column};
@@ -21,6 +21,8 @@ pub struct DiagnosticReport {
 pub struct LinterCodeAction {
     pub range: Range,
     pub fixed_content: Vec<FixedContent>,
+    /// The name of the reported rule as used in the config file, e.g. `import/no-cycle`
+    pub rule_name: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -101,6 +103,8 @@ pub fn message_to_lsp_diagnostic(
             std::borrow::Cow::Borrowed("Fix this problem")
         };
 
+    let rule_name = rule_name_with_plugin(&message.error.code);
+
     let diagnostic = Diagnostic {
         range,
         severity,
@@ -109,7 +113,7 @@ pub fn message_to_lsp_diagnostic(
         source: Some("oxc".into()),
         code_description,
         related_information,
-        tags: None,
+        tags: rule_name.as_deref().and_then(diagnostic_tags),
         data: None,
     };
 
@@ -143,13 +147,13 @@ pub fn message_to_lsp_diagnostic(
     if error_offset == section_offset && message.span.end == section_offset {
         return DiagnosticReport {
             diagnostic,
-            code_action: Some(LinterCodeAction { range, fixed_content }),
+            code_action: Some(LinterCodeAction { range, fixed_content, rule_name }),
         };
     }
 
     add_ignore_fixes(
         &mut fixed_content,
-        &message.error.code,
+        rule_name.as_deref(),
         error_offset,
         section_offset,
         rope,
@@ -159,7 +163,7 @@ pub fn message_to_lsp_diagnostic(
     let code_action = if fixed_content.is_empty() {
         None
     } else {
-        Some(LinterCodeAction { range, fixed_content })
+        Some(LinterCodeAction { range, fixed_content, rule_name })
     };
 
     DiagnosticReport { diagnostic, code_action }
@@ -302,6 +306,26 @@ pub fn offset_to_position(rope: &Rope, offset: u32, source_text: &str) -> Positi
     Position::new(line, column)
 }
 
+/// Convert an UTF-16 based position to an UTF-8 offset.
+/// Positions after the end of a line or the source text are clamped to it.
+#[expect(clippy::cast_possible_truncation)]
+pub fn position_to_offset(rope: &Rope, position: Position, source_text: &str) -> u32 {
+    let line = position.line as usize;
+    if line >= rope.len_lines() {
+        return source_text.len() as u32;
+    }
+
+    let line_offset = rope.line_to_byte(line);
+    let mut column = 0;
+    for (index, char) in source_text[line_offset..].char_indices() {
+        if column >= position.character as usize || matches!(char, '\n' | '\r') {
+            return (line_offset + index) as u32;
+        }
+        column += char.len_utf16();
+    }
+    source_text.len() as u32
+}
+
 /// Counter part of `oxc_linter::*::plugin_name_to_prefix`.
 fn prefix_to_plugin_name(prefix: &str) -> &str {
     match prefix {
@@ -327,7 +351,7 @@ fn prefix_to_plugin_name(prefix: &str) -> &str {
 /// then no ignore fixes will be added.
 fn add_ignore_fixes(
     fixes: &mut Vec<FixedContent>,
-    code: &OxcCode,
+    rule_name: Option<&str>,
     error_offset: u32,
     section_offset: u32,
     rope: &Rope,
@@ -338,9 +362,25 @@ fn add_ignore_fixes(
         return;
     }
 
-    if let Some(rule_name) = code.number.as_ref() {
-        // this conversion is a bit messy, but basically we need to reconstruct the rule name with plugin prefix
-        let rule_name_with_plugin = if let Some(scope) = &code.scope
+    if let Some(rule_name) = rule_name {
+        // TODO: doesn't support disabling multiple rules by name for a given line.
+        fixes.push(disable_for_this_line(
+            rule_name,
+            error_offset,
+            section_offset,
+            rope,
+            source_text,
+        ));
+        fixes.push(disable_for_this_section(rule_name, section_offset, rope, source_text));
+    }
+}
+
+/// Reconstruct the rule name with plugin prefix from the diagnostic code, e.g. `eslint-plugin-import(no-cycle)`
+/// becomes `import/no-cycle`. Returns `None` if the diagnostic is not reported by a rule.
+fn rule_name_with_plugin(code: &OxcCode) -> Option<String> {
+    let rule_name = code.number.as_ref()?;
+    Some(
+        if let Some(scope) = &code.scope
             && !scope.is_empty()
             // eslint does not has a plugin prefix
             && scope != "eslint"
@@ -348,22 +388,21 @@ fn add_ignore_fixes(
             format!("{}/{rule_name}", prefix_to_plugin_name(scope))
         } else {
             rule_name.to_string()
-        };
+        },
+    )
+}
 
-        // TODO: doesn't support disabling multiple rules by name for a given line.
-        fixes.push(disable_for_this_line(
-            &rule_name_with_plugin,
-            error_offset,
-            section_offset,
-            rope,
-            source_text,
-        ));
-        fixes.push(disable_for_this_section(
-            &rule_name_with_plugin,
-            section_offset,
-            rope,
-            source_text,
-        ));
+/// Tags which let the client render the reported code as faded out or struck through.
+fn diagnostic_tags(rule_name: &str) -> Option<Vec<DiagnosticTag>> {
+    match rule_name {
+        "no-unused-vars"
+        | "no-unused-labels"
+        | "no-unused-private-class-members"
+        | "no-unreachable" => Some(vec![DiagnosticTag::UNNECESSARY]),
+        "typescript/no-deprecated"
+        | "jest/no-deprecated-functions"
+        | "vue/no-deprecated-destroyed-lifecycle" => Some(vec![DiagnosticTag::DEPRECATED]),
+        _ => None,
     }
 }
 
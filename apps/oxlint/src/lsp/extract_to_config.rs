@@ -0,0 +1,323 @@
+use std::path::Path;
+
+use oxc_data_structures::rope::Rope;
+use oxc_span::Span;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::value::RawValue;
+use tower_lsp_server::ls_types::{CodeAction, CodeActionKind, Range, TextEdit, Uri, WorkspaceEdit};
+
+use crate::lsp::{
+    ast_cache::ParsedProgram,
+    completion::DIRECTIVES,
+    error_with_position::{offset_to_position, position_to_offset},
+};
+
+/// A disable directive comment with the rule names it lists.
+struct Directive<'a> {
+    /// The span of the complete comment, including `//` or `/* */`
+    span: Span,
+    /// The rule names and their spans in the source text
+    rules: Vec<(&'a str, Span)>,
+}
+
+/// Get the code actions which move a rule disabled by a directive comment inside `range` into the config file.
+///
+/// Every rule listed by the directives in `range` gets an action, which removes the rule from all directive
+/// comments of the file and sets it to `"off"` in the config file at `config_path`.
+/// Comments which only list this rule are removed completely.
+/// Only rules for which `is_editable` returns `true` get an action.
+pub fn get_extract_to_config_code_actions(
+    uri: &Uri,
+    parsed: &ParsedProgram<'_>,
+    range: &Range,
+    config_path: &Path,
+    config_text: &str,
+    is_editable: impl Fn(&str) -> bool,
+) -> Vec<CodeAction> {
+    let Some(config_uri) = Uri::from_file_path(config_path) else {
+        return Vec::new();
+    };
+    if parsed.panicked {
+        return Vec::new();
+    }
+    let source_text = parsed.program.source_text;
+    let directives = parsed
+        .program
+        .comments
+        .iter()
+        .filter_map(|comment| {
+            let content_span = Span::new(
+                comment.span.start + 2,
+                if comment.is_block() { comment.span.end - 2 } else { comment.span.end },
+            );
+            parse_directive(source_text, content_span)
+                .map(|rules| Directive { span: comment.span, rules })
+        })
+        .collect::<Vec<_>>();
+
+    let rope = Rope::from_str(source_text);
+    let start = position_to_offset(&rope, range.start, source_text);
+    let end = position_to_offset(&rope, range.end, source_text);
+
+    let mut seen = FxHashSet::default();
+    let rules = directives
+        .iter()
+        .filter(|directive| directive.span.start <= end && start <= directive.span.end)
+        .flat_map(|directive| directive.rules.iter().map(|(rule, _)| *rule))
+        .filter(|rule| seen.insert(*rule))
+        .filter(|rule| is_editable(rule));
+
+    let config_name = config_path
+        .file_name()
+        .map_or_else(|| config_path.to_string_lossy(), |file_name| file_name.to_string_lossy());
+    let config_rope = Rope::from_str(config_text);
+
+    rules
+        .filter_map(|rule| {
+            let config_edit = set_rule_severity_in_config(config_text, &config_rope, rule, "off")?;
+            let source_edits = directives
+                .iter()
+                .filter_map(|directive| remove_rule_from_directive(source_text, directive, rule))
+                .map(|span| TextEdit {
+                    range: Range::new(
+                        offset_to_position(&rope, span.start, source_text),
+                        offset_to_position(&rope, span.end, source_text),
+                    ),
+                    new_text: String::new(),
+                })
+                .collect();
+
+            Some(CodeAction {
+                title: format!("Disable `{rule}` in `{config_name}`"),
+                kind: Some(CodeActionKind::REFACTOR_EXTRACT),
+                edit: Some(WorkspaceEdit {
+                    #[expect(clippy::disallowed_types)]
+                    changes: Some(std::collections::HashMap::from([
+                        (uri.clone(), source_edits),
+                        (config_uri.clone(), vec![config_edit]),
+                    ])),
+                    ..WorkspaceEdit::default()
+                }),
+                ..CodeAction::default()
+            })
+        })
+        .collect()
+}
+
+/// Parse the rule names of a disable directive, `content_span` is the span of the comment without delimiters.
+/// Returns `None` if the comment is not a directive or does not list any rule.
+fn parse_directive(source_text: &str, content_span: Span) -> Option<Vec<(&str, Span)>> {
+    let content = &source_text[content_span.start as usize..content_span.end as usize];
+    let comment = content.trim_start();
+    let directive = comment.strip_prefix("eslint-").or_else(|| comment.strip_prefix("oxlint-"))?;
+    let rule_list = DIRECTIVES.iter().find_map(|name| directive.strip_prefix(name))?;
+    if !rule_list.starts_with(char::is_whitespace) {
+        return None;
+    }
+    // A description after `--` ends the rule list
+    let rule_list = rule_list.split_once("--").map_or(rule_list, |(rules, _)| rules);
+
+    let rules = rule_list
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            let start = content_span.start + offset_of(content, name);
+            #[expect(clippy::cast_possible_truncation)]
+            (name, Span::new(start, start + name.len() as u32))
+        })
+        .collect::<Vec<_>>();
+
+    (!rules.is_empty()).then_some(rules)
+}
+
+/// The byte offset of `inner`, which must be a sub slice of `outer`.
+#[expect(clippy::cast_possible_truncation)]
+pub fn offset_of(outer: &str, inner: &str) -> u32 {
+    (inner.as_ptr() as usize - outer.as_ptr() as usize) as u32
+}
+
+/// Get the span to delete for removing `rule` from the directive.
+/// The complete comment is removed if it only lists `rule`, including its line if nothing else is on it.
+fn remove_rule_from_directive(
+    source_text: &str,
+    directive: &Directive,
+    rule: &str,
+) -> Option<Span> {
+    let index = directive.rules.iter().position(|(name, _)| *name == rule)?;
+
+    if directive.rules.iter().all(|(name, _)| *name == rule) {
+        let span = directive.span;
+        let line_start =
+            source_text[..span.start as usize].rfind(['\n', '\r']).map_or(0, |index| index + 1);
+        let before = &source_text[line_start..span.start as usize];
+        let after = &source_text[span.end as usize..];
+        let line_end = after.find(['\n', '\r']).map_or(after.len(), |index| index);
+
+        #[expect(clippy::cast_possible_truncation)]
+        if before.trim().is_empty() && after[..line_end].trim().is_empty() {
+            // The comment is on its own line, remove the line including its line break
+            let line_break = match &after[line_end..] {
+                rest if rest.starts_with("\r\n") => 2,
+                "" => 0,
+                _ => 1,
+            };
+            return Some(Span::new(line_start as u32, span.end + (line_end + line_break) as u32));
+        }
+        // Remove the comment and the whitespace separating it from the code before
+        #[expect(clippy::cast_possible_truncation)]
+        let whitespace = (before.len() - before.trim_end().len()) as u32;
+        return Some(Span::new(span.start - whitespace, span.end));
+    }
+
+    let span = directive.rules[index].1;
+    Some(match directive.rules.get(index + 1) {
+        // Remove the name and the separator to the next name
+        Some((_, next)) => Span::new(span.start, next.start),
+        // Remove the separator to the previous name and the name
+        None => Span::new(directive.rules[index - 1].1.end, span.end),
+    })
+}
+
+/// Get the edit which sets `rule` to `severity` in the top level `rules` of the JSON config.
+/// The options of the rule are kept, unless it is turned `"off"`.
+/// Returns `None` if the config can not be parsed.
+pub fn set_rule_severity_in_config(
+    config_text: &str,
+    rope: &Rope,
+    rule: &str,
+    severity: &str,
+) -> Option<TextEdit> {
+    // Parse the config like the config loader does. Comments are replaced with whitespace,
+    // so the borrowed raw values point to the same offsets in `json` and `config_text`.
+    let mut json = config_text.to_string();
+    json_strip_comments::strip(&mut json).ok()?;
+    let config = serde_json::from_str::<FxHashMap<String, &RawValue>>(&json).ok()?;
+    let root = json.len() - json.trim_start().len();
+    let rule_entry = format!("\"{rule}\": \"{severity}\"");
+
+    let (start, end, new_text) = match config.get("rules") {
+        Some(rules) => {
+            let rule_configs =
+                serde_json::from_str::<FxHashMap<String, &RawValue>>(rules.get()).ok()?;
+            match rule_configs.get(rule) {
+                Some(value) => {
+                    // Replace only the severity of `["error", { ... }]`
+                    let replaced = serde_json::from_str::<Vec<&RawValue>>(value.get())
+                        .ok()
+                        .and_then(|items| items.first().copied())
+                        .filter(|_| severity != "off")
+                        .unwrap_or(value);
+                    let start = offset_of(&json, replaced.get()) as usize;
+                    (start, start + replaced.get().len(), format!("\"{severity}\""))
+                }
+                None => insert_member(
+                    config_text,
+                    &json,
+                    offset_of(&json, rules.get()) as usize,
+                    &rule_entry,
+                ),
+            }
+        }
+        None => insert_member(config_text, &json, root, &format!("\"rules\": {{ {rule_entry} }}")),
+    };
+
+    #[expect(clippy::cast_possible_truncation)]
+    Some(TextEdit {
+        range: Range::new(
+            offset_to_position(rope, start as u32, config_text),
+            offset_to_position(rope, end as u32, config_text),
+        ),
+        new_text,
+    })
+}
+
+/// Insert `entry` as the first member of the object starting with `{` at `object_start`,
+/// in the same line and with the same indentation as the current first member.
+fn insert_member(
+    config_text: &str,
+    json: &str,
+    object_start: usize,
+    entry: &str,
+) -> (usize, usize, String) {
+    let inner = &json[object_start + 1..];
+    let first_start = object_start + 1 + inner.len() - inner.trim_start().len();
+    if json[first_start..].starts_with('}') {
+        // Empty object, replace everything between the braces
+        return (object_start + 1, first_start, format!(" {entry} "));
+    }
+    let line_start = config_text[..first_start].rfind('\n').map_or(0, |index| index + 1);
+    let indent = &config_text[line_start..first_start];
+    let separator = if indent.trim().is_empty() { format!("\n{indent}") } else { " ".to_string() };
+    (first_start, first_start, format!("{entry},{separator}"))
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_data_structures::rope::Rope;
+
+    use super::set_rule_severity_in_config;
+
+    fn apply(config: &str, rule: &str) -> String {
+        apply_severity(config, rule, "off")
+    }
+
+    fn apply_severity(config: &str, rule: &str, severity: &str) -> String {
+        let rope = Rope::from_str(config);
+        let edit = set_rule_severity_in_config(config, &rope, rule, severity)
+            .expect("config should be edited");
+        let lines: Vec<&str> = config.split('\n').collect();
+        let offset = |line: u32, character: u32| {
+            lines[..line as usize].iter().map(|line| line.len() + 1).sum::<usize>()
+                + character as usize
+        };
+        let start = offset(edit.range.start.line, edit.range.start.character);
+        let end = offset(edit.range.end.line, edit.range.end.character);
+        format!("{}{}{}", &config[..start], edit.new_text, &config[end..])
+    }
+
+    #[test]
+    fn test_disable_rule_in_config() {
+        // existing rule
+        assert_eq!(
+            apply("{\n  \"rules\": {\n    \"no-debugger\": [\"error\", {}]\n  }\n}", "no-debugger"),
+            "{\n  \"rules\": {\n    \"no-debugger\": \"off\"\n  }\n}"
+        );
+        // new rule, with comments
+        assert_eq!(
+            apply(
+                "{\n  // comment\n  \"rules\": {\n    \"eqeqeq\": \"error\"\n  }\n}",
+                "no-debugger"
+            ),
+            "{\n  // comment\n  \"rules\": {\n    \"no-debugger\": \"off\",\n    \"eqeqeq\": \"error\"\n  }\n}"
+        );
+        // empty rules
+        assert_eq!(
+            apply(r#"{ "rules": {} }"#, "no-debugger"),
+            r#"{ "rules": { "no-debugger": "off" } }"#
+        );
+        // no rules
+        assert_eq!(
+            apply("{\n  \"plugins\": [\"import\"]\n}", "no-debugger"),
+            "{\n  \"rules\": { \"no-debugger\": \"off\" },\n  \"plugins\": [\"import\"]\n}"
+        );
+        assert_eq!(apply("{}", "no-debugger"), r#"{ "rules": { "no-debugger": "off" } }"#);
+    }
+
+    #[test]
+    fn test_set_rule_severity_in_config() {
+        // the options are kept
+        assert_eq!(
+            apply_severity(r#"{ "rules": { "eqeqeq": ["error", "smart"] } }"#, "eqeqeq", "warn"),
+            r#"{ "rules": { "eqeqeq": ["warn", "smart"] } }"#
+        );
+        assert_eq!(
+            apply_severity(r#"{ "rules": { "eqeqeq": "off" } }"#, "eqeqeq", "error"),
+            r#"{ "rules": { "eqeqeq": "error" } }"#
+        );
+        assert_eq!(
+            apply_severity(r#"{ "rules": {} }"#, "eqeqeq", "warn"),
+            r#"{ "rules": { "eqeqeq": "warn" } }"#
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use oxc_ast::ast::{
+    ArrayExpression, ArrowFunctionExpression, Class, Function, ObjectExpression, Program,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_data_structures::rope::{Rope, get_line_column};
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+use tower_lsp_server::ls_types::{FoldingRange, FoldingRangeKind};
+
+/// Collect the folding ranges of a JavaScript / TypeScript source text.
+///
+/// Functions, classes, objects and arrays are reported as regions,
+/// block comments are reported with [`FoldingRangeKind::Comment`].
+/// Only ranges spanning multiple lines are returned.
+pub fn get_folding_ranges(program: &Program<'_>) -> Vec<FoldingRange> {
+    let source_text = program.source_text;
+    let mut collector = FoldingRangeCollector {
+        rope: Rope::from_str(source_text),
+        source_text,
+        ranges: Vec::new(),
+    };
+
+    collector.visit_program(program);
+
+    for comment in program.comments.iter().filter(|comment| comment.is_block()) {
+        collector.add_range(comment.span, Some(FoldingRangeKind::Comment));
+    }
+
+    collector.ranges.sort_by_key(|range| (range.start_line, range.end_line));
+    collector.ranges
+}
+
+struct FoldingRangeCollector<'s> {
+    rope: Rope,
+    source_text: &'s str,
+    ranges: Vec<FoldingRange>,
+}
+
+impl FoldingRangeCollector<'_> {
+    fn add_range(&mut self, span: Span, kind: Option<FoldingRangeKind>) {
+        let (start_line, _) = get_line_column(&self.rope, span.start, self.source_text);
+        let (end_line, _) = get_line_column(&self.rope, span.end, self.source_text);
+
+        if end_line <= start_line {
+            return;
+        }
+
+        self.ranges.push(FoldingRange {
+            start_line,
+            end_line,
+            kind: kind.or(Some(FoldingRangeKind::Region)),
+            ..Default::default()
+        });
+    }
+}
+
+impl<'a> Visit<'a> for FoldingRangeCollector<'_> {
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        self.add_range(it.span, None);
+        walk::walk_function(self, it, flags);
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        self.add_range(it.span, None);
+        walk::walk_arrow_function_expression(self, it);
+    }
+
+    fn visit_class(&mut self, it: &Class<'a>) {
+        self.add_range(it.span, None);
+        walk::walk_class(self, it);
+    }
+
+    fn visit_object_expression(&mut self, it: &ObjectExpression<'a>) {
+        self.add_range(it.span, None);
+        walk::walk_object_expression(self, it);
+    }
+
+    fn visit_array_expression(&mut self, it: &ArrayExpression<'a>) {
+        self.add_range(it.span, None);
+        walk::walk_array_expression(self, it);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+    use tower_lsp_server::ls_types::{FoldingRange, FoldingRangeKind};
+
+    use crate::lsp::ast_cache::ParsedSource;
+
+    fn get_folding_ranges(source_text: &str) -> Vec<FoldingRange> {
+        let parsed = ParsedSource::parse(source_text.to_string(), SourceType::mjs());
+        super::get_folding_ranges(&parsed.borrow_dependent().program)
+    }
+
+    #[test]
+    fn test_single_line_nodes_are_skipped() {
+        let ranges = get_folding_ranges("const a = { b: [1, 2] }; function c() {} /* d */");
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    fn test_nested_ranges() {
+        let source = "class A {\n  b() {\n    return [\n      1,\n    ];\n  }\n}\n";
+        let ranges = get_folding_ranges(source);
+        let lines: Vec<_> = ranges.iter().map(|r| (r.start_line, r.end_line)).collect();
+        assert_eq!(lines, vec![(0, 6), (1, 5), (2, 4)]);
+        assert!(ranges.iter().all(|r| r.kind == Some(FoldingRangeKind::Region)));
+    }
+}
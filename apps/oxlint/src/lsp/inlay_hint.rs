@@ -0,0 +1,49 @@
+use tower_lsp_server::ls_types::{InlayHint, InlayHintLabel, InlayHintTooltip};
+
+use oxc_data_structures::rope::Rope;
+use oxc_linter::{DisableDirectives, RuleCommentType};
+
+use crate::lsp::error_with_position::offset_to_position;
+
+/// Create an `unused` inlay hint after every disable directive which suppressed nothing.
+///
+/// For directives disabling specific rules, the hint is placed after each unused rule name,
+/// otherwise after the whole comment.
+pub fn get_unused_directive_hints(
+    directives: &DisableDirectives,
+    source_text: &str,
+    rope: &Rope,
+) -> Vec<InlayHint> {
+    let mut hints = Vec::new();
+    let mut push_hint = |offset: u32, tooltip: String| {
+        hints.push(InlayHint {
+            position: offset_to_position(rope, offset, source_text),
+            label: InlayHintLabel::String("unused".to_string()),
+            kind: None,
+            text_edits: None,
+            tooltip: Some(InlayHintTooltip::String(tooltip)),
+            padding_left: Some(true),
+            padding_right: None,
+            data: None,
+        });
+    };
+
+    for unused_comment in directives.collect_unused_disable_comments() {
+        match unused_comment.r#type {
+            RuleCommentType::All => push_hint(
+                unused_comment.span.end,
+                "No problems were reported by this directive.".to_string(),
+            ),
+            RuleCommentType::Single(rules) => {
+                for rule in rules {
+                    push_hint(
+                        rule.name_span.end,
+                        format!("No problems were reported from {}.", rule.rule_name),
+                    );
+                }
+            }
+        }
+    }
+
+    hints
+}
@@ -3,12 +3,22 @@ use oxc_linter::ExternalLinter;
 #[cfg(feature = "napi")]
 use crate::js_config::JsConfigLoaderCb;
 
+mod ast_cache;
 mod code_actions;
 mod commands;
+mod completion;
+mod config_diagnostics;
+mod document_symbol;
 mod error_with_position;
+mod extract_to_config;
+mod folding_range;
+mod inlay_hint;
 mod lsp_file_system;
 mod options;
+mod rename;
+mod selection_range;
 mod server_linter;
+mod stats;
 #[cfg(test)]
 mod tester;
 mod utils;
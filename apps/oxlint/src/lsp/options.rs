@@ -21,6 +21,15 @@ pub enum Run {
     OnType,
 }
 
+/// The lowest severity of the diagnostics reported to the client.
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum MinSeverity {
+    #[default]
+    Warning,
+    Error,
+}
+
 #[derive(Debug, Default, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct LintOptions {
@@ -33,6 +42,20 @@ pub struct LintOptions {
     pub type_aware: bool,
     pub disable_nested_config: bool,
     pub fix_kind: LintFixKindFlag,
+    pub min_severity: MinSeverity,
+    /// The maximum number of diagnostics reported per file, `None` for no limit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_number_of_problems: Option<usize>,
+    /// Send trace output of the linter lifecycle to the client as `window/logMessage`
+    pub verbose: bool,
+    /// Skip the workspace scan and only lint the files opened by the client
+    pub lint_only_open_files: bool,
+    /// The directory relative config, tsconfig and ignore paths are resolved against, defaults to the workspace root
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_directory: Option<String>,
+    /// The `source` of every published diagnostic, defaults to `oxc`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diagnostic_source: Option<String>,
 }
 
 #[derive(Debug, Default, Serialize, PartialEq, Eq, Deserialize, Clone)]
@@ -137,6 +160,25 @@ impl TryFrom<Value> for LintOptions {
                     Some(&"all") => LintFixKindFlag::All,
                     _ => LintFixKindFlag::default(),
                 }),
+            min_severity: object
+                .get("minSeverity")
+                .and_then(|key| serde_json::from_value::<MinSeverity>(key.clone()).ok())
+                .unwrap_or_default(),
+            max_number_of_problems: object
+                .get("maxNumberOfProblems")
+                .and_then(|key| serde_json::from_value::<usize>(key.clone()).ok()),
+            verbose: object
+                .get("verbose")
+                .is_some_and(|key| serde_json::from_value::<bool>(key.clone()).unwrap_or_default()),
+            lint_only_open_files: object
+                .get("lintOnlyOpenFiles")
+                .is_some_and(|key| serde_json::from_value::<bool>(key.clone()).unwrap_or_default()),
+            working_directory: object
+                .get("workingDirectory")
+                .and_then(|key| serde_json::from_value::<String>(key.clone()).ok()),
+            diagnostic_source: object
+                .get("diagnosticSource")
+                .and_then(|key| serde_json::from_value::<String>(key.clone()).ok()),
         })
     }
 }
@@ -145,7 +187,7 @@ impl TryFrom<Value> for LintOptions {
 mod test {
     use serde_json::json;
 
-    use super::{LintOptions, Run, UnusedDisableDirectives};
+    use super::{LintOptions, MinSeverity, Run, UnusedDisableDirectives};
 
     #[test]
     fn test_valid_options_json() {
@@ -155,7 +197,13 @@ mod test {
             "unusedDisableDirectives": "warn",
             "typeAware": true,
             "disableNestedConfig": true,
-            "fixKind": "dangerous_fix"
+            "fixKind": "dangerous_fix",
+            "minSeverity": "error",
+            "maxNumberOfProblems": 10,
+            "verbose": true,
+            "lintOnlyOpenFiles": true,
+            "workingDirectory": "./packages/app",
+            "diagnosticSource": "oxlint"
         });
 
         let options = LintOptions::try_from(json).unwrap();
@@ -165,6 +213,12 @@ mod test {
         assert!(options.type_aware);
         assert!(options.disable_nested_config);
         assert_eq!(options.fix_kind, super::LintFixKindFlag::DangerousFix);
+        assert_eq!(options.min_severity, MinSeverity::Error);
+        assert_eq!(options.max_number_of_problems, Some(10));
+        assert!(options.verbose);
+        assert!(options.lint_only_open_files);
+        assert_eq!(options.working_directory, Some("./packages/app".into()));
+        assert_eq!(options.diagnostic_source, Some("oxlint".into()));
     }
 
     #[test]
@@ -178,6 +232,9 @@ mod test {
         assert!(!options.type_aware);
         assert!(!options.disable_nested_config);
         assert_eq!(options.fix_kind, super::LintFixKindFlag::SafeFix);
+        assert_eq!(options.min_severity, MinSeverity::Warning);
+        assert_eq!(options.max_number_of_problems, None);
+        assert_eq!(options.diagnostic_source, None);
     }
 
     #[test]
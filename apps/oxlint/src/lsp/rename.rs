@@ -0,0 +1,363 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AssignmentTargetPropertyIdentifier, BindingIdentifier, BindingProperty, ExportSpecifier,
+        IdentifierReference, ObjectProperty, Program,
+    },
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_data_structures::rope::Rope;
+use oxc_semantic::{Scoping, Semantic, SemanticBuilder, SymbolId};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{identifier::is_identifier_name, keyword::is_reserved_keyword};
+use tower_lsp_server::ls_types::{Position, Range, TextEdit};
+
+use crate::lsp::{
+    ast_cache::ParsedProgram,
+    error_with_position::{offset_to_position, position_to_offset},
+};
+
+/// Get the range of the identifier at the position, if it can be renamed.
+///
+/// Only identifiers of local bindings can be renamed, that are bindings declared in this file which are not imported or exported.
+/// For every other position (keywords, literals, global variables, imports, ...) `None` is returned.
+pub fn get_rename_range(parsed: &ParsedProgram<'_>, position: Position) -> Option<Range> {
+    if parsed.panicked {
+        return None;
+    }
+    let program = &parsed.program;
+    let source_text = program.source_text;
+    let semantic = SemanticBuilder::new().build(program).semantic;
+    let rope = Rope::from_str(source_text);
+
+    let offset = position_to_offset(&rope, position, source_text);
+    let (span, _) = find_renameable_symbol(program, &semantic, offset)?;
+
+    Some(Range::new(
+        offset_to_position(&rope, span.start, source_text),
+        offset_to_position(&rope, span.end, source_text),
+    ))
+}
+
+/// Get the edits renaming the local binding at the position and all its references to `new_name`.
+///
+/// An empty vector is returned, when the position can not be renamed (see [`get_rename_range`]),
+/// `new_name` is not a valid identifier or the rename would conflict with another binding or global variable.
+pub fn get_rename_edits(
+    parsed: &ParsedProgram<'_>,
+    position: Position,
+    new_name: &str,
+) -> Vec<TextEdit> {
+    if !is_identifier_name(new_name) || is_reserved_keyword(new_name) || parsed.panicked {
+        return Vec::new();
+    }
+
+    let program = &parsed.program;
+    let source_text = program.source_text;
+    let semantic = SemanticBuilder::new().build(program).semantic;
+    let rope = Rope::from_str(source_text);
+
+    let offset = position_to_offset(&rope, position, source_text);
+    let Some((_, symbol_id)) = find_renameable_symbol(program, &semantic, offset) else {
+        return Vec::new();
+    };
+
+    let scoping = semantic.scoping();
+    let old_name = scoping.symbol_name(symbol_id);
+    if old_name == new_name || has_conflicting_binding(&semantic, symbol_id, new_name) {
+        return Vec::new();
+    }
+
+    let mut collector = RenameCollector {
+        symbol_id,
+        scoping,
+        shorthand_spans: Vec::new(),
+        export_spans: Vec::new(),
+        spans: Vec::new(),
+    };
+    collector.visit_program(program);
+
+    let mut spans = collector.spans;
+    spans.sort_unstable_by_key(|span| span.start);
+    spans.dedup();
+
+    spans
+        .into_iter()
+        .map(|span| {
+            // Keep the key of shorthand properties and the name of exports.
+            let new_text = if collector.shorthand_spans.contains(&span) {
+                format!("{old_name}: {new_name}")
+            } else if collector.export_spans.contains(&span) {
+                format!("{new_name} as {old_name}")
+            } else {
+                new_name.to_string()
+            };
+            TextEdit::new(
+                Range::new(
+                    offset_to_position(&rope, span.start, source_text),
+                    offset_to_position(&rope, span.end, source_text),
+                ),
+                new_text,
+            )
+        })
+        .collect()
+}
+
+/// Find the identifier containing the offset and the local binding it declares or refers to.
+/// Imported bindings and bindings of exported declarations are not renameable.
+fn find_renameable_symbol(
+    program: &Program<'_>,
+    semantic: &Semantic<'_>,
+    offset: u32,
+) -> Option<(Span, SymbolId)> {
+    let scoping = semantic.scoping();
+    let mut finder = IdentifierFinder { offset, scoping, found: None };
+    finder.visit_program(program);
+
+    let (span, symbol_id) = finder.found?;
+    let symbol_id = symbol_id?;
+    if scoping.symbol_flags(symbol_id).is_import() || is_exported(semantic, symbol_id) {
+        return None;
+    }
+
+    Some((span, symbol_id))
+}
+
+/// Whether the symbol is declared by an exported declaration, e.g. `export const a = 1;`.
+fn is_exported(semantic: &Semantic<'_>, symbol_id: SymbolId) -> bool {
+    let scoping = semantic.scoping();
+    let nodes = semantic.nodes();
+    nodes
+        .ancestors(scoping.symbol_declaration(symbol_id))
+        .find(|node| matches!(node.kind(), AstKind::ExportNamedDeclaration(_)))
+        .is_some_and(|node| node.scope_id() == scoping.symbol_scope_id(symbol_id))
+}
+
+/// Whether renaming the symbol to `new_name` would shadow or be shadowed by another binding,
+/// or would capture a reference to a global variable.
+fn has_conflicting_binding(semantic: &Semantic<'_>, symbol_id: SymbolId, new_name: &str) -> bool {
+    let scoping = semantic.scoping();
+    if scoping.root_unresolved_references().contains_key(new_name) {
+        return true;
+    }
+
+    std::iter::once(scoping.symbol_scope_id(symbol_id))
+        .chain(
+            scoping
+                .get_resolved_references(symbol_id)
+                .map(|reference| semantic.nodes().get_node(reference.node_id()).scope_id()),
+        )
+        .any(|scope_id| scoping.find_binding(scope_id, new_name).is_some())
+}
+
+/// Find the identifier containing the offset, together with the symbol it declares or refers to.
+struct IdentifierFinder<'s> {
+    offset: u32,
+    scoping: &'s Scoping,
+    /// The span of the first identifier containing the offset, and its symbol if it is resolved.
+    found: Option<(Span, Option<SymbolId>)>,
+}
+
+impl IdentifierFinder<'_> {
+    fn contains_offset(&self, span: Span) -> bool {
+        self.found.is_none() && span.start <= self.offset && self.offset <= span.end
+    }
+}
+
+impl<'a> Visit<'a> for IdentifierFinder<'_> {
+    fn visit_binding_identifier(&mut self, it: &BindingIdentifier<'a>) {
+        if self.contains_offset(it.span) {
+            self.found = Some((it.span, it.symbol_id.get()));
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if self.contains_offset(it.span) {
+            let symbol_id = it
+                .reference_id
+                .get()
+                .and_then(|reference_id| self.scoping.get_reference(reference_id).symbol_id());
+            self.found = Some((it.span, symbol_id));
+        }
+    }
+}
+
+/// Collect the spans of all identifiers declaring or referring to the symbol.
+struct RenameCollector<'s> {
+    symbol_id: SymbolId,
+    scoping: &'s Scoping,
+    /// Spans of shorthand properties, e.g. `{ a }`, which are renamed to `{ a: b }`.
+    shorthand_spans: Vec<Span>,
+    /// Spans of export specifiers without an alias, e.g. `export { a }`, which are renamed to `export { b as a }`.
+    export_spans: Vec<Span>,
+    spans: Vec<Span>,
+}
+
+impl<'a> Visit<'a> for RenameCollector<'_> {
+    fn visit_binding_identifier(&mut self, it: &BindingIdentifier<'a>) {
+        if it.symbol_id.get() == Some(self.symbol_id) {
+            self.spans.push(it.span);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        let symbol_id = it
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.scoping.get_reference(reference_id).symbol_id());
+        if symbol_id == Some(self.symbol_id) {
+            self.spans.push(it.span);
+        }
+    }
+
+    fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
+        if it.shorthand {
+            self.shorthand_spans.push(it.key.span());
+        }
+        walk::walk_object_property(self, it);
+    }
+
+    fn visit_binding_property(&mut self, it: &BindingProperty<'a>) {
+        if it.shorthand {
+            self.shorthand_spans.push(it.key.span());
+        }
+        walk::walk_binding_property(self, it);
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        it: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.shorthand_spans.push(it.binding.span);
+        walk::walk_assignment_target_property_identifier(self, it);
+    }
+
+    fn visit_export_specifier(&mut self, it: &ExportSpecifier<'a>) {
+        if it.local.span() == it.exported.span() {
+            self.export_spans.push(it.local.span());
+        }
+        walk::walk_export_specifier(self, it);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+    use tower_lsp_server::ls_types::{Position, Range};
+
+    use super::{get_rename_edits, get_rename_range};
+    use crate::lsp::ast_cache::ParsedSource;
+
+    fn rename_range(source: &str, position: Position) -> Option<Range> {
+        let parsed = ParsedSource::parse(source.to_string(), SourceType::mjs());
+        get_rename_range(parsed.borrow_dependent(), position)
+    }
+
+    /// Rename the binding at the position and apply the edits to the source.
+    fn rename(source: &str, position: Position, new_name: &str) -> Option<String> {
+        let parsed = ParsedSource::parse(source.to_string(), SourceType::mjs());
+        let edits = get_rename_edits(parsed.borrow_dependent(), position, new_name);
+        if edits.is_empty() {
+            return None;
+        }
+        let mut lines = source.lines().map(str::to_string).collect::<Vec<_>>();
+        // the edits are sorted, apply them from the end to keep the positions valid
+        for edit in edits.iter().rev() {
+            assert_eq!(edit.range.start.line, edit.range.end.line);
+            let line = &mut lines[edit.range.start.line as usize];
+            line.replace_range(
+                edit.range.start.character as usize..edit.range.end.character as usize,
+                &edit.new_text,
+            );
+        }
+        Some(lines.join("\n"))
+    }
+
+    #[test]
+    fn test_local_bindings() {
+        let source = "function foo(bar) {\n  return bar + 1;\n}";
+        // declarations
+        assert_eq!(
+            rename_range(source, Position::new(0, 10)),
+            Some(Range::new(Position::new(0, 9), Position::new(0, 12)))
+        );
+        assert_eq!(
+            rename_range(source, Position::new(0, 13)),
+            Some(Range::new(Position::new(0, 13), Position::new(0, 16)))
+        );
+        // reference, with the cursor at the end of the identifier
+        assert_eq!(
+            rename_range(source, Position::new(1, 12)),
+            Some(Range::new(Position::new(1, 9), Position::new(1, 12)))
+        );
+    }
+
+    #[test]
+    fn test_not_renameable() {
+        let source = "import { a } from 'a';\nconsole.log(a, 'b', 1);";
+        // keyword
+        assert_eq!(rename_range(source, Position::new(0, 2)), None);
+        // import
+        assert_eq!(rename_range(source, Position::new(0, 9)), None);
+        assert_eq!(rename_range(source, Position::new(1, 12)), None);
+        // global
+        assert_eq!(rename_range(source, Position::new(1, 2)), None);
+        // property
+        assert_eq!(rename_range(source, Position::new(1, 9)), None);
+        // literals
+        assert_eq!(rename_range(source, Position::new(1, 16)), None);
+        assert_eq!(rename_range(source, Position::new(1, 20)), None);
+    }
+
+    #[test]
+    fn test_rename_edits() {
+        let source = "function foo(bar) {\n  return bar + 1;\n}";
+        assert_eq!(
+            rename(source, Position::new(1, 10), "baz").as_deref(),
+            Some("function foo(baz) {\n  return baz + 1;\n}")
+        );
+        assert_eq!(
+            rename(source, Position::new(0, 10), "total").as_deref(),
+            Some("function total(bar) {\n  return bar + 1;\n}")
+        );
+    }
+
+    #[test]
+    fn test_rename_keeps_property_keys_and_export_names() {
+        let source = "const a = 1;\nconst { b = a } = { a };\n({ a } = {});\nexport { a, b as c };";
+        assert_eq!(
+            rename(source, Position::new(0, 6), "x").as_deref(),
+            Some(
+                "const x = 1;\nconst { b = x } = { a: x };\n({ a: x } = {});\nexport { x as a, b as c };"
+            )
+        );
+        assert_eq!(
+            rename(source, Position::new(1, 8), "y").as_deref(),
+            Some("const a = 1;\nconst { b: y = a } = { a };\n({ a } = {});\nexport { a, y as c };")
+        );
+    }
+
+    #[test]
+    fn test_rename_rejected() {
+        let source = "import { a } from 'a';\nexport const b = 1;\nfunction foo(c) {\n  const d = 2;\n  return c + d + a + e;\n}";
+        // imports and exports
+        assert_eq!(rename(source, Position::new(0, 9), "x"), None);
+        assert_eq!(rename(source, Position::new(1, 13), "x"), None);
+        // globals
+        assert_eq!(rename(source, Position::new(4, 22), "x"), None);
+        // invalid names
+        assert_eq!(rename(source, Position::new(2, 13), "1x"), None);
+        assert_eq!(rename(source, Position::new(2, 13), "class"), None);
+        // conflicts with a local binding, an outer binding or a global variable
+        assert_eq!(rename(source, Position::new(2, 13), "d"), None);
+        assert_eq!(rename(source, Position::new(2, 13), "foo"), None);
+        assert_eq!(rename(source, Position::new(2, 13), "e"), None);
+        // the same name
+        assert_eq!(rename(source, Position::new(2, 13), "c"), None);
+        // renameable
+        assert_eq!(
+            rename(source, Position::new(2, 13), "x").map(|s| s.contains("return x + d")),
+            Some(true)
+        );
+    }
+}
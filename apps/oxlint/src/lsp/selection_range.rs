@@ -0,0 +1,128 @@
+use oxc_ast::{AstKind, ast::Program};
+use oxc_ast_visit::Visit;
+use oxc_data_structures::rope::Rope;
+use oxc_span::{GetSpan, Span};
+use tower_lsp_server::ls_types::{Position, Range, SelectionRange};
+
+use crate::lsp::error_with_position::{offset_to_position, position_to_offset};
+
+/// Collect the selection ranges of a JavaScript / TypeScript source text for every position.
+///
+/// Each selection range starts at the innermost AST node containing the position,
+/// its parents are the enclosing nodes up to the program. Nodes sharing the same span are reported once.
+pub fn get_selection_ranges(program: &Program<'_>, positions: &[Position]) -> Vec<SelectionRange> {
+    let source_text = program.source_text;
+    let rope = Rope::from_str(source_text);
+
+    positions
+        .iter()
+        .map(|position| {
+            let mut collector = SelectionRangeCollector {
+                offset: position_to_offset(&rope, *position, source_text),
+                spans: Vec::new(),
+                done: false,
+            };
+            collector.visit_program(program);
+
+            let mut selection_range: Option<SelectionRange> = None;
+            for span in collector.spans {
+                selection_range = Some(SelectionRange {
+                    range: Range::new(
+                        offset_to_position(&rope, span.start, source_text),
+                        offset_to_position(&rope, span.end, source_text),
+                    ),
+                    parent: selection_range.map(Box::new),
+                });
+            }
+            // Every position is inside the program, fall back to an empty range for empty files
+            selection_range
+                .unwrap_or(SelectionRange { range: Range::new(*position, *position), parent: None })
+        })
+        .collect()
+}
+
+struct SelectionRangeCollector {
+    offset: u32,
+    /// The spans containing the offset, from the program to the innermost node.
+    spans: Vec<Span>,
+    /// Set once the innermost node is left, following siblings touching the offset are ignored.
+    done: bool,
+}
+
+impl<'a> Visit<'a> for SelectionRangeCollector {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let span = kind.span();
+        if self.done || self.offset < span.start || self.offset > span.end {
+            return;
+        }
+        if self.spans.last() != Some(&span) {
+            self.spans.push(span);
+        }
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if self.spans.last() == Some(&kind.span()) {
+            self.done = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+    use tower_lsp_server::ls_types::{Position, SelectionRange};
+
+    use crate::lsp::ast_cache::ParsedSource;
+
+    fn get_selection_ranges(source_text: &str, positions: &[Position]) -> Vec<SelectionRange> {
+        let parsed = ParsedSource::parse(source_text.to_string(), SourceType::mjs());
+        super::get_selection_ranges(&parsed.borrow_dependent().program, positions)
+    }
+
+    /// The source text of every range, from the innermost to the outermost one.
+    fn get_chain(source: &str, position: Position) -> Vec<String> {
+        let lines: Vec<&str> = source.lines().collect();
+        let mut result = Vec::new();
+        let selection_ranges = get_selection_ranges(source, &[position]);
+        let mut current: Option<&SelectionRange> = selection_ranges.first();
+        while let Some(selection_range) = current {
+            let range = selection_range.range;
+            let text = if range.start.line == range.end.line {
+                lines[range.start.line as usize]
+                    [range.start.character as usize..range.end.character as usize]
+                    .to_string()
+            } else {
+                format!("{}..{}", range.start.line, range.end.line)
+            };
+            result.push(text);
+            current = selection_range.parent.as_deref();
+        }
+        result
+    }
+
+    #[test]
+    fn test_identifier_to_program() {
+        let chain = get_chain("let a = foo(bar + 1);", Position::new(0, 13));
+        assert_eq!(
+            chain,
+            vec!["bar", "bar + 1", "foo(bar + 1)", "a = foo(bar + 1)", "let a = foo(bar + 1);"]
+        );
+    }
+
+    #[test]
+    fn test_touching_siblings_prefer_first() {
+        // The position is at the end of `a` and at the start of `.b`
+        let chain = get_chain("a.b;", Position::new(0, 1));
+        assert_eq!(chain, vec!["a", "a.b", "a.b;"]);
+    }
+
+    #[test]
+    fn test_position_out_of_bounds() {
+        let chain = get_chain("foo;\n", Position::new(5, 0));
+        assert_eq!(chain, vec!["0..1"]);
+
+        let ranges = get_selection_ranges("", &[Position::new(0, 0)]);
+        assert_eq!(ranges.len(), 1);
+        assert!(ranges[0].parent.is_none());
+    }
+}
@@ -1,16 +1,25 @@
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, OnceLock};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::time::Instant;
 
+use cow_utils::CowUtils;
 use ignore::gitignore::Gitignore;
 use oxc_data_structures::rope::Rope;
+use rayon::prelude::*;
 use rustc_hash::{FxHashMap, FxHashSet};
 use tower_lsp_server::ls_types::{DiagnosticOptions, DiagnosticServerCapabilities};
 use tower_lsp_server::{
     jsonrpc::ErrorCode,
     ls_types::{
-        CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionProviderCapability,
-        Diagnostic, ExecuteCommandOptions, Pattern, Range, ServerCapabilities, Uri,
-        WorkDoneProgressOptions, WorkspaceEdit,
+        AnnotatedTextEdit, ChangeAnnotation, CodeActionKind, CodeActionOptions,
+        CodeActionOrCommand, CodeActionProviderCapability, CompletionItem, CompletionOptions,
+        Diagnostic, DiagnosticSeverity, DocumentChanges, DocumentSymbol, ExecuteCommandOptions,
+        FoldingRange, FoldingRangeProviderCapability, InlayHint, OneOf,
+        OptionalVersionedTextDocumentIdentifier, Pattern, Position, Range, RenameOptions,
+        SelectionRange, SelectionRangeProviderCapability, ServerCapabilities, SymbolInformation,
+        TextDocumentEdit, TextEdit, Uri, WorkDoneProgress, WorkDoneProgressBegin,
+        WorkDoneProgressEnd, WorkDoneProgressOptions, WorkDoneProgressReport, WorkspaceEdit,
     },
 };
 use tracing::{debug, error, warn};
@@ -21,29 +30,51 @@ use oxc_linter::{
     LintServiceOptions, Linter, Oxlintrc, read_to_string,
 };
 
+use oxc_span::SourceType;
+
 use oxc_language_server::{
-    Capabilities, ConcurrentHashMap, DiagnosticMode, DiagnosticResult, Tool, ToolBuilder,
-    ToolRestartChanges,
+    Capabilities, CommandResult, ConcurrentHashMap, DiagnosticMode, DiagnosticResult,
+    LSPFileSystem, Tool, ToolBuilder, ToolRestartChanges,
 };
 
 use crate::{
+    DEFAULT_OXLINTRC_NAME, DEFAULT_TS_OXLINTRC_NAME,
+    cli::IgnoreOptions,
     config_loader::{ConfigLoader, build_nested_configs, discover_configs_in_tree},
     lsp::{
+        ast_cache::{AstCache, ParsedProgram},
         code_actions::{
-            CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, apply_all_fix_code_action, apply_fix_code_actions,
-            fix_all_text_edit,
+            CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, FixAllTextEdits, apply_all_fix_code_action,
+            apply_fix_code_actions, fix_all_text_edit, rule_severity_code_actions,
+        },
+        commands::{
+            FIX_ALL_CHANGE_ANNOTATION_ID, FIX_ALL_COMMAND_ID, FixAllCommandArgs,
+            LINT_FILE_COMMAND_ID, LintFileCommandArgs, SHOW_STATS_COMMAND_ID,
+            WHICH_CONFIG_COMMAND_ID, WhichConfig, WhichConfigCommandArgs,
         },
-        commands::{FIX_ALL_COMMAND_ID, FixAllCommandArgs},
+        completion::get_rule_name_completions,
+        config_diagnostics::{get_config_diagnostics, get_rule_conflict_diagnostics},
+        document_symbol::{get_document_symbols, get_workspace_symbols},
         error_with_position::{
             DiagnosticReport, LinterCodeAction, create_unused_directives_messages,
             generate_inverted_diagnostics, message_to_lsp_diagnostic,
         },
+        extract_to_config::get_extract_to_config_code_actions,
+        folding_range::get_folding_ranges,
+        inlay_hint::get_unused_directive_hints,
         lsp_file_system::LspFileSystem,
-        options::{LintOptions as LSPLintOptions, Run, UnusedDisableDirectives},
+        options::{LintOptions as LSPLintOptions, MinSeverity, Run, UnusedDisableDirectives},
+        rename::{get_rename_edits, get_rename_range},
+        selection_range::get_selection_ranges,
+        stats::{FileStats, LintStats},
         utils::normalize_path,
     },
+    walk::Walk,
 };
 
+/// Number of files per thread, which are linted in one batch of the workspace scan.
+const WORKSPACE_SCAN_FILES_PER_THREAD: usize = 8;
+
 #[derive(Default)]
 pub struct ServerLinterBuilder {
     external_linter: Option<ExternalLinter>,
@@ -76,6 +107,12 @@ impl ServerLinterBuilder {
             }
         };
         let root_path = root_uri.to_file_path().unwrap();
+        // Relative paths of the options and the root config are resolved against the working directory
+        let working_directory = match options.working_directory.as_deref().filter(|p| !p.is_empty())
+        {
+            Some(dir) => normalize_path(root_path.join(dir)),
+            None => root_path.to_path_buf(),
+        };
         let mut external_plugin_store = ExternalPluginStore::new(self.external_linter.is_some());
 
         // Setup JS workspace. This must be done before loading any configs
@@ -89,18 +126,23 @@ impl ServerLinterBuilder {
 
         let mut nested_ignore_patterns = Vec::new();
         let mut extended_paths = FxHashSet::default();
+        let mut config_files = FxHashMap::default();
         let nested_configs = if options.use_nested_configs() {
             self.create_nested_configs(
                 &root_path,
                 &mut external_plugin_store,
                 &mut nested_ignore_patterns,
                 &mut extended_paths,
+                &mut config_files,
                 Some(root_uri.as_str()),
             )
         } else {
             FxHashMap::default()
         };
 
+        // The config of the root directory is also discovered as nested config
+        let nested_config_count = nested_configs.keys().filter(|dir| **dir != *root_path).count();
+
         let config_path = options.config_path.as_ref().filter(|p| !p.is_empty()).map(PathBuf::from);
         let loader = ConfigLoader::new(
             self.external_linter.as_ref(),
@@ -111,7 +153,7 @@ impl ServerLinterBuilder {
         #[cfg(feature = "napi")]
         let loader = loader.with_js_config_loader(self.js_config_loader.as_ref());
 
-        let oxlintrc = match loader.load_root_config(&root_path, config_path.as_ref()) {
+        let oxlintrc = match loader.load_root_config(&working_directory, config_path.as_ref()) {
             Ok(config) => config,
             Err(e) => {
                 warn!("Failed to load config: {e}");
@@ -120,6 +162,8 @@ impl ServerLinterBuilder {
         };
 
         let base_patterns = oxlintrc.ignore_patterns.clone();
+        let root_config_file = oxlintrc.path.clone();
+        let root_config_display = root_config_file.to_string_lossy().into_owned();
 
         let config_builder = ConfigStoreBuilder::from_oxlintrc(
             false,
@@ -139,6 +183,14 @@ impl ServerLinterBuilder {
                 && nested_configs.values().any(|config| config.plugins().has_import()));
 
         extended_paths.extend(config_builder.extended_paths.clone());
+        // The root config is used for every file without a nested config, the default config has no file
+        if !root_config_file.as_os_str().is_empty() {
+            config_files.entry(root_path.to_path_buf()).or_insert_with(|| {
+                std::iter::once(root_config_file)
+                    .chain(config_builder.extended_paths.iter().cloned())
+                    .collect()
+            });
+        }
         let base_config = config_builder.build(&mut external_plugin_store).unwrap_or_else(|err| {
             warn!("Failed to build config: {err}");
             ConfigStoreBuilder::empty().build(&mut ExternalPluginStore::new(false)).unwrap()
@@ -158,6 +210,7 @@ impl ServerLinterBuilder {
 
         let config_store = ConfigStore::new(base_config, nested_configs, external_plugin_store);
         let config_store_clone = config_store.clone();
+        let server_config_store = config_store.clone();
 
         // Send JS plugins config to JS side
         if let Some(external_linter) = &external_linter {
@@ -178,7 +231,8 @@ impl ServerLinterBuilder {
 
         if let Some(ts_path) = options.ts_config_path.as_ref() {
             let ts_path = Path::new(ts_path).to_path_buf();
-            let ts_path = if ts_path.is_relative() { root_path.join(ts_path) } else { ts_path };
+            let ts_path =
+                if ts_path.is_relative() { working_directory.join(ts_path) } else { ts_path };
             if ts_path.is_file() {
                 lint_service_options = lint_service_options.with_tsconfig(&ts_path);
             }
@@ -203,15 +257,35 @@ impl ServerLinterBuilder {
             }
         };
 
-        ServerLinter::new(
+        let server_linter = ServerLinter::new(
             options.run,
             root_path.to_path_buf(),
-            LintIgnoreMatcher::new(&base_patterns, &root_path, nested_ignore_patterns),
+            LintIgnoreMatcher::new(&base_patterns, &working_directory, nested_ignore_patterns),
             Self::create_ignore_glob(&root_path),
             extended_paths,
+            config_path.map(|path| working_directory.join(path)),
+            config_files,
+            server_config_store,
             runner,
             lint_options.report_unused_directive,
-        )
+            options.min_severity,
+            options.max_number_of_problems,
+            options.verbose,
+            options.lint_only_open_files,
+            options.diagnostic_source.unwrap_or_else(|| "oxc".to_string()),
+        );
+        server_linter.log(|| {
+            let root_config = if root_config_display.is_empty() {
+                "default config".to_string()
+            } else {
+                root_config_display
+            };
+            format!(
+                "config loaded for {}: {root_config}, {nested_config_count} nested configs",
+                root_path.display()
+            )
+        });
+        server_linter
     }
 }
 
@@ -239,6 +313,9 @@ impl ToolBuilder for ServerLinterBuilder {
         if !code_action_kinds.contains(&CodeActionKind::SOURCE_FIX_ALL) {
             code_action_kinds.push(CodeActionKind::SOURCE_FIX_ALL);
         }
+        if !code_action_kinds.contains(&CodeActionKind::REFACTOR_EXTRACT) {
+            code_action_kinds.push(CodeActionKind::REFACTOR_EXTRACT);
+        }
 
         // override code action kinds if the code action provider is already set
         capabilities.code_action_provider =
@@ -267,8 +344,15 @@ impl ToolBuilder for ServerLinterBuilder {
             .as_ref()
             .map_or(vec![], |opts| opts.commands.clone());
 
-        if !commands.contains(&FIX_ALL_COMMAND_ID.to_string()) {
-            commands.push(FIX_ALL_COMMAND_ID.to_string());
+        for command in [
+            FIX_ALL_COMMAND_ID,
+            SHOW_STATS_COMMAND_ID,
+            LINT_FILE_COMMAND_ID,
+            WHICH_CONFIG_COMMAND_ID,
+        ] {
+            if !commands.iter().any(|c| c == command) {
+                commands.push(command.to_string());
+            }
         }
 
         capabilities.execute_command_provider = Some(ExecuteCommandOptions {
@@ -281,6 +365,22 @@ impl ToolBuilder for ServerLinterBuilder {
             },
         });
 
+        capabilities.folding_range_provider = Some(FoldingRangeProviderCapability::Simple(true));
+        capabilities.document_symbol_provider = Some(OneOf::Left(true));
+        capabilities.workspace_symbol_provider = Some(OneOf::Left(true));
+        capabilities.inlay_hint_provider = Some(OneOf::Left(true));
+        capabilities.selection_range_provider =
+            Some(SelectionRangeProviderCapability::Simple(true));
+        capabilities.rename_provider = Some(OneOf::Right(RenameOptions {
+            prepare_provider: Some(true),
+            work_done_progress_options: WorkDoneProgressOptions::default(),
+        }));
+        // Complete rule names in disable directives, which are separated by spaces and commas
+        capabilities.completion_provider = Some(CompletionOptions {
+            trigger_characters: Some(vec![" ".to_string(), ",".to_string()]),
+            ..CompletionOptions::default()
+        });
+
         // The server supports pull and push diagnostics.
         // Only use push diagnostics if the client does not support pull diagnostics,
         // or we cannot ask the client to refresh diagnostics.
@@ -329,6 +429,7 @@ impl ServerLinterBuilder {
         external_plugin_store: &mut ExternalPluginStore,
         nested_ignore_patterns: &mut Vec<(Vec<String>, PathBuf)>,
         extended_paths: &mut FxHashSet<PathBuf>,
+        config_files: &mut FxHashMap<PathBuf, Vec<PathBuf>>,
         workspace_uri: Option<&str>,
     ) -> FxHashMap<PathBuf, Config> {
         let config_paths = discover_configs_in_tree(root_path);
@@ -356,6 +457,13 @@ impl ServerLinterBuilder {
             }
         }
 
+        for loaded in &configs {
+            let files = std::iter::once(loaded.path.clone())
+                .chain(loaded.extended_paths.iter().cloned())
+                .collect();
+            config_files.insert(loaded.dir.clone(), files);
+        }
+
         build_nested_configs(configs, nested_ignore_patterns, Some(extended_paths))
     }
 
@@ -397,12 +505,38 @@ impl ServerLinterBuilder {
 pub struct ServerLinter {
     run: Run,
     cwd: PathBuf,
-    ignore_matcher: LintIgnoreMatcher,
+    ignore_matcher: Arc<LintIgnoreMatcher>,
     gitignore_glob: Vec<Gitignore>,
     extended_paths: FxHashSet<PathBuf>,
+    /// The config path provided by the client, if any
+    config_path: Option<PathBuf>,
+    /// The config file of every directory with a config, followed by the files it extends in precedence order
+    config_files: FxHashMap<PathBuf, Vec<PathBuf>>,
+    /// The text of the nearest config file of every directory, if it is a JSON config which can be edited.
+    /// Loaded together with the config, the linter is rebuilt when a config file changes.
+    config_texts: FxHashMap<PathBuf, String>,
+    /// Used to resolve the rules of a file, e.g. for the severity code actions
+    /// and the rule count of the [`LintStats`]
+    config_store: ConfigStore,
     code_actions: Arc<ConcurrentHashMap<Uri, Option<Vec<LinterCodeAction>>>>,
+    /// Inlay hints of the unused disable directives of every linted file
+    unused_directive_hints: ConcurrentHashMap<Uri, Vec<InlayHint>>,
+    /// Lint statistics of every linted file, reset when the linter is rebuilt on config changes
+    stats: ConcurrentHashMap<Uri, FileStats>,
+    /// The parsed program of every document, shared by the requests for the same version of a document
+    ast_cache: AstCache,
     runner: LintRunner,
     unused_directives_severity: Option<AllowWarnDeny>,
+    min_severity: MinSeverity,
+    /// The maximum number of diagnostics reported per file, `None` for no limit
+    max_number_of_problems: Option<usize>,
+    /// Collect trace messages of the lifecycle events, see [`Tool::take_log_messages`]
+    verbose: bool,
+    log_messages: Mutex<Vec<String>>,
+    /// Skip the workspace scan, only the files opened by the client are linted
+    lint_only_open_files: bool,
+    /// The `source` of every published diagnostic, to tell them apart from other linters
+    diagnostic_source: String,
 }
 
 impl Tool for ServerLinter {
@@ -520,12 +654,19 @@ impl Tool for ServerLinter {
     /// Check if the linter should know about the given command
     fn is_responsible_for_command(&self, command: &str) -> bool {
         command == FIX_ALL_COMMAND_ID
+            || command == SHOW_STATS_COMMAND_ID
+            || command == LINT_FILE_COMMAND_ID
+            || command == WHICH_CONFIG_COMMAND_ID
     }
 
     /// Tries to execute the given command with the provided arguments.
     /// If the command is not recognized, returns `Ok(None)`.
     /// If the command is recognized and executed it can return:
-    /// - `Ok(Some(WorkspaceEdit))` if the command was executed successfully and produced a workspace edit.
+    /// - `Ok(Some(CommandResult::WorkspaceEdit))` if the command was executed successfully and produced a workspace edit,
+    ///   with a message when conflicting fixes were skipped.
+    /// - `Ok(Some(CommandResult::Value))` with the [`LintStats`] for the `oxc.showStats` command.
+    /// - `Ok(Some(CommandResult::Diagnostics))` with the diagnostics of the file for the `oxc.lintFile` command.
+    /// - `Ok(Some(CommandResult::Value))` with the [`WhichConfig`] of the file for the `oxc.whichConfig` command.
     /// - `Ok(None)` if the command was executed successfully but did not produce any workspace edit.
     ///
     /// # Errors
@@ -534,7 +675,47 @@ impl Tool for ServerLinter {
         &self,
         command: &str,
         arguments: Vec<serde_json::Value>,
-    ) -> Result<Option<WorkspaceEdit>, ErrorCode> {
+        file_system: &LSPFileSystem,
+    ) -> Result<Option<CommandResult>, ErrorCode> {
+        if command == SHOW_STATS_COMMAND_ID {
+            let stats = LintStats::new(self.stats.pin().values().cloned().collect());
+            let value = serde_json::to_value(stats).map_err(|_| ErrorCode::InternalError)?;
+            return Ok(Some(CommandResult::Value(value)));
+        }
+
+        if command == LINT_FILE_COMMAND_ID {
+            let args =
+                LintFileCommandArgs::try_from(arguments).map_err(|_| ErrorCode::InvalidParams)?;
+            let uri: Uri = args.uri.parse().map_err(|_| ErrorCode::InvalidParams)?;
+
+            if !self.is_responsible_for_uri(&uri) {
+                return Ok(None);
+            }
+
+            // The file does not need to be opened by the client, unopened files are read from disk
+            let content = file_system.get(&uri);
+            let diagnostics = self.run_diagnostic(&uri, content.as_deref()).map_err(|err| {
+                error!("linting {} failed: {err}", uri.as_str());
+                ErrorCode::InternalError
+            })?;
+            return Ok(Some(CommandResult::Diagnostics(diagnostics)));
+        }
+
+        if command == WHICH_CONFIG_COMMAND_ID {
+            let args = WhichConfigCommandArgs::try_from(arguments)
+                .map_err(|_| ErrorCode::InvalidParams)?;
+            let uri: Uri = args.uri.parse().map_err(|_| ErrorCode::InvalidParams)?;
+
+            if !self.is_responsible_for_uri(&uri) {
+                return Ok(None);
+            }
+
+            let path = uri.to_file_path().ok_or(ErrorCode::InvalidParams)?;
+            let which_config = WhichConfig::new(&path, self.get_config_files(&path));
+            let value = serde_json::to_value(which_config).map_err(|_| ErrorCode::InternalError)?;
+            return Ok(Some(CommandResult::Value(value)));
+        }
+
         if command != FIX_ALL_COMMAND_ID {
             return Ok(None);
         }
@@ -546,7 +727,8 @@ impl Tool for ServerLinter {
             return Ok(None);
         }
 
-        let actions = self.get_code_actions_for_uri(&uri);
+        let content = file_system.get(&uri);
+        let actions = self.get_code_actions_for_uri(&uri, content.as_deref());
 
         let Some(actions) = actions else {
             return Ok(None);
@@ -556,60 +738,139 @@ impl Tool for ServerLinter {
             return Ok(None);
         }
 
-        let text_edits = fix_all_text_edit(actions.into_iter());
+        let FixAllTextEdits { text_edits, skipped_conflicts } =
+            fix_all_text_edit(actions.into_iter());
+        self.log(|| format!("applied {} fixes to {}", text_edits.len(), uri.as_str()));
 
-        Ok(Some(WorkspaceEdit {
-            #[expect(clippy::disallowed_types)]
-            changes: Some(std::collections::HashMap::from([(uri, text_edits)])),
-            document_changes: None,
-            change_annotations: None,
+        // Tell the user about the skipped fixes, a rerun of the command can apply them
+        let message = (skipped_conflicts > 0).then(|| {
+            let fixes = if skipped_conflicts == 1 { "fix was" } else { "fixes were" };
+            format!(
+                "{skipped_conflicts} {fixes} skipped because of conflicts with other fixes. Run the command again to apply them."
+            )
+        });
+
+        // The annotation groups all fixes into one labeled undo step in the editor
+        let text_edits = text_edits
+            .into_iter()
+            .map(|text_edit| {
+                OneOf::Right(AnnotatedTextEdit {
+                    text_edit,
+                    annotation_id: FIX_ALL_CHANGE_ANNOTATION_ID.to_string(),
+                })
+            })
+            .collect();
+
+        Ok(Some(CommandResult::WorkspaceEdit {
+            edit: WorkspaceEdit {
+                changes: None,
+                document_changes: Some(DocumentChanges::Edits(vec![TextDocumentEdit {
+                    text_document: OptionalVersionedTextDocumentIdentifier { uri, version: None },
+                    edits: text_edits,
+                }])),
+                #[expect(clippy::disallowed_types)]
+                change_annotations: Some(std::collections::HashMap::from([(
+                    FIX_ALL_CHANGE_ANNOTATION_ID.to_string(),
+                    ChangeAnnotation {
+                        label: "Fix all oxlint problems".to_string(),
+                        needs_confirmation: None,
+                        description: None,
+                    },
+                )])),
+            },
+            message,
         }))
     }
 
+    /// Merge the [`LintStats`] of every workspace for the `oxc.showStats` command.
+    fn merge_command_values(
+        &self,
+        command: &str,
+        values: Vec<serde_json::Value>,
+    ) -> serde_json::Value {
+        if command != SHOW_STATS_COMMAND_ID {
+            return values.into_iter().next().unwrap_or_default();
+        }
+        let files = values
+            .into_iter()
+            .filter_map(|value| serde_json::from_value::<LintStats>(value).ok())
+            .flat_map(|stats| stats.files)
+            .collect();
+        serde_json::to_value(LintStats::new(files)).unwrap_or_default()
+    }
+
     fn get_code_actions_or_commands(
         &self,
         uri: &Uri,
+        content: Option<&str>,
         range: &Range,
         only_code_action_kinds: Option<&Vec<CodeActionKind>>,
     ) -> Vec<CodeActionOrCommand> {
-        let actions = self.get_code_actions_for_uri(uri);
+        // Refactorings edit the config file, only offer them when the client asks for them
+        let is_extract_requested = only_code_action_kinds.is_some_and(|only| {
+            only.iter().any(|kind| is_kind_or_parent_kind(kind, &CodeActionKind::REFACTOR_EXTRACT))
+        });
+        let is_refactor_requested = only_code_action_kinds.is_some_and(|only| {
+            only.iter().any(|kind| is_kind_or_parent_kind(kind, &CodeActionKind::REFACTOR))
+        });
+        let extract_actions = if is_extract_requested {
+            self.get_extract_to_config_code_actions(uri, content, range)
+        } else {
+            vec![]
+        };
+
+        let actions = self.get_code_actions_for_uri(uri, content);
 
         let Some(actions) = actions else {
-            return vec![];
+            return extract_actions;
         };
 
         if actions.is_empty() {
-            return vec![];
+            return extract_actions;
         }
 
-        let actions =
-            actions.into_iter().filter(|r| r.range == *range || range_overlaps(*range, r.range));
-        // if `source.fixAll.oxc` or `source.fixAll` is requested, return a single code action that applies all fixes
+        // if `source.fixAll.oxc` or one of its parent kinds (e.g. `source.fixAll`) is requested,
+        // return a single code action that applies all fixes of the file, independent of the range
         let is_source_fix_all = only_code_action_kinds.is_some_and(|only| {
-            only.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC)
-                || only.contains(&CodeActionKind::SOURCE_FIX_ALL)
+            only.iter()
+                .any(|kind| is_kind_or_parent_kind(kind, &CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC))
         });
 
         if is_source_fix_all {
-            return apply_all_fix_code_action(actions, uri.clone())
+            return apply_all_fix_code_action(actions.into_iter(), uri.clone())
                 .map_or(vec![], |code_actions| {
                     vec![CodeActionOrCommand::CodeAction(code_actions)]
                 });
         }
 
+        let actions =
+            actions.into_iter().filter(|r| r.range == *range || range_overlaps(*range, r.range));
+
         let mut code_actions_vec: Vec<CodeActionOrCommand> = vec![];
+        let mut rule_names = FxHashSet::default();
 
         for action in actions {
+            if is_refactor_requested && let Some(rule_name) = &action.rule_name {
+                rule_names.insert(rule_name.clone());
+            }
             let fix_actions = apply_fix_code_actions(action, uri);
             code_actions_vec.extend(fix_actions.into_iter().map(CodeActionOrCommand::CodeAction));
         }
+        code_actions_vec.extend(self.get_rule_severity_code_actions(uri, rule_names));
+        code_actions_vec.extend(extract_actions);
 
         code_actions_vec
     }
 
     /// Lint a file with the current linter
     /// - If the file is not lintable or ignored, an empty vector is returned
+    /// - If the file is a JSON config file, the problems of its rules are reported
     fn run_diagnostic(&self, uri: &Uri, content: Option<&str>) -> DiagnosticResult {
+        if let Some(path) = uri.to_file_path()
+            && self.is_json_config_file(&path)
+        {
+            return Ok(vec![(uri.clone(), self.run_config_file(&path, content)?)]);
+        }
         Ok(vec![(uri.clone(), self.run_file(uri, content)?)])
     }
 
@@ -626,6 +887,8 @@ impl Tool for ServerLinter {
     /// Lint a file with the current linter
     /// - If the file is not lintable or ignored, an empty vector is returned
     /// - If the linter is not set to `OnSave`, an empty vector is returned
+    ///
+    /// A save directly after a change is never linted twice, because only one of both runs for the configured mode.
     fn run_diagnostic_on_save(&self, uri: &Uri, content: Option<&str>) -> DiagnosticResult {
         if self.run != Run::OnSave {
             return Ok(vec![]);
@@ -633,8 +896,170 @@ impl Tool for ServerLinter {
         self.run_diagnostic(uri, content)
     }
 
+    /// Lint every file of the workspace, used when the workspace is opened
+    /// - The files are linted in parallel batches, the progress is reported after each batch
+    /// - Only files with diagnostics are returned
+    fn run_workspace_diagnostic(
+        &self,
+        progress: &(dyn Fn(WorkDoneProgress) + Sync),
+    ) -> DiagnosticResult {
+        if self.lint_only_open_files {
+            self.log(|| "skipping the workspace scan, only open files are linted".to_string());
+            return Ok(Vec::new());
+        }
+
+        let uris = self.collect_workspace_files();
+        let total = uris.len();
+
+        progress(WorkDoneProgress::Begin(WorkDoneProgressBegin {
+            title: "Linting workspace".to_string(),
+            cancellable: Some(false),
+            message: Some(format!("0/{total} files")),
+            percentage: Some(0),
+        }));
+
+        // Bound the number of files linted at once, so the progress can be reported in between
+        let batch_size = rayon::current_num_threads() * WORKSPACE_SCAN_FILES_PER_THREAD;
+        let mut diagnostics = Vec::new();
+        let mut done = 0;
+        for batch in uris.chunks(batch_size) {
+            diagnostics.par_extend(batch.par_iter().filter_map(
+                |uri| match self.run_file(uri, None) {
+                    Ok(file_diagnostics) if !file_diagnostics.is_empty() => {
+                        Some((uri.clone(), file_diagnostics))
+                    }
+                    Ok(_) => None,
+                    Err(err) => {
+                        warn!("linting {} failed: {err}", uri.as_str());
+                        None
+                    }
+                },
+            ));
+
+            done += batch.len();
+            #[expect(clippy::cast_possible_truncation)]
+            let percentage = (done * 100 / total) as u32;
+            progress(WorkDoneProgress::Report(WorkDoneProgressReport {
+                cancellable: Some(false),
+                message: Some(format!("{done}/{total} files")),
+                percentage: Some(percentage),
+            }));
+        }
+
+        progress(WorkDoneProgress::End(WorkDoneProgressEnd {
+            message: Some(format!("Linted {total} files")),
+        }));
+
+        Ok(diagnostics)
+    }
+
+    /// Get the folding ranges of a file from its parsed program
+    /// - If the file is not lintable or ignored, an empty vector is returned
+    fn get_folding_ranges(&self, uri: &Uri, content: Option<&str>) -> Vec<FoldingRange> {
+        self.with_program(uri, content, |parsed| get_folding_ranges(&parsed.program))
+            .unwrap_or_default()
+    }
+
+    /// Get the document symbols of a file from its parsed program
+    /// - If the file is not lintable or ignored, an empty vector is returned
+    fn get_document_symbols(&self, uri: &Uri, content: Option<&str>) -> Vec<DocumentSymbol> {
+        self.with_program(uri, content, |parsed| get_document_symbols(&parsed.program))
+            .unwrap_or_default()
+    }
+
+    /// Get the symbols of the open documents matching the query, from their parsed programs
+    /// - Documents which are not lintable or ignored are skipped
+    fn get_workspace_symbols(
+        &self,
+        query: &str,
+        documents: &[(Uri, String)],
+    ) -> Vec<SymbolInformation> {
+        documents
+            .iter()
+            .filter_map(|(uri, content)| {
+                self.with_program(uri, Some(content), |parsed| {
+                    get_workspace_symbols(uri, &parsed.program, query)
+                })
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Get the selection ranges of a file from its parsed program
+    /// - If the file is not lintable or ignored, an empty vector is returned
+    fn get_selection_ranges(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        positions: &[Position],
+    ) -> Vec<SelectionRange> {
+        self.with_program(uri, content, |parsed| get_selection_ranges(&parsed.program, positions))
+            .unwrap_or_default()
+    }
+
+    /// Get the inlay hints of the unused disable directives inside the range, from the last lint run
+    /// - If the file was not linted yet, it is linted first
+    /// - If the file is not lintable or ignored, an empty vector is returned
+    fn get_inlay_hints(&self, uri: &Uri, content: Option<&str>, range: &Range) -> Vec<InlayHint> {
+        if self.unused_directive_hints.pin().get(uri).is_none() {
+            let _ = self.run_file(uri, content);
+        }
+        self.unused_directive_hints.pin().get(uri).map_or_else(Vec::new, |hints| {
+            hints
+                .iter()
+                .filter(|hint| range.start <= hint.position && hint.position <= range.end)
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Get the range of the local binding at the position, which can be renamed
+    /// - If the file is not lintable or ignored, `None` is returned
+    fn prepare_rename(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        position: Position,
+    ) -> Option<Range> {
+        self.with_program(uri, content, |parsed| get_rename_range(parsed, position))?
+    }
+
+    /// Get the edits renaming the local binding at the position and all its references
+    /// - If the file is not lintable or ignored, or the binding can not be renamed, an empty vector is returned
+    fn rename(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        position: Position,
+        new_name: &str,
+    ) -> Vec<TextEdit> {
+        self.with_program(uri, content, |parsed| get_rename_edits(parsed, position, new_name))
+            .unwrap_or_default()
+    }
+
+    /// Get the rule names to complete inside a disable directive comment
+    /// - If the file is not lintable or ignored, an empty vector is returned
+    fn get_completions(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        position: Position,
+    ) -> Vec<CompletionItem> {
+        let Some((source_text, _)) = self.get_source(uri, content) else {
+            return Vec::new();
+        };
+        get_rule_name_completions(&source_text, position)
+    }
+
     fn remove_uri_cache(&self, uri: &Uri) {
         self.code_actions.pin().remove(uri);
+        self.unused_directive_hints.pin().remove(uri);
+        self.stats.pin().remove(uri);
+        self.ast_cache.remove(uri);
+    }
+
+    fn take_log_messages(&self) -> Vec<String> {
+        std::mem::take(&mut *self.log_messages.lock().unwrap_or_else(PoisonError::into_inner))
     }
 }
 
@@ -647,26 +1072,163 @@ impl ServerLinter {
         ignore_matcher: LintIgnoreMatcher,
         gitignore_glob: Vec<Gitignore>,
         extended_paths: FxHashSet<PathBuf>,
+        config_path: Option<PathBuf>,
+        config_files: FxHashMap<PathBuf, Vec<PathBuf>>,
+        config_store: ConfigStore,
         runner: LintRunner,
         unused_directives_severity: Option<AllowWarnDeny>,
+        min_severity: MinSeverity,
+        max_number_of_problems: Option<usize>,
+        verbose: bool,
+        lint_only_open_files: bool,
+        diagnostic_source: String,
     ) -> Self {
+        let config_texts = config_files
+            .values()
+            .filter_map(|files| files.first())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| Some((path.clone(), read_to_string(path).ok()?)))
+            .collect();
+
         Self {
             run,
             cwd,
-            ignore_matcher,
+            ignore_matcher: Arc::new(ignore_matcher),
             gitignore_glob,
             extended_paths,
+            config_path,
+            config_files,
+            config_texts,
+            config_store,
             code_actions: Arc::new(ConcurrentHashMap::default()),
+            unused_directive_hints: ConcurrentHashMap::default(),
+            stats: ConcurrentHashMap::default(),
+            ast_cache: AstCache::default(),
             runner,
             unused_directives_severity,
+            min_severity,
+            max_number_of_problems,
+            verbose,
+            log_messages: Mutex::new(Vec::new()),
+            lint_only_open_files,
+            diagnostic_source,
+        }
+    }
+
+    /// Collect a trace message, only built when the client asked for verbose output.
+    fn log(&self, message: impl FnOnce() -> String) {
+        if self.verbose {
+            self.log_messages.lock().unwrap_or_else(PoisonError::into_inner).push(message());
+        }
+    }
+
+    /// The config files which apply to the file, in the same way as the [`ConfigStore`] resolves its config:
+    /// the config of the nearest directory, followed by the files it extends.
+    fn get_config_files(&self, path: &Path) -> &[PathBuf] {
+        path.ancestors()
+            .skip(1)
+            .find_map(|dir| self.config_files.get(dir))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The path and content of the nearest config file of the file, if it can be edited.
+    /// Only JSON config files can be edited.
+    fn get_editable_config(&self, uri: &Uri) -> Option<(&Path, &str)> {
+        let path = uri.to_file_path()?;
+        let config_path = self.get_config_files(&path).first()?;
+        let config_text = self.config_texts.get(config_path)?;
+        Some((config_path, config_text))
+    }
+
+    /// The severity of `rule` in the loaded config of the file, rules which are not enabled are `Allow`.
+    /// `rule` is the name of the rule as used in the config file, e.g. `import/no-cycle`.
+    fn get_rule_severity(&self, path: &Path, rule: &str) -> AllowWarnDeny {
+        let (plugin_name, rule_name) = rule.split_once('/').unwrap_or(("eslint", rule));
+        let plugin_name = plugin_name.cow_replace('-', "_");
+        self.config_store
+            .resolve(path)
+            .rules
+            .iter()
+            .find(|(rule, _)| rule.name() == rule_name && rule.plugin_name() == plugin_name)
+            .map_or(AllowWarnDeny::Allow, |(_, severity)| *severity)
+    }
+
+    /// Whether the severity of `rule` for the file is determined by the top level `rules` of its config,
+    /// which are edited by the code actions. It is not when an override which applies to the file configures the rule.
+    fn is_rule_set_by_top_level_rules(&self, path: &Path, rule: &str) -> bool {
+        let (plugin_name, rule_name) = rule.split_once('/').unwrap_or(("eslint", rule));
+        let plugin_name = plugin_name.cow_replace('-', "_");
+        !self.config_store.is_rule_overridden(path, &plugin_name, rule_name)
+    }
+
+    /// Get the code actions which change the severity of the reported rules in the nearest config file.
+    fn get_rule_severity_code_actions(
+        &self,
+        uri: &Uri,
+        rule_names: FxHashSet<String>,
+    ) -> Vec<CodeActionOrCommand> {
+        if rule_names.is_empty() {
+            return vec![];
         }
+        let Some(path) = uri.to_file_path() else {
+            return vec![];
+        };
+        let Some((config_path, config_text)) = self.get_editable_config(uri) else {
+            return vec![];
+        };
+        let mut rule_names = rule_names.into_iter().collect::<Vec<_>>();
+        rule_names.sort_unstable();
+
+        rule_names
+            .iter()
+            .filter(|rule| self.is_rule_set_by_top_level_rules(&path, rule))
+            .flat_map(|rule| {
+                let current = self.get_rule_severity(&path, rule);
+                rule_severity_code_actions(rule, current, config_path, config_text)
+            })
+            .map(CodeActionOrCommand::CodeAction)
+            .collect()
     }
 
-    fn get_code_actions_for_uri(&self, uri: &Uri) -> Option<Vec<LinterCodeAction>> {
+    /// Get the code actions which move a rule of the disable directives in the range to the nearest config file.
+    fn get_extract_to_config_code_actions(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        range: &Range,
+    ) -> Vec<CodeActionOrCommand> {
+        let Some(path) = uri.to_file_path() else {
+            return vec![];
+        };
+        let Some((config_path, config_text)) = self.get_editable_config(uri) else {
+            return vec![];
+        };
+
+        self.with_program(uri, content, |parsed| {
+            get_extract_to_config_code_actions(
+                uri,
+                parsed,
+                range,
+                config_path,
+                config_text,
+                |rule| self.is_rule_set_by_top_level_rules(&path, rule),
+            )
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .map(CodeActionOrCommand::CodeAction)
+        .collect()
+    }
+
+    fn get_code_actions_for_uri(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+    ) -> Option<Vec<LinterCodeAction>> {
         if let Some(cached_code_actions) = self.code_actions.pin().get(uri) {
             cached_code_actions.clone()
         } else {
-            let _ = self.run_file(uri, None);
+            let _ = self.run_file(uri, content);
             self.code_actions.pin().get(uri).and_then(std::clone::Clone::clone)
         }
     }
@@ -704,6 +1266,77 @@ impl ServerLinter {
         false
     }
 
+    /// Collect the lintable files of the workspace, which are not ignored.
+    ///
+    /// The walk reads the same ignore files as [`ServerLinterBuilder::create_ignore_glob`],
+    /// and does not enter the directories ignored by the `ignorePatterns` of the configs.
+    fn collect_workspace_files(&self) -> Vec<Uri> {
+        let ignore_options = IgnoreOptions {
+            ignore_path: ".eslintignore".into(),
+            ignore_pattern: vec![],
+            no_ignore: false,
+        };
+        let ignore_matcher = Arc::clone(&self.ignore_matcher);
+
+        let mut uris: Vec<Uri> = Walk::new(std::slice::from_ref(&self.cwd), &ignore_options, None)
+            .with_skip_directory(Arc::new(move |path| ignore_matcher.should_ignore(path)))
+            .paths()
+            .into_iter()
+            .map(|path| PathBuf::from(&*path))
+            .filter(|path| !self.is_ignored(path))
+            .filter_map(Uri::from_file_path)
+            .collect();
+        // The walk is parallel, keep the order of the diagnostics stable
+        uris.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+        uris
+    }
+
+    /// Get the source text and source type of a file which is not ignored,
+    /// reading the file from disk when no `content` is given.
+    fn get_source<'c>(
+        &self,
+        uri: &Uri,
+        content: Option<&'c str>,
+    ) -> Option<(Cow<'c, str>, SourceType)> {
+        let uri_path = uri.to_file_path()?;
+        if self.is_ignored(&uri_path) {
+            return None;
+        }
+        // framework files (e.g. `.vue`, `.svelte`) have no single JS source type
+        let source_type = SourceType::from_path(&uri_path).ok()?;
+
+        let source_text = match content {
+            Some(content) => Cow::Borrowed(content),
+            None => match read_to_string(&uri_path) {
+                Ok(content) => Cow::Owned(content),
+                Err(e) => {
+                    debug!("failed to read file {uri_path:?}: {e}");
+                    return None;
+                }
+            },
+        };
+
+        Some((source_text, source_type))
+    }
+
+    /// How many times a source text was parsed for the requests of this linter.
+    #[cfg(test)]
+    pub fn parse_count(&self) -> usize {
+        self.ast_cache.parse_count()
+    }
+
+    /// Run `f` with the parsed program of the file, see [`AstCache`].
+    /// Returns `None` if the file is not lintable or ignored.
+    fn with_program<R>(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        f: impl FnOnce(&ParsedProgram<'_>) -> R,
+    ) -> Option<R> {
+        let (source_text, source_type) = self.get_source(uri, content)?;
+        Some(self.ast_cache.with_program(uri, &source_text, source_type, f))
+    }
+
     /// Lint a single file, returning an empty diagnostics list if the file is ignored.
     fn run_file(&self, uri: &Uri, content: Option<&str>) -> Result<Vec<Diagnostic>, String> {
         let Some(uri_path) = uri.to_file_path() else {
@@ -713,7 +1346,18 @@ impl ServerLinter {
             return Ok(Vec::new());
         }
 
-        let reports = self.lint_path(&uri_path, uri, content)?;
+        let start = Instant::now();
+        let LintedFile { reports, unreported_code_actions, unused_directive_hints } =
+            self.lint_path(&uri_path, uri, content)?;
+        self.record_stats(uri, &uri_path, start);
+        self.log(|| {
+            format!(
+                "linted {} in {:.2?}: {} diagnostics",
+                uri_path.display(),
+                start.elapsed(),
+                reports.len()
+            )
+        });
 
         let mut diagnostics = Vec::with_capacity(reports.len());
         // mostly all diagnostics will have code actions (fix + ignoring line/file), only following diagnostics won't:
@@ -721,8 +1365,9 @@ impl ServerLinter {
         // - diagnostics with span(0,0) and no fixes
         // - tsgolint internal diagnostics
         // - unused directives diagnostics
-        let mut code_actions = vec![];
-        for report in reports {
+        let mut code_actions = unreported_code_actions;
+        for mut report in reports {
+            report.diagnostic.source = Some(self.diagnostic_source.clone());
             diagnostics.push(report.diagnostic);
 
             if let Some(code_action) = report.code_action {
@@ -731,16 +1376,39 @@ impl ServerLinter {
         }
 
         self.code_actions.pin().insert(uri.clone(), Some(code_actions));
+        self.unused_directive_hints.pin().insert(uri.clone(), unused_directive_hints);
 
         Ok(diagnostics)
     }
 
+    /// Record the duration of a lint run, started at `start`,
+    /// and the number of rules of the config which applies to the file.
+    fn record_stats(&self, uri: &Uri, path: &Path, start: Instant) {
+        let duration = start.elapsed();
+        let rule_count =
+            self.config_store.number_of_rules_for_path(path, self.runner.has_type_aware());
+
+        self.stats.pin().update_or_insert_with(
+            uri.clone(),
+            |stats| {
+                let mut stats = stats.clone();
+                stats.record(duration, rule_count);
+                stats
+            },
+            || {
+                let mut stats = FileStats::new(path.to_string_lossy().into_owned());
+                stats.record(duration, rule_count);
+                stats
+            },
+        );
+    }
+
     fn lint_path(
         &self,
         path: &Path,
         uri: &Uri,
         content: Option<&str>,
-    ) -> Result<Vec<DiagnosticReport>, String> {
+    ) -> Result<LintedFile, String> {
         debug!("lint {}", path.display());
 
         let source_text = if let Some(content) = content {
@@ -767,32 +1435,134 @@ impl ServerLinter {
                 }
             };
 
-        messages.append(&mut generate_inverted_diagnostics(&messages, uri));
-
-        // Add unused directives if configured
-        if let Some(severity) = self.unused_directives_severity
-            && let Some(directives) = self.runner.directives_coordinator().get(path)
-        {
-            messages.extend(
-                create_unused_directives_messages(&directives, severity, source_text)
-                    .into_iter()
-                    .map(|message| message_to_lsp_diagnostic(message, uri, source_text, rope)),
-            );
+        // Unused directives are always hinted and can be removed, but only reported if configured
+        let mut unreported_code_actions = vec![];
+        let mut unused_directive_hints = vec![];
+        if let Some(directives) = self.runner.directives_coordinator().get(path) {
+            unused_directive_hints = get_unused_directive_hints(&directives, source_text, rope);
+
+            let severity = self.unused_directives_severity.unwrap_or(AllowWarnDeny::Warn);
+            let reports = create_unused_directives_messages(&directives, severity, source_text)
+                .into_iter()
+                .map(|message| message_to_lsp_diagnostic(message, uri, source_text, rope));
+            if self.unused_directives_severity.is_some() {
+                messages.extend(reports);
+            } else {
+                unreported_code_actions.extend(reports.filter_map(|report| report.code_action));
+            }
         }
 
+        self.filter_reports(&mut messages);
+        messages.append(&mut generate_inverted_diagnostics(&messages, uri));
+
         // Clear any stale directives because they are no longer needed.
         // This prevents using outdated directive spans if the new linting run fails.
         self.runner.directives_coordinator().remove(path);
 
-        Ok(messages)
+        Ok(LintedFile { reports: messages, unreported_code_actions, unused_directive_hints })
+    }
+
+    /// Check if the path is a JSON config file used by this linter:
+    /// a `.oxlintrc.json`, the config path provided by the client, or an extended JSON config.
+    fn is_json_config_file(&self, path: &Path) -> bool {
+        if path.extension().is_none_or(|ext| ext != "json") {
+            return false;
+        }
+
+        path.file_name().is_some_and(|name| name == DEFAULT_OXLINTRC_NAME)
+            || self.config_path.as_deref() == Some(path)
+            || self.extended_paths.contains(path)
+    }
+
+    /// Report the problems of the rules configured in a JSON config file,
+    /// and the other config files of the same directory which change how it is used.
+    fn run_config_file(
+        &self,
+        path: &Path,
+        content: Option<&str>,
+    ) -> Result<Vec<Diagnostic>, String> {
+        let source_text = if let Some(content) = content {
+            content
+        } else {
+            &read_to_string(path).map_err(|e| format!("Failed to read file: {e}"))?
+        };
+
+        let mut diagnostics = get_config_diagnostics(source_text);
+        diagnostics.extend(self.get_config_conflict_diagnostics(path, source_text));
+        for diagnostic in &mut diagnostics {
+            diagnostic.source = Some(self.diagnostic_source.clone());
+        }
+        Ok(diagnostics)
+    }
+
+    /// Report the config files of the same directory, which take precedence over each other:
+    /// - `.oxlintrc.json` next to `oxlint.config.ts`: neither of them is used
+    /// - the config path provided by the client next to `.oxlintrc.json`: only the config path is used,
+    ///   the rules configured differently in both files are reported
+    fn get_config_conflict_diagnostics(&self, path: &Path, source_text: &str) -> Vec<Diagnostic> {
+        let Some(dir) = path.parent() else {
+            return Vec::new();
+        };
+        let default_config_path = dir.join(DEFAULT_OXLINTRC_NAME);
+
+        let Some(config_path) = self.config_path.as_deref() else {
+            if path != default_config_path || !dir.join(DEFAULT_TS_OXLINTRC_NAME).is_file() {
+                return Vec::new();
+            }
+            return vec![Diagnostic {
+                severity: Some(DiagnosticSeverity::WARNING),
+                message: format!(
+                    "Both `{DEFAULT_OXLINTRC_NAME}` and `{DEFAULT_TS_OXLINTRC_NAME}` found in this directory, neither of them is used. Delete one of the configuration files."
+                ),
+                source: Some("oxc".into()),
+                ..Default::default()
+            }];
+        };
+
+        if config_path.parent() != Some(dir) || config_path == default_config_path {
+            return Vec::new();
+        }
+        let config_name = config_path.file_name().unwrap_or_default().to_string_lossy();
+        let (other_path, note) = if path == config_path {
+            (
+                default_config_path.as_path(),
+                format!("This file is used, `{DEFAULT_OXLINTRC_NAME}` is ignored."),
+            )
+        } else if path == default_config_path {
+            (config_path, format!("`{config_name}` is used, this file is ignored."))
+        } else {
+            return Vec::new();
+        };
+        let Ok(other_source_text) = read_to_string(other_path) else {
+            return Vec::new();
+        };
+        let other_name = other_path.file_name().unwrap_or_default().to_string_lossy();
+        get_rule_conflict_diagnostics(source_text, &other_source_text, &other_name, &note)
+    }
+
+    /// Remove the reports below `minSeverity`, and the reports exceeding `maxNumberOfProblems`.
+    fn filter_reports(&self, reports: &mut Vec<DiagnosticReport>) {
+        if self.min_severity == MinSeverity::Error {
+            reports.retain(|report| report.diagnostic.severity == Some(DiagnosticSeverity::ERROR));
+        }
+        if let Some(max_number_of_problems) = self.max_number_of_problems {
+            reports.truncate(max_number_of_problems);
+        }
     }
 
     fn needs_restart(old_options: &LSPLintOptions, new_options: &LSPLintOptions) -> bool {
-        old_options.config_path != new_options.config_path
+        old_options.run != new_options.run
+            || old_options.min_severity != new_options.min_severity
+            || old_options.max_number_of_problems != new_options.max_number_of_problems
+            || old_options.config_path != new_options.config_path
             || old_options.ts_config_path != new_options.ts_config_path
             || old_options.use_nested_configs() != new_options.use_nested_configs()
             || old_options.fix_kind != new_options.fix_kind
             || old_options.unused_disable_directives != new_options.unused_disable_directives
+            || old_options.verbose != new_options.verbose
+            || old_options.lint_only_open_files != new_options.lint_only_open_files
+            || old_options.working_directory != new_options.working_directory
+            || old_options.diagnostic_source != new_options.diagnostic_source
             // TODO: only the TsgoLinter needs to be dropped or created
             || old_options.type_aware != new_options.type_aware
     }
@@ -809,21 +1579,41 @@ impl ServerLinter {
     }
 }
 
+/// The result of linting a single file.
+struct LintedFile {
+    reports: Vec<DiagnosticReport>,
+    /// Code actions without a reported diagnostic, e.g. for removing unused directives
+    unreported_code_actions: Vec<LinterCodeAction>,
+    unused_directive_hints: Vec<InlayHint>,
+}
+
 fn range_overlaps(a: Range, b: Range) -> bool {
     a.start <= b.end && a.end >= b.start
 }
 
+/// Code action kinds are hierarchical, e.g. `source.fixAll` also requests `source.fixAll.oxc`.
+fn is_kind_or_parent_kind(requested: &CodeActionKind, kind: &CodeActionKind) -> bool {
+    kind.as_str()
+        .strip_prefix(requested.as_str())
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
 #[cfg(test)]
 mod tests_builder {
     use tower_lsp_server::ls_types::{
         CodeActionKind, CodeActionOptions, CodeActionProviderCapability, ExecuteCommandOptions,
+        FoldingRangeProviderCapability, OneOf, RenameOptions, SelectionRangeProviderCapability,
         ServerCapabilities, WorkDoneProgressOptions,
     };
 
     use oxc_language_server::{Capabilities, DiagnosticMode, ToolBuilder};
 
     use crate::lsp::{
-        code_actions::CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, commands::FIX_ALL_COMMAND_ID,
+        code_actions::CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC,
+        commands::{
+            FIX_ALL_COMMAND_ID, LINT_FILE_COMMAND_ID, SHOW_STATS_COMMAND_ID,
+            WHICH_CONFIG_COMMAND_ID,
+        },
         server_linter::ServerLinterBuilder,
     };
 
@@ -841,15 +1631,50 @@ mod tests_builder {
                 assert!(code_action_kinds.contains(&CodeActionKind::QUICKFIX));
                 assert!(code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC));
                 assert!(code_action_kinds.contains(&CodeActionKind::SOURCE_FIX_ALL));
-                assert_eq!(code_action_kinds.len(), 3);
+                assert!(code_action_kinds.contains(&CodeActionKind::REFACTOR_EXTRACT));
+                assert_eq!(code_action_kinds.len(), 4);
             }
             _ => panic!("Expected code action provider options"),
         }
 
-        // Should set execute command provider with fix all command
+        // Should set execute command provider with fix all and show stats commands
         let execute_command_provider = capabilities.execute_command_provider.as_ref().unwrap();
         assert!(execute_command_provider.commands.contains(&FIX_ALL_COMMAND_ID.to_string()));
-        assert_eq!(execute_command_provider.commands.len(), 1);
+        assert!(execute_command_provider.commands.contains(&SHOW_STATS_COMMAND_ID.to_string()));
+        assert!(execute_command_provider.commands.contains(&LINT_FILE_COMMAND_ID.to_string()));
+        assert!(execute_command_provider.commands.contains(&WHICH_CONFIG_COMMAND_ID.to_string()));
+        assert_eq!(execute_command_provider.commands.len(), 4);
+
+        // Should support folding ranges
+        assert_eq!(
+            capabilities.folding_range_provider,
+            Some(FoldingRangeProviderCapability::Simple(true))
+        );
+
+        // Should support document symbols
+        assert_eq!(capabilities.document_symbol_provider, Some(OneOf::Left(true)));
+        assert_eq!(capabilities.workspace_symbol_provider, Some(OneOf::Left(true)));
+
+        // Should support selection ranges
+        assert_eq!(
+            capabilities.selection_range_provider,
+            Some(SelectionRangeProviderCapability::Simple(true))
+        );
+
+        // Should support inlay hints
+        assert_eq!(capabilities.inlay_hint_provider, Some(OneOf::Left(true)));
+
+        // Should support rename with prepare
+        assert_eq!(
+            capabilities.rename_provider,
+            Some(OneOf::Right(RenameOptions {
+                prepare_provider: Some(true),
+                work_done_progress_options: WorkDoneProgressOptions::default(),
+            }))
+        );
+
+        // Should support completions
+        assert!(capabilities.completion_provider.is_some());
     }
 
     #[test]
@@ -873,7 +1698,8 @@ mod tests_builder {
                 assert!(code_action_kinds.contains(&CodeActionKind::QUICKFIX));
                 assert!(code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC));
                 assert!(code_action_kinds.contains(&CodeActionKind::SOURCE_FIX_ALL));
-                assert_eq!(code_action_kinds.len(), 4);
+                assert!(code_action_kinds.contains(&CodeActionKind::REFACTOR_EXTRACT));
+                assert_eq!(code_action_kinds.len(), 5);
                 assert_eq!(options.resolve_provider, Some(true));
             }
             _ => panic!("Expected code action provider options"),
@@ -900,7 +1726,8 @@ mod tests_builder {
                 assert!(code_action_kinds.contains(&CodeActionKind::QUICKFIX));
                 assert!(code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC));
                 assert!(code_action_kinds.contains(&CodeActionKind::SOURCE_FIX_ALL));
-                assert_eq!(code_action_kinds.len(), 3);
+                assert!(code_action_kinds.contains(&CodeActionKind::REFACTOR_EXTRACT));
+                assert_eq!(code_action_kinds.len(), 4);
             }
             _ => panic!("Expected code action provider options"),
         }
@@ -923,7 +1750,8 @@ mod tests_builder {
                 assert!(code_action_kinds.contains(&CodeActionKind::QUICKFIX));
                 assert!(code_action_kinds.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC));
                 assert!(code_action_kinds.contains(&CodeActionKind::SOURCE_FIX_ALL));
-                assert_eq!(code_action_kinds.len(), 3);
+                assert!(code_action_kinds.contains(&CodeActionKind::REFACTOR_EXTRACT));
+                assert_eq!(code_action_kinds.len(), 4);
             }
             _ => panic!("Expected code action provider options"),
         }
@@ -947,7 +1775,7 @@ mod tests_builder {
         let execute_command_provider = capabilities.execute_command_provider.as_ref().unwrap();
         assert!(execute_command_provider.commands.contains(&"existing.command".to_string()));
         assert!(execute_command_provider.commands.contains(&FIX_ALL_COMMAND_ID.to_string()));
-        assert_eq!(execute_command_provider.commands.len(), 2);
+        assert_eq!(execute_command_provider.commands.len(), 5);
         assert_eq!(
             execute_command_provider.work_done_progress_options.work_done_progress,
             Some(true)
@@ -969,7 +1797,7 @@ mod tests_builder {
 
         let execute_command_provider = capabilities.execute_command_provider.as_ref().unwrap();
         assert!(execute_command_provider.commands.contains(&FIX_ALL_COMMAND_ID.to_string()));
-        assert_eq!(execute_command_provider.commands.len(), 1);
+        assert_eq!(execute_command_provider.commands.len(), 4);
     }
 
     #[test]
@@ -1103,9 +1931,10 @@ mod test_watchers {
     }
 
     mod handle_configuration_change {
-        use crate::lsp::tester::Tester;
+        use crate::lsp::tester::{Tester, get_file_uri};
         use oxc_language_server::ToolRestartChanges;
         use serde_json::json;
+        use tower_lsp_server::ls_types::DiagnosticSeverity;
 
         #[test]
         fn test_no_change() {
@@ -1134,7 +1963,7 @@ mod test_watchers {
             let ToolRestartChanges { watch_patterns, .. } =
                 Tester::new("fixtures/lsp/watchers/default", json!({}))
                     .handle_configuration_change(json!({
-                        // run is the only option that does not require a restart
+                        // run requires a new linter, but no new watchers
                         "run": "onSave"
                     }));
 
@@ -1154,20 +1983,51 @@ mod test_watchers {
             assert_eq!(watch_patterns.as_ref().unwrap()[1], "**/oxlint.config.ts".to_string());
             assert_eq!(watch_patterns.as_ref().unwrap()[2], "**/tsconfig*.json".to_string());
         }
+
+        #[test]
+        fn test_report_filter_change() {
+            let tester = Tester::new("fixtures/lsp/min_severity", json!({}));
+            let uri = get_file_uri("fixtures/lsp/min_severity/index.js");
+
+            let diagnostics = tester.lint_file("index.js");
+            assert_eq!(diagnostics[0].1.len(), 3);
+
+            let ToolRestartChanges { tool, watch_patterns } =
+                tester.handle_configuration_change(json!({ "minSeverity": "error" }));
+            assert!(watch_patterns.is_none());
+            let diagnostics =
+                tool.expect("the linter should be rebuilt").run_diagnostic(&uri, None);
+            let diagnostics = &diagnostics.unwrap()[0].1;
+            assert_eq!(diagnostics.len(), 1);
+            assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+
+            let ToolRestartChanges { tool, .. } =
+                tester.handle_configuration_change(json!({ "maxNumberOfProblems": 2 }));
+            let diagnostics =
+                tool.expect("the linter should be rebuilt").run_diagnostic(&uri, None);
+            assert_eq!(diagnostics.unwrap()[0].1.len(), 2);
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
 
+    use oxc_language_server::{CommandResult, Tool};
     use oxc_linter::ExternalPluginStore;
-    use rustc_hash::FxHashSet;
+    use rustc_hash::{FxHashMap, FxHashSet};
     use serde_json::json;
+    use tower_lsp_server::ls_types::{
+        CodeActionKind, CodeActionOrCommand, DiagnosticSeverity, DiagnosticTag, DocumentChanges,
+        FoldingRangeKind, InlayHintLabel, NumberOrString, OneOf, Position, Range, SymbolKind,
+        TextEdit, WorkDoneProgress,
+    };
 
     use crate::lsp::{
+        code_actions::CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC,
         server_linter::ServerLinterBuilder,
-        tester::{Tester, get_file_path},
+        tester::{Tester, get_file_path, get_file_uri},
     };
 
     #[test]
@@ -1176,11 +2036,13 @@ mod test {
         let mut nested_ignore_patterns = Vec::new();
         let mut external_plugin_store = ExternalPluginStore::new(false);
         let mut extended_paths = FxHashSet::default();
+        let mut config_files = FxHashMap::default();
         let configs = builder.create_nested_configs(
             &get_file_path("fixtures/lsp/init_nested_configs"),
             &mut external_plugin_store,
             &mut nested_ignore_patterns,
             &mut extended_paths,
+            &mut config_files,
             None,
         );
         let mut configs_dirs = configs.keys().collect::<Vec<&PathBuf>>();
@@ -1191,6 +2053,7 @@ mod test {
         assert!(configs_dirs[2].ends_with("deep2"));
         assert!(configs_dirs[1].ends_with("deep1"));
         assert!(configs_dirs[0].ends_with("init_nested_configs"));
+        assert_eq!(config_files.len(), 3);
     }
 
     #[test]
@@ -1205,6 +2068,21 @@ mod test {
             .test_and_snapshot_single_file("hello_world.js");
     }
 
+    #[test]
+    fn test_custom_diagnostic_source() {
+        let tester =
+            Tester::new("fixtures/lsp/deny_no_console", json!({ "diagnosticSource": "oxlint" }));
+        let diagnostics = tester.lint_file("hello_world.js");
+        assert_eq!(diagnostics.len(), 1);
+        assert!(!diagnostics[0].1.is_empty());
+        assert!(
+            diagnostics[0]
+                .1
+                .iter()
+                .all(|diagnostic| diagnostic.source.as_deref() == Some("oxlint"))
+        );
+    }
+
     // Test case for https://github.com/oxc-project/oxc/issues/9958
     #[test]
     fn test_issue_9958() {
@@ -1269,6 +2147,17 @@ mod test {
         .test_and_snapshot_single_file("forward_ref.ts");
     }
 
+    #[test]
+    fn test_require_to_import() {
+        Tester::new(
+            "fixtures/lsp/require_to_import",
+            json!({
+                "fixKind": "safe_fix_or_suggestion"
+            }),
+        )
+        .test_and_snapshot_multiple_file(&["default.ts", "destructured.ts", "script.cjs"]);
+    }
+
     #[test]
     fn test_report_unused_directives() {
         Tester::new(
@@ -1343,4 +2232,830 @@ mod test {
         );
         tester.test_and_snapshot_single_file("foo-bar.astro");
     }
+
+    #[test]
+    fn test_rule_name_completions() {
+        let tester = Tester::new("fixtures/lsp/completion", json!({}));
+
+        let completions = tester.get_completions("index.js", Position::new(0, 34));
+        assert!(completions.iter().any(|item| item.label == "no-debugger"));
+        assert!(completions.iter().all(|item| item.label.starts_with("no-deb")));
+
+        // outside of a disable directive
+        assert!(tester.get_completions("index.js", Position::new(2, 10)).is_empty());
+        assert!(tester.get_completions("index.js", Position::new(1, 3)).is_empty());
+    }
+
+    #[test]
+    fn test_change_and_save_lint_once() {
+        for run in ["onType", "onSave"] {
+            let tester = Tester::new("fixtures/lsp/source_fix_all", json!({ "run": run }));
+            assert_eq!(tester.change_and_save("index.js"), 1, "run: {run}");
+        }
+    }
+
+    #[test]
+    fn test_config_file_diagnostics() {
+        let tester = Tester::new("fixtures/lsp/config_diagnostics", json!({}));
+        tester.test_and_snapshot_single_file(".oxlintrc.json");
+    }
+
+    #[test]
+    fn test_config_file_rule_conflicts() {
+        let tester =
+            Tester::new("fixtures/lsp/config_conflicts", json!({ "configPath": "custom.json" }));
+        tester.test_and_snapshot_multiple_file(&[".oxlintrc.json", "custom.json"]);
+    }
+
+    #[test]
+    fn test_config_file_ts_config_conflict() {
+        let tester = Tester::new("fixtures/lsp/config_conflicts_ts", json!({}));
+        tester.test_and_snapshot_single_file(".oxlintrc.json");
+    }
+
+    #[test]
+    fn test_folding_ranges() {
+        let tester = Tester::new("fixtures/lsp/folding_range", json!({}));
+        let ranges = tester.get_folding_ranges("index.js");
+
+        assert_eq!(ranges.len(), 2);
+        assert_eq!((ranges[0].start_line, ranges[0].end_line), (0, 3));
+        assert_eq!(ranges[0].kind, Some(FoldingRangeKind::Comment));
+        assert_eq!((ranges[1].start_line, ranges[1].end_line), (4, 6));
+        assert_eq!(ranges[1].kind, Some(FoldingRangeKind::Region));
+    }
+
+    #[test]
+    fn test_scan_workspace() {
+        let tester = Tester::new("fixtures/lsp/workspace_scan", json!({}));
+        let (progress, diagnostics) = tester.scan_workspace();
+
+        let Some(WorkDoneProgress::Begin(begin)) = progress.first() else {
+            panic!("expected the scan to begin with a progress, got {progress:?}");
+        };
+        assert_eq!(begin.message.as_deref(), Some("0/3 files"));
+        let Some(WorkDoneProgress::End(end)) = progress.last() else {
+            panic!("expected the scan to end with a progress, got {progress:?}");
+        };
+        assert_eq!(end.message.as_deref(), Some("Linted 3 files"));
+        let Some(WorkDoneProgress::Report(report)) = progress.get(progress.len() - 2) else {
+            panic!("expected a progress report before the end, got {progress:?}");
+        };
+        assert_eq!(report.percentage, Some(100));
+
+        // Only files with diagnostics are reported
+        let root = get_file_path("fixtures/lsp/workspace_scan");
+        let mut files: Vec<_> = diagnostics
+            .iter()
+            .map(|(uri, diagnostics)| {
+                let path = uri.to_file_path().unwrap();
+                (path.strip_prefix(&root).unwrap().to_path_buf(), diagnostics.len())
+            })
+            .collect();
+        files.sort();
+        assert_eq!(
+            files,
+            vec![(PathBuf::from("a.js"), 1), (PathBuf::from("nested").join("c.js"), 1)]
+        );
+    }
+
+    #[test]
+    fn test_scan_workspace_ignore_patterns() {
+        let tester = Tester::new("fixtures/lsp/workspace_scan_ignore_patterns", json!({}));
+        let (progress, diagnostics) = tester.scan_workspace();
+
+        let Some(WorkDoneProgress::Begin(begin)) = progress.first() else {
+            panic!("expected the scan to begin with a progress, got {progress:?}");
+        };
+        assert_eq!(begin.message.as_deref(), Some("0/1 files"));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].0,
+            get_file_uri("fixtures/lsp/workspace_scan_ignore_patterns/a.js")
+        );
+    }
+
+    #[test]
+    fn test_lint_only_open_files() {
+        let tester =
+            Tester::new("fixtures/lsp/workspace_scan", json!({ "lintOnlyOpenFiles": true }));
+        let (progress, diagnostics) = tester.scan_workspace();
+        assert!(progress.is_empty());
+        assert!(diagnostics.is_empty());
+
+        // Diagnostics are still reported once the file is opened
+        let (scanned, opened) = tester.scan_workspace_and_open("a.js");
+        assert_eq!(scanned, 0);
+        assert_eq!(opened.len(), 1);
+        assert_eq!(opened[0].0, get_file_uri("fixtures/lsp/workspace_scan/a.js"));
+        assert_eq!(opened[0].1.len(), 1);
+    }
+
+    #[test]
+    fn test_diagnostic_tags() {
+        let tester = Tester::new("fixtures/lsp/diagnostic_tags", json!({}));
+        let diagnostics = tester.lint_file("unused.js");
+        assert_eq!(diagnostics.len(), 1);
+
+        let tags_of = |code: &str| {
+            diagnostics[0]
+                .1
+                .iter()
+                .find(|diagnostic| {
+                    diagnostic.code == Some(NumberOrString::String(code.to_string()))
+                })
+                .unwrap_or_else(|| panic!("expected a {code} diagnostic"))
+                .tags
+                .clone()
+        };
+        assert_eq!(tags_of("eslint(no-unused-vars)"), Some(vec![DiagnosticTag::UNNECESSARY]));
+        assert_eq!(tags_of("eslint(no-debugger)"), None);
+    }
+
+    #[test]
+    fn test_workspace_symbols_across_open_documents() {
+        let tester = Tester::new("fixtures/lsp/workspace_symbol", json!({}));
+        let symbols = tester.get_workspace_symbols("reset", &["counter.js", "timer.js"]);
+
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols[0].name, "reset");
+        assert_eq!(symbols[0].kind, SymbolKind::METHOD);
+        assert_eq!(symbols[0].container_name.as_deref(), Some("Counter"));
+        assert_eq!(
+            symbols[0].location.uri,
+            get_file_uri("fixtures/lsp/workspace_symbol/counter.js")
+        );
+        assert_eq!(symbols[0].location.range, Range::new(Position::new(5, 2), Position::new(7, 3)));
+        assert_eq!(symbols[1].name, "resetTimer");
+        assert_eq!(symbols[1].kind, SymbolKind::FUNCTION);
+        assert_eq!(symbols[1].container_name, None);
+        assert_eq!(symbols[1].location.uri, get_file_uri("fixtures/lsp/workspace_symbol/timer.js"));
+
+        // The query matches fuzzily, ignoring case
+        let symbols = tester.get_workspace_symbols("rsTmr", &["counter.js", "timer.js"]);
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "resetTimer");
+    }
+
+    #[test]
+    fn test_document_symbols() {
+        let tester = Tester::new("fixtures/lsp/document_symbol", json!({}));
+        let symbols = tester.get_document_symbols("index.js");
+
+        assert_eq!(symbols.len(), 1);
+        let class = &symbols[0];
+        assert_eq!(class.name, "Counter");
+        assert_eq!(class.kind, SymbolKind::CLASS);
+        // The range spans the class, the selection range only its name
+        assert_eq!(class.range, Range::new(Position::new(0, 7), Position::new(8, 1)));
+        assert_eq!(class.selection_range, Range::new(Position::new(0, 13), Position::new(0, 20)));
+
+        let methods = class.children.as_ref().unwrap();
+        assert_eq!(methods.len(), 2);
+        assert_eq!(methods[0].name, "increment");
+        assert_eq!(methods[0].kind, SymbolKind::METHOD);
+        assert_eq!(methods[0].range, Range::new(Position::new(1, 2), Position::new(3, 3)));
+        assert_eq!(
+            methods[0].selection_range,
+            Range::new(Position::new(1, 2), Position::new(1, 11))
+        );
+        assert_eq!(methods[1].name, "reset");
+        assert_eq!(methods[1].kind, SymbolKind::METHOD);
+        assert_eq!(methods[1].range, Range::new(Position::new(5, 2), Position::new(7, 3)));
+        assert_eq!(
+            methods[1].selection_range,
+            Range::new(Position::new(5, 2), Position::new(5, 7))
+        );
+        assert!(methods.iter().all(|method| method.children.as_ref().unwrap().is_empty()));
+    }
+
+    #[test]
+    fn test_unused_directive_inlay_hints() {
+        let tester = Tester::new("fixtures/lsp/inlay_hint", json!({}));
+        // Only the `no-console` directive is unused, `debugger` is suppressed
+        let hints = tester.get_inlay_hints("index.js");
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].position, Position::new(3, 38));
+        assert!(matches!(&hints[0].label, InlayHintLabel::String(label) if label == "unused"));
+
+        // The unused directive is not reported by default, but can still be removed
+        let diagnostics = tester.lint_file("index.js");
+        assert!(diagnostics[0].1.is_empty());
+        let code_actions = tester
+            .get_code_actions("index.js", Range::new(Position::new(3, 30), Position::new(3, 30)));
+        assert_eq!(code_actions.len(), 1);
+        let CodeActionOrCommand::CodeAction(code_action) = &code_actions[0] else {
+            panic!("expected a code action");
+        };
+        assert_eq!(code_action.title, "remove unused disable directive");
+    }
+
+    #[test]
+    fn test_type_import_code_actions() {
+        let tester = Tester::new("fixtures/lsp/type_import", json!({}));
+        let cursor = Range::new(Position::new(0, 9), Position::new(0, 9));
+        let first_code_action = |file: &str| {
+            tester.get_code_actions(file, cursor).into_iter().find_map(|action| match action {
+                CodeActionOrCommand::CodeAction(action) if !action.title.starts_with("Disable") => {
+                    Some(action)
+                }
+                _ => None,
+            })
+        };
+
+        // `Foo` is only used as a type, the import becomes a type import
+        let code_action = first_code_action("type-only.ts").expect("expected a type import fix");
+        assert_eq!(code_action.title, "Add type specifier to this import declaration");
+        assert_eq!(code_action.kind, Some(CodeActionKind::QUICKFIX));
+        let changes = code_action.edit.and_then(|edit| edit.changes).unwrap();
+        assert_eq!(
+            changes[&get_file_uri("fixtures/lsp/type_import/type-only.ts")],
+            vec![TextEdit {
+                range: Range::new(Position::new(0, 0), Position::new(0, 6)),
+                new_text: "import type".to_string(),
+            }]
+        );
+
+        // Only the type-only specifier is moved to a separate type import
+        let code_action = first_code_action("split.ts").expect("expected a type import fix");
+        assert_eq!(code_action.title, "Mark all type-only imports with the type specifier");
+        let changes = code_action.edit.and_then(|edit| edit.changes).unwrap();
+        let edits = &changes[&get_file_uri("fixtures/lsp/type_import/split.ts")];
+        assert!(edits.iter().any(|edit| edit.new_text.starts_with("import type { Foo")));
+        assert!(edits.iter().all(|edit| !edit.new_text.contains("createFoo")));
+
+        // `Foo` is also used as a value, the import is left alone
+        assert!(tester.get_code_actions("value-used.ts", cursor).is_empty());
+        assert!(tester.lint_file("value-used.ts")[0].1.is_empty());
+    }
+
+    #[test]
+    fn test_selection_ranges() {
+        let tester = Tester::new("fixtures/lsp/selection_range", json!({}));
+        // Inside `price` of `item.price`
+        let selection_ranges = tester.get_selection_ranges("index.js", &[Position::new(1, 50)]);
+        assert_eq!(selection_ranges.len(), 1);
+
+        let mut ranges = Vec::new();
+        let mut current = selection_ranges.first();
+        while let Some(selection_range) = current {
+            ranges.push(selection_range.range);
+            current = selection_range.parent.as_deref();
+        }
+        let range = |start: (u32, u32), end: (u32, u32)| {
+            Range::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+        };
+        assert_eq!(
+            ranges,
+            vec![
+                range((1, 48), (1, 53)), // price
+                range((1, 43), (1, 53)), // item.price
+                range((1, 37), (1, 53)), // sum + item.price
+                range((1, 22), (1, 53)), // (sum, item) => sum + item.price
+                range((1, 9), (1, 57)),  // items.reduce(...)
+                range((1, 2), (1, 58)),  // return statement
+                range((0, 22), (2, 1)),  // function body
+                range((0, 0), (2, 1)),   // function declaration
+                range((0, 0), (3, 0)),   // program
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prepare_rename() {
+        let tester = Tester::new("fixtures/lsp/rename", json!({}));
+        // `let` keyword
+        assert_eq!(tester.prepare_rename("index.js", Position::new(1, 3)), None);
+        // `0` literal
+        assert_eq!(tester.prepare_rename("index.js", Position::new(1, 12)), None);
+        // `sum` reference
+        assert_eq!(
+            tester.prepare_rename("index.js", Position::new(3, 10)),
+            Some(Range::new(Position::new(3, 9), Position::new(3, 12)))
+        );
+    }
+
+    #[test]
+    fn test_rename() {
+        let tester = Tester::new("fixtures/lsp/rename", json!({}));
+        // `sum` is declared once and used twice
+        let edits = tester.rename("index.js", Position::new(3, 10), "count");
+        assert_eq!(
+            edits,
+            vec![
+                TextEdit::new(Range::new(Position::new(1, 6), Position::new(1, 9)), "count".into()),
+                TextEdit::new(
+                    Range::new(Position::new(2, 28), Position::new(2, 31)),
+                    "count".into()
+                ),
+                TextEdit::new(
+                    Range::new(Position::new(3, 9), Position::new(3, 12)),
+                    "count".into()
+                ),
+            ]
+        );
+        // `items` and `total` are already declared
+        assert!(tester.rename("index.js", Position::new(1, 7), "items").is_empty());
+        assert!(tester.rename("index.js", Position::new(1, 7), "total").is_empty());
+        // `let` keyword
+        assert!(tester.rename("index.js", Position::new(1, 3), "count").is_empty());
+    }
+
+    #[test]
+    fn test_show_stats() {
+        let tester = Tester::new("fixtures/lsp/deny_no_console", json!({}));
+        let (stats, stats_after_close) = tester.get_stats("hello_world.js");
+
+        let files = stats["files"].as_array().unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files[0]["path"].as_str().unwrap().ends_with("hello_world.js"));
+        assert_eq!(files[0]["lintCount"], 1);
+        assert!(files[0]["ruleCount"].as_u64().unwrap() > 0);
+        assert!(files[0]["lastDurationMs"].as_f64().unwrap() > 0.0);
+        assert!(files[0]["totalDurationMs"].as_f64().unwrap() > 0.0);
+
+        assert_eq!(stats_after_close["files"], json!([]));
+    }
+
+    #[test]
+    fn test_show_stats_rule_count_of_nested_configs() {
+        let tester = Tester::new("fixtures/lsp/which_config", json!({}));
+        let rule_count = |relative_file_path| {
+            let (stats, _) = tester.get_stats(relative_file_path);
+            stats["files"][0]["ruleCount"].as_u64().unwrap()
+        };
+
+        // The nested config turns `no-console` off
+        assert_eq!(rule_count("index.js"), rule_count("nested/deep/index.js") + 1);
+    }
+
+    #[test]
+    fn test_show_stats_of_multiple_workspaces() {
+        let (stats, _) =
+            Tester::new("fixtures/lsp/deny_no_console", json!({})).get_stats("hello_world.js");
+        let tester = Tester::new("fixtures/lsp/which_config", json!({}));
+        let (other_stats, _) = tester.get_stats("index.js");
+
+        let merged = tester.merge_stats(vec![stats, other_stats]);
+        let mut paths: Vec<_> = merged["files"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|file| PathBuf::from(file["path"].as_str().unwrap()))
+            .collect();
+        paths.sort();
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].ends_with(Path::new("deny_no_console").join("hello_world.js")));
+        assert!(paths[1].ends_with(Path::new("which_config").join("index.js")));
+    }
+
+    #[test]
+    fn test_source_fix_all_code_action() {
+        let tester = Tester::new("fixtures/lsp/source_fix_all", json!({}));
+        // Editors request the fix all action with the cursor position on save
+        let cursor = Range::new(Position::new(0, 0), Position::new(0, 0));
+
+        for kind in [
+            CodeActionKind::SOURCE,
+            CodeActionKind::SOURCE_FIX_ALL,
+            CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC,
+        ] {
+            let code_actions = tester.get_code_actions_of_kinds("index.js", cursor, &[kind]);
+            assert_eq!(code_actions.len(), 1);
+            let CodeActionOrCommand::CodeAction(code_action) = &code_actions[0] else {
+                panic!("expected a code action");
+            };
+            assert_eq!(code_action.kind, Some(CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC));
+
+            // The fixes of the whole file are combined into one edit
+            let text_edits = code_action
+                .edit
+                .clone()
+                .and_then(|edit| edit.changes)
+                .and_then(|changes| changes.into_values().next())
+                .expect("the fix all action should contain text edits");
+            assert_eq!(text_edits.len(), 2);
+            assert_eq!(text_edits[0].range.start.line, 0);
+            assert_eq!(text_edits[1].range.start.line, 1);
+        }
+
+        // Quick fixes are still returned per diagnostic
+        let code_actions =
+            tester.get_code_actions_of_kinds("index.js", cursor, &[CodeActionKind::QUICKFIX]);
+        assert!(!code_actions.is_empty());
+        assert!(code_actions.iter().all(|action| matches!(
+            action,
+            CodeActionOrCommand::CodeAction(action) if action.kind == Some(CodeActionKind::QUICKFIX)
+        )));
+    }
+
+    #[test]
+    fn test_extract_disable_directive_to_config() {
+        let tester = Tester::new("fixtures/lsp/extract_to_config", json!({}));
+        let cursor = Range::new(Position::new(0, 5), Position::new(0, 5));
+        let code_actions = tester.get_code_actions_of_kinds(
+            "index.js",
+            cursor,
+            &[CodeActionKind::REFACTOR_EXTRACT],
+        );
+        assert_eq!(code_actions.len(), 1);
+        let CodeActionOrCommand::CodeAction(code_action) = &code_actions[0] else {
+            panic!("expected a code action");
+        };
+        assert_eq!(code_action.title, "Disable `no-debugger` in `.oxlintrc.json`");
+        assert_eq!(code_action.kind, Some(CodeActionKind::REFACTOR_EXTRACT));
+
+        let changes = code_action.edit.clone().and_then(|edit| edit.changes).unwrap();
+        assert_eq!(changes.len(), 2);
+        let source_uri = get_file_uri("fixtures/lsp/extract_to_config/index.js");
+        let config_uri = get_file_uri("fixtures/lsp/extract_to_config/.oxlintrc.json");
+
+        // The rule is turned off in the config
+        assert_eq!(
+            changes[&config_uri],
+            vec![TextEdit {
+                range: Range::new(Position::new(2, 19), Position::new(2, 26)),
+                new_text: "\"off\"".to_string(),
+            }]
+        );
+
+        // Every directive of the rule is removed, other rules of a directive are kept
+        let ranges = changes[&source_uri]
+            .iter()
+            .map(|edit| (edit.range, edit.new_text.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranges,
+            vec![
+                (Range::new(Position::new(0, 0), Position::new(1, 0)), ""),
+                (Range::new(Position::new(4, 40), Position::new(4, 53)), ""),
+                (Range::new(Position::new(8, 9), Position::new(8, 44)), ""),
+            ]
+        );
+
+        // Outside of a directive, no action is offered
+        let cursor = Range::new(Position::new(1, 3), Position::new(1, 3));
+        assert!(
+            tester
+                .get_code_actions_of_kinds("index.js", cursor, &[CodeActionKind::REFACTOR])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_rule_severity_code_actions() {
+        let tester = Tester::new("fixtures/lsp/rule_severity", json!({}));
+        let cursor = Range::new(Position::new(0, 3), Position::new(0, 3));
+        // Only offered when refactorings are requested
+        assert!(!tester.get_code_actions("index.js", cursor).iter().any(|action| matches!(
+            action,
+            CodeActionOrCommand::CodeAction(action) if action.title.starts_with("Set")
+        )));
+
+        let code_actions =
+            tester.get_code_actions_of_kinds("index.js", cursor, &[CodeActionKind::REFACTOR]);
+        let titles = code_actions
+            .iter()
+            .filter_map(|action| match action {
+                CodeActionOrCommand::CodeAction(action) => Some(action.title.as_str()),
+                CodeActionOrCommand::Command(_) => None,
+            })
+            .filter(|title| title.starts_with("Set"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            titles,
+            vec![
+                // `error` is the current severity of the rule
+                "Set `no-debugger` to `off` in `.oxlintrc.json`",
+                "Set `no-debugger` to `warn` in `.oxlintrc.json`",
+            ]
+        );
+
+        let Some(CodeActionOrCommand::CodeAction(off_action)) = code_actions.iter().find(
+            |action| matches!(action, CodeActionOrCommand::CodeAction(action) if action.title.starts_with("Set `no-debugger` to `off`")),
+        ) else {
+            panic!("expected a code action to turn the rule off");
+        };
+        assert_eq!(off_action.kind, Some(CodeActionKind::REFACTOR));
+        let changes = off_action.edit.clone().and_then(|edit| edit.changes).unwrap();
+        let config_uri = get_file_uri("fixtures/lsp/rule_severity/.oxlintrc.json");
+        assert_eq!(
+            changes[&config_uri],
+            vec![TextEdit {
+                range: Range::new(Position::new(2, 19), Position::new(2, 26)),
+                new_text: "\"off\"".to_string(),
+            }]
+        );
+
+        // Applying the edit turns the rule off in the config
+        let edit = &changes[&config_uri][0];
+        let config =
+            std::fs::read_to_string(get_file_path("fixtures/lsp/rule_severity/.oxlintrc.json"))
+                .unwrap();
+        let line = config.lines().nth(edit.range.start.line as usize).unwrap();
+        let edited_line = format!(
+            "{}{}{}",
+            &line[..edit.range.start.character as usize],
+            edit.new_text,
+            &line[edit.range.end.character as usize..]
+        );
+        assert_eq!(edited_line.trim(), "\"no-debugger\": \"off\"");
+    }
+
+    #[test]
+    fn test_rule_severity_code_actions_of_overridden_rule() {
+        let tester = Tester::new("fixtures/lsp/rule_severity_overrides", json!({}));
+        let cursor = Range::new(Position::new(0, 3), Position::new(0, 3));
+        let titles = |file: &str| {
+            tester
+                .get_code_actions_of_kinds(file, cursor, &[CodeActionKind::REFACTOR])
+                .into_iter()
+                .filter_map(|action| match action {
+                    CodeActionOrCommand::CodeAction(action) => Some(action.title),
+                    CodeActionOrCommand::Command(_) => None,
+                })
+                .filter(|title| title.starts_with("Set"))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            titles("index.js"),
+            vec![
+                "Set `no-debugger` to `off` in `.oxlintrc.json`",
+                "Set `no-debugger` to `warn` in `.oxlintrc.json`",
+            ]
+        );
+        // The override determines the severity of the rule, the top level `rules` do not
+        assert!(titles("index.test.js").is_empty());
+
+        // Turning the rule off in the top level `rules` would not disable it either
+        let content = "// eslint-disable-next-line no-debugger\ndebugger;\n";
+        let extract = |file: &str| {
+            tester.get_unsaved_code_actions_of_kinds(
+                file,
+                content,
+                cursor,
+                &[CodeActionKind::REFACTOR_EXTRACT],
+            )
+        };
+        assert_eq!(extract("index.js").len(), 1);
+        assert!(extract("index.test.js").is_empty());
+    }
+
+    #[test]
+    fn test_extract_disable_directive_of_unsaved_content() {
+        let tester = Tester::new("fixtures/lsp/extract_to_config", json!({}));
+        // The directive moved down by one line in the editor, the file on disk is unchanged
+        let content = "\n// eslint-disable-next-line no-debugger\ndebugger;\n";
+        let cursor = Range::new(Position::new(1, 5), Position::new(1, 5));
+        let code_actions = tester.get_unsaved_code_actions_of_kinds(
+            "index.js",
+            content,
+            cursor,
+            &[CodeActionKind::REFACTOR_EXTRACT],
+        );
+        assert_eq!(code_actions.len(), 1);
+        let CodeActionOrCommand::CodeAction(code_action) = &code_actions[0] else {
+            panic!("expected a code action");
+        };
+
+        let changes = code_action.edit.clone().and_then(|edit| edit.changes).unwrap();
+        let source_uri = get_file_uri("fixtures/lsp/extract_to_config/index.js");
+        assert_eq!(
+            changes[&source_uri],
+            vec![TextEdit {
+                range: Range::new(Position::new(1, 0), Position::new(2, 0)),
+                new_text: String::new(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_requests_share_one_parse() {
+        let tester = Tester::new("fixtures/lsp/extract_to_config", json!({}));
+        let parse_count = tester.count_parses("index.js", |linter, uri| {
+            linter.get_folding_ranges(uri, None);
+            linter.get_document_symbols(uri, None);
+            linter.get_selection_ranges(uri, None, &[Position::new(1, 0)]);
+            linter.get_code_actions_or_commands(
+                uri,
+                None,
+                &Range::default(),
+                Some(&vec![CodeActionKind::REFACTOR_EXTRACT]),
+            );
+            linter.prepare_rename(uri, None, Position::new(3, 10));
+        });
+        assert_eq!(parse_count, 1);
+
+        // A new version of the document is parsed again, once
+        let parse_count = tester.count_parses("index.js", |linter, uri| {
+            linter.get_folding_ranges(uri, None);
+            linter.get_folding_ranges(uri, Some("function foo() {}\n"));
+            linter.get_document_symbols(uri, Some("function foo() {}\n"));
+        });
+        assert_eq!(parse_count, 2);
+
+        // Closing the document drops its program
+        let parse_count = tester.count_parses("index.js", |linter, uri| {
+            linter.get_folding_ranges(uri, None);
+            linter.remove_uri_cache(uri);
+            linter.get_folding_ranges(uri, None);
+        });
+        assert_eq!(parse_count, 2);
+    }
+
+    #[test]
+    fn test_fix_all_reports_skipped_conflicts() {
+        let tester = Tester::new(
+            "fixtures/lsp/fix_all_conflicts",
+            json!({ "fixKind": "safe_fix_or_suggestion" }),
+        );
+        let Some(CommandResult::WorkspaceEdit { edit, message }) = tester.fix_all("index.js")
+        else {
+            panic!("the fix all command should return a workspace edit");
+        };
+
+        // `!!!foo` reports two overlapping fixes, only the first one is applied
+        let Some(DocumentChanges::Edits(document_edits)) = edit.document_changes else {
+            panic!("the fix all command should return text document edits");
+        };
+        let text_edits = &document_edits[0].edits;
+        assert_eq!(text_edits.len(), 1);
+        assert!(matches!(&text_edits[0], OneOf::Right(edit) if edit.text_edit.new_text == "!foo"));
+        assert_eq!(
+            message.as_deref(),
+            Some(
+                "1 fix was skipped because of conflicts with other fixes. Run the command again to apply them."
+            )
+        );
+    }
+
+    #[test]
+    fn test_fix_all_of_unsaved_content() {
+        let tester = Tester::new(
+            "fixtures/lsp/fix_all_conflicts",
+            json!({ "fixKind": "safe_fix_or_suggestion" }),
+        );
+        let Some(CommandResult::WorkspaceEdit { edit, .. }) =
+            tester.fix_all_unsaved("index.js", "if (!!!bar) {}\n")
+        else {
+            panic!("the fix all command should return a workspace edit");
+        };
+
+        // The fixes are computed from the open document, not from the file on disk
+        let Some(DocumentChanges::Edits(document_edits)) = edit.document_changes else {
+            panic!("the fix all command should return text document edits");
+        };
+        let text_edits = &document_edits[0].edits;
+        assert_eq!(text_edits.len(), 1);
+        assert!(matches!(&text_edits[0], OneOf::Right(edit) if edit.text_edit.new_text == "!bar"));
+
+        assert!(tester.fix_all_unsaved("index.js", "if (bar) {}\n").is_none());
+    }
+
+    #[test]
+    fn test_fix_all_change_annotation() {
+        let tester = Tester::new(
+            "fixtures/lsp/fix_all_conflicts",
+            json!({ "fixKind": "safe_fix_or_suggestion" }),
+        );
+        let Some(CommandResult::WorkspaceEdit { edit, .. }) = tester.fix_all("index.js") else {
+            panic!("the fix all command should return a workspace edit");
+        };
+
+        assert_eq!(edit.changes, None);
+        let annotations = edit.change_annotations.expect("the fixes should be annotated");
+        assert_eq!(annotations.len(), 1);
+        let (annotation_id, annotation) = annotations.into_iter().next().unwrap();
+        assert_eq!(annotation.label, "Fix all oxlint problems");
+
+        let Some(DocumentChanges::Edits(document_edits)) = edit.document_changes else {
+            panic!("the fix all command should return text document edits");
+        };
+        assert_eq!(document_edits.len(), 1);
+        assert_eq!(
+            document_edits[0].text_document.uri,
+            get_file_uri("fixtures/lsp/fix_all_conflicts/index.js")
+        );
+        assert!(!document_edits[0].edits.is_empty());
+        // every fix is part of the annotated group
+        assert!(
+            document_edits[0].edits.iter().all(
+                |edit| matches!(edit, OneOf::Right(edit) if edit.annotation_id == annotation_id)
+            )
+        );
+    }
+
+    #[test]
+    fn test_verbose_log_messages() {
+        let tester = Tester::new("fixtures/lsp/deny_no_console", json!({ "verbose": true }));
+        let messages = tester.lint_and_take_log_messages("hello_world.js");
+
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].starts_with("config loaded for "));
+        assert!(messages[0].ends_with(".oxlintrc.json, 0 nested configs"));
+        assert!(messages[1].starts_with("linted "));
+        assert!(messages[1].ends_with(": 1 diagnostics"));
+
+        // Without the verbose option no messages are collected
+        let tester = Tester::new("fixtures/lsp/deny_no_console", json!({}));
+        assert!(tester.lint_and_take_log_messages("hello_world.js").is_empty());
+    }
+
+    #[test]
+    fn test_lint_file_command() {
+        let tester = Tester::new("fixtures/lsp/deny_no_console", json!({}));
+        let diagnostics = tester.lint_file("hello_world.js");
+
+        assert_eq!(diagnostics.len(), 1);
+        let (uri, diagnostics) = &diagnostics[0];
+        assert!(uri.as_str().ends_with("hello_world.js"));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].code,
+            Some(NumberOrString::String("eslint(no-console)".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_lint_file_command_of_unsaved_content() {
+        let tester = Tester::new("fixtures/lsp/deny_no_console", json!({}));
+        let diagnostics =
+            tester.lint_unsaved_file("hello_world.js", "console.log(1);\nconsole.log(2);\n");
+
+        // The open document is linted instead of the file on disk
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].1.len(), 2);
+    }
+
+    #[test]
+    fn test_code_description_href() {
+        let tester = Tester::new("fixtures/lsp/deny_no_console", json!({}));
+        let diagnostics = tester.lint_file("hello_world.js");
+        let href = diagnostics[0].1[0]
+            .code_description
+            .as_ref()
+            .map(|description| description.href.as_str().to_string());
+        assert_eq!(
+            href.as_deref(),
+            Some("https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-console.html")
+        );
+
+        // Parse errors have no rule documentation
+        let tester = Tester::new("fixtures/lsp/invalid_syntax", json!({}));
+        let diagnostics = tester.lint_file("debugger.ts");
+        assert!(!diagnostics[0].1.is_empty());
+        assert!(diagnostics[0].1.iter().all(|diagnostic| diagnostic.code_description.is_none()));
+    }
+
+    #[test]
+    fn test_working_directory() {
+        let tester = Tester::new(
+            "fixtures/lsp/working_directory",
+            json!({ "workingDirectory": "project", "disableNestedConfig": true }),
+        );
+
+        // The ignore pattern of `project/.oxlintrc.json` is relative to the working directory
+        let diagnostics = tester.lint_file("project/generated/debugger.js");
+        assert!(diagnostics[0].1.is_empty());
+
+        let diagnostics = tester.lint_file("generated/debugger.js");
+        assert_eq!(diagnostics[0].1.len(), 1);
+        assert_eq!(diagnostics[0].1[0].severity, Some(DiagnosticSeverity::ERROR));
+
+        // Without a working directory, the config is not found in the workspace root
+        let tester =
+            Tester::new("fixtures/lsp/working_directory", json!({ "disableNestedConfig": true }));
+        let diagnostics = tester.lint_file("project/generated/debugger.js");
+        assert_eq!(diagnostics[0].1.len(), 1);
+        assert_eq!(diagnostics[0].1[0].severity, Some(DiagnosticSeverity::WARNING));
+    }
+
+    #[test]
+    fn test_which_config_command() {
+        let tester = Tester::new("fixtures/lsp/which_config", json!({}));
+
+        let root = tester.which_config("index.js");
+        let root_paths = root["configPaths"].as_array().unwrap();
+        assert_eq!(root_paths.len(), 1);
+        assert!(
+            Path::new(root_paths[0].as_str().unwrap()).ends_with("which_config/.oxlintrc.json")
+        );
+        assert_eq!(root["message"], serde_json::Value::Null);
+
+        // the nearest nested config wins, followed by the config it extends
+        let nested = tester.which_config("nested/deep/index.js");
+        let nested_paths = nested["configPaths"].as_array().unwrap();
+        assert_eq!(nested_paths.len(), 2);
+        assert!(
+            Path::new(nested_paths[0].as_str().unwrap())
+                .ends_with("which_config/nested/.oxlintrc.json")
+        );
+        assert!(nested_paths[1].as_str().unwrap().ends_with("shared.json"));
+
+        // without any config file, the defaults are used
+        let tester = Tester::new("fixtures/lsp/folding_range", json!({}));
+        let default = tester.which_config("index.js");
+        assert!(default["configPaths"].as_array().unwrap().is_empty());
+        assert!(default["message"].as_str().unwrap().contains("the default config is used"));
+    }
 }
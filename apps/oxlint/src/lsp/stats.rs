@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Lint statistics of a single file, accumulated over all lint runs of the current linter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileStats {
+    pub path: String,
+    /// How many times the file was linted.
+    pub lint_count: u32,
+    /// Number of rules of the config which applies to the file, without the rules of `overrides`.
+    pub rule_count: usize,
+    /// Duration of the last lint run in milliseconds.
+    pub last_duration_ms: f64,
+    /// Sum of the durations of all lint runs in milliseconds.
+    pub total_duration_ms: f64,
+}
+
+impl FileStats {
+    pub fn new(path: String) -> Self {
+        Self { path, lint_count: 0, rule_count: 0, last_duration_ms: 0.0, total_duration_ms: 0.0 }
+    }
+
+    pub fn record(&mut self, duration: Duration, rule_count: usize) {
+        let duration_ms = duration.as_secs_f64() * 1000.0;
+        self.lint_count += 1;
+        self.rule_count = rule_count;
+        self.last_duration_ms = duration_ms;
+        self.total_duration_ms += duration_ms;
+    }
+}
+
+/// The response of the `oxc.showStats` command.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintStats {
+    /// Files sorted by their total lint duration, the most expensive first.
+    pub files: Vec<FileStats>,
+}
+
+impl LintStats {
+    pub fn new(mut files: Vec<FileStats>) -> Self {
+        files.sort_by(|a, b| b.total_duration_ms.total_cmp(&a.total_duration_ms));
+        Self { files }
+    }
+}
@@ -1,12 +1,22 @@
-use std::{fmt::Write, path::PathBuf};
+use std::{fmt::Write, path::PathBuf, sync::Mutex};
 
-use oxc_language_server::{DiagnosticResult, Tool, ToolRestartChanges};
+use serde_json::json;
+
+use oxc_language_server::{
+    CommandResult, DiagnosticResult, LSPFileSystem, Tool, ToolRestartChanges,
+};
 use tower_lsp_server::ls_types::{
-    CodeAction, CodeActionKind, CodeActionOrCommand, CodeDescription, Diagnostic, NumberOrString,
-    Position, Range, Uri,
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeDescription, CompletionItem, Diagnostic,
+    DocumentSymbol, FoldingRange, InlayHint, NumberOrString, Position, Range, SelectionRange,
+    SymbolInformation, TextEdit, Uri, WorkDoneProgress,
 };
 
-use crate::lsp::server_linter::{ServerLinter, ServerLinterBuilder};
+use crate::lsp::{
+    commands::{
+        FIX_ALL_COMMAND_ID, LINT_FILE_COMMAND_ID, SHOW_STATS_COMMAND_ID, WHICH_CONFIG_COMMAND_ID,
+    },
+    server_linter::{ServerLinter, ServerLinterBuilder},
+};
 
 /// Given a file path relative to the crate root directory, return the absolute path of the file.
 pub fn get_file_path(relative_file_path: &str) -> PathBuf {
@@ -112,6 +122,10 @@ fn get_snapshot_for_code_action(code_action: &CodeAction) -> String {
         return "No changes in workspace edit".to_string();
     };
 
+    // a workspace edit can change multiple files, print them in a stable order
+    let mut changes = changes.iter().collect::<Vec<_>>();
+    changes.sort_unstable_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+
     let mut result = String::new();
     let _ = writeln!(result, "Title: {}", code_action.title);
     let _ = writeln!(result, "Is Preferred: {:?}", code_action.is_preferred);
@@ -119,8 +133,8 @@ fn get_snapshot_for_code_action(code_action: &CodeAction) -> String {
         result,
         "{}",
         changes
-            .values()
-            .map(|text_edits| {
+            .into_iter()
+            .map(|(_, text_edits)| {
                 let mut result = String::new();
                 for text_edit in text_edits {
                     let _ = writeln!(result, "TextEdit: {text_edit:#?}");
@@ -218,12 +232,14 @@ impl Tester<'_> {
                 diagnostic: linter.run_diagnostic(&uri, None),
                 actions: linter.get_code_actions_or_commands(
                     &uri,
+                    None,
                     &Range::new(Position::new(0, 0), Position::new(u32::MAX, u32::MAX)),
                     None,
                 ),
                 fix_all_action: linter
                     .get_code_actions_or_commands(
                         &uri,
+                        None,
                         &Range::new(Position::new(0, 0), Position::new(u32::MAX, u32::MAX)),
                         Some(&vec![CodeActionKind::SOURCE_FIX_ALL]),
                     )
@@ -251,6 +267,282 @@ impl Tester<'_> {
         });
     }
 
+    pub fn get_folding_ranges(&self, relative_file_path: &str) -> Vec<FoldingRange> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        self.create_linter().get_folding_ranges(&uri, None)
+    }
+
+    pub fn get_document_symbols(&self, relative_file_path: &str) -> Vec<DocumentSymbol> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        self.create_linter().get_document_symbols(&uri, None)
+    }
+
+    /// Search the symbols of the given files, as if they were the open documents of the workspace.
+    pub fn get_workspace_symbols(
+        &self,
+        query: &str,
+        relative_file_paths: &[&str],
+    ) -> Vec<SymbolInformation> {
+        let documents = relative_file_paths
+            .iter()
+            .map(|relative_file_path| {
+                let relative_file_path =
+                    format!("{}/{}", self.relative_root_dir, relative_file_path);
+                let content = std::fs::read_to_string(get_file_path(&relative_file_path))
+                    .expect("could not read fixture file");
+                (get_file_uri(&relative_file_path), content)
+            })
+            .collect::<Vec<_>>();
+        self.create_linter().get_workspace_symbols(query, &documents)
+    }
+
+    pub fn get_selection_ranges(
+        &self,
+        relative_file_path: &str,
+        positions: &[Position],
+    ) -> Vec<SelectionRange> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        self.create_linter().get_selection_ranges(&uri, None, positions)
+    }
+
+    pub fn prepare_rename(&self, relative_file_path: &str, position: Position) -> Option<Range> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        self.create_linter().prepare_rename(&uri, None, position)
+    }
+
+    pub fn rename(
+        &self,
+        relative_file_path: &str,
+        position: Position,
+        new_name: &str,
+    ) -> Vec<TextEdit> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        self.create_linter().rename(&uri, None, position, new_name)
+    }
+
+    /// Run the requests for the file against one linter, then return how many times a source text was parsed.
+    pub fn count_parses(
+        &self,
+        relative_file_path: &str,
+        requests: impl FnOnce(&ServerLinter, &Uri),
+    ) -> usize {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let linter = self.create_linter();
+        requests(&linter, &uri);
+        linter.parse_count()
+    }
+
+    pub fn get_completions(
+        &self,
+        relative_file_path: &str,
+        position: Position,
+    ) -> Vec<CompletionItem> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        self.create_linter().get_completions(&uri, None, position)
+    }
+
+    /// Lint the given file, then return the inlay hints of the whole file.
+    pub fn get_inlay_hints(&self, relative_file_path: &str) -> Vec<InlayHint> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let linter = self.create_linter();
+        linter.run_diagnostic(&uri, None).expect("linting should succeed");
+        linter.get_inlay_hints(
+            &uri,
+            None,
+            &Range::new(Position::new(0, 0), Position::new(u32::MAX, u32::MAX)),
+        )
+    }
+
+    /// Lint the given file, then return the code actions of the range.
+    pub fn get_code_actions(
+        &self,
+        relative_file_path: &str,
+        range: Range,
+    ) -> Vec<CodeActionOrCommand> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let linter = self.create_linter();
+        linter.run_diagnostic(&uri, None).expect("linting should succeed");
+        linter.get_code_actions_or_commands(&uri, None, &range, None)
+    }
+
+    /// Lint the given file, then return the code actions of the range, limited to the requested kinds.
+    pub fn get_code_actions_of_kinds(
+        &self,
+        relative_file_path: &str,
+        range: Range,
+        only: &[CodeActionKind],
+    ) -> Vec<CodeActionOrCommand> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let linter = self.create_linter();
+        linter.run_diagnostic(&uri, None).expect("linting should succeed");
+        linter.get_code_actions_or_commands(&uri, None, &range, Some(&only.to_vec()))
+    }
+
+    /// Lint the unsaved `content` of the given file, then return the code actions of the range,
+    /// limited to the requested kinds.
+    pub fn get_unsaved_code_actions_of_kinds(
+        &self,
+        relative_file_path: &str,
+        content: &str,
+        range: Range,
+        only: &[CodeActionKind],
+    ) -> Vec<CodeActionOrCommand> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let linter = self.create_linter();
+        linter.run_diagnostic(&uri, Some(content)).expect("linting should succeed");
+        linter.get_code_actions_or_commands(&uri, Some(content), &range, Some(&only.to_vec()))
+    }
+
+    /// Scan the complete workspace, returning the reported progress and the diagnostics.
+    pub fn scan_workspace(&self) -> (Vec<WorkDoneProgress>, Vec<(Uri, Vec<Diagnostic>)>) {
+        let progress = Mutex::new(Vec::new());
+        let diagnostics = self
+            .create_linter()
+            .run_workspace_diagnostic(&|report| progress.lock().unwrap().push(report))
+            .expect("scanning the workspace should succeed");
+        (progress.into_inner().unwrap(), diagnostics)
+    }
+
+    /// Scan the workspace, then open the given file with the same linter.
+    /// Returns the number of files reported by the scan and the diagnostics of the opened file.
+    pub fn scan_workspace_and_open(
+        &self,
+        relative_file_path: &str,
+    ) -> (usize, Vec<(Uri, Vec<Diagnostic>)>) {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let linter = self.create_linter();
+        let scanned = linter
+            .run_workspace_diagnostic(&|_| {})
+            .expect("scanning the workspace should succeed");
+        let opened = linter.run_diagnostic(&uri, None).expect("linting should succeed");
+        (scanned.len(), opened)
+    }
+
+    /// Lint the given file, then return the responses of the `oxc.showStats` command
+    /// before and after the file is closed.
+    pub fn get_stats(&self, relative_file_path: &str) -> (serde_json::Value, serde_json::Value) {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let linter = self.create_linter();
+        linter.run_diagnostic(&uri, None).expect("linting should succeed");
+
+        let show_stats = || match linter.execute_command(
+            SHOW_STATS_COMMAND_ID,
+            vec![],
+            &LSPFileSystem::default(),
+        ) {
+            Ok(Some(CommandResult::Value(value))) => value,
+            result => panic!("unexpected result of the show stats command: {result:?}"),
+        };
+        let stats = show_stats();
+        linter.remove_uri_cache(&uri);
+        (stats, show_stats())
+    }
+
+    /// Merge the responses of the `oxc.showStats` command of several workspaces.
+    pub fn merge_stats(&self, values: Vec<serde_json::Value>) -> serde_json::Value {
+        self.create_linter().merge_command_values(SHOW_STATS_COMMAND_ID, values)
+    }
+
+    /// Lint the given file, then return the result of the `oxc.fixAll` command.
+    pub fn fix_all(&self, relative_file_path: &str) -> Option<CommandResult> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let linter = self.create_linter();
+        linter.run_diagnostic(&uri, None).expect("linting should succeed");
+
+        linter
+            .execute_command(
+                FIX_ALL_COMMAND_ID,
+                vec![json!({ "uri": uri.as_str() })],
+                &LSPFileSystem::default(),
+            )
+            .expect("the fix all command should succeed")
+    }
+
+    /// Return the result of the `oxc.fixAll` command for the given file,
+    /// which is open with the unsaved `content`.
+    pub fn fix_all_unsaved(
+        &self,
+        relative_file_path: &str,
+        content: &str,
+    ) -> Option<CommandResult> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let file_system = LSPFileSystem::default();
+        file_system.set(uri.clone(), content.to_string());
+
+        self.create_linter()
+            .execute_command(FIX_ALL_COMMAND_ID, vec![json!({ "uri": uri.as_str() })], &file_system)
+            .expect("the fix all command should succeed")
+    }
+
+    /// Lint the file and return the trace messages collected by the linter, including the ones of its creation.
+    pub fn lint_and_take_log_messages(&self, relative_file_path: &str) -> Vec<String> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let linter = self.create_linter();
+        linter.run_diagnostic(&uri, None).expect("linting should succeed");
+        linter.take_log_messages()
+    }
+
+    /// Return the diagnostics published by the `oxc.lintFile` command, without opening the file.
+    pub fn lint_file(&self, relative_file_path: &str) -> Vec<(Uri, Vec<Diagnostic>)> {
+        self.lint_file_with(relative_file_path, &LSPFileSystem::default())
+    }
+
+    /// Return the diagnostics published by the `oxc.lintFile` command,
+    /// for the given file which is open with the unsaved `content`.
+    pub fn lint_unsaved_file(
+        &self,
+        relative_file_path: &str,
+        content: &str,
+    ) -> Vec<(Uri, Vec<Diagnostic>)> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let file_system = LSPFileSystem::default();
+        file_system.set(uri, content.to_string());
+        self.lint_file_with(relative_file_path, &file_system)
+    }
+
+    fn lint_file_with(
+        &self,
+        relative_file_path: &str,
+        file_system: &LSPFileSystem,
+    ) -> Vec<(Uri, Vec<Diagnostic>)> {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+
+        match self.create_linter().execute_command(
+            LINT_FILE_COMMAND_ID,
+            vec![json!({ "uri": uri.as_str() })],
+            file_system,
+        ) {
+            Ok(Some(CommandResult::Diagnostics(diagnostics))) => diagnostics,
+            result => panic!("unexpected result of the lint file command: {result:?}"),
+        }
+    }
+
+    /// Return the response of the `oxc.whichConfig` command for the given file.
+    pub fn which_config(&self, relative_file_path: &str) -> serde_json::Value {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+
+        match self.create_linter().execute_command(
+            WHICH_CONFIG_COMMAND_ID,
+            vec![json!({ "uri": uri.as_str() })],
+            &LSPFileSystem::default(),
+        ) {
+            Ok(Some(CommandResult::Value(value))) => value,
+            result => panic!("unexpected result of the which config command: {result:?}"),
+        }
+    }
+
+    /// Fire a change of the file, immediately followed by a save, and return how often the file was linted.
+    pub fn change_and_save(&self, relative_file_path: &str) -> usize {
+        let uri = get_file_uri(&format!("{}/{}", self.relative_root_dir, relative_file_path));
+        let linter = self.create_linter();
+
+        let on_change =
+            linter.run_diagnostic_on_change(&uri, None).expect("linting should succeed");
+        let on_save = linter.run_diagnostic_on_save(&uri, None).expect("linting should succeed");
+
+        [on_change, on_save].iter().filter(|diagnostics| !diagnostics.is_empty()).count()
+    }
+
     pub fn get_watcher_patterns(&self) -> Vec<String> {
         self.create_linter().get_watcher_patterns(self.options.clone())
     }
@@ -1,10 +1,18 @@
-use std::{ffi::OsStr, path::PathBuf, sync::Arc, sync::mpsc};
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    sync::Arc,
+    sync::mpsc,
+};
 
 use ignore::{DirEntry, overrides::Override};
 use oxc_linter::LINTABLE_EXTENSIONS;
 
 use crate::cli::IgnoreOptions;
 
+/// Decides whether a directory and everything in it is skipped during the traversal.
+pub type SkipDirectory = Arc<dyn Fn(&Path) -> bool + Send + Sync>;
+
 #[derive(Debug, Clone)]
 pub struct Extensions(pub Vec<&'static str>);
 
@@ -18,11 +26,13 @@ pub struct Walk {
     inner: ignore::WalkParallel,
     /// The file extensions to include during the traversal.
     extensions: Extensions,
+    skip_directory: Option<SkipDirectory>,
 }
 
 struct WalkBuilder {
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    skip_directory: Option<SkipDirectory>,
 }
 
 impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
@@ -31,6 +41,7 @@ impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
             paths: vec![],
             sender: self.sender.clone(),
             extensions: self.extensions.clone(),
+            skip_directory: self.skip_directory.clone(),
         })
     }
 }
@@ -39,6 +50,7 @@ struct WalkCollector {
     paths: Vec<Arc<OsStr>>,
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    skip_directory: Option<SkipDirectory>,
 }
 
 impl Drop for WalkCollector {
@@ -54,7 +66,10 @@ impl ignore::ParallelVisitor for WalkCollector {
             Ok(entry) => {
                 // Skip traversing `.git` directories because `.git` is not a special case for `.hidden(false)`.
                 // <https://github.com/BurntSushi/ripgrep/issues/3099#issuecomment-3052460027>
-                if entry.file_type().is_some_and(|ty| ty.is_dir()) && entry.file_name() == ".git" {
+                if entry.file_type().is_some_and(|ty| ty.is_dir())
+                    && (entry.file_name() == ".git"
+                        || self.skip_directory.as_ref().is_some_and(|skip| skip(entry.path())))
+                {
                     return ignore::WalkState::Skip;
                 }
                 if Walk::is_wanted_entry(&entry, &self.extensions) {
@@ -105,17 +120,27 @@ impl Walk {
             .hidden(false)
             .require_git(false)
             .build_parallel();
-        Self { inner, extensions: Extensions::default() }
+        Self { inner, extensions: Extensions::default(), skip_directory: None }
     }
 
     pub fn paths(self) -> Vec<Arc<OsStr>> {
         let (sender, receiver) = mpsc::channel::<Vec<Arc<OsStr>>>();
-        let mut builder = WalkBuilder { sender, extensions: self.extensions };
+        let mut builder = WalkBuilder {
+            sender,
+            extensions: self.extensions,
+            skip_directory: self.skip_directory,
+        };
         self.inner.visit(&mut builder);
         drop(builder);
         receiver.into_iter().flatten().collect()
     }
 
+    /// Skip the directories for which `skip_directory` returns `true`, together with everything in them.
+    pub fn with_skip_directory(mut self, skip_directory: SkipDirectory) -> Self {
+        self.skip_directory = Some(skip_directory);
+        self
+    }
+
     #[cfg_attr(not(test), expect(dead_code))]
     pub fn with_extensions(mut self, extensions: Extensions) -> Self {
         self.extensions = extensions;
@@ -645,6 +645,20 @@ fn v8_intrinsics() {
     );
 }
 
+#[test]
+fn pipeline_operator() {
+    let parse_opts = oxc_parser::ParseOptions {
+        allow_pipeline_operator: true,
+        ..oxc_parser::ParseOptions::default()
+    };
+
+    test_with_parse_options("x |> f |> g", "x |> f |> g;\n", parse_opts);
+    test_with_parse_options("(a ?? b) |> f", "a ?? b |> f;\n", parse_opts);
+    test_with_parse_options("a ?? (b |> f)", "a ?? (b |> f);\n", parse_opts);
+    test_with_parse_options("a |> (b |> c)", "a |> (b |> c);\n", parse_opts);
+    test_with_parse_options("x = a |> f", "x = a |> f;\n", parse_opts);
+}
+
 #[test]
 fn indentation() {
     // Test default - tabs with width 1
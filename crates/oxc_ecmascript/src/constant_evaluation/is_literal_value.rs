@@ -153,7 +153,8 @@ impl<'a> IsLiteralValue<'a, '_> for BinaryExpression<'a> {
             | BinaryOperator::Equality
             | BinaryOperator::Inequality
             | BinaryOperator::In
-            | BinaryOperator::Instanceof => false,
+            | BinaryOperator::Instanceof
+            | BinaryOperator::Pipeline => false,
         }
     }
 }
@@ -277,7 +278,8 @@ fn can_convert_to_number_transparently<'a>(
             | BinaryOperator::Equality
             | BinaryOperator::Inequality
             | BinaryOperator::In
-            | BinaryOperator::Instanceof => false,
+            | BinaryOperator::Instanceof
+            | BinaryOperator::Pipeline => false,
         },
         Expression::LogicalExpression(e) => {
             can_convert_to_number_transparently(&e.left, include_functions, ctx)
@@ -389,7 +389,7 @@ fn binary_operation_evaluate_value_to<'a>(
             let value = abstract_equality_comparison(ctx, left, right)?;
             Some(ConstantValue::Boolean(!value))
         }
-        BinaryOperator::In => None,
+        BinaryOperator::In | BinaryOperator::Pipeline => None,
     }
 }
 
@@ -154,6 +154,8 @@ impl<'a> DetermineValueType<'a> for BinaryExpression<'a> {
                 }
             }
             BinaryOperator::ShiftRightZeroFill => ValueType::Number,
+            // `x |> f` returns the result of calling `f`
+            BinaryOperator::Pipeline => ValueType::Undetermined,
             BinaryOperator::Instanceof
             | BinaryOperator::In
             | BinaryOperator::Equality
@@ -146,10 +146,8 @@ impl<'a> MayHaveSideEffects<'a> for BinaryExpression<'a> {
                 // instanceof can throw `TypeError`
                 true
             }
-            BinaryOperator::In => {
-                // in can throw `TypeError`
-                true
-            }
+            // in can throw `TypeError`, `x |> f` calls `f`
+            BinaryOperator::In | BinaryOperator::Pipeline => true,
             BinaryOperator::Addition => {
                 let left = self.left.to_primitive(ctx);
                 let right = self.right.to_primitive(ctx);
@@ -20,17 +20,21 @@ fn main() -> Result<(), String> {
     let partition_by_newline = args.contains("--partition_by_newline");
     let partition_by_comment = args.contains("--partition_by_comment");
     let sort_side_effects = args.contains("--sort_side_effects");
+    let partition_by_side_effect = args.contains("--partition_by_side_effect");
     let order = args.opt_value_from_str("--order").unwrap_or(None).unwrap_or(SortOrder::Asc);
     let ignore_case = !args.contains("--no_ignore_case");
     let newlines_between = !args.contains("--no_newlines_between");
+    let preserve_newlines_between = args.contains("--preserve_newlines_between");
 
     let sort_imports_options = SortImportsOptions {
         order,
         partition_by_newline,
         partition_by_comment,
         sort_side_effects,
+        partition_by_side_effect,
         ignore_case,
         newlines_between,
+        preserve_newlines_between,
         internal_pattern: default_internal_patterns(),
         groups: default_groups(),
         custom_groups: vec![],
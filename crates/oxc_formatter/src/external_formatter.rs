@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use rustc_hash::FxHashMap;
+
 /// Callback function type for formatting embedded code.
 /// Takes (tag_name, code) and returns formatted code or an error.
 pub type EmbeddedFormatterCallback =
@@ -17,13 +19,19 @@ pub type TailwindCallback = Arc<dyn Fn(Vec<String>) -> Vec<String> + Send + Sync
 #[derive(Default)]
 pub struct ExternalCallbacks {
     embedded_formatter: Option<EmbeddedFormatterCallback>,
+    /// Overrides of the parser names passed to the embedded formatter callback.
+    embedded_language_mapping: FxHashMap<String, String>,
     tailwind: Option<TailwindCallback>,
 }
 
 impl ExternalCallbacks {
     /// Create a new `ExternalCallbacks` with no callbacks set.
     pub fn new() -> Self {
-        Self { embedded_formatter: None, tailwind: None }
+        Self {
+            embedded_formatter: None,
+            embedded_language_mapping: FxHashMap::default(),
+            tailwind: None,
+        }
     }
 
     /// Set the embedded formatter callback.
@@ -33,6 +41,20 @@ impl ExternalCallbacks {
         self
     }
 
+    /// Set the mapping from the detected embedded languages to the parser names passed to the embedded formatter callback,
+    /// e.g. `{ "tagged-css": "scss" }` to format `` css`...` `` templates with the `scss` parser.
+    ///
+    /// The mapped name replaces the built-in parser the callback would choose for the language,
+    /// so it must be a parser name the callback supports (e.g. `css`, `scss`, `less`, `graphql`, `html`, `markdown`).
+    /// Languages without an entry are passed with their built-in name:
+    /// `tagged-css`, `styled-jsx`, `tagged-graphql`, `tagged-html`, `tagged-markdown`, `tagged-sql`,
+    /// `angular-template` and `angular-styles`.
+    #[must_use]
+    pub fn with_embedded_language_mapping(mut self, mapping: FxHashMap<String, String>) -> Self {
+        self.embedded_language_mapping = mapping;
+        self
+    }
+
     /// Set the Tailwind callback.
     #[must_use]
     pub fn with_tailwind(mut self, callback: Option<TailwindCallback>) -> Self {
@@ -43,7 +65,8 @@ impl ExternalCallbacks {
     /// Format embedded code with the given tag name.
     ///
     /// # Arguments
-    /// * `tag_name` - The template tag (e.g., "css", "gql", "html")
+    /// * `tag_name` - The detected language (e.g., "tagged-css", "tagged-graphql"),
+    ///   which is passed to the callback as mapped by [`Self::with_embedded_language_mapping`]
     /// * `code` - The code to format
     ///
     /// # Returns
@@ -51,7 +74,9 @@ impl ExternalCallbacks {
     /// * `Some(Err(String))` - An error message if formatting failed
     /// * `None` - No embedded formatter callback is set
     pub fn format_embedded(&self, tag_name: &str, code: &str) -> Option<Result<String, String>> {
-        self.embedded_formatter.as_ref().map(|cb| cb(tag_name, code))
+        let language =
+            self.embedded_language_mapping.get(tag_name).map_or(tag_name, String::as_str);
+        self.embedded_formatter.as_ref().map(|cb| cb(language, code))
     }
 
     /// Sort Tailwind CSS classes.
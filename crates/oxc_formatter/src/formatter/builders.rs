@@ -1502,9 +1502,8 @@ impl<'ast> Format<'ast> for BlockIndent<'_, 'ast> {
 
         Arguments::from(&self.content).fmt(f);
 
-        debug_assert_ne!(
-            elements_length,
-            f.elements().len(),
+        debug_assert!(
+            elements_length != f.elements().len() || f.context().nesting_depth_exceeded(),
             "BlockIndent's content must produce at least one element"
         );
 
@@ -248,6 +248,21 @@ impl<'a> Comments<'a> {
         self.comments_before_iter(start).any(|comment| comment.followed_by_newline())
     }
 
+    /// Checks if the comment is the first comment of the file, with only a BOM, a hashbang
+    /// or whitespace before it.
+    pub fn is_leading_file_comment(&self, comment: &Comment) -> bool {
+        if self.inner.first().is_none_or(|first| first.span != comment.span) {
+            return false;
+        }
+        let before = self.source_text.slice_to(comment.span.start).trim_start_matches('\u{feff}');
+        let before = if before.starts_with("#!") {
+            before.split_once(['\n', '\r']).map_or("", |(_, rest)| rest)
+        } else {
+            before
+        };
+        before.trim().is_empty()
+    }
+
     pub fn has_end_of_line_comment_after(&self, pos: u32) -> bool {
         !self.end_of_line_comments_after(pos).is_empty()
     }
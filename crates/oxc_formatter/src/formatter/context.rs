@@ -9,7 +9,7 @@ use crate::{
     external_formatter::ExternalCallbacks, formatter::FormatElement, options::FormatOptions,
 };
 
-use super::{Comments, SourceText};
+use super::{Comments, GroupId, SourceText};
 
 /// Entry in the Tailwind context stack, tracking whether we're inside a Tailwind class context.
 #[derive(Clone, Copy, Debug)]
@@ -79,6 +79,24 @@ impl TailwindContextEntry {
     }
 }
 
+/// The default maximum nesting depth of the formatted content.
+///
+/// Every nested [`crate::write!`] call counts as one level, a single AST node usually takes a few of them.
+/// The limit is chosen to leave enough room for real-world code while staying well within the stack
+/// size of a thread.
+pub const DEFAULT_MAX_NESTING_DEPTH: u32 = 2048;
+
+/// Entry in the object property alignment stack, used when
+/// [`FormatOptions::align_object_properties`] or [`FormatOptions::align_enum_members`] is enabled.
+#[derive(Debug)]
+pub struct ObjectPropertyAlignment {
+    /// The group of the object expression or enum body, the padding is only printed when it breaks.
+    pub group_id: GroupId,
+    /// The padding after the colon of each aligned property, or before the `=` of each aligned enum member,
+    /// keyed by the span of the property or member.
+    pub paddings: FxHashMap<Span, usize>,
+}
+
 /// Context object storing data relevant when formatting an object.
 pub struct FormatContext<'ast> {
     options: FormatOptions,
@@ -98,6 +116,11 @@ pub struct FormatContext<'ast> {
     /// structures (e.g., `{ a: { "b-c": 1 } }` where only the inner object needs quoted keys).
     quote_needed_stack: Vec<bool>,
 
+    /// Tracks the alignment of the property values in the current object expression.
+    ///
+    /// A stack is used to handle nested object expressions, each of them aligns its own properties.
+    object_property_alignment_stack: Vec<ObjectPropertyAlignment>,
+
     /// Collected Tailwind CSS class strings from JSX attributes.
     /// These will be sorted by an external callback and replaced during printing.
     tailwind_classes: Vec<String>,
@@ -108,6 +131,16 @@ pub struct FormatContext<'ast> {
 
     external_callbacks: ExternalCallbacks,
 
+    /// The maximum nesting depth of the formatted content, see [`Self::enter_nesting`].
+    max_nesting_depth: u32,
+
+    /// The nesting depth of the content that is currently formatted.
+    nesting_depth: u32,
+
+    /// Whether the content was nested deeper than [`Self::max_nesting_depth`].
+    /// The formatted document is incomplete in this case and must not be printed.
+    nesting_depth_exceeded: bool,
+
     allocator: &'ast Allocator,
 }
 
@@ -142,9 +175,13 @@ impl<'ast> FormatContext<'ast> {
             comments: Comments::new(source_text, comments),
             cached_elements: FxHashMap::default(),
             quote_needed_stack: Vec::new(),
+            object_property_alignment_stack: Vec::new(),
             tailwind_classes: Vec::new(),
             tailwind_context_stack: Vec::new(),
             external_callbacks: external_callbacks.unwrap_or_default(),
+            max_nesting_depth: DEFAULT_MAX_NESTING_DEPTH,
+            nesting_depth: 0,
+            nesting_depth_exceeded: false,
             allocator,
         }
     }
@@ -157,13 +194,51 @@ impl<'ast> FormatContext<'ast> {
             comments: Comments::new(SourceText::new(""), &[]),
             cached_elements: FxHashMap::default(),
             quote_needed_stack: Vec::new(),
+            object_property_alignment_stack: Vec::new(),
             tailwind_classes: Vec::new(),
             tailwind_context_stack: Vec::new(),
             external_callbacks: ExternalCallbacks::default(),
+            max_nesting_depth: DEFAULT_MAX_NESTING_DEPTH,
+            nesting_depth: 0,
+            nesting_depth_exceeded: false,
             allocator,
         }
     }
 
+    /// Sets the maximum nesting depth of the formatted content.
+    #[must_use]
+    pub fn with_max_nesting_depth(mut self, max_nesting_depth: u32) -> Self {
+        self.max_nesting_depth = max_nesting_depth;
+        self
+    }
+
+    /// Returns the maximum nesting depth of the formatted content.
+    pub fn max_nesting_depth(&self) -> u32 {
+        self.max_nesting_depth
+    }
+
+    /// Returns `true` if the content was nested deeper than [`Self::max_nesting_depth`].
+    pub fn nesting_depth_exceeded(&self) -> bool {
+        self.nesting_depth_exceeded
+    }
+
+    /// Enters a nested level of formatting, returns `false` if this exceeds the maximum nesting depth.
+    ///
+    /// Every successful call must be paired with a call to [`Self::leave_nesting`].
+    pub(crate) fn enter_nesting(&mut self) -> bool {
+        if self.nesting_depth >= self.max_nesting_depth {
+            self.nesting_depth_exceeded = true;
+            return false;
+        }
+        self.nesting_depth += 1;
+        true
+    }
+
+    /// Leaves a nested level of formatting entered by [`Self::enter_nesting`].
+    pub(crate) fn leave_nesting(&mut self) {
+        self.nesting_depth -= 1;
+    }
+
     /// Get the external callbacks if set
     pub fn external_callbacks(&self) -> &ExternalCallbacks {
         &self.external_callbacks
@@ -226,6 +301,21 @@ impl<'ast> FormatContext<'ast> {
         *self.quote_needed_stack.last().unwrap_or(&false)
     }
 
+    /// Pushes the property alignment of an object expression onto the stack.
+    pub fn push_object_property_alignment(&mut self, alignment: ObjectPropertyAlignment) {
+        self.object_property_alignment_stack.push(alignment);
+    }
+
+    /// Pops the top property alignment from the stack.
+    pub fn pop_object_property_alignment(&mut self) {
+        self.object_property_alignment_stack.pop();
+    }
+
+    /// Returns the property alignment of the innermost object expression, if any.
+    pub fn object_property_alignment(&self) -> Option<&ObjectPropertyAlignment> {
+        self.object_property_alignment_stack.last()
+    }
+
     pub fn allocator(&self) -> &'ast Allocator {
         self.allocator
     }
@@ -26,6 +26,9 @@ pub enum FormatError {
     /// This error should not be raised if there's no outer [crate::Format] handling the poor layout error,
     /// avoiding that formatting of the whole document fails.
     PoorLayout,
+
+    /// Formatting stopped because the content is nested deeper than the maximum nesting depth.
+    NestingTooDeep { max_depth: u32 },
 }
 
 impl std::fmt::Display for FormatError {
@@ -44,6 +47,10 @@ impl std::fmt::Display for FormatError {
             FormatError::PoorLayout => fmt.write_str(
                 "Poor layout: The formatter wasn't able to pick a good layout for your document. This is an internal Biome error. Please report if necessary.",
             ),
+            FormatError::NestingTooDeep { max_depth } => std::write!(
+                fmt,
+                "The code is nested too deeply to be formatted (maximum nesting depth: {max_depth})"
+            ),
         }
     }
 }
@@ -76,6 +83,9 @@ impl From<&PrintError> for FormatError {
     fn from(error: &PrintError) -> Self {
         match error {
             PrintError::InvalidDocument(reason) => FormatError::InvalidDocument(*reason),
+            PrintError::NestingTooDeep { max_depth } => {
+                FormatError::NestingTooDeep { max_depth: *max_depth }
+            }
         }
     }
 }
@@ -208,6 +218,10 @@ impl std::fmt::Display for InvalidDocumentError {
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum PrintError {
     InvalidDocument(InvalidDocumentError),
+    /// The document is incomplete because the content is nested deeper than the maximum nesting depth.
+    NestingTooDeep {
+        max_depth: u32,
+    },
 }
 
 impl Error for PrintError {}
@@ -218,6 +232,12 @@ impl std::fmt::Display for PrintError {
             PrintError::InvalidDocument(inner) => {
                 std::write!(f, "Invalid document: {inner}")
             }
+            PrintError::NestingTooDeep { max_depth } => {
+                std::write!(
+                    f,
+                    "The code is nested too deeply to be formatted (maximum nesting depth: {max_depth})"
+                )
+            }
         }
     }
 }
@@ -263,9 +263,15 @@ impl<'ast> Buffer<'ast> for Formatter<'_, 'ast> {
 
     #[inline(always)]
     fn write_fmt(&mut self, arguments: Arguments<'_, 'ast>) {
+        // Stop formatting deeply nested content instead of overflowing the stack,
+        // the error is reported when printing the document.
+        if !self.context_mut().enter_nesting() {
+            return;
+        }
         for argument in arguments.items() {
             argument.format(self);
         }
+        self.context_mut().leave_nesting();
     }
 
     fn state(&self) -> &FormatState<'ast> {
@@ -47,7 +47,9 @@ pub use self::comments::Comments;
 use self::printer::Printer;
 pub use self::{
     arguments::{Argument, Arguments},
-    context::{FormatContext, TailwindContextEntry},
+    context::{
+        DEFAULT_MAX_NESTING_DEPTH, FormatContext, ObjectPropertyAlignment, TailwindContextEntry,
+    },
     diagnostics::{ActualStart, FormatError, InvalidDocumentError, PrintError},
     formatter::Formatter,
     source_text::SourceText,
@@ -89,6 +91,7 @@ impl<'a> Formatted<'a> {
 
 impl Formatted<'_> {
     pub fn print(self) -> PrintResult<Printed> {
+        self.check_nesting_depth()?;
         let print_options = self.context.options().as_print_options();
         let (elements, sorted_tailwind_classes) =
             self.document.into_elements_and_tailwind_classes();
@@ -97,6 +100,7 @@ impl Formatted<'_> {
     }
 
     pub fn print_with_indent(self, indent: u16) -> PrintResult<Printed> {
+        self.check_nesting_depth()?;
         let print_options = self.context.options().as_print_options();
         let (elements, sorted_tailwind_classes) =
             self.document.into_elements_and_tailwind_classes();
@@ -104,6 +108,14 @@ impl Formatted<'_> {
             .print_with_indent(elements, indent)?;
         Ok(printed)
     }
+
+    /// The document is incomplete if the content was nested too deeply, so it can't be printed.
+    fn check_nesting_depth(&self) -> PrintResult<()> {
+        if self.context.nesting_depth_exceeded() {
+            return Err(PrintError::NestingTooDeep { max_depth: self.context.max_nesting_depth() });
+        }
+        Ok(())
+    }
 }
 pub type PrintResult<T> = Result<T, PrintError>;
 
@@ -421,7 +421,11 @@ impl<'a> Format<'a> for Comment {
         let content = f.source_text().text_for(&self.span);
         if self.is_multiline_block() {
             let mut lines = LineTerminatorSplitter::new(content);
-            if is_alignable_comment(content) {
+            // A license banner at the top of the file is kept as written, so are all comments
+            // there with `preserve_first_comment`.
+            let is_verbatim = f.context().comments().is_leading_file_comment(self)
+                && (f.options().preserve_first_comment || is_license_comment(content));
+            if !is_verbatim && is_alignable_comment(content) {
                 // Using `write_element` directly instead of `labelled()`
                 // to avoid allocating a `Vec` for `lines` iter
                 let sort_imports_enabled = f.options().experimental_sort_imports.is_some();
@@ -461,6 +465,25 @@ impl<'a> Format<'a> for Comment {
     }
 }
 
+/// Returns `true` if `comment` looks like a license banner: a `/**` or `/*!` block comment
+/// which mentions a copyright or license.
+///
+/// # Examples
+///
+/// ```rs,ignore
+/// assert!(is_license_comment("/*!\n * Copyright (c) 2024 Oxc\n */"));
+/// assert!(is_license_comment("/**\n * @license MIT\n */"));
+///
+/// assert!(!is_license_comment("/*\n * Copyright (c) 2024 Oxc\n */"));
+/// assert!(!is_license_comment("/**\n * Returns the license of the package.\n */"));
+/// ```
+pub fn is_license_comment(content: &str) -> bool {
+    (content.starts_with("/**") || content.starts_with("/*!"))
+        && (content.contains("Copyright")
+            || content.contains("License")
+            || content.contains("@license"))
+}
+
 /// Returns `true` if `comment` is a multi line block comment where each line
 /// starts with a star (`*`). These comments can be formatted to always have
 /// the leading stars line up in a column.
@@ -35,6 +35,7 @@ pub fn compute_import_metadata<'a>(
     // Determine if this import should be ignored (not moved between groups)
     // - If `sort_side_effects: true`, never ignore
     // - If `sort_side_effects: false` and this is a side-effect:
+    //   - If `partition_by_side_effect: true`, always keep in original position (ignored)
     //   - Check if groups contain `side-effect` or `side-effect-style`
     //     - If yes, allow regrouping (not ignored)
     //     - If no, keep in original position (ignored)
@@ -43,8 +44,9 @@ pub fn compute_import_metadata<'a>(
 
     let is_ignored = !options.sort_side_effects
         && metadata.is_side_effect
-        && !should_regroup_side_effect
-        && (!is_style_import || !should_regroup_side_effect_style);
+        && (options.partition_by_side_effect
+            || (!should_regroup_side_effect
+                && (!is_style_import || !should_regroup_side_effect_style)));
 
     (group_idx, normalized_source, is_ignored)
 }
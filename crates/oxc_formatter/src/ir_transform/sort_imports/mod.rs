@@ -7,6 +7,7 @@ mod sortable_imports;
 mod source_line;
 
 use oxc_allocator::{Allocator, Vec as ArenaVec};
+use rustc_hash::FxHashSet;
 
 use crate::{
     JsLabels, SortImportsOptions,
@@ -255,6 +256,30 @@ impl SortImportsTransform {
                         }
                     }
 
+                    // With `preserve_newlines_between`, collect groups whose imports
+                    // were separated from another group by an empty line in the source.
+                    // e.g.
+                    // ```
+                    // import b from "b";
+                    //
+                    // import a from "~/a";
+                    // ```
+                    // Here, the empty line before the `internal` group is kept after sorting.
+                    let separated_groups: FxHashSet<usize> =
+                        if !options.newlines_between && options.preserve_newlines_between {
+                            sorted_imports
+                                .iter()
+                                .filter(|import| {
+                                    import
+                                        .separated_from_group_idx
+                                        .is_some_and(|prev_idx| prev_idx != import.group_idx)
+                                })
+                                .map(|import| import.group_idx)
+                                .collect()
+                        } else {
+                            FxHashSet::default()
+                        };
+
                     // Output sorted import units with orphan content at their slot positions
                     let mut prev_group_idx = None;
                     let mut prev_was_ignored = false;
@@ -272,6 +297,15 @@ impl SortImportsTransform {
                             }
                             prev_group_idx = Some(current_group_idx);
                             prev_was_ignored = sorted_import.is_ignored;
+                        } else if !separated_groups.is_empty() {
+                            let current_group_idx = sorted_import.group_idx;
+                            if let Some(prev_idx) = prev_group_idx
+                                && prev_idx != current_group_idx
+                                && separated_groups.contains(&current_group_idx)
+                            {
+                                next_elements.push(FormatElement::Line(LineMode::Empty));
+                            }
+                            prev_group_idx = Some(current_group_idx);
                         }
 
                         // Output leading lines and import line
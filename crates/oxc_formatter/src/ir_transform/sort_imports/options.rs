@@ -12,6 +12,12 @@ pub struct SortImportsOptions {
     /// Sort side effects imports.
     /// Default is `false`.
     pub sort_side_effects: bool,
+    /// Partition imports by side-effect imports.
+    /// When `true`, side-effect imports always stay at their original position,
+    /// and no other import is moved across them, even if `groups` contain `side-effect`.
+    /// Has no effect when `sort_side_effects` is `true`.
+    /// Default is `false`.
+    pub partition_by_side_effect: bool,
     /// Sort order (asc or desc).
     /// Default is ascending (asc).
     pub order: SortOrder,
@@ -24,6 +30,12 @@ pub struct SortImportsOptions {
     ///
     /// NOTE: Cannot be used together with `partition_by_newline: true`.
     pub newlines_between: bool,
+    /// Whether to keep existing blank lines between different import groups.
+    /// When `true`, a blank line is kept before a group
+    /// if one of its imports was separated from another group by a blank line in the source.
+    /// Has no effect when `newlines_between` is `true`.
+    /// Default is `false`.
+    pub preserve_newlines_between: bool,
     /// Prefixes for internal imports.
     /// Defaults to `["~/", "@/"]`.
     pub internal_pattern: Vec<String>,
@@ -42,9 +54,11 @@ impl Default for SortImportsOptions {
             partition_by_newline: false,
             partition_by_comment: false,
             sort_side_effects: false,
+            partition_by_side_effect: false,
             order: SortOrder::default(),
             ignore_case: true,
             newlines_between: true,
+            preserve_newlines_between: false,
             internal_pattern: default_internal_patterns(),
             groups: default_groups(),
             custom_groups: vec![],
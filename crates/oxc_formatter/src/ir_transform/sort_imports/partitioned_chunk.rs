@@ -85,6 +85,8 @@ impl<'a> PartitionedChunk<'a> {
         // Comments directly before the next import (no empty line between).
         // These attach to the next import as leading lines.
         let mut current_pending: Vec<SourceLine<'a>> = vec![];
+        // Whether an empty line appeared since the previous import.
+        let mut seen_empty_line = false;
 
         for line in lines {
             match line {
@@ -122,6 +124,12 @@ impl<'a> PartitionedChunk<'a> {
                     let is_side_effect = metadata.is_side_effect;
                     let (group_idx, normalized_source, is_ignored) =
                         compute_import_metadata(metadata, group_matcher, options);
+                    let separated_from_group_idx = if seen_empty_line {
+                        sortable_imports.last().map(|prev| prev.group_idx)
+                    } else {
+                        None
+                    };
+                    seen_empty_line = false;
 
                     sortable_imports.push(SortableImport {
                         leading_lines: std::mem::take(&mut current_pending),
@@ -130,6 +138,7 @@ impl<'a> PartitionedChunk<'a> {
                         group_idx,
                         normalized_source,
                         is_ignored,
+                        separated_from_group_idx,
                     });
                 }
                 SourceLine::Empty => {
@@ -137,6 +146,7 @@ impl<'a> PartitionedChunk<'a> {
                     // Move `current_pending` to `orphan_pending`, then add empty line.
                     orphan_pending.append(&mut current_pending);
                     orphan_pending.push(line);
+                    seen_empty_line = true;
                 }
                 SourceLine::CommentOnly(..) => {
                     current_pending.push(line);
@@ -14,6 +14,9 @@ pub struct SortableImport<'a> {
     pub normalized_source: Cow<'a, str>,
     pub is_side_effect: bool,
     pub is_ignored: bool,
+    /// Group of the previous import in the source, if an empty line separated them.
+    /// Used by `preserve_newlines_between` to keep existing separators between groups.
+    pub separated_from_group_idx: Option<usize>,
 }
 
 // ---
@@ -40,24 +43,35 @@ impl SortSortableImports for Vec<SortableImport<'_>> {
             return;
         }
 
-        // Stage 2: Group non-ignored imports by `group_idx`
-        let mut imports_by_group: FxHashMap<usize, Vec<usize>> = FxHashMap::default();
-        for &idx in &sortable_indices {
-            imports_by_group.entry(self[idx].group_idx).or_default().push(idx);
-        }
-
-        // Stage 3: Sort within each group and build sorted list
-        // Need to process `groups` in order by `group_idx`
-        let mut groups: Vec<_> = imports_by_group.iter_mut().collect();
-        groups.sort_unstable_by_key(|(gidx, _)| *gidx);
+        // Stage 2: Split non-ignored imports into segments
+        // With `partition_by_side_effect: true`, ignored (side-effect) imports act as boundaries,
+        // so no import is moved across them. Otherwise, all non-ignored imports form a single segment.
+        let segments: Vec<&[usize]> = if options.partition_by_side_effect {
+            sortable_indices.chunk_by(|&prev, &next| next == prev + 1).collect()
+        } else {
+            vec![&sortable_indices]
+        };
 
         let mut sorted_indices = Vec::with_capacity(sortable_indices.len());
-        for (_, group_indices) in groups {
-            sort_within_group(group_indices, self, options);
-            sorted_indices.extend_from_slice(group_indices);
+        for segment in segments {
+            // Stage 3: Group imports of the segment by `group_idx`
+            let mut imports_by_group: FxHashMap<usize, Vec<usize>> = FxHashMap::default();
+            for &idx in segment {
+                imports_by_group.entry(self[idx].group_idx).or_default().push(idx);
+            }
+
+            // Stage 4: Sort within each group and build sorted list
+            // Need to process `groups` in order by `group_idx`
+            let mut groups: Vec<_> = imports_by_group.iter_mut().collect();
+            groups.sort_unstable_by_key(|(gidx, _)| *gidx);
+
+            for (_, group_indices) in groups {
+                sort_within_group(group_indices, self, options);
+                sorted_indices.extend_from_slice(group_indices);
+            }
         }
 
-        // Stage 4: Build final permutation by inserting ignored imports at their original positions
+        // Stage 5: Build final permutation by inserting ignored imports at their original positions
         // If no ignored imports, we can skip the permutation step
         if ignored_indices.is_empty() {
             apply_permutation(self, &sorted_indices);
@@ -146,18 +160,19 @@ fn sort_within_group(
 }
 
 /// Sort indices by their normalized source.
+///
+/// The sort is stable in both orders, imports of the same source keep their relative order.
+/// They are never merged, so e.g. `import type { T } from "a"` stays separate from `import { a } from "a"`.
 fn sort_indices_by_source(
     indices: &mut [usize],
     imports: &[SortableImport],
     options: &SortImportsOptions,
 ) {
     indices.sort_by(|&a, &b| {
-        natord::compare(&imports[a].normalized_source, &imports[b].normalized_source)
+        let ordering =
+            natord::compare(&imports[a].normalized_source, &imports[b].normalized_source);
+        if options.order.is_desc() { ordering.reverse() } else { ordering }
     });
-
-    if options.order.is_desc() {
-        indices.reverse();
-    }
 }
 
 // ---
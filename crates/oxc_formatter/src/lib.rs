@@ -18,6 +18,7 @@ use oxc_ast::ast::*;
 pub use crate::external_formatter::{
     EmbeddedFormatterCallback, ExternalCallbacks, TailwindCallback,
 };
+pub use crate::formatter::DEFAULT_MAX_NESTING_DEPTH;
 pub use crate::ir_transform::options::*;
 pub use crate::options::*;
 pub use crate::service::*;
@@ -34,11 +35,22 @@ use self::formatter::prelude::tag::Label;
 pub struct Formatter<'a> {
     allocator: &'a Allocator,
     options: FormatOptions,
+    max_nesting_depth: u32,
 }
 
 impl<'a> Formatter<'a> {
     pub fn new(allocator: &'a Allocator, options: FormatOptions) -> Self {
-        Self { allocator, options }
+        Self { allocator, options, max_nesting_depth: DEFAULT_MAX_NESTING_DEPTH }
+    }
+
+    /// Sets the maximum nesting depth of the formatted content, defaults to [`DEFAULT_MAX_NESTING_DEPTH`].
+    ///
+    /// Content nested deeper than this is not formatted, instead printing the formatted
+    /// document returns an error. This prevents deeply nested code from overflowing the stack.
+    #[must_use]
+    pub fn with_max_nesting_depth(mut self, max_nesting_depth: u32) -> Self {
+        self.max_nesting_depth = max_nesting_depth;
+        self
     }
 
     /// Formats the given AST `Program` and returns the formatted string.
@@ -67,7 +79,8 @@ impl<'a> Formatter<'a> {
             self.allocator,
             self.options,
             external_callbacks,
-        );
+        )
+        .with_max_nesting_depth(self.max_nesting_depth);
 
         let mut formatted = formatter::format(
             context,
@@ -31,9 +31,19 @@ pub struct FormatOptions {
     /// The style for JSX quotes. Defaults to double.
     pub jsx_quote_style: QuoteStyle,
 
+    /// How quotes of JSX attribute values are chosen. Defaults to as-needed.
+    pub jsx_attribute_quotes: JsxAttributeQuotes,
+
     /// When properties in objects are quoted. Defaults to as-needed.
     pub quote_properties: QuoteProperties,
 
+    /// Whether numeric literals are normalized (`0XFF` to `0xff`, `.5` to `0.5`, `5.` to `5`, ...). Defaults to "normalize".
+    pub numeric_literals: NumericLiterals,
+
+    /// Whether to lowercase the hex digits of `\x` and `\u` escape sequences in strings (`"\xFF"` to `"\xff"`),
+    /// like the hex digits of numeric literals. Defaults to `false`.
+    pub normalize_escape_sequences: bool,
+
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
     pub trailing_commas: TrailingCommas,
 
@@ -46,15 +56,111 @@ pub struct FormatOptions {
     /// Whether to insert spaces around brackets in object literals. Defaults to true.
     pub bracket_spacing: BracketSpacing,
 
+    /// Whether to insert a space inside the brackets of empty object literals, object types and arrays: `{ }` and `[ ]`. Defaults to false.
+    pub empty_bracket_spacing: EmptyBracketSpacing,
+
     /// Whether to hug the closing bracket of multiline HTML/JSX tags to the end of the last line, rather than being alone on the following line. Defaults to false.
     pub bracket_same_line: BracketSameLine,
 
     /// Attribute position style. By default auto.
     pub attribute_position: AttributePosition,
 
+    /// How JSX text is wrapped. Defaults to "reflow", same as Prettier.
+    pub jsx_text_wrap: JsxTextWrap,
+
+    /// Whether a conditional whose JSX branches are single-line elements, like `<A />` or `<b>text</b>`,
+    /// is printed like any other conditional. Defaults to `false`, same as Prettier.
+    ///
+    /// When such a conditional doesn't fit on a line, its branches are printed on their own lines after `?` and `:`,
+    /// instead of being wrapped in parentheses.
+    pub compact_jsx_conditionals: bool,
+
     /// Whether to expand object and array literals to multiple lines. Defaults to "auto".
     pub expand: Expand,
 
+    /// Whether arrays of objects (or arrays of arrays) are printed with one element per line,
+    /// regardless of whether they fit on a single line. Defaults to "auto".
+    pub expand_arrays_of_objects: ExpandArraysOfObjects,
+
+    /// How far the statements of a `switch` case are indented relative to the case. Defaults to "single".
+    pub switch_case_body_indent: SwitchCaseBodyIndent,
+
+    /// Whether the braces of empty function and class bodies stay on the same line. Defaults to "collapse".
+    pub empty_body: EmptyBody,
+
+    /// Whether to always print a blank line between consecutive class members. Defaults to `false`.
+    ///
+    /// An existing blank line is kept as is, the first member of a class body isn't affected.
+    pub blank_line_between_class_members: bool,
+
+    /// Whether to keep a blank line directly after the `{` and before the `}` of blocks and function bodies.
+    /// Defaults to `false`, which removes them like Prettier.
+    ///
+    /// Multiple blank lines are collapsed into one.
+    pub preserve_block_blank_lines: bool,
+
+    /// Whether to print an `else` block which only contains an `if` statement as `else if`. Defaults to `false`.
+    ///
+    /// `} else { if (a) {} }` becomes `} else if (a) {}`, blocks containing comments are kept.
+    pub collapse_else_if: bool,
+
+    /// Whether to print a space after the `function` keyword (or the `*` of generators) of anonymous functions,
+    /// e.g. `function () {}` or `function* () {}`. Defaults to `true`, same as Prettier.
+    ///
+    /// Named functions are always printed like `function name() {}`.
+    pub anonymous_function_space: AnonymousFunctionSpace,
+
+    /// Whether to hug the sole object or array argument of a call, e.g. `foo({` with the properties on the next lines.
+    /// Defaults to `true`, same as Prettier.
+    ///
+    /// When `false`, the argument is moved to its own line, if the call doesn't fit on a single line.
+    /// Function arguments are always hugged.
+    pub hug_sole_argument: HugSoleArgument,
+
+    /// The number of calls a member chain can have before it is always printed on multiple lines,
+    /// if any of the calls has a complex argument, like a function. Defaults to 2, same as Prettier.
+    pub method_chain_break_threshold: MethodChainBreakThreshold,
+
+    /// Whether to align the values of object literal properties in multi-line objects.
+    ///
+    /// Keys are padded to the longest key of their group, where groups are separated by blank lines.
+    /// Defaults to `false`.
+    pub align_object_properties: bool,
+
+    /// Whether to align the `=` of enum members with an initializer.
+    ///
+    /// Members are padded to the longest name of their group, where groups are separated by blank lines.
+    /// Defaults to `false`.
+    pub align_enum_members: bool,
+
+    /// Whether to sort the properties of object literals by their keys. Defaults to "none".
+    ///
+    /// Only objects whose keys are all statically known are sorted, objects with spread elements,
+    /// computed keys or comments are left as is.
+    /// Note that sorting changes the evaluation order of the property values.
+    pub sort_object_keys: SortObjectKeys,
+
+    /// Whether to keep the first comment of the file as written, e.g. for license headers. Defaults to `false`.
+    ///
+    /// A leading `/**` or `/*!` comment which mentions a copyright or license is always kept as written.
+    pub preserve_first_comment: bool,
+
+    /// How the continuation operands of a broken string concatenation are indented. Defaults to "indent".
+    pub string_concatenation_indent: StringConcatenationIndent,
+
+    /// How the branches of a conditional in the alternate of another conditional are indented. Defaults to "indent".
+    ///
+    /// Independent of [`FormatOptions::experimental_ternaries`].
+    pub ternary_chain_indent: TernaryChainIndent,
+
+    /// Where the `|` and `&` operators of broken union and intersection types are printed. Defaults to "auto".
+    pub type_operator_position: TypeOperatorPosition,
+
+    /// Whether the members of a broken union type are indented. Defaults to `true`, same as Prettier.
+    ///
+    /// Unions which are already indented by their parent, e.g. in tuples or type arguments, aren't affected.
+    pub indent_union_types: IndentUnionTypes,
+
     /// Controls the position of operators in binary expressions. [**NOT SUPPORTED YET**]
     ///
     /// Accepted values are:
@@ -69,9 +175,33 @@ pub struct FormatOptions {
     /// - `false` - Retain the default behavior of ternaries; keep question marks on the same line as the consequent.
     pub experimental_ternaries: bool,
 
+    /// Parse and format the experimental [pipeline operator](https://github.com/tc39/proposal-pipeline-operator) `x |> f`.
+    /// Defaults to `false`, files using it fail to parse.
+    ///
+    /// Only the minimal proposal is supported. Use [`get_parse_options_with`] to parse a file with this option.
+    ///
+    /// [`get_parse_options_with`]: crate::get_parse_options_with
+    pub experimental_pipeline_operator: bool,
+
     /// Enable formatting for embedded languages (e.g., CSS, SQL, GraphQL) within template literals. Defaults to "auto".
     pub embedded_language_formatting: EmbeddedLanguageFormatting,
 
+    /// Re-indent the content of embedded language templates relative to the indentation of the template.
+    /// Defaults to `false`, because this changes the content of the string.
+    ///
+    /// This only applies to templates marked as embedded (e.g. `` css`...` ``, `` graphql`...` ``,
+    /// `` html`...` ``, the `css` prop, `<style jsx>` and Angular `@Component` templates),
+    /// whose content starts on a new line and isn't formatted by the embedded formatter.
+    /// Other template literals are always printed as is.
+    pub reindent_embedded_templates: bool,
+
+    /// The tags of template literals which are formatted as SQL by the embedded formatter,
+    /// e.g. `` sql`SELECT * FROM users WHERE id = ${id}` ``. Defaults to `[]`,
+    /// because the embedded formatter needs a plugin to format SQL.
+    ///
+    /// Interpolations are kept as written.
+    pub embedded_sql_tags: Vec<String>,
+
     /// Sort import statements. By default disabled.
     pub experimental_sort_imports: Option<SortImportsOptions>,
 
@@ -135,17 +265,43 @@ impl FormatOptions {
             line_width: LineWidth::default(),
             quote_style: QuoteStyle::default(),
             jsx_quote_style: QuoteStyle::default(),
+            jsx_attribute_quotes: JsxAttributeQuotes::default(),
             quote_properties: QuoteProperties::default(),
+            numeric_literals: NumericLiterals::default(),
+            normalize_escape_sequences: false,
             trailing_commas: TrailingCommas::default(),
             semicolons: Semicolons::default(),
             arrow_parentheses: ArrowParentheses::default(),
             bracket_spacing: BracketSpacing::default(),
+            empty_bracket_spacing: EmptyBracketSpacing::default(),
             bracket_same_line: BracketSameLine::default(),
             attribute_position: AttributePosition::default(),
+            jsx_text_wrap: JsxTextWrap::default(),
+            compact_jsx_conditionals: false,
             expand: Expand::default(),
+            expand_arrays_of_objects: ExpandArraysOfObjects::default(),
+            switch_case_body_indent: SwitchCaseBodyIndent::default(),
+            empty_body: EmptyBody::default(),
+            blank_line_between_class_members: false,
+            preserve_block_blank_lines: false,
+            collapse_else_if: false,
+            anonymous_function_space: AnonymousFunctionSpace::default(),
+            hug_sole_argument: HugSoleArgument::default(),
+            method_chain_break_threshold: MethodChainBreakThreshold::default(),
+            align_object_properties: false,
+            align_enum_members: false,
+            sort_object_keys: SortObjectKeys::default(),
+            preserve_first_comment: false,
+            string_concatenation_indent: StringConcatenationIndent::default(),
+            ternary_chain_indent: TernaryChainIndent::default(),
+            type_operator_position: TypeOperatorPosition::default(),
+            indent_union_types: IndentUnionTypes::default(),
             experimental_operator_position: OperatorPosition::default(),
             experimental_ternaries: false,
+            experimental_pipeline_operator: false,
             embedded_language_formatting: EmbeddedLanguageFormatting::default(),
+            reindent_embedded_templates: false,
+            embedded_sql_tags: Vec::new(),
             experimental_sort_imports: None,
             experimental_tailwindcss: None,
         }
@@ -164,16 +320,42 @@ impl fmt::Display for FormatOptions {
         writeln!(f, "Line width: {}", self.line_width.value())?;
         writeln!(f, "Quote style: {}", self.quote_style)?;
         writeln!(f, "JSX quote style: {}", self.jsx_quote_style)?;
+        writeln!(f, "JSX attribute quotes: {}", self.jsx_attribute_quotes)?;
         writeln!(f, "Quote properties: {}", self.quote_properties)?;
+        writeln!(f, "Numeric literals: {}", self.numeric_literals)?;
+        writeln!(f, "Normalize escape sequences: {}", self.normalize_escape_sequences)?;
         writeln!(f, "Trailing commas: {}", self.trailing_commas)?;
         writeln!(f, "Semicolons: {}", self.semicolons)?;
         writeln!(f, "Arrow parentheses: {}", self.arrow_parentheses)?;
         writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())?;
+        writeln!(f, "Empty bracket spacing: {}", self.empty_bracket_spacing.value())?;
         writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
         writeln!(f, "Attribute Position: {}", self.attribute_position)?;
+        writeln!(f, "JSX text wrap: {}", self.jsx_text_wrap)?;
+        writeln!(f, "Compact JSX conditionals: {}", self.compact_jsx_conditionals)?;
         writeln!(f, "Expand lists: {}", self.expand)?;
+        writeln!(f, "Expand arrays of objects: {}", self.expand_arrays_of_objects)?;
+        writeln!(f, "Switch case body indent: {}", self.switch_case_body_indent)?;
+        writeln!(f, "Empty body: {}", self.empty_body)?;
+        writeln!(f, "Blank line between class members: {}", self.blank_line_between_class_members)?;
+        writeln!(f, "Preserve block blank lines: {}", self.preserve_block_blank_lines)?;
+        writeln!(f, "Collapse else if: {}", self.collapse_else_if)?;
+        writeln!(f, "Anonymous function space: {}", self.anonymous_function_space.value())?;
+        writeln!(f, "Hug sole argument: {}", self.hug_sole_argument.value())?;
+        writeln!(f, "Method chain break threshold: {}", self.method_chain_break_threshold)?;
+        writeln!(f, "Align object properties: {}", self.align_object_properties)?;
+        writeln!(f, "Align enum members: {}", self.align_enum_members)?;
+        writeln!(f, "Sort object keys: {}", self.sort_object_keys)?;
+        writeln!(f, "Preserve first comment: {}", self.preserve_first_comment)?;
+        writeln!(f, "String concatenation indent: {}", self.string_concatenation_indent)?;
+        writeln!(f, "Ternary chain indent: {}", self.ternary_chain_indent)?;
+        writeln!(f, "Type operator position: {}", self.type_operator_position)?;
+        writeln!(f, "Indent union types: {}", self.indent_union_types.value())?;
         writeln!(f, "Experimental operator position: {}", self.experimental_operator_position)?;
+        writeln!(f, "Experimental pipeline operator: {}", self.experimental_pipeline_operator)?;
         writeln!(f, "Embedded language formatting: {}", self.embedded_language_formatting)?;
+        writeln!(f, "Reindent embedded templates: {}", self.reindent_embedded_templates)?;
+        writeln!(f, "Embedded SQL tags: {:?}", self.embedded_sql_tags)?;
         writeln!(f, "Experimental sort imports: {:?}", self.experimental_sort_imports)?;
         writeln!(f, "Experimental tailwindcss: {:?}", self.experimental_tailwindcss)
     }
@@ -621,11 +803,51 @@ impl fmt::Display for QuoteProperties {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum JsxAttributeQuotes {
+    /// Use the JSX quote style, unless the value contains more of it than of the other quote,
+    /// so that the fewest quotes need to be escaped. Same as Prettier.
+    #[default]
+    AsNeeded,
+    /// Always use the JSX quote style, escaping quotes in the value as HTML entities.
+    Consistent,
+}
+
+impl JsxAttributeQuotes {
+    pub const fn is_consistent(self) -> bool {
+        matches!(self, Self::Consistent)
+    }
+}
+
+impl FromStr for JsxAttributeQuotes {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "as-needed" => Ok(Self::AsNeeded),
+            "consistent" => Ok(Self::Consistent),
+            _ => Err("Value not supported for JsxAttributeQuotes"),
+        }
+    }
+}
+
+impl fmt::Display for JsxAttributeQuotes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            JsxAttributeQuotes::AsNeeded => "As needed",
+            JsxAttributeQuotes::Consistent => "Consistent",
+        };
+        f.write_str(s)
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub enum Semicolons {
     #[default]
     Always,
     AsNeeded,
+    /// Same as [Semicolons::AsNeeded], but class properties always end with a semicolon.
+    Hybrid,
 }
 
 impl Semicolons {
@@ -636,6 +858,10 @@ impl Semicolons {
     pub const fn is_always(self) -> bool {
         matches!(self, Self::Always)
     }
+
+    pub const fn is_hybrid(self) -> bool {
+        matches!(self, Self::Hybrid)
+    }
 }
 
 impl FromStr for Semicolons {
@@ -645,8 +871,9 @@ impl FromStr for Semicolons {
         match s {
             "as-needed" => Ok(Self::AsNeeded),
             "always" => Ok(Self::Always),
+            "hybrid" => Ok(Self::Hybrid),
             _ => Err(
-                "Value not supported for Semicolons. Supported values are 'as-needed' and 'always'.",
+                "Value not supported for Semicolons. Supported values are 'as-needed', 'always' and 'hybrid'.",
             ),
         }
     }
@@ -657,6 +884,7 @@ impl fmt::Display for Semicolons {
         let s = match self {
             Semicolons::AsNeeded => "As needed",
             Semicolons::Always => "Always",
+            Semicolons::Hybrid => "Hybrid",
         };
         f.write_str(s)
     }
@@ -840,6 +1068,81 @@ impl FromStr for AttributePosition {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum JsxTextWrap {
+    /// Words of JSX text are filled up to the print width, ignoring the line breaks of the input.
+    #[default]
+    Reflow,
+    /// Line breaks between the words of JSX text are kept, and no new ones are inserted.
+    Preserve,
+}
+
+impl JsxTextWrap {
+    pub const fn is_preserve(self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+}
+
+impl FromStr for JsxTextWrap {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "reflow" => Ok(Self::Reflow),
+            "preserve" => Ok(Self::Preserve),
+            _ => Err("Value not supported for JsxTextWrap"),
+        }
+    }
+}
+
+impl fmt::Display for JsxTextWrap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            JsxTextWrap::Reflow => "Reflow",
+            JsxTextWrap::Preserve => "Preserve",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum NumericLiterals {
+    /// Numeric and BigInt literals are printed in lowercase, with a leading zero before the decimal point
+    /// and without redundant zeros, dots and exponent signs, same as Prettier.
+    #[default]
+    Normalize,
+    /// Numeric and BigInt literals are printed as written.
+    Preserve,
+}
+
+impl NumericLiterals {
+    pub const fn is_preserve(self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+}
+
+impl FromStr for NumericLiterals {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "normalize" => Ok(Self::Normalize),
+            "preserve" => Ok(Self::Preserve),
+            _ => Err("Value not supported for NumericLiterals"),
+        }
+    }
+}
+
+impl fmt::Display for NumericLiterals {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            NumericLiterals::Normalize => "Normalize",
+            NumericLiterals::Preserve => "Preserve",
+        };
+        f.write_str(s)
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct BracketSpacing(bool);
 
@@ -883,6 +1186,43 @@ impl FromStr for BracketSpacing {
     }
 }
 
+/// Insert a space inside the brackets of empty object literals and arrays.
+/// Prettier never does, so this is disabled by default.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct EmptyBracketSpacing(bool);
+
+impl EmptyBracketSpacing {
+    /// Return the boolean value for this [EmptyBracketSpacing]
+    pub fn value(self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for EmptyBracketSpacing {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for EmptyBracketSpacing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+impl FromStr for EmptyBracketSpacing {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match bool::from_str(s) {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(
+                "Value not supported for EmptyBracketSpacing. Supported values are 'true' and 'false'.",
+            ),
+        }
+    }
+}
+
 /// Put the `>` of a multi-line HTML or JSX element at the end of the last line instead of being alone on the next line (does not apply to self closing elements).
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct BracketSameLine(bool);
@@ -919,6 +1259,210 @@ impl FromStr for BracketSameLine {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum SwitchCaseBodyIndent {
+    /// Indent the statements of a case one level relative to the case. Same as Prettier.
+    #[default]
+    Single,
+    /// Indent the statements of a case two levels relative to the case.
+    /// A block statement hugging the case is not affected.
+    Double,
+}
+
+impl SwitchCaseBodyIndent {
+    pub const fn is_double(self) -> bool {
+        matches!(self, Self::Double)
+    }
+}
+
+impl FromStr for SwitchCaseBodyIndent {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "single" => Ok(Self::Single),
+            "double" => Ok(Self::Double),
+            _ => Err("Value not supported for SwitchCaseBodyIndent"),
+        }
+    }
+}
+
+impl fmt::Display for SwitchCaseBodyIndent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            SwitchCaseBodyIndent::Single => "Single",
+            SwitchCaseBodyIndent::Double => "Double",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum EmptyBody {
+    /// Print the braces of an empty body together: `function f() {}`. Same as Prettier.
+    #[default]
+    Collapse,
+    /// Print the closing brace of an empty body on its own line: `function f() {\n}`.
+    /// Bodies containing comments are not affected.
+    Expand,
+}
+
+impl EmptyBody {
+    pub const fn is_expand(self) -> bool {
+        matches!(self, Self::Expand)
+    }
+}
+
+impl FromStr for EmptyBody {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "collapse" => Ok(Self::Collapse),
+            "expand" => Ok(Self::Expand),
+            _ => Err("Value not supported for EmptyBody"),
+        }
+    }
+}
+
+impl fmt::Display for EmptyBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            EmptyBody::Collapse => "Collapse",
+            EmptyBody::Expand => "Expand",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Print a space after the `function` keyword (or the `*` of generators) of anonymous functions.
+/// Prettier always does, so this is enabled by default.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct AnonymousFunctionSpace(bool);
+
+impl AnonymousFunctionSpace {
+    /// Return the boolean value for this [AnonymousFunctionSpace]
+    pub fn value(self) -> bool {
+        self.0
+    }
+}
+
+impl Default for AnonymousFunctionSpace {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl From<bool> for AnonymousFunctionSpace {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for AnonymousFunctionSpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+impl FromStr for AnonymousFunctionSpace {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match bool::from_str(s) {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(
+                "Value not supported for AnonymousFunctionSpace. Supported values are 'true' and 'false'.",
+            ),
+        }
+    }
+}
+
+/// The number of calls a member chain can have before it breaks, if any call has complex arguments.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MethodChainBreakThreshold(u8);
+
+impl MethodChainBreakThreshold {
+    /// Return the numeric value for this [MethodChainBreakThreshold]
+    pub fn value(self) -> u8 {
+        self.0
+    }
+}
+
+impl Default for MethodChainBreakThreshold {
+    fn default() -> Self {
+        Self(2)
+    }
+}
+
+impl From<u8> for MethodChainBreakThreshold {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl FromStr for MethodChainBreakThreshold {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match u8::from_str(s) {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(
+                "Value not supported for MethodChainBreakThreshold. Supported values are 0 to 255.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for MethodChainBreakThreshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+/// Hug the sole object or array argument of a call.
+/// Prettier always does, so this is enabled by default.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HugSoleArgument(bool);
+
+impl HugSoleArgument {
+    /// Return the boolean value for this [HugSoleArgument]
+    pub fn value(self) -> bool {
+        self.0
+    }
+}
+
+impl Default for HugSoleArgument {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl From<bool> for HugSoleArgument {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for HugSoleArgument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+impl FromStr for HugSoleArgument {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match bool::from_str(s) {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(
+                "Value not supported for HugSoleArgument. Supported values are 'true' and 'false'.",
+            ),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub enum Expand {
     /// Objects are expanded when the first property has a leading newline. Arrays are always
@@ -951,6 +1495,289 @@ impl fmt::Display for Expand {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ExpandArraysOfObjects {
+    /// Same as Prettier: arrays with at least two elements are expanded if all elements are
+    /// objects with at least two properties, or all elements are arrays with at least two elements.
+    #[default]
+    Auto,
+    /// Arrays are always expanded if all elements are objects, or all elements are arrays.
+    Always,
+    /// Arrays are only expanded if they don't fit on a single line.
+    Never,
+}
+
+impl FromStr for ExpandArraysOfObjects {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err("Value not supported for ExpandArraysOfObjects"),
+        }
+    }
+}
+
+impl fmt::Display for ExpandArraysOfObjects {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ExpandArraysOfObjects::Auto => "Auto",
+            ExpandArraysOfObjects::Always => "Always",
+            ExpandArraysOfObjects::Never => "Never",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum SortObjectKeys {
+    /// Keep the properties in their original order.
+    #[default]
+    None,
+    /// Sort the properties by their keys in ascending order.
+    Asc,
+    /// Sort the properties by their keys in descending order.
+    Desc,
+}
+
+impl SortObjectKeys {
+    pub const fn is_none(self) -> bool {
+        matches!(self, Self::None)
+    }
+}
+
+impl FromStr for SortObjectKeys {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "asc" => Ok(Self::Asc),
+            "desc" => Ok(Self::Desc),
+            _ => Err("Value not supported for SortObjectKeys"),
+        }
+    }
+}
+
+impl fmt::Display for SortObjectKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            SortObjectKeys::None => "None",
+            SortObjectKeys::Asc => "Asc",
+            SortObjectKeys::Desc => "Desc",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum StringConcatenationIndent {
+    /// Indent the continuation operands, unless the parent already indents them. Same as Prettier.
+    #[default]
+    Indent,
+    /// Align the continuation operands under the first operand:
+    /// ```js
+    /// foo(
+    ///   "The quick brown fox " +
+    ///   "jumps over the lazy dog",
+    /// );
+    /// ```
+    Align,
+}
+
+impl StringConcatenationIndent {
+    pub const fn is_align(self) -> bool {
+        matches!(self, Self::Align)
+    }
+}
+
+impl FromStr for StringConcatenationIndent {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "indent" => Ok(Self::Indent),
+            "align" => Ok(Self::Align),
+            _ => Err("Value not supported for StringConcatenationIndent"),
+        }
+    }
+}
+
+impl fmt::Display for StringConcatenationIndent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            StringConcatenationIndent::Indent => "Indent",
+            StringConcatenationIndent::Align => "Align",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TernaryChainIndent {
+    /// Indent each conditional of a chain one level more than its parent. Same as Prettier.
+    /// ```js
+    /// const animal = isBird
+    ///   ? "bird"
+    ///   : isCat
+    ///     ? "cat"
+    ///     : "fish";
+    /// ```
+    #[default]
+    Indent,
+    /// Align all conditionals of a chain with the root conditional:
+    /// ```js
+    /// const animal = isBird
+    ///   ? "bird"
+    ///   : isCat
+    ///   ? "cat"
+    ///   : "fish";
+    /// ```
+    Flat,
+}
+
+impl TernaryChainIndent {
+    pub const fn is_flat(self) -> bool {
+        matches!(self, Self::Flat)
+    }
+}
+
+impl FromStr for TernaryChainIndent {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "indent" => Ok(Self::Indent),
+            "flat" => Ok(Self::Flat),
+            _ => Err("Value not supported for TernaryChainIndent"),
+        }
+    }
+}
+
+impl fmt::Display for TernaryChainIndent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            TernaryChainIndent::Indent => "Indent",
+            TernaryChainIndent::Flat => "Flat",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TypeOperatorPosition {
+    /// Print the `|` of unions at the start of lines and the `&` of intersections at the end of lines.
+    /// Same as Prettier.
+    /// ```ts
+    /// type A =
+    ///   | "aaa"
+    ///   | "bbb";
+    /// type B = Aaaaaa &
+    ///   Bbbbbb;
+    /// ```
+    #[default]
+    Auto,
+    /// Print the operators at the start of lines:
+    /// ```ts
+    /// type A =
+    ///   | "aaa"
+    ///   | "bbb";
+    /// type B = Aaaaaa
+    ///   & Bbbbbb;
+    /// ```
+    Start,
+    /// Print the operators at the end of lines:
+    /// ```ts
+    /// type A =
+    ///   "aaa" |
+    ///   "bbb";
+    /// type B = Aaaaaa &
+    ///   Bbbbbb;
+    /// ```
+    End,
+}
+
+impl TypeOperatorPosition {
+    /// Whether the `|` of broken unions is printed at the start of lines.
+    pub const fn is_union_start(self) -> bool {
+        !matches!(self, Self::End)
+    }
+
+    /// Whether the `&` of broken intersections is printed at the start of lines.
+    pub const fn is_intersection_start(self) -> bool {
+        matches!(self, Self::Start)
+    }
+}
+
+impl FromStr for TypeOperatorPosition {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "start" => Ok(Self::Start),
+            "end" => Ok(Self::End),
+            _ => Err("Value not supported for TypeOperatorPosition"),
+        }
+    }
+}
+
+impl fmt::Display for TypeOperatorPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            TypeOperatorPosition::Auto => "Auto",
+            TypeOperatorPosition::Start => "Start",
+            TypeOperatorPosition::End => "End",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Indent the members of a broken union type.
+/// Prettier always does, so this is enabled by default.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IndentUnionTypes(bool);
+
+impl IndentUnionTypes {
+    /// Return the boolean value for this [IndentUnionTypes]
+    pub fn value(self) -> bool {
+        self.0
+    }
+}
+
+impl Default for IndentUnionTypes {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl From<bool> for IndentUnionTypes {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for IndentUnionTypes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+impl FromStr for IndentUnionTypes {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match bool::from_str(s) {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(
+                "Value not supported for IndentUnionTypes. Supported values are 'true' and 'false'.",
+            ),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub enum OperatorPosition {
     /// When binary expressions wrap lines, print operators at the start of new lines.
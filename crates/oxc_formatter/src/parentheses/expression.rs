@@ -663,8 +663,10 @@ impl NeedsParentheses<'_> for AstNode<'_, SequenceExpression<'_>> {
         }
 
         match self.parent() {
+            // The argument of these is wrapped by `FormatAdjacentArgument`
             AstNodes::ReturnStatement(_)
             | AstNodes::ThrowStatement(_)
+            | AstNodes::YieldExpression(_)
             // There's a precedence for writing `x++, y++`
             | AstNodes::ForStatement(_)
             | AstNodes::SequenceExpression(_) => false,
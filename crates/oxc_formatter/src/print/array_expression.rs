@@ -1,6 +1,7 @@
 use oxc_ast::ast::*;
 
 use crate::{
+    ExpandArraysOfObjects,
     ast_nodes::AstNode,
     formatter::{Buffer, Formatter, prelude::*},
     write,
@@ -29,10 +30,21 @@ impl<'a> Format<'a> for FormatArrayExpression<'a, '_> {
         write!(f, "[");
 
         if self.array.elements().is_empty() {
-            write!(f, format_dangling_comments(self.array.span).with_block_indent());
+            if f.options().empty_bracket_spacing.value()
+                && !f.comments().has_comment_before(self.array.span.end)
+            {
+                write!(f, space());
+            } else {
+                write!(f, format_dangling_comments(self.array.span).with_block_indent());
+            }
         } else {
             let group_id = f.group_id("array");
-            let should_expand = !self.options.is_force_flat_mode && should_break(self.array);
+            let should_expand = !self.options.is_force_flat_mode
+                && match f.options().expand_arrays_of_objects {
+                    ExpandArraysOfObjects::Auto => should_break(self.array),
+                    ExpandArraysOfObjects::Always => is_array_of_objects(self.array),
+                    ExpandArraysOfObjects::Never => false,
+                };
 
             let elements = ArrayElementList::new(self.array.elements(), group_id);
 
@@ -87,3 +99,15 @@ fn should_break(array: &ArrayExpression<'_>) -> bool {
         true
     }
 }
+
+/// Returns `true` if all elements are object expressions, or all elements are array expressions.
+fn is_array_of_objects(array: &ArrayExpression<'_>) -> bool {
+    let is_object = |element: &ArrayExpressionElement<'_>| {
+        matches!(element, ArrayExpressionElement::ObjectExpression(_))
+    };
+    let is_array = |element: &ArrayExpressionElement<'_>| {
+        matches!(element, ArrayExpressionElement::ArrayExpression(_))
+    };
+
+    array.elements.iter().all(is_object) || array.elements.iter().all(is_array)
+}
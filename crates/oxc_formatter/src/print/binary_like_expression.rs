@@ -50,6 +50,10 @@ impl BinaryLikeOperator {
     pub fn is_remainder(self) -> bool {
         matches!(self, Self::BinaryOperator(BinaryOperator::Remainder))
     }
+
+    pub fn is_pipeline(self) -> bool {
+        matches!(self, Self::BinaryOperator(BinaryOperator::Pipeline))
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -136,6 +140,38 @@ impl<'a, 'b> BinaryLikeExpression<'a, 'b> {
         }
     }
 
+    /// Returns `true` if this is a chain of `+` operators where at least one operand is a string.
+    ///
+    /// ```javascript
+    /// "a" + b + "c" // true
+    /// a + b * "c" // false, `b * "c"` is an operand of its own
+    /// ```
+    fn is_string_concatenation(&self) -> bool {
+        fn is_string(expression: &Expression) -> bool {
+            matches!(expression, Expression::StringLiteral(_) | Expression::TemplateLiteral(_))
+        }
+
+        let Self::BinaryExpression(binary) = self else {
+            return false;
+        };
+
+        let mut current: &BinaryExpression = binary;
+        loop {
+            if current.operator != BinaryOperator::Addition {
+                return false;
+            }
+            if is_string(&current.right) {
+                return true;
+            }
+            match &current.left {
+                Expression::BinaryExpression(left) if left.operator == BinaryOperator::Addition => {
+                    current = left;
+                }
+                left => return is_string(left),
+            }
+        }
+    }
+
     /// This function checks whether the chain of logical/binary expressions **should not** be indented
     ///
     /// There are some cases where the indentation is done by the parent, so if the parent is already doing
@@ -266,15 +302,25 @@ impl<'a> Format<'a> for BinaryLikeExpression<'a, '_> {
         let tail_parts = if last_is_jsx { &parts[1..parts.len() - 1] } else { &parts[1..] };
 
         let group_id = f.group_id("logicalChain");
+        let align_tail_parts =
+            f.options().string_concatenation_indent.is_align() && self.is_string_concatenation();
 
         let format_non_jsx_parts = format_with(|f| {
+            let format_tail_parts = format_with(|f| {
+                f.join().entries(tail_parts.iter());
+            });
+
             write!(
                 f,
                 [group(&format_args!(
                     first,
-                    (!tail_parts.is_empty()).then_some(indent(&format_with(|f| {
-                        f.join().entries(tail_parts.iter());
-                    })))
+                    (!tail_parts.is_empty()).then_some(format_with(|f| {
+                        if align_tail_parts {
+                            write!(f, format_tail_parts);
+                        } else {
+                            write!(f, indent(&format_tail_parts));
+                        }
+                    }))
                 ))
                 .with_group_id(Some(group_id))]
             );
@@ -437,7 +483,21 @@ impl<'a> Format<'a> for BinaryLeftOrRightSide<'a, '_> {
                 let right = binary_like_expression.right();
 
                 let operator_and_right_expression = format_with(|f| {
-                    write!(f, [space(), binary_like_expression.operator()]);
+                    let operator = binary_like_expression.operator();
+
+                    // Each stage of a pipeline starts its own line, unless a comment is on the line before it
+                    // ```js
+                    // value
+                    //   |> first
+                    //   |> second;
+                    // ```
+                    if operator.is_pipeline()
+                        && !f.comments().has_leading_own_line_comment(right.span().start)
+                    {
+                        return write!(f, [soft_line_break_or_space(), operator, space(), right]);
+                    }
+
+                    write!(f, [space(), operator]);
 
                     let should_inline = binary_like_expression.should_inline_logical_expression();
 
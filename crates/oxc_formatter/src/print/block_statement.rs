@@ -1,5 +1,6 @@
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
+use oxc_span::Span;
 
 use super::FormatWrite;
 use crate::{
@@ -55,9 +56,10 @@ impl<'a> FormatWrite<'a> for AstNode<'a, BlockStatement<'a>> {
                 write!(f, hard_line_break());
             }
         } else {
-            write!(
+            write_block_content(
+                self.span,
+                &format_args!(&formatted_comments_before_catch_clause, self.body()),
                 f,
-                block_indent(&format_args!(&formatted_comments_before_catch_clause, self.body()))
             );
         }
         write!(f, "}");
@@ -68,6 +70,43 @@ pub fn is_empty_block(block: &[Statement<'_>]) -> bool {
     block.is_empty() || block.iter().all(|s| matches!(s, Statement::EmptyStatement(_)))
 }
 
+/// Formats the non-empty content of the block at `span` between its braces, each on its own line.
+///
+/// With [`crate::FormatOptions::preserve_block_blank_lines`], a blank line directly after the `{`
+/// or before the `}` in the source is kept.
+pub fn write_block_content<'a>(span: Span, content: &impl Format<'a>, f: &mut Formatter<'_, 'a>) {
+    if !f.options().preserve_block_blank_lines {
+        write!(f, block_indent(content));
+        return;
+    }
+
+    let source_text = f.source_text();
+    let inner = source_text.slice_range(span.start + 1, span.end - 1);
+    let is_blank_line = |whitespace: &str| whitespace.matches('\n').count() > 1;
+    let leading = &inner[..inner.len() - inner.trim_start().len()];
+    let trailing = &inner[inner.trim_end().len()..];
+
+    write!(
+        f,
+        block_indent(&format_args!(
+            is_blank_line(leading).then_some(empty_line()),
+            content,
+            is_blank_line(trailing).then_some(empty_line())
+        ))
+    );
+}
+
+/// Formats the braces of an empty function or class body ending at `span`, with its dangling comments.
+///
+/// The braces stay together unless [`crate::EmptyBody::Expand`] is set and the body has no comments.
+pub fn write_empty_body(span: Span, f: &mut Formatter<'_, '_>) {
+    if f.options().empty_body.is_expand() && !f.context().comments().has_comment_before(span.end) {
+        write!(f, ["{", hard_line_break(), "}"]);
+    } else {
+        write!(f, ["{", format_dangling_comments(span).with_block_indent(), "}"]);
+    }
+}
+
 /// Formatting of curly braces for an:
 /// * empty block: same line `{}`,
 /// * empty block that is the 'bons' or 'alt' of an if statement: two lines `{\n}`
@@ -301,6 +301,12 @@ pub fn arguments_grouped_layout(
                 .then_some(GroupedCallArgumentLayout::GroupedFirstArgument)
         }
     } else {
+        if !f.options().hug_sole_argument.value()
+            && matches!(args, [Argument::ObjectExpression(_) | Argument::ArrayExpression(_)])
+        {
+            return None;
+        }
+
         // For other cases (not exactly 2 arguments), only check last argument grouping
         should_group_last_argument(args, f)
             .then_some(GroupedCallArgumentLayout::GroupedLastArgument)
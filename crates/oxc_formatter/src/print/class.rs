@@ -15,7 +15,10 @@ use crate::{
         trivia::{FormatLeadingComments, FormatTrailingComments},
     },
     parentheses::NeedsParentheses,
-    print::{function::should_group_function_parameters, semicolon::OptionalSemicolon},
+    print::{
+        block_statement::write_empty_body, function::should_group_function_parameters,
+        semicolon::OptionalSemicolon,
+    },
     utils::{
         assignment_like::AssignmentLike,
         format_node_without_trailing_comments::FormatNodeWithoutTrailingComments,
@@ -55,11 +58,21 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ClassBody<'a>> {
 
 impl<'a> Format<'a> for AstNode<'a, Vec<'a, ClassElement<'a>>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
-        // Join class elements with hard line breaks between them
-        let mut join = f.join_nodes_with_hardline();
         // Iterate through pairs of consecutive elements to handle semicolons properly
         // Each element is paired with the next one (or None for the last element)
         let mut iter = self.iter().peekable();
+
+        if f.options().blank_line_between_class_members {
+            // Always separate class elements with an empty line, regardless of the input
+            let mut join = f.join_with(empty_line());
+            while let Some(element) = iter.next() {
+                join.entry(&(element, iter.peek().copied()));
+            }
+            return;
+        }
+
+        // Join class elements with hard line breaks between them
+        let mut join = f.join_nodes_with_hardline();
         while let Some(element) = iter.next() {
             join.entry(element.span(), &(element, iter.peek().copied()));
         }
@@ -477,7 +490,7 @@ impl<'a> Format<'a> for FormatClass<'a, '_> {
         }
 
         if body.body.is_empty() {
-            write!(f, ["{", format_dangling_comments(self.span).with_block_indent(), "}"]);
+            write_empty_body(self.span, f);
         } else {
             body.fmt(f);
         }
@@ -611,7 +624,7 @@ impl<'a> Format<'a> for FormatClassElementWithSemicolon<'a, '_> {
 
         let needs_semi = needs_semi
             && match f.options().semicolons {
-                Semicolons::Always => true,
+                Semicolons::Always | Semicolons::Hybrid => true,
                 Semicolons::AsNeeded => self.needs_semicolon(),
             }
             // Don't add semicolon if the element is suppressed (has `oxfmt-ignore`),
@@ -5,7 +5,7 @@ use oxc_ast::ast::*;
 use super::{
     FormatWrite,
     arrow_function_expression::{FunctionCacheMode, GroupedCallArgumentLayout},
-    block_statement::is_empty_block,
+    block_statement::{is_empty_block, write_block_content, write_empty_body},
 };
 use crate::{
     ast_nodes::AstNode,
@@ -62,6 +62,7 @@ impl<'a, 'b> FormatFunction<'a, 'b> {
     #[inline]
     pub fn format(&self, f: &mut Formatter<'_, 'a>) {
         let head = format_with(|f| {
+            let has_space = self.id.is_some() || f.options().anonymous_function_space.value();
             write!(
                 f,
                 [
@@ -69,7 +70,7 @@ impl<'a, 'b> FormatFunction<'a, 'b> {
                     self.r#async.then_some("async "),
                     "function",
                     self.generator().then_some("*"),
-                    space(),
+                    has_space.then_some(space()),
                     self.id(),
                     group(&self.type_parameters()),
                 ]
@@ -156,9 +157,11 @@ impl<'a> FormatWrite<'a> for AstNode<'a, FunctionBody<'a>> {
         let statements = self.statements();
         let directives = self.directives();
         if is_empty_block(statements) && directives.is_empty() {
-            write!(f, ["{", format_dangling_comments(self.span).with_block_indent(), "}"]);
+            write_empty_body(self.span, f);
         } else {
-            write!(f, ["{", block_indent(&format_args!(directives, statements)), "}"]);
+            write!(f, "{");
+            write_block_content(self.span, &format_args!(directives, statements), f);
+            write!(f, "}");
         }
     }
 }
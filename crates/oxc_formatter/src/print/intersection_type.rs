@@ -26,6 +26,7 @@ fn format_intersection_types<'a>(
     let last_index = node.len().saturating_sub(1);
     let mut is_prev_object_like = false;
     let mut is_chain_indented = false;
+    let operator_at_start = f.options().type_operator_position.is_intersection_start();
 
     for (index, item) in node.iter().enumerate() {
         let is_object_like = is_object_like_type(item.as_ref());
@@ -39,6 +40,9 @@ fn format_intersection_types<'a>(
                 || f.comments().has_leading_own_line_comment(item.span().start)
             {
                 let content = format_with(|f| {
+                    if operator_at_start {
+                        write!(f, ["&", space()]);
+                    }
                     if item.needs_parentheses(f) {
                         write!(f, format_leading_comments(item.span()));
                     }
@@ -46,6 +50,9 @@ fn format_intersection_types<'a>(
                 });
                 write!(f, soft_line_indent_or_space(&content));
             } else {
+                if operator_at_start {
+                    write!(f, [space(), "&"]);
+                }
                 write!(f, space());
 
                 if !is_prev_object_like || !is_object_like {
@@ -62,7 +69,7 @@ fn format_intersection_types<'a>(
         }
 
         // Add separator if not the last element
-        if index < last_index {
+        if index < last_index && !operator_at_start {
             write!(f, [space(), "&"]);
         }
 
@@ -80,9 +80,15 @@ impl FormatJsxChildList {
                 // A single word: Both `a` and `b` are a word in `a b` because they're separated by JSX Whitespace.
                 JsxChild::Word(word) => {
                     let separator = match children_iter.peek() {
-                        Some(JsxChild::Word(_)) => {
-                            // Separate words by a space or line break in extended mode
-                            Some(WordSeparator::BetweenWords)
+                        Some(JsxChild::Word(next_word)) => {
+                            if f.options().jsx_text_wrap.is_preserve() {
+                                Some(WordSeparator::PreservedBetweenWords {
+                                    is_line_break: next_word.has_line_break_before(),
+                                })
+                            } else {
+                                // Separate words by a space or line break in extended mode
+                                Some(WordSeparator::BetweenWords)
+                            }
                         }
 
                         // Last word or last word before an element without any whitespace in between
@@ -455,6 +461,17 @@ enum WordSeparator {
     /// `a b`
     BetweenWords,
 
+    /// Separator between two words when [`crate::JsxTextWrap::Preserve`] is used.
+    /// Creates a hard line break if the words were on different lines, otherwise a space.
+    ///
+    /// ```javascript
+    /// <div>
+    ///   a b
+    ///   c
+    /// </div>
+    /// ```
+    PreservedBetweenWords { is_line_break: bool },
+
     /// A separator of a word at the end of a [`JSXText`] element. Either because it is the last
     /// child in its parent OR it is right before the start of another child (element, expression, ...).
     ///
@@ -485,7 +502,11 @@ enum WordSeparator {
 impl WordSeparator {
     /// Returns if formatting this separator will result in a child that expands
     fn will_break(self) -> bool {
-        matches!(self, Self::EndOfText { is_soft_line_break: false })
+        matches!(
+            self,
+            Self::EndOfText { is_soft_line_break: false }
+                | Self::PreservedBetweenWords { is_line_break: true }
+        )
     }
 }
 
@@ -493,6 +514,8 @@ impl<'a> Format<'a> for WordSeparator {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
         match self {
             Self::BetweenWords => soft_line_break_or_space().fmt(f),
+            Self::PreservedBetweenWords { is_line_break: true } => hard_line_break().fmt(f),
+            Self::PreservedBetweenWords { is_line_break: false } => space().fmt(f),
             Self::EndOfText { is_soft_line_break } => {
                 if *is_soft_line_break {
                     soft_line_break().fmt(f);
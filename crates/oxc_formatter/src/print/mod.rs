@@ -39,17 +39,20 @@ pub use arrow_function_expression::{
 pub use binary_like_expression::{BinaryLikeExpression, should_flatten};
 pub use function::FormatFunctionOptions;
 
+use std::borrow::Cow;
+
 use cow_utils::CowUtils;
 
 use oxc_allocator::{StringBuilder, Vec};
 use oxc_ast::ast::*;
-use oxc_span::GetSpan;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
 
 use crate::{
     ast_nodes::{AstNode, AstNodes},
     best_fitting, format_args,
     formatter::{
-        Format, Formatter,
+        Format, Formatter, ObjectPropertyAlignment,
         prelude::*,
         separated::FormatSeparatedIter,
         token::number::{
@@ -60,7 +63,7 @@ use crate::{
             FormatTrailingComments,
         },
     },
-    options::{FormatTrailingCommas, Semicolons, TrailingSeparator},
+    options::{FormatTrailingCommas, Semicolons, SortObjectKeys, TrailingSeparator},
     parentheses::NeedsParentheses,
     print::parameters::can_avoid_parentheses,
     utils::{
@@ -81,7 +84,7 @@ use self::{
     array_expression::FormatArrayExpression,
     block_statement::is_empty_block,
     class::format_grouped_parameters_with_return_type_for_method,
-    object_like::ObjectLike,
+    object_like::{ObjectLike, align_group, has_blank_line_between},
     object_pattern_like::ObjectPatternLike,
     return_or_throw_statement::FormatAdjacentArgument,
     semicolon::OptionalSemicolon,
@@ -171,6 +174,23 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ObjectExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Vec<'a, ObjectPropertyKind<'a>>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
         let trailing_separator = FormatTrailingCommas::ES5.trailing_separator(f.options());
+        let sort_object_keys = f.options().sort_object_keys;
+        if !sort_object_keys.is_none()
+            && let Some(mut properties) = sortable_properties(self, f)
+        {
+            // A stable sort keeps duplicate keys in their order, so the last one still wins
+            properties.sort_by(|(a, _), (b, _)| match sort_object_keys {
+                SortObjectKeys::Desc => b.cmp(a),
+                _ => a.cmp(b),
+            });
+            f.join_nodes_with_soft_line().entries_with_trailing_separator(
+                properties.into_iter().map(|(_, property)| property),
+                ",",
+                trailing_separator,
+            );
+            return;
+        }
+
         f.join_nodes_with_soft_line().entries_with_trailing_separator(
             self.iter(),
             ",",
@@ -179,6 +199,51 @@ impl<'a> Format<'a> for AstNode<'a, Vec<'a, ObjectPropertyKind<'a>>> {
     }
 }
 
+/// Returns the properties together with their keys, if all keys are statically known,
+/// all values are free of side effects so that reordering them doesn't change the program,
+/// and there are no comments which could be moved to another property.
+///
+/// Objects with spread elements or a `__proto__` key are never sorted.
+fn sortable_properties<'a>(
+    properties: &AstNode<'a, Vec<'a, ObjectPropertyKind<'a>>>,
+    f: &Formatter<'_, 'a>,
+) -> Option<std::vec::Vec<(Cow<'a, str>, &'a AstNode<'a, ObjectPropertyKind<'a>>)>> {
+    if f.comments().has_comment_in_span(properties.parent().span()) {
+        return None;
+    }
+
+    properties
+        .iter()
+        .map(|kind| match kind.as_ref() {
+            ObjectPropertyKind::ObjectProperty(property)
+                if !property.computed && is_side_effect_free_value(&property.value) =>
+            {
+                property.key.static_name().filter(|key| key != "__proto__").map(|key| (key, kind))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether evaluating the property value can't have side effects:
+/// literals, identifiers, functions, and array and object literals of those.
+fn is_side_effect_free_value(value: &Expression<'_>) -> bool {
+    match value {
+        Expression::Identifier(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ArrowFunctionExpression(_) => true,
+        Expression::ArrayExpression(array) => array.elements.iter().all(|element| {
+            element.is_elision() || element.as_expression().is_some_and(is_side_effect_free_value)
+        }),
+        Expression::ObjectExpression(object) => object.properties.iter().all(|property| {
+            property.as_property().is_some_and(|property| {
+                !property.computed && is_side_effect_free_value(&property.value)
+            })
+        }),
+        _ => value.is_literal(),
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, ObjectProperty<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
         let is_accessor = match &self.kind() {
@@ -555,9 +620,7 @@ fn expression_statement_needs_semicolon<'a>(
 impl<'a> FormatWrite<'a> for AstNode<'a, ExpressionStatement<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
         // Check if we need a leading semicolon to prevent ASI issues
-        if f.options().semicolons == Semicolons::AsNeeded
-            && expression_statement_needs_semicolon(self, f)
-        {
+        if !f.options().semicolons.is_always() && expression_statement_needs_semicolon(self, f) {
             write!(f, ";");
         }
 
@@ -804,6 +867,7 @@ impl<'a> FormatWrite<'a> for AstNode<'a, IfStatement<'a>> {
                 }
             }
 
+            let alternate = collapsible_else_if(alternate, f).unwrap_or(alternate);
             write!(
                 f,
                 [
@@ -819,6 +883,54 @@ impl<'a> FormatWrite<'a> for AstNode<'a, IfStatement<'a>> {
     }
 }
 
+/// Returns the `if` statement of an `else` block which only contains it, if
+/// [`crate::FormatOptions::collapse_else_if`] is enabled and the block has no other comments.
+/// The block is kept when the `if` ends with an `if` without `else`,
+/// because that `if` would take over an `else` following the block.
+fn collapsible_else_if<'a, 'b>(
+    alternate: &'b AstNode<'a, Statement<'a>>,
+    f: &Formatter<'_, 'a>,
+) -> Option<&'b AstNode<'a, Statement<'a>>> {
+    if !f.options().collapse_else_if {
+        return None;
+    }
+    let AstNodes::BlockStatement(block) = alternate.as_ast_nodes() else {
+        return None;
+    };
+    let mut body = block.body().iter();
+    let statement = body.next()?;
+    if body.next().is_some()
+        || !matches!(statement.as_ref(), Statement::IfStatement(_))
+        || ends_with_if_without_else(statement)
+    {
+        return None;
+    }
+    let comments = f.comments();
+    if comments.has_comment_in_range(block.span.start, statement.span().start)
+        || comments.has_comment_in_range(statement.span().end, block.span.end)
+    {
+        return None;
+    }
+    Some(statement)
+}
+
+fn ends_with_if_without_else(statement: &Statement) -> bool {
+    match statement {
+        Statement::IfStatement(if_statement) => {
+            if_statement.alternate.as_ref().is_none_or(ends_with_if_without_else)
+        }
+        Statement::WhileStatement(while_statement) => {
+            ends_with_if_without_else(&while_statement.body)
+        }
+        Statement::ForStatement(for_statement) => ends_with_if_without_else(&for_statement.body),
+        Statement::ForInStatement(for_in) => ends_with_if_without_else(&for_in.body),
+        Statement::ForOfStatement(for_of) => ends_with_if_without_else(&for_of.body),
+        Statement::LabeledStatement(labeled) => ends_with_if_without_else(&labeled.body),
+        Statement::WithStatement(with) => ends_with_if_without_else(&with.body),
+        _ => false,
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, ContinueStatement<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
         write!(f, "continue");
@@ -972,6 +1084,7 @@ impl<'a> FormatWrite<'a> for AstNode<'a, NumericLiteral<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
         let source_text = f.source_text().text_for(self);
         let options = NumberFormatOptions::keep_one_trailing_decimal_zero();
+        let preserve = f.options().numeric_literals.is_preserve();
 
         // Check if this numeric literal is a property key (not a value) that should be quoted
         // when quoteProps is "consistent" and another property requires quotes.
@@ -988,7 +1101,11 @@ impl<'a> FormatWrite<'a> for AstNode<'a, NumericLiteral<'a>> {
 
         if is_property_key && f.context().is_quote_needed() {
             // Get the formatted number text
-            let formatted = format_trimmed_number(source_text, options);
+            let formatted = if preserve {
+                Cow::Borrowed(source_text)
+            } else {
+                format_trimmed_number(source_text, options)
+            };
 
             // Check if the number is "simple" (only digits, or digits.digits)
             // and the value matches the formatted representation.
@@ -1021,7 +1138,11 @@ impl<'a> FormatWrite<'a> for AstNode<'a, NumericLiteral<'a>> {
             }
         }
 
-        format_number_token(source_text, options).fmt(f);
+        if preserve {
+            write!(f, text_without_whitespace(source_text));
+        } else {
+            format_number_token(source_text, options).fmt(f);
+        }
     }
 }
 
@@ -1047,10 +1168,12 @@ impl<'a> FormatWrite<'a> for AstNode<'a, StringLiteral<'a>> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, BigIntLiteral<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(
-            f,
-            text(f.context().allocator().alloc_str(&self.raw().unwrap().cow_to_ascii_lowercase()))
-        );
+        let raw = self.raw().unwrap().as_str();
+        if f.options().numeric_literals.is_preserve() {
+            write!(f, text(raw));
+        } else {
+            write!(f, text(f.context().allocator().alloc_str(&raw.cow_to_ascii_lowercase())));
+        }
     }
 }
 
@@ -1093,12 +1216,67 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TSEnumBody<'a>> {
                 f,
                 group(&format_args!(format_dangling_comments(self.span()), soft_line_break()))
             );
+        } else if let Some(alignment) = self.member_alignment(f) {
+            let group_id = alignment.group_id;
+            f.context_mut().push_object_property_alignment(alignment);
+            write!(f, group(&block_indent(self.members())).with_group_id(Some(group_id)));
+            f.context_mut().pop_object_property_alignment();
         } else {
             write!(f, block_indent(self.members()));
         }
     }
 }
 
+impl<'a> AstNode<'a, TSEnumBody<'a>> {
+    /// Returns the alignment of the `=` of the members, if [`crate::FormatOptions::align_enum_members`]
+    /// is enabled.
+    ///
+    /// Consecutive members form a group, and a blank line between two members starts a new one.
+    /// Each member with an initializer is padded to the longest name of its group.
+    fn member_alignment(&self, f: &Formatter<'_, 'a>) -> Option<ObjectPropertyAlignment> {
+        if !f.options().align_enum_members {
+            return None;
+        }
+
+        let mut paddings = FxHashMap::default();
+        let mut group: std::vec::Vec<(Span, usize)> = vec![];
+        let mut previous_end = None;
+        for member in &self.members {
+            if previous_end.is_some_and(|end| has_blank_line_between(end, member.span.start, f)) {
+                align_group(&mut group, &mut paddings);
+            }
+            previous_end = Some(member.span.end);
+
+            if member.initializer.is_some() {
+                group.push((member.span, enum_member_name_width(&member.id, f)));
+            }
+        }
+        align_group(&mut group, &mut paddings);
+
+        Some(ObjectPropertyAlignment { group_id: f.group_id("enum"), paddings })
+    }
+}
+
+/// The width of the printed name of an enum member.
+fn enum_member_name_width<'a>(id: &TSEnumMemberName<'a>, f: &Formatter<'_, 'a>) -> usize {
+    match id {
+        TSEnumMemberName::String(string) | TSEnumMemberName::ComputedString(string) => {
+            FormatLiteralStringToken::new(
+                f.source_text().text_for(string.as_ref()),
+                false,
+                StringLiteralParentKind::Expression,
+            )
+            .clean_text(f)
+            .width()
+        }
+        // Printed with `[` and `]`
+        TSEnumMemberName::ComputedTemplateString(template) => {
+            f.source_text().span_width(template.span) + 2
+        }
+        TSEnumMemberName::Identifier(_) => f.source_text().span_width(id.span()),
+    }
+}
+
 impl<'a> Format<'a> for AstNode<'a, Vec<'a, TSEnumMember<'a>>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
         let trailing_separator = FormatTrailingCommas::ES5.trailing_separator(f.options());
@@ -1126,6 +1304,14 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TSEnumMember<'a>> {
         }
 
         if let Some(init) = self.initializer() {
+            if let Some(alignment) = f.context().object_property_alignment() {
+                let group_id = alignment.group_id;
+                let padding = alignment.paddings.get(&self.span).copied().unwrap_or_default();
+                if padding > 0 {
+                    let padding = f.context().allocator().alloc_str(&" ".repeat(padding));
+                    write!(f, [if_group_breaks(&text(padding)).with_group_id(Some(group_id))]);
+                }
+            }
             write!(f, [space(), "=", space(), init]);
         }
     }
@@ -1485,7 +1671,7 @@ impl<'a> Format<'a> for FormatTSSignature<'a, '_> {
                     token(";").fmt(f);
                 }
             }
-            Semicolons::AsNeeded => {
+            Semicolons::AsNeeded | Semicolons::Hybrid => {
                 // Needs semicolon anyway when:
                 // 1. It's a non-computed property signature with type annotation followed by
                 //    a call signature that has type parameters
@@ -1,15 +1,17 @@
 use oxc_ast::ast::*;
 use oxc_span::GetSpan;
+use rustc_hash::FxHashMap;
 
 use crate::{
     ast_nodes::{AstNode, AstNodes},
     formatter::{
-        Buffer, Format, Formatter,
-        prelude::{format_with, group, soft_block_indent_with_maybe_space},
+        Buffer, Format, Formatter, ObjectPropertyAlignment,
+        prelude::{format_with, group, soft_block_indent_with_maybe_space, space},
         trivia::format_dangling_comments,
     },
     options::Expand,
     print::parameters::{get_this_param, should_hug_function_parameters},
+    utils::object::member_name_width,
     write,
 };
 
@@ -84,6 +86,41 @@ impl<'a> ObjectLike<'a, '_> {
         }
     }
 
+    /// Returns the alignment of the property values, if [`crate::FormatOptions::align_object_properties`]
+    /// is enabled and this is an object expression.
+    ///
+    /// Consecutive properties form a group, and a blank line between two properties starts a new one.
+    /// Each property of a group is padded to the longest key of its group.
+    /// Shorthand, computed, method and accessor properties, and spread elements are not aligned.
+    fn property_alignment(&self, f: &Formatter<'_, 'a>) -> Option<ObjectPropertyAlignment> {
+        let Self::ObjectExpression(object) = self else {
+            return None;
+        };
+        if !f.options().align_object_properties {
+            return None;
+        }
+
+        let mut paddings = FxHashMap::default();
+        let mut group: Vec<(Span, usize)> = vec![];
+        let mut previous_end = None;
+        for kind in &object.properties {
+            if previous_end.is_some_and(|end| has_blank_line_between(end, kind.span().start, f)) {
+                align_group(&mut group, &mut paddings);
+            }
+            previous_end = Some(kind.span().end);
+
+            if let ObjectPropertyKind::ObjectProperty(property) = kind
+                && property.kind == PropertyKind::Init
+                && !(property.method || property.shorthand || property.computed)
+            {
+                group.push((property.span, member_name_width(&property.key, f)));
+            }
+        }
+        align_group(&mut group, &mut paddings);
+
+        Some(ObjectPropertyAlignment { group_id: f.group_id("object"), paddings })
+    }
+
     fn write_members(&self, f: &mut Formatter<'_, 'a>) {
         match self {
             Self::ObjectExpression(o) => o.properties().fmt(f),
@@ -103,7 +140,11 @@ impl<'a> Format<'a> for ObjectLike<'a, '_> {
             // can stay on one line if it fits (e.g., `<div {...{/* comment */}} />`).
             // In other contexts like variable declarations, use block indent to
             // expand the object (e.g., `var a = {\n  /* comment */\n};`).
-            if self.is_inside_jsx_spread() {
+            if f.options().empty_bracket_spacing.value()
+                && !f.comments().has_comment_before(self.span().end)
+            {
+                write!(f, space());
+            } else if self.is_inside_jsx_spread() {
                 write!(f, format_dangling_comments(self.span()).with_soft_block_indent());
             } else {
                 write!(f, format_dangling_comments(self.span()).with_block_indent());
@@ -128,6 +169,14 @@ impl<'a> Format<'a> for ObjectLike<'a, '_> {
 
             if should_hug {
                 write!(f, inner);
+            } else if let Some(alignment) = self.property_alignment(f) {
+                let group_id = alignment.group_id;
+                f.context_mut().push_object_property_alignment(alignment);
+                write!(
+                    f,
+                    [group(&inner).with_group_id(Some(group_id)).should_expand(should_expand)]
+                );
+                f.context_mut().pop_object_property_alignment();
             } else {
                 write!(f, [group(&inner).should_expand(should_expand)]);
             }
@@ -136,3 +185,15 @@ impl<'a> Format<'a> for ObjectLike<'a, '_> {
         write!(f, "}");
     }
 }
+
+/// Moves the properties of `group` into `paddings`, with the padding needed to reach the longest key.
+pub fn align_group(group: &mut Vec<(Span, usize)>, paddings: &mut FxHashMap<Span, usize>) {
+    let max_width = group.iter().map(|(_, width)| *width).max().unwrap_or_default();
+    paddings.extend(group.drain(..).map(|(span, width)| (span, max_width - width)));
+}
+
+/// Whether there is an empty line between `start` and `end`, ignoring the lines they are on.
+pub fn has_blank_line_between(start: u32, end: u32, f: &Formatter<'_, '_>) -> bool {
+    let lines = f.source_text().slice_range(start, end).split('\n').collect::<Vec<_>>();
+    lines.len() > 2 && lines[1..lines.len() - 1].iter().any(|line| line.trim().is_empty())
+}
@@ -10,7 +10,7 @@ impl<'a> Format<'a> for OptionalSemicolon {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
         match f.options().semicolons {
             Semicolons::Always => write!(f, ";"),
-            Semicolons::AsNeeded => (),
+            Semicolons::AsNeeded | Semicolons::Hybrid => (),
         }
     }
 }
@@ -5,7 +5,6 @@ use oxc_span::GetSpan;
 use crate::{
     Format,
     ast_nodes::AstNode,
-    format_args,
     formatter::{
         Formatter,
         prelude::*,
@@ -135,7 +134,12 @@ impl<'a> FormatWrite<'a> for AstNode<'a, SwitchCase<'a>> {
             write!(f, [FormatStatementBody::new(first_statement)]);
         } else {
             // no line break needed after because it is added by the indent in the switch statement
-            write!(f, indent(&format_args!(hard_line_break(), consequent)));
+            let format_consequent = format_with(|f| write!(f, [hard_line_break(), consequent]));
+            if f.options().switch_case_body_indent.is_double() {
+                write!(f, indent(&indent(&format_consequent)));
+            } else {
+                write!(f, indent(&format_consequent));
+            }
         }
     }
 }
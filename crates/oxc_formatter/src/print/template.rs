@@ -1,3 +1,4 @@
+use cow_utils::CowUtils;
 use unicode_width::UnicodeWidthStr;
 
 use std::cmp;
@@ -794,7 +795,7 @@ fn get_tag_name<'a>(expr: &'a Expression<'a>) -> Option<&'a str> {
 fn format_embedded_template<'a>(
     f: &mut Formatter<'_, 'a>,
     language: &str,
-    template_content: &str,
+    template_content: &'a str,
 ) -> bool {
     // Whitespace-only templates become empty backticks.
     // Regular template literals would preserve them as-is.
@@ -811,9 +812,17 @@ fn format_embedded_template<'a>(
     let Some(Ok(formatted)) =
         f.context().external_callbacks().format_embedded(language, template_content)
     else {
-        return false;
+        return f.options().reindent_embedded_templates
+            && write_reindented_template(f, template_content);
     };
 
+    write_formatted_embedded_template(f, &formatted);
+
+    true
+}
+
+/// Write the content formatted by the embedded formatter as an indented block between backticks.
+fn write_formatted_embedded_template<'a>(f: &mut Formatter<'_, 'a>, formatted: &str) {
     // Format with proper template literal structure:
     // - Opening backtick
     // - Hard line break (newline after backtick)
@@ -821,7 +830,7 @@ fn format_embedded_template<'a>(
     // - Hard line break (newline before closing backtick)
     // - Closing backtick
     let format_content = format_with(|f: &mut Formatter<'_, 'a>| {
-        let content = f.context().allocator().alloc_str(&formatted);
+        let content = f.context().allocator().alloc_str(formatted);
         for line in LineTerminatorSplitter::new(content) {
             if line.is_empty() {
                 write!(f, [empty_line()]);
@@ -832,6 +841,37 @@ fn format_embedded_template<'a>(
     });
 
     write!(f, ["`", block_indent(&format_content), "`"]);
+}
+
+/// Re-indent the (already dedented) content of an embedded template which wasn't formatted
+/// by the embedded formatter, see [`crate::FormatOptions::reindent_embedded_templates`].
+///
+/// Returns `false` if the content starts on the same line as the opening backtick,
+/// it can't be moved to a new line without changing the meaning of the template.
+fn write_reindented_template<'a>(f: &mut Formatter<'_, 'a>, template_content: &'a str) -> bool {
+    let Some((first_line, _)) = template_content.split_once('\n') else {
+        return false;
+    };
+    if !first_line.trim_ascii().is_empty() {
+        return false;
+    }
+
+    // Leading and trailing blank lines are replaced by the line breaks around the content
+    let content = template_content.trim_ascii_end();
+    let leading_blank_lines = &content[..content.len() - content.trim_ascii_start().len()];
+    let content = &content[leading_blank_lines.rfind('\n').map_or(0, |index| index + 1)..];
+
+    let format_content = format_with(|f: &mut Formatter<'_, 'a>| {
+        for line in LineTerminatorSplitter::new(content) {
+            if line.trim_ascii().is_empty() {
+                write!(f, [empty_line()]);
+            } else {
+                write!(f, [text(line), hard_line_break()]);
+            }
+        }
+    });
+
+    write!(f, ["`", block_indent(&format_content), "`"]);
 
     true
 }
@@ -869,16 +909,24 @@ fn try_format_embedded_template<'a>(
     f: &mut Formatter<'_, 'a>,
 ) -> bool {
     let quasi = &tagged.quasi;
+    let Some(tag_name) = get_tag_name(&tagged.tag) else {
+        return false;
+    };
+
+    if f.options().embedded_sql_tags.iter().any(|sql_tag| sql_tag == tag_name) {
+        return try_format_sql_template(quasi, f);
+    }
+
     // TODO: Support expressions in the template
     if !quasi.is_no_substitution_template() {
         return false;
     }
 
-    let language = match get_tag_name(&tagged.tag) {
-        Some("css" | "styled") => "tagged-css",
-        Some("gql" | "graphql") => "tagged-graphql",
-        Some("html") => "tagged-html",
-        Some("md" | "markdown") => "tagged-markdown",
+    let language = match tag_name {
+        "css" | "styled" => "tagged-css",
+        "gql" | "graphql" => "tagged-graphql",
+        "html" => "tagged-html",
+        "md" | "markdown" => "tagged-markdown",
         _ => return false,
     };
 
@@ -887,6 +935,58 @@ fn try_format_embedded_template<'a>(
     format_embedded_template(f, language, template_content)
 }
 
+/// Try to format a template tagged with one of [`crate::FormatOptions::embedded_sql_tags`]
+/// with the embedded formatter, using the `sql` parser.
+///
+/// Each `${...}` is replaced by a placeholder before formatting and restored as written afterwards.
+/// Returns `false` if the template is left as is: when no embedded formatter is available, formatting failed,
+/// an interpolation spans multiple lines or contains a comment, or a placeholder didn't survive formatting.
+fn try_format_sql_template<'a>(quasi: &TemplateLiteral<'a>, f: &mut Formatter<'_, 'a>) -> bool {
+    // The interpolations are copied as written, so their comments would be printed again after the template
+    if f.comments().has_comment_in_span(quasi.span) {
+        return false;
+    }
+
+    let source_text = f.source_text();
+    let mut content = String::new();
+    let mut interpolations = Vec::with_capacity(quasi.expressions.len());
+    for (index, element) in quasi.quasis.iter().enumerate() {
+        content.push_str(element.value.raw.as_str());
+        if let Some(next) = quasi.quasis.get(index + 1) {
+            let interpolation = source_text.slice_range(element.span.end, next.span.start);
+            if interpolation.contains(['\n', '\r']) {
+                return false;
+            }
+            let placeholder = format!("__oxfmt_placeholder_{index}__");
+            content.push_str(&placeholder);
+            interpolations.push((placeholder, interpolation));
+        }
+    }
+
+    if content.trim().is_empty() {
+        write!(f, ["``"]);
+        return true;
+    }
+
+    let content = dedent(&content, f.context().allocator());
+    let Some(Ok(mut formatted)) =
+        f.context().external_callbacks().format_embedded("tagged-sql", content)
+    else {
+        return false;
+    };
+
+    for (placeholder, interpolation) in &interpolations {
+        if formatted.matches(placeholder.as_str()).count() != 1 {
+            return false;
+        }
+        formatted = formatted.cow_replace(placeholder.as_str(), interpolation).into_owned();
+    }
+
+    write_formatted_embedded_template(f, &formatted);
+
+    true
+}
+
 /// Check if the template literal is inside a `css` prop or `<style jsx>` element.
 ///
 /// ```jsx
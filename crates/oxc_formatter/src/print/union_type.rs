@@ -28,7 +28,7 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TSUnionType<'a>> {
         // should be inlined and not be printed in the multi-line variant
         let should_hug = should_hug_type(self, f);
         if should_hug {
-            return format_union_types(self.types(), Span::default(), true, f);
+            return format_union_types(self.types(), Span::default(), true, true, f);
         }
 
         // Find the head of the nest union type chain
@@ -81,17 +81,20 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TSUnionType<'a>> {
                 if is_suppressed { self.types.first().unwrap().span() } else { Span::default() };
 
             let leading_soft_line_break_or_space = should_indent && !has_leading_comments;
+            let operator_at_start = f.options().type_operator_position.is_union_start();
 
             let separator = format_with(|f| {
                 if leading_soft_line_break_or_space {
                     write!(f, [soft_line_break_or_space()]);
                 }
-                write!(f, [token("|"), space()]);
+                if operator_at_start {
+                    write!(f, [token("|"), space()]);
+                }
             });
 
             write!(f, [if_group_breaks(&separator)]);
 
-            format_union_types(types, suppressed_node_span, false, f);
+            format_union_types(types, suppressed_node_span, false, operator_at_start, f);
         });
 
         let content = format_with(|f| {
@@ -148,7 +151,7 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TSUnionType<'a>> {
             );
         });
 
-        if should_indent && !self.needs_parentheses(f) {
+        if should_indent && f.options().indent_union_types.value() && !self.needs_parentheses(f) {
             write!(f, [group(&indent(&format_inner_content))]);
         } else {
             write!(f, [group(&format_inner_content)]);
@@ -160,6 +163,7 @@ fn format_union_types<'a>(
     node: &AstNode<'a, Vec<'a, TSType<'a>>>,
     mut suppressed_node_span: Span,
     should_hug: bool,
+    operator_at_start: bool,
     f: &mut Formatter<'_, 'a>,
 ) {
     let mut node_iter = node.iter().peekable();
@@ -177,7 +181,7 @@ fn format_union_types<'a>(
             if needs_parentheses {
                 write!(f, ")");
             }
-        } else if should_hug {
+        } else if should_hug || !operator_at_start {
             write!(f, [element]);
         } else {
             write!(f, [align(2, &element)]);
@@ -211,7 +215,7 @@ fn format_union_types<'a>(
                 FormatTrailingComments::Comments(comments).fmt(f);
             }
 
-            if should_hug {
+            if should_hug || !operator_at_start {
                 write!(f, [space()]);
             } else {
                 write!(f, [soft_line_break_or_space()]);
@@ -233,7 +237,11 @@ fn format_union_types<'a>(
         }
 
         if node_iter.peek().is_some() {
-            write!(f, space());
+            if should_hug || operator_at_start {
+                write!(f, space());
+            } else {
+                write!(f, soft_line_break_or_space());
+            }
         }
     }
 }
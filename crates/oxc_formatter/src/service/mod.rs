@@ -2,6 +2,8 @@ use oxc_parser::ParseOptions;
 use oxc_span::SourceType;
 use phf::phf_set;
 
+use crate::FormatOptions;
+
 pub fn get_parse_options() -> ParseOptions {
     ParseOptions {
         // Do not need to parse regexp
@@ -9,11 +11,21 @@ pub fn get_parse_options() -> ParseOptions {
         // Enable all syntax features
         allow_return_outside_function: true,
         allow_v8_intrinsics: true,
+        // Experimental proposals are opted into by `FormatOptions`, see `get_parse_options_with`
+        allow_pipeline_operator: false,
         // `oxc_formatter` expects this to be `false`, otherwise panics
         preserve_parens: false,
     }
 }
 
+/// Same as [`get_parse_options`], but also enables the experimental syntax opted into by `options`.
+pub fn get_parse_options_with(options: &FormatOptions) -> ParseOptions {
+    ParseOptions {
+        allow_pipeline_operator: options.experimental_pipeline_operator,
+        ..get_parse_options()
+    }
+}
+
 // Additional extensions from linguist-languages, which Prettier also supports
 // - https://github.com/ikatyang-collab/linguist-languages/blob/d1dc347c7ced0f5b42dd66c7d1c4274f64a3eb6b/data/JavaScript.js
 // No special extensions for TypeScript
@@ -53,7 +65,7 @@ static SPECIAL_JS_FILENAMES: phf::Set<&'static str> = phf_set! {
 
 pub fn get_supported_source_type(path: &std::path::Path) -> Option<SourceType> {
     // Standard extensions, also supported by `oxc_span::VALID_EXTENSIONS`
-    // NOTE: Use `path` directly for `.d.ts` detection
+    // NOTE: Use `path` directly for `.d.ts`, `.d.mts` and `.d.cts` detection
     if let Ok(source_type) = SourceType::from_path(path) {
         return Some(source_type);
     }
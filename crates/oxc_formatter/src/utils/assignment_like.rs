@@ -3,6 +3,7 @@ use oxc_span::GetSpan;
 
 use crate::{
     ast_nodes::{AstNode, AstNodes},
+    format_args,
     formatter::{
         Buffer, BufferExtensions, Format, Formatter, VecBuffer,
         prelude::{FormatElements, format_once, line_suffix_boundary, *},
@@ -368,6 +369,23 @@ impl<'a> AssignmentLike<'a, '_> {
         }
     }
 
+    /// Writes the padding after the colon of an object property, which lines up the values
+    /// of its group when [`crate::FormatOptions::align_object_properties`] is enabled.
+    fn write_alignment_padding(&self, f: &mut Formatter<'_, 'a>) {
+        let Self::ObjectProperty(property) = self else {
+            return;
+        };
+        let Some(alignment) = f.context().object_property_alignment() else {
+            return;
+        };
+        let group_id = alignment.group_id;
+        let padding = alignment.paddings.get(&property.span).copied().unwrap_or_default();
+        if padding > 0 {
+            let padding = f.context().allocator().alloc_str(&" ".repeat(padding));
+            write!(f, [if_group_breaks(&text(padding)).with_group_id(Some(group_id))]);
+        }
+    }
+
     fn write_right(&self, f: &mut Formatter<'_, 'a>, layout: AssignmentLikeLayout) {
         match self {
             Self::VariableDeclarator(declarator) => {
@@ -772,6 +790,8 @@ impl<'a> Format<'a> for AssignmentLike<'a, '_> {
             // so we can continue the chain of checks
             let layout = self.layout(is_left_short, left_may_break, f);
             let right = format_with(|f| self.write_right(f, layout));
+            // Only printed where the right side stays on the same line, to avoid trailing whitespace
+            let padding = format_with(|f| self.write_alignment_padding(f));
 
             let inner_content = format_with(|f| {
                 if matches!(&layout, AssignmentLikeLayout::BreakLeftHandSide) {
@@ -793,19 +813,26 @@ impl<'a> Format<'a> for AssignmentLike<'a, '_> {
                             [
                                 group(&indent(&soft_line_break_or_space()))
                                     .with_group_id(Some(group_id)),
+                                if_group_fits_on_line(&padding).with_group_id(Some(group_id)),
                                 line_suffix_boundary(),
                                 indent_if_group_breaks(&right, group_id)
                             ]
                         );
                     }
                     AssignmentLikeLayout::BreakAfterOperator => {
-                        write!(f, [group(&soft_line_indent_or_space(&right))]);
+                        write!(
+                            f,
+                            [group(&format_args!(
+                                if_group_fits_on_line(&padding),
+                                soft_line_indent_or_space(&right)
+                            ))]
+                        );
                     }
                     AssignmentLikeLayout::NeverBreakAfterOperator => {
-                        write!(f, [space(), right]);
+                        write!(f, [space(), padding, right]);
                     }
                     AssignmentLikeLayout::BreakLeftHandSide => {
-                        write!(f, [space(), group(&right)]);
+                        write!(f, [space(), padding, group(&right)]);
                     }
                     AssignmentLikeLayout::Chain => {
                         write!(f, [soft_line_break_or_space(), right]);
@@ -814,7 +841,7 @@ impl<'a> Format<'a> for AssignmentLike<'a, '_> {
                         write!(f, [soft_line_indent_or_space(&right)]);
                     }
                     AssignmentLikeLayout::ChainTailArrowFunction => {
-                        write!(f, [space(), right]);
+                        write!(f, [space(), padding, right]);
                     }
                     AssignmentLikeLayout::SuppressedInitializer => {
                         unreachable!();
@@ -188,8 +188,9 @@ impl<'a> FormatConditionalLike<'a, '_> {
                 }
             }
             _ => {
-                let jsx_chain =
-                    f.context().source_type().is_jsx() && self.is_jsx_conditional_chain();
+                let jsx_chain = f.context().source_type().is_jsx()
+                    && self.is_jsx_conditional_chain()
+                    && !(f.options().compact_jsx_conditionals && self.has_only_single_line_jsx());
                 ConditionalLayout::Root { jsx_chain }
             }
         }
@@ -220,6 +221,37 @@ impl<'a> FormatConditionalLike<'a, '_> {
         recurse(conditional)
     }
 
+    /// Checks if every JSX element of this conditional chain is self-closing or only contains text on one line,
+    /// e.g. `<A />` or `<b>text</b>`, see [`crate::FormatOptions::compact_jsx_conditionals`].
+    fn has_only_single_line_jsx(&self) -> bool {
+        fn is_single_line_children(children: &[JSXChild]) -> bool {
+            children
+                .iter()
+                .all(|child| matches!(child, JSXChild::Text(text) if !text.value.contains('\n')))
+        }
+
+        fn is_single_line(expr: &Expression) -> bool {
+            match expr {
+                Expression::JSXElement(element) => is_single_line_children(&element.children),
+                Expression::JSXFragment(fragment) => is_single_line_children(&fragment.children),
+                Expression::ConditionalExpression(conditional) => recurse(conditional),
+                _ => true,
+            }
+        }
+
+        fn recurse(expr: &ConditionalExpression<'_>) -> bool {
+            is_single_line(&expr.test)
+                && is_single_line(&expr.consequent)
+                && is_single_line(&expr.alternate)
+        }
+
+        let ConditionalLike::ConditionalExpression(conditional) = self.conditional else {
+            return false;
+        };
+
+        recurse(conditional)
+    }
+
     /// It is desired to add an extra indent if this conditional is a ConditionalExpression and is directly inside
     /// of a member chain:
     ///
@@ -453,8 +485,20 @@ impl<'a> FormatConditionalLike<'a, '_> {
                 write!(f, [FormatNodeWithoutTrailingComments(conditional.false_type())]);
             }
         });
+        // With `TernaryChainIndent::Flat`, a conditional in the alternate is aligned with this conditional
+        let is_flat_chain = f.options().ternary_chain_indent.is_flat()
+            && match self.conditional {
+                ConditionalLike::ConditionalExpression(conditional) => {
+                    matches!(conditional.alternate, Expression::ConditionalExpression(_))
+                }
+                ConditionalLike::TSConditionalType(conditional) => {
+                    matches!(conditional.false_type, TSType::TSConditionalType(_))
+                }
+            };
         let format_alternative = format_with(|f| {
-            if f.options().indent_style.is_space() {
+            if is_flat_chain {
+                write!(f, format_alternative);
+            } else if f.options().indent_style.is_space() {
                 write!(f, [align(2, &format_alternative)]);
             } else {
                 write!(f, [indent(&format_alternative)]);
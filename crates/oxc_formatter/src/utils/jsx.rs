@@ -190,11 +190,17 @@ impl Eq for JsxChild<'_, '_> {}
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct JsxWord<'a> {
     text: &'a str,
+    /// Whether the word is separated from the previous word of the same text by a line break.
+    line_break_before: bool,
 }
 
 impl<'a> JsxWord<'a> {
-    fn new(text: &'a str) -> Self {
-        Self { text }
+    fn new(text: &'a str, line_break_before: bool) -> Self {
+        Self { text, line_break_before }
+    }
+
+    pub(crate) fn has_line_break_before(&self) -> bool {
+        self.line_break_before
     }
 
     pub(crate) fn is_single_character(&self) -> bool {
@@ -311,6 +317,7 @@ pub fn jsx_split_children<'a, 'b>(
                     }
                 }
 
+                let mut line_break_before = false;
                 while let Some(chunk) = chunks.next() {
                     match chunk {
                         JsxTextChunk::Whitespace(whitespace) => {
@@ -321,11 +328,14 @@ pub fn jsx_split_children<'a, 'b>(
                                 } else {
                                     builder.entry(JsxChild::Whitespace);
                                 }
+                            } else {
+                                line_break_before = whitespace.contains('\n');
                             }
                         }
 
                         JsxTextChunk::Word(word) => {
-                            builder.entry(JsxChild::Word(JsxWord::new(word)));
+                            builder.entry(JsxChild::Word(JsxWord::new(word, line_break_before)));
+                            line_break_before = false;
                         }
                     }
                 }
@@ -115,7 +115,11 @@ impl<'a, 'b> MemberChain<'a, 'b> {
     }
 
     /// It tells if the groups should break on multiple lines
+    ///
+    /// A chain with more calls than [`FormatOptions::method_chain_break_threshold`](crate::FormatOptions::method_chain_break_threshold)
+    /// always breaks when any call has complex arguments.
     fn groups_should_break(&self, f: &Formatter<'_, 'a>) -> bool {
+        let threshold = usize::from(f.options().method_chain_break_threshold.value());
         let mut call_expressions = self
             .members()
             .filter_map(|member| match member {
@@ -138,7 +142,7 @@ impl<'a, 'b> MemberChain<'a, 'b> {
 
             has_complex_args = has_complex_args || !has_simple_arguments(call);
 
-            if calls_count > 2 && has_complex_args {
+            if calls_count > threshold && has_complex_args {
                 return true;
             }
         }
@@ -78,6 +78,24 @@ pub fn write_member_name<'a>(
     }
 }
 
+/// Returns the width of the property `key` as it is printed by [`write_member_name`].
+pub fn member_name_width<'a>(key: &PropertyKey<'a>, f: &Formatter<'_, 'a>) -> usize {
+    match key {
+        PropertyKey::StringLiteral(string) => FormatLiteralStringToken::new(
+            f.source_text().text_for(string.as_ref()),
+            false,
+            StringLiteralParentKind::Member,
+        )
+        .clean_text(f)
+        .width(),
+        // Identifiers are quoted when another key of the object needs quotes
+        PropertyKey::StaticIdentifier(_) if f.context().is_quote_needed() => {
+            f.source_text().span_width(key.span()) + 2
+        }
+        _ => f.source_text().span_width(key.span()),
+    }
+}
+
 /// Determine if the property key string literal should preserve its quotes
 pub fn should_preserve_quote(key: &PropertyKey<'_>, f: &Formatter<'_, '_>) -> bool {
     matches!(&key, PropertyKey::StringLiteral(string) if {
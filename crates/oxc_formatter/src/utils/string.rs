@@ -5,7 +5,7 @@ use oxc_syntax::identifier::{is_identifier_part, is_identifier_start};
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    QuoteProperties, QuoteStyle,
+    JsxAttributeQuotes, QuoteProperties, QuoteStyle,
     formatter::{Format, Formatter, prelude::*},
 };
 
@@ -52,8 +52,13 @@ impl<'a> FormatLiteralStringToken<'a> {
             QuoteProperties::Consistent => f.context().is_quote_needed(),
         };
 
-        let string_cleaner =
-            LiteralStringNormalizer::new(*self, chosen_quote_style, is_quote_needed);
+        let string_cleaner = LiteralStringNormalizer::new(
+            *self,
+            chosen_quote_style,
+            is_quote_needed,
+            options.jsx_attribute_quotes,
+            options.normalize_escape_sequences,
+        );
 
         let content = string_cleaner.normalize_text(source_type);
 
@@ -184,6 +189,10 @@ struct LiteralStringNormalizer<'a> {
     chosen_quote_style: QuoteStyle,
     /// State whether we need to print the quotes or not.
     is_quote_needed: bool,
+    /// How quotes of JSX attribute values are chosen.
+    jsx_attribute_quotes: JsxAttributeQuotes,
+    /// Whether the hex digits of escape sequences are lowercased.
+    normalize_escape_sequences: bool,
 }
 
 impl<'a> LiteralStringNormalizer<'a> {
@@ -191,8 +200,16 @@ impl<'a> LiteralStringNormalizer<'a> {
         token: FormatLiteralStringToken<'a>,
         chosen_quote_style: QuoteStyle,
         is_quote_needed: bool,
+        jsx_attribute_quotes: JsxAttributeQuotes,
+        normalize_escape_sequences: bool,
     ) -> Self {
-        Self { token, chosen_quote_style, is_quote_needed }
+        Self {
+            token,
+            chosen_quote_style,
+            is_quote_needed,
+            jsx_attribute_quotes,
+            normalize_escape_sequences,
+        }
     }
 
     fn normalize_text(&self, source_type: SourceType) -> Cow<'a, str> {
@@ -216,7 +233,8 @@ impl<'a> LiteralStringNormalizer<'a> {
         let current_quote =
             self.token.string.bytes().next().and_then(QuoteStyle::from_byte).unwrap_or_default();
 
-        let (normalized, chosen_quote) = normalize_jsx_string(raw_content, self.chosen_quote_style);
+        let (normalized, chosen_quote) =
+            normalize_jsx_string(raw_content, self.chosen_quote_style, self.jsx_attribute_quotes);
 
         let quote_char = chosen_quote.as_char();
 
@@ -323,7 +341,7 @@ impl<'a> LiteralStringNormalizer<'a> {
         let polished_raw_content = normalize_string(
             self.raw_content(),
             string_information.preferred_quote,
-            string_information.current_quote != string_information.preferred_quote,
+            self.normalize_escape_sequences,
         );
 
         match polished_raw_content {
@@ -365,8 +383,9 @@ impl<'a> Format<'a> for FormatLiteralStringToken<'a> {
 /// This function is responsible of:
 ///
 /// - escaping `preferred_quote`
-/// - unescape alternate quotes of `preferred_quote` if `quotes_will_change`
+/// - unescape alternate quotes of `preferred_quote`, which never need to be escaped
 /// - normalize the new lines by replacing `\r\n` and `\r` with `\n`.
+/// - lowercase the hex digits of `\x` and `\u` escape sequences if `lowercase_escapes`
 ///
 /// The function allocates a new string only if at least one change is performed.
 ///
@@ -375,14 +394,14 @@ impl<'a> Format<'a> for FormatLiteralStringToken<'a> {
 /// ```
 /// use biome_formatter::token::string::{normalize_string, Quote};
 /// assert_eq!(
-///     normalize_string(" \"He\\llo\\tworld\" \\' \\' \r\n ", Quote::Double, true),
+///     normalize_string(" \"He\\llo\\tworld\" \\' \\' \r\n ", Quote::Double, false),
 ///     " \\\"He\\llo\\tworld\\\" ' ' \n ",
 /// );
 /// ```
 pub fn normalize_string(
     raw_content: &str,
     preferred_quote: QuoteStyle,
-    quotes_will_change: bool,
+    lowercase_escapes: bool,
 ) -> Cow<'_, str> {
     let alternate_quote = preferred_quote.other().as_byte();
     let preferred_quote = preferred_quote.as_byte();
@@ -406,13 +425,26 @@ pub fn normalize_string(
                             reduced_string.push('\n');
                         }
                         copy_start = escaped_index + 1;
-                    } else if quotes_will_change && escaped == alternate_quote {
+                    } else if escaped == alternate_quote {
                         bytes.next(); // consume the escaped character
-                        // Unescape alternate quotes if quotes are changing
+                        // Unescape alternate quotes, e.g. `'\"'` -> `'"'`
                         reduced_string.push_str(&raw_content[copy_start..byte_index]);
                         copy_start = escaped_index;
                     } else {
                         bytes.next(); // consume the escaped character
+                        if lowercase_escapes
+                            && matches!(escaped, b'x' | b'u')
+                            && let Some((start, end)) =
+                                escape_hex_digits(raw_content, escaped_index + 1, escaped)
+                            && raw_content[start..end].bytes().any(|b| b.is_ascii_uppercase())
+                        {
+                            // The hex digits are skipped by the loop, as they are neither quotes nor `\`
+                            reduced_string.push_str(&raw_content[copy_start..start]);
+                            reduced_string.extend(
+                                raw_content[start..end].chars().map(|c| c.to_ascii_lowercase()),
+                            );
+                            copy_start = end;
+                        }
                     }
                 }
             }
@@ -449,6 +481,18 @@ pub fn normalize_string(
     }
 }
 
+/// Get the range of the hex digits of a `\x` or `\u` escape sequence, `start` is the offset after `x` or `u`.
+fn escape_hex_digits(raw_content: &str, start: usize, kind: u8) -> Option<(usize, usize)> {
+    let rest = &raw_content[start..];
+    let (start, max_len) = match kind {
+        b'x' => (start, 2),
+        _ if rest.starts_with('{') => (start + 1, usize::MAX),
+        _ => (start, 4),
+    };
+    let len = raw_content[start..].bytes().take(max_len).take_while(u8::is_ascii_hexdigit).count();
+    (len > 0).then_some((start, start + len))
+}
+
 /// Counts actual single and double quotes in JSX attribute content,
 /// accounting for HTML entities `&apos;` and `&quot;`.
 fn count_jsx_quotes(raw_content: &str) -> (u32, u32) {
@@ -487,19 +531,23 @@ fn count_jsx_quotes(raw_content: &str) -> (u32, u32) {
 ///
 /// Algorithm (matching Prettier):
 /// 1. Unescape `&apos;` → `'` and `&quot;` → `"`
-/// 2. Count quotes to pick the style that minimizes escaping
+/// 2. Count quotes to pick the style that minimizes escaping,
+///    unless `attribute_quotes` is [`JsxAttributeQuotes::Consistent`]
 /// 3. Escape only the chosen quote type using HTML entities
 ///
 /// Returns the normalized content (without quotes) and the chosen quote style.
 fn normalize_jsx_string(
     raw_content: &str,
     preferred_quote: QuoteStyle,
+    attribute_quotes: JsxAttributeQuotes,
 ) -> (Cow<'_, str>, QuoteStyle) {
     // Count quotes (accounting for HTML entities)
     let (single_count, double_count) = count_jsx_quotes(raw_content);
 
     // Choose quote that minimizes escaping, preferring the configured quote when counts are equal
-    let chosen_quote = if preferred_quote == QuoteStyle::Double {
+    let chosen_quote = if attribute_quotes.is_consistent() {
+        preferred_quote
+    } else if preferred_quote == QuoteStyle::Double {
         if double_count > single_count { QuoteStyle::Single } else { QuoteStyle::Double }
     } else if single_count > double_count {
         QuoteStyle::Double
@@ -589,41 +637,59 @@ mod tests {
     #[test]
     fn normalize_newline() {
         // \n unchanged
-        assert_eq!(normalize_string("a\nb", QuoteStyle::Double, true), "a\nb");
+        assert_eq!(normalize_string("a\nb", QuoteStyle::Double, false), "a\nb");
         // \r\n -> \n
-        assert_eq!(normalize_string("a\r\nb", QuoteStyle::Double, true), "a\nb");
+        assert_eq!(normalize_string("a\r\nb", QuoteStyle::Double, false), "a\nb");
         // \r -> \n (single CR)
-        assert_eq!(normalize_string("a\rb", QuoteStyle::Double, true), "a\nb");
-        assert_eq!(normalize_string("a\r", QuoteStyle::Double, true), "a\n");
-        assert_eq!(normalize_string("\rb", QuoteStyle::Double, true), "\nb");
+        assert_eq!(normalize_string("a\rb", QuoteStyle::Double, false), "a\nb");
+        assert_eq!(normalize_string("a\r", QuoteStyle::Double, false), "a\n");
+        assert_eq!(normalize_string("\rb", QuoteStyle::Double, false), "\nb");
         // escaped \r\n -> escaped \n
-        assert_eq!(normalize_string("a\\\r\nb", QuoteStyle::Double, true), "a\\\nb");
+        assert_eq!(normalize_string("a\\\r\nb", QuoteStyle::Double, false), "a\\\nb");
         // escaped \r -> escaped \n (single CR)
-        assert_eq!(normalize_string("a\\\rb", QuoteStyle::Double, true), "a\\\nb");
+        assert_eq!(normalize_string("a\\\rb", QuoteStyle::Double, false), "a\\\nb");
     }
 
     #[test]
     fn normalize_escapes() {
-        assert_eq!(normalize_string("\\", QuoteStyle::Double, true), "\\");
-        assert_eq!(normalize_string("\\t", QuoteStyle::Double, true), "\\t");
-        assert_eq!(normalize_string("\\\u{2028}", QuoteStyle::Double, true), "\\\u{2028}");
-        assert_eq!(normalize_string("\\\u{2029}", QuoteStyle::Double, true), "\\\u{2029}");
+        assert_eq!(normalize_string("\\", QuoteStyle::Double, false), "\\");
+        assert_eq!(normalize_string("\\t", QuoteStyle::Double, false), "\\t");
+        assert_eq!(normalize_string("\\\u{2028}", QuoteStyle::Double, false), "\\\u{2028}");
+        assert_eq!(normalize_string("\\\u{2029}", QuoteStyle::Double, false), "\\\u{2029}");
 
-        assert_eq!(normalize_string(r"a\a", QuoteStyle::Double, true), r"a\a");
-        assert_eq!(normalize_string(r"👍\👍", QuoteStyle::Single, true), r"👍\👍");
-        assert_eq!(normalize_string("\\\u{2027}", QuoteStyle::Double, true), "\\\u{2027}");
-        assert_eq!(normalize_string("\\\u{2030}", QuoteStyle::Double, true), "\\\u{2030}");
+        assert_eq!(normalize_string(r"a\a", QuoteStyle::Double, false), r"a\a");
+        assert_eq!(normalize_string(r"👍\👍", QuoteStyle::Single, false), r"👍\👍");
+        assert_eq!(normalize_string("\\\u{2027}", QuoteStyle::Double, false), "\\\u{2027}");
+        assert_eq!(normalize_string("\\\u{2030}", QuoteStyle::Double, false), "\\\u{2030}");
     }
 
     #[test]
     fn normalize_quotes() {
-        assert_eq!(normalize_string("\"", QuoteStyle::Double, true), "\\\"");
-        assert_eq!(normalize_string(r"\'", QuoteStyle::Double, true), r"'");
-
-        assert_eq!(normalize_string(r"\'", QuoteStyle::Double, false), r"\'");
+        assert_eq!(normalize_string("\"", QuoteStyle::Double, false), "\\\"");
+        assert_eq!(normalize_string(r"\'", QuoteStyle::Double, false), r"'");
         assert_eq!(normalize_string("\"", QuoteStyle::Single, false), "\"");
         assert_eq!(normalize_string("\\'", QuoteStyle::Single, false), "\\'");
-        assert_eq!(normalize_string("\\\"", QuoteStyle::Single, false), "\\\"");
+        // Alternate quotes never need to be escaped
+        assert_eq!(normalize_string("\\\"", QuoteStyle::Single, false), "\"");
+        assert_eq!(normalize_string(r#"it\'s \"a\""#, QuoteStyle::Single, false), r#"it\'s "a""#);
+        // An escaped backslash doesn't escape the quote
+        assert_eq!(normalize_string(r"\\'", QuoteStyle::Double, false), r"\\'");
+    }
+
+    #[test]
+    fn normalize_escape_case() {
+        assert_eq!(normalize_string(r"\xFF", QuoteStyle::Double, false), r"\xFF");
+        assert_eq!(normalize_string(r"\xFF", QuoteStyle::Double, true), r"\xff");
+        assert_eq!(
+            normalize_string(r"\u00E9\u{1F600}", QuoteStyle::Double, true),
+            r"\u00e9\u{1f600}"
+        );
+        // Only the hex digits of the escape sequence are lowercased
+        assert_eq!(
+            normalize_string(r"\xABCD \u00E9EF", QuoteStyle::Double, true),
+            r"\xabCD \u00e9EF"
+        );
+        assert_eq!(normalize_string(r"\\xFF \X \tA", QuoteStyle::Double, true), r"\\xFF \X \tA");
     }
 
     #[test]
@@ -650,7 +716,8 @@ mod tests {
     #[test]
     fn jsx_normalize_no_changes() {
         // No quotes, no entities - should return borrowed
-        let (result, quote) = normalize_jsx_string("foo", QuoteStyle::Double);
+        let (result, quote) =
+            normalize_jsx_string("foo", QuoteStyle::Double, JsxAttributeQuotes::AsNeeded);
         assert!(matches!(result, Cow::Borrowed(_)));
         assert_eq!(result, "foo");
         assert_eq!(quote, QuoteStyle::Double);
@@ -659,12 +726,14 @@ mod tests {
     #[test]
     fn jsx_normalize_unescape_entities() {
         // &apos; should be unescaped when using double quotes
-        let (result, quote) = normalize_jsx_string("&apos;", QuoteStyle::Double);
+        let (result, quote) =
+            normalize_jsx_string("&apos;", QuoteStyle::Double, JsxAttributeQuotes::AsNeeded);
         assert_eq!(result, "'");
         assert_eq!(quote, QuoteStyle::Double);
 
         // &quot; should be unescaped when using single quotes
-        let (result, quote) = normalize_jsx_string("&quot;", QuoteStyle::Single);
+        let (result, quote) =
+            normalize_jsx_string("&quot;", QuoteStyle::Single, JsxAttributeQuotes::AsNeeded);
         assert_eq!(result, "\"");
         assert_eq!(quote, QuoteStyle::Single);
     }
@@ -672,23 +741,27 @@ mod tests {
     #[test]
     fn jsx_normalize_escape_raw_quotes() {
         // Raw ' with single quote preference -> switches to double quotes to avoid escaping
-        let (result, quote) = normalize_jsx_string("'", QuoteStyle::Single);
+        let (result, quote) =
+            normalize_jsx_string("'", QuoteStyle::Single, JsxAttributeQuotes::AsNeeded);
         assert_eq!(result, "'");
         assert_eq!(quote, QuoteStyle::Double);
 
         // Raw " with double quote preference -> switches to single quotes to avoid escaping
-        let (result, quote) = normalize_jsx_string("\"", QuoteStyle::Double);
+        let (result, quote) =
+            normalize_jsx_string("\"", QuoteStyle::Double, JsxAttributeQuotes::AsNeeded);
         assert_eq!(result, "\"");
         assert_eq!(quote, QuoteStyle::Single);
 
         // When both quotes are present and counts are equal, use preferred and escape
         // ' " with single preferred -> use single, escape the '
-        let (result, quote) = normalize_jsx_string("' \"", QuoteStyle::Single);
+        let (result, quote) =
+            normalize_jsx_string("' \"", QuoteStyle::Single, JsxAttributeQuotes::AsNeeded);
         assert_eq!(result, "&apos; \"");
         assert_eq!(quote, QuoteStyle::Single);
 
         // ' " with double preferred -> use double, escape the "
-        let (result, quote) = normalize_jsx_string("' \"", QuoteStyle::Double);
+        let (result, quote) =
+            normalize_jsx_string("' \"", QuoteStyle::Double, JsxAttributeQuotes::AsNeeded);
         assert_eq!(result, "' &quot;");
         assert_eq!(quote, QuoteStyle::Double);
     }
@@ -697,13 +770,15 @@ mod tests {
     fn jsx_normalize_prefer_less_escaping() {
         // When preferred is double but double quotes are more common, switch to single
         // Input: ' " " -> 1 single, 2 double
-        let (result, quote) = normalize_jsx_string("' \" \"", QuoteStyle::Double);
+        let (result, quote) =
+            normalize_jsx_string("' \" \"", QuoteStyle::Double, JsxAttributeQuotes::AsNeeded);
         assert_eq!(quote, QuoteStyle::Single);
         assert_eq!(result, "&apos; \" \"");
 
         // When preferred is single but single quotes are more common, switch to double
         // Input: ' ' " -> 2 single, 1 double
-        let (result, quote) = normalize_jsx_string("' ' \"", QuoteStyle::Single);
+        let (result, quote) =
+            normalize_jsx_string("' ' \"", QuoteStyle::Single, JsxAttributeQuotes::AsNeeded);
         assert_eq!(quote, QuoteStyle::Double);
         assert_eq!(result, "' ' &quot;");
     }
@@ -711,12 +786,14 @@ mod tests {
     #[test]
     fn jsx_normalize_with_entities_and_raw() {
         // &apos; " -> 1 single, 1 double - prefer double quotes
-        let (result, quote) = normalize_jsx_string("&apos; \"", QuoteStyle::Double);
+        let (result, quote) =
+            normalize_jsx_string("&apos; \"", QuoteStyle::Double, JsxAttributeQuotes::AsNeeded);
         assert_eq!(quote, QuoteStyle::Double);
         assert_eq!(result, "' &quot;");
 
         // ' &quot; -> 1 single, 1 double - prefer single quotes
-        let (result, quote) = normalize_jsx_string("' &quot;", QuoteStyle::Single);
+        let (result, quote) =
+            normalize_jsx_string("' &quot;", QuoteStyle::Single, JsxAttributeQuotes::AsNeeded);
         assert_eq!(quote, QuoteStyle::Single);
         assert_eq!(result, "&apos; \"");
     }
@@ -724,13 +801,35 @@ mod tests {
     #[test]
     fn jsx_normalize_other_entities_preserved() {
         // &amp; and other entities should be preserved
-        let (result, quote) = normalize_jsx_string("&amp;", QuoteStyle::Double);
+        let (result, quote) =
+            normalize_jsx_string("&amp;", QuoteStyle::Double, JsxAttributeQuotes::AsNeeded);
         assert_eq!(result, "&amp;");
         assert_eq!(quote, QuoteStyle::Double);
 
         // Mixed entities
-        let (result, quote) = normalize_jsx_string("&apos;&amp;&quot;", QuoteStyle::Double);
+        let (result, quote) = normalize_jsx_string(
+            "&apos;&amp;&quot;",
+            QuoteStyle::Double,
+            JsxAttributeQuotes::AsNeeded,
+        );
         assert_eq!(quote, QuoteStyle::Double);
         assert_eq!(result, "'&amp;&quot;");
     }
+
+    #[test]
+    fn jsx_normalize_consistent_quotes() {
+        // The preferred quote is always used, even if it needs more escaping
+        let (result, quote) =
+            normalize_jsx_string("it's", QuoteStyle::Single, JsxAttributeQuotes::Consistent);
+        assert_eq!(quote, QuoteStyle::Single);
+        assert_eq!(result, "it&apos;s");
+
+        let (result, quote) = normalize_jsx_string(
+            "say &quot;hi&quot;",
+            QuoteStyle::Single,
+            JsxAttributeQuotes::Consistent,
+        );
+        assert_eq!(quote, QuoteStyle::Single);
+        assert_eq!(result, "say \"hi\"");
+    }
 }
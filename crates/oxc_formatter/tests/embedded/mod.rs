@@ -0,0 +1,135 @@
+use std::sync::{Arc, Mutex};
+
+use oxc_allocator::Allocator;
+use oxc_formatter::{ExternalCallbacks, FormatOptions, Formatter, get_parse_options};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::FxHashMap;
+
+type EmbeddedCalls = Arc<Mutex<Vec<(String, String)>>>;
+
+/// Formats the source text with an embedded formatter that collapses all whitespace of the code,
+/// returns the formatted code and the `(language, code)` pairs passed to the embedded formatter.
+fn format_with_embedded_formatter(
+    source_text: &str,
+    options: FormatOptions,
+) -> (String, Vec<(String, String)>) {
+    let calls = EmbeddedCalls::default();
+    let callbacks = {
+        let calls = Arc::clone(&calls);
+        ExternalCallbacks::new().with_embedded_formatter(Some(Arc::new(
+            move |language: &str, code: &str| {
+                calls.lock().unwrap().push((language.to_string(), code.to_string()));
+                Ok(code.split_whitespace().collect::<Vec<_>>().join(" "))
+            },
+        )))
+    };
+    let code = format(source_text, options, Some(callbacks));
+    let calls = calls.lock().unwrap().clone();
+    (code, calls)
+}
+
+fn format(
+    source_text: &str,
+    options: FormatOptions,
+    callbacks: Option<ExternalCallbacks>,
+) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs())
+        .with_options(get_parse_options())
+        .parse();
+    assert!(ret.errors.is_empty());
+
+    Formatter::new(&allocator, options)
+        .format_with_external_callbacks(&ret.program, callbacks)
+        .print()
+        .unwrap()
+        .into_code()
+}
+
+fn sql_options() -> FormatOptions {
+    FormatOptions { embedded_sql_tags: vec!["sql".to_string()], ..FormatOptions::default() }
+}
+
+#[test]
+fn sql_template_with_interpolations() {
+    let source_text =
+        "const q = sql`select *   from users\n  where id = ${id} and name = ${ user.name }`;\n";
+    let (code, calls) = format_with_embedded_formatter(source_text, sql_options());
+
+    assert_eq!(
+        calls,
+        vec![(
+            "tagged-sql".to_string(),
+            "select *   from users\n  where id = __oxfmt_placeholder_0__ and name = __oxfmt_placeholder_1__"
+                .to_string()
+        )]
+    );
+    assert_eq!(
+        code,
+        "const q = sql`\n  select * from users where id = ${id} and name = ${ user.name }\n`;\n"
+    );
+}
+
+#[test]
+fn sql_template_with_comment_in_interpolation() {
+    let source_text = "const q = sql`select * from users where id = ${/* the id */ id}`;\n";
+    let (code, calls) = format_with_embedded_formatter(source_text, sql_options());
+
+    assert!(calls.is_empty());
+    assert_eq!(code, source_text);
+    assert_eq!(format_with_embedded_formatter(&code, sql_options()).0, code);
+}
+
+#[test]
+fn sql_template_without_embedded_formatter() {
+    let source_text = "const q = sql`select *   from users where id = ${id}`;\n";
+    assert_eq!(format(source_text, sql_options(), None), source_text);
+}
+
+#[test]
+fn sql_template_without_sql_tags() {
+    let source_text = "const q = sql`select *   from users where id = ${id}`;\n";
+    let (code, calls) = format_with_embedded_formatter(source_text, FormatOptions::default());
+
+    assert!(calls.is_empty());
+    assert_eq!(code, source_text);
+}
+
+#[test]
+fn custom_sql_tags() {
+    let source_text = "const a = query`select  1`;\nconst b = sql`select  2`;\n";
+    let options =
+        FormatOptions { embedded_sql_tags: vec!["query".to_string()], ..FormatOptions::default() };
+    let (code, calls) = format_with_embedded_formatter(source_text, options);
+
+    assert_eq!(calls, vec![("tagged-sql".to_string(), "select  1".to_string())]);
+    assert_eq!(code, "const a = query`\n  select 1\n`;\nconst b = sql`select  2`;\n");
+}
+
+#[test]
+fn custom_embedded_language_mapping() {
+    let source_text = "const a = css`a { color: red }`;\nconst b = gql`query { a }`;\n";
+    let calls = EmbeddedCalls::default();
+    let callbacks = {
+        let calls = Arc::clone(&calls);
+        ExternalCallbacks::new()
+            .with_embedded_formatter(Some(Arc::new(move |language: &str, code: &str| {
+                calls.lock().unwrap().push((language.to_string(), code.to_string()));
+                Ok(code.to_string())
+            })))
+            .with_embedded_language_mapping(FxHashMap::from_iter([(
+                "tagged-css".to_string(),
+                "scss".to_string(),
+            )]))
+    };
+    format(source_text, FormatOptions::default(), Some(callbacks));
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec![
+            ("scss".to_string(), "a { color: red }".to_string()),
+            ("tagged-graphql".to_string(), "query { a }".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,25 @@
+const config = {
+  a: 1,
+  bbb: "two",
+  "quoted-key": true,
+  cc: [1, 2, 3],
+};
+
+const inline = { a: 1, bbb: 2 };
+
+const mixed = {
+  shorthand,
+  key: value,
+  longerKey: value,
+  ...spread,
+  method() {},
+  [computed]: 1,
+};
+
+const nested = {
+  outer: 1,
+  nestedObject: {
+    x: 1,
+    yyyy: 2,
+  },
+};
@@ -0,0 +1,15 @@
+const groups = {
+  a: 1,
+  bb: 2,
+
+  cccccc: 3,
+  // comment
+  d: 4,
+
+  eeeeeeeeee: 5,
+};
+
+const longValue = {
+  a: "a very long string value which does not fit on the line after the padding",
+  bbbbbbbbbbbbbbbbbbbbbbbbbbbbbb: "another very long string value which breaks the line",
+};
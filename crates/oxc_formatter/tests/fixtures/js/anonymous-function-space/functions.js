@@ -0,0 +1,15 @@
+function named(){}
+function* namedGenerator(){}
+async function namedAsync(){}
+async function* namedAsyncGenerator(){}
+
+const anonymous = function(){};
+const anonymousGenerator = function*(){};
+const anonymousAsync = async function (){};
+const anonymousAsyncGenerator = async function * (){};
+const namedExpression = function named(){};
+
+export default function(a, b){}
+
+setTimeout(function(){ run(); });
+items.map(function*(item){ yield item; });
@@ -0,0 +1,16 @@
+const object = () => ({});
+const objectWithProperties = () => ({ a: 1, b: 2 });
+const nestedParens = () => (({}));
+const commented = () => /* comment */ ({ a: 1 });
+const member = () => ({ a: 1 }).a;
+const assignment = () => ({} = x);
+
+const sequence = () => (a, b);
+const sequenceWithObject = () => ({}, b);
+const nestedSequence = () => ((a, b));
+const returnedSequence = () => {
+  return a, b;
+};
+
+const longObject = () => ({ firstProperty: firstValue, secondProperty: secondValue, third: 3 });
+const longSequence = () => (firstSideEffect(argument), secondSideEffect(argument), resultValue);
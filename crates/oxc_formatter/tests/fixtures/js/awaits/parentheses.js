@@ -0,0 +1,15 @@
+async function f() {
+  (await foo()).bar;
+  (await foo())();
+  (await foo())[0];
+  (await foo())?.bar;
+  new (await getClass())();
+  (await a) ** 2;
+  -(await a);
+  (await a) + 1;
+  await (a || b);
+  await (a, b);
+  `${await a}`;
+  const x = (await a) ? b : c;
+  const y = await a;
+}
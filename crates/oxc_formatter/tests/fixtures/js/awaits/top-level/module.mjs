@@ -0,0 +1,9 @@
+await import("./setup.js");
+const x = await f();
+const { default: config } = await import(`./config/${process.env.NODE_ENV}.js`);
+(await getModule()).init();
+[a, b] = await Promise.all([loadA(), loadB()]);
+await (async () => {})();
+for await (const chunk of stream) process.stdout.write(chunk);
+export default await load();
+const result = await someVeryLongFunctionName(firstArgument, secondArgument, thirdArgument);
@@ -0,0 +1,23 @@
+class Adjacent {
+  constructor() {}
+  foo() {}
+  // comment of bar
+  bar() {}
+  static baz = 1;
+}
+
+class WithBlankLines {
+
+  constructor() {}
+
+  foo() {}
+
+
+  bar() {}
+}
+
+class Single {
+  foo() {}
+}
+
+class Empty {}
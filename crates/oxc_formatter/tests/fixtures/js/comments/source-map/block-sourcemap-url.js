@@ -0,0 +1,2 @@
+foo()
+/*# sourceMappingURL=index.js.map */
@@ -0,0 +1,6 @@
+import   a from "a";
+export const value = someFunction(argumentNumberOne, argumentNumberTwo, argumentThree)
+
+
+//# sourceURL=webpack://app/src/index.js
+//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJzb3VyY2VzIjpbImluZGV4LmpzIl0sIm1hcHBpbmdzIjoiQUFBQSxDQUFDIn0=
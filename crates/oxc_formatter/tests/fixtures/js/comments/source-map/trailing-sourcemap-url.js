@@ -0,0 +1,3 @@
+function f() {
+  return 1
+} //# sourceMappingURL=index.js.map
@@ -0,0 +1,47 @@
+if (a) { one(); } else if (b) { two(); } else if (c) { three(); } else { four(); }
+
+if (a) one(); else if (b) two(); else if (c) three(); else four();
+
+if (a) {
+  one();
+} else {
+  if (b) {
+    two();
+  } else {
+    if (c) {
+      three();
+    } else {
+      four();
+    }
+  }
+}
+
+if (a) {
+  one();
+} else {
+  // keep the block
+  if (b) {
+    two();
+  }
+}
+
+if (a) {
+  one();
+} else {
+  if (b) two();
+  three();
+}
+
+if (a) {
+  one();
+} else {
+  if (b) {
+    two();
+  } else {
+    three();
+  }
+}
+
+if (x) if (a) f(); else { if (b) g(); } else h();
+
+if (x) if (a) f(); else { if (b) g(); else for (;;) if (c) k(); } else h();
@@ -0,0 +1,10 @@
+const noop = () => {};
+
+const asyncNoop = async () => {
+};
+
+promise.then(() => {});
+
+const withComment = () => {
+  /* comment */
+};
@@ -0,0 +1,14 @@
+class Foo {}
+
+class Bar extends Foo {
+}
+
+const Baz = class {};
+
+class WithComment {
+  // comment
+}
+
+class WithEmptyMethod {
+  method() {}
+}
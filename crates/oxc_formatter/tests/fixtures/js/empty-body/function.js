@@ -0,0 +1,14 @@
+function foo() {}
+
+async function* bar() {
+}
+
+const baz = function () {};
+
+function withComment() {
+  // comment
+}
+
+function withDirective() {
+  "use strict";
+}
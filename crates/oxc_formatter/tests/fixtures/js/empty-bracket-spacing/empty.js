@@ -0,0 +1,13 @@
+const object = {};
+const array = [];
+const spaced = { };
+const nested = { a: {}, b: [] };
+const list = [{}, []];
+call({}, []);
+
+const withComment = {
+  // comment
+};
+const arrayWithComment = [
+  // comment
+];
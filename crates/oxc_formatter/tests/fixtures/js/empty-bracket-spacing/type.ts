@@ -0,0 +1,2 @@
+type Empty = {};
+function f(options: {} = {}): [] {}
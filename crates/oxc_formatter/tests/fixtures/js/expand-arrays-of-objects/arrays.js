@@ -0,0 +1,9 @@
+const points = [{ x: 1, y: 2 }, { x: 3, y: 4 }];
+
+const single = [{ x: 1 }, { x: 2 }];
+
+const matrix = [[1, 0], [0, 1]];
+
+const numbers = [1, 2, 3, 4];
+
+const mixed = [{ x: 1, y: 2 }, 3];
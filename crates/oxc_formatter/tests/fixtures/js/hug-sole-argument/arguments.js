@@ -0,0 +1,16 @@
+foo({ a: 1, b: 2 });
+foo([1, 2, 3]);
+
+foo({ aaaaaaaaaaaaaaaa: 1, bbbbbbbbbbbbbbbbbbbbbb: 2, cccccccccccccccccccccc: 3, ddddddd: 4 });
+foo([1111111111111111, 2222222222222222222, 3333333333333333333, 4444444444444444444, 5]);
+
+foo({
+  a: 1,
+});
+
+someObject.someMethod({ aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa: 1, bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb: 2 });
+
+new Foo({ aaaaaaaaaaaaaaaa: 1, bbbbbbbbbbbbbbbbbbbbbb: 2, cccccccccccccccccccccc: 3, ddddddd: 4 });
+
+foo(function () { return 1 }, );
+foo(() => { return 1 });
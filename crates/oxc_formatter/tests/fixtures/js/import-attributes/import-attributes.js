@@ -0,0 +1,13 @@
+import json from "./x.json" with { type: "json" };
+import json2 from "./x.json"   with{type:"json"};
+import legacy from "./x.json" assert { type: "json" };
+import legacy2 from "./x.json" assert {type : "json"};
+import {a, b} from "./x.json" with { type: "json", "other-key": "value" };
+import "./side-effect.json" with { type: "json" };
+export { default } from "./x.json" with { type: "json" };
+export * from "./x.json" assert { type: "json" };
+import empty from "./x.json" with {};
+const dynamic = await import("./x.json", { with: { type: "json" } });
+import veryLongName from "./a/very/long/path/to/some/module.json" with { type: "json", integrity: "sha384-abc" };
+import multiline from "./x.json" with {
+  type: "json" };
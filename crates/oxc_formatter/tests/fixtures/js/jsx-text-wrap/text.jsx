@@ -0,0 +1,15 @@
+const long = (
+  <p>
+    Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.
+  </p>
+);
+
+const manual = (
+  <p>
+    First sentence on its own line.
+    Second sentence,
+    with a <b>bold</b> word.
+  </p>
+);
+
+const short = <span>Hello world</span>;
@@ -0,0 +1,4 @@
+<div title="it's" />;
+<div title='say "hi"' />;
+<div title='it&apos;s "mixed"' />;
+<div title="plain" />;
@@ -0,0 +1,36 @@
+const block = (
+  <div>
+    {/* comment */}
+    <span>child</span>
+  </div>
+);
+
+const line = (
+  <div>
+    {
+      // comment
+    }
+    <span>child</span>
+  </div>
+);
+
+const inside = (
+  <div>
+    {/* before */ <span>child</span>}
+    {
+      // leading
+      value
+    }
+  </div>
+);
+
+const misindented = <div>
+{/* comment */}
+        <span>child</span></div>;
+
+const inline = <div>{/* comment */}<Foo /></div>;
+
+const beforeText = <div>
+      {/* comment */}
+  text
+  </div>;
@@ -0,0 +1,22 @@
+const single = <div>{/* comment */}</div>;
+
+const multiline = (
+  <div>
+    {/*
+      multi
+      line
+    */}
+  </div>
+);
+
+const lineComment = (
+  <div>
+    {
+      // line comment
+    }
+  </div>
+);
+
+const siblings = <div>{/* a */}{/* b */}</div>;
+
+const padded = <div>{   /* comment */   }</div>;
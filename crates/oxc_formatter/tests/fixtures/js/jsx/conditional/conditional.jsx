@@ -0,0 +1,21 @@
+const short = cond ? <A /> : <B />;
+
+const long = isLoading ? <Spinner size="large" color="blue" /> : <Content items={items} onSelect={handleSelect} />;
+
+const text = isLoggedIn ? <b>Welcome back to the dashboard, friend</b> : <i>Please sign in to continue here</i>;
+
+const arrow = () => cond ? <A /> : <B />;
+
+function Greeting() {
+  return user ? <Welcome name={user.name} /> : <LoginButton onClick={handleLogin} label="Sign in" />;
+}
+
+const nested = isLoading ? <Spinner size="large" /> : hasError ? <ErrorMessage error={error} /> : <Content items={items} />;
+
+const multiline = cond ? (
+  <div>
+    <A />
+  </div>
+) : <SomeOtherVeryLongComponentName withSomeAttribute="and a value that is long" />;
+
+<div>{cond ? <A /> : <B />}</div>;
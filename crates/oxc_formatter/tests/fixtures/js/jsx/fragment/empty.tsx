@@ -0,0 +1,20 @@
+const a = <>   </>;
+const b = <React.Fragment>   </React.Fragment>;
+const c = () => <></>;
+const d = () => (
+  <>
+  </>
+);
+const e = cond && <></>;
+const f = cond ? <></> : null;
+const g = [<></>, <React.Fragment key="a"></React.Fragment>];
+render(<></>);
+render(<>
+</>, container);
+const h = <>{/* comment */}</>;
+const i = <>
+  {/* comment */}
+</>;
+const j = <>
+  // not a comment
+</>;
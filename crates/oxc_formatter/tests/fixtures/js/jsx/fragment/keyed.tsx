@@ -0,0 +1,7 @@
+const a = items.map((item) => <React.Fragment key={item.id}><dt>{item.term}</dt><dd>{item.description}</dd></React.Fragment>);
+const b = items.map((item) => <Fragment key={item.id}>{item.label}</Fragment>);
+const c = <React.Fragment key={id}>
+</React.Fragment>;
+const d = <React.Fragment key={veryLongIdentifierNameForTheKey.someOtherProperty.andMore}>text</React.Fragment>;
+// Without attributes the fragment is kept as written as well
+const e = <React.Fragment><span /></React.Fragment>;
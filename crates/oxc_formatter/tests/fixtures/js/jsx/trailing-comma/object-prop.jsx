@@ -0,0 +1,5 @@
+<Component style={{ color: "red", backgroundColor: "blue", fontSize: 12, fontWeight: "bold" }} />;
+
+<Component render={({ firstArgument, secondArgument, thirdArgument, fourthArgument }) => null} />;
+
+<Component options={[{ key: "first", value: 1 }, { key: "second", value: 2 }, { key: "third" }]} />;
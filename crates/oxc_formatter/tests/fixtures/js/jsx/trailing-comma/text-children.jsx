@@ -0,0 +1,10 @@
+<div>
+  first, second, third, fourth, fifth, sixth, seventh, eighth, ninth, tenth, eleventh,
+  {value}, more text after the expression container with a trailing comma,
+</div>;
+
+<p>a, b, c,</p>;
+
+<Component style={{ color: "red", backgroundColor: "blue", fontSize: 12, fontWeight: "bold" }}>
+  text, with, commas,
+</Component>;
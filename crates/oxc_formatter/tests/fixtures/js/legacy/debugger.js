@@ -0,0 +1,3 @@
+debugger
+if (a) debugger;
+function f() { debugger; /* pause */ }
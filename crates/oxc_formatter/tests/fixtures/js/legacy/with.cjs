@@ -0,0 +1,11 @@
+with (obj) {
+  a = b;
+}
+
+with(obj) foo();
+
+with (Math) x = cos(PI) + sin(PI / 2);
+
+with (obj)
+  // comment
+  bar();
@@ -0,0 +1,7 @@
+const twoCalls = items.filter((item) => item.visible).map((item) => item.id);
+
+const fourCalls = items.filter((item) => item.visible).map((item) => item.id).sort().slice(0, 10);
+
+const fourSimpleCalls = value.trim().toLowerCase().split(",").join(";");
+
+z.object({ name: z.string() }).strict();
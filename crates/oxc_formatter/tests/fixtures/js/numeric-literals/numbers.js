@@ -0,0 +1,5 @@
+a = [0XFF, 0xAbC, 0B11, 0O17];
+b = [.5, -.5, 5., 5.0, 1.50, 0.0];
+c = [1.0e10, 1E+05, 2e-05, 1.5E10, 3e0];
+d = [10n, 0XFFn, 1_000.000_0];
+x = { 0XA: 1, .5: 2 };
@@ -0,0 +1,16 @@
+const obj = {
+  foo() { return 1 },
+  *gen() { yield 1 },
+  async [computedName]() { await x },
+  async *[Symbol.asyncIterator]() {},
+  get value() { return this._v },
+  set value(v) { this._v = v },
+  "quoted-name"(a, b) {},
+  async   bar  ( ) { },
+};
+class A {
+  foo() { return 1 }
+  *gen() { yield 1 }
+  async [computedName]() { await x }
+  async *[Symbol.asyncIterator]() {}
+}
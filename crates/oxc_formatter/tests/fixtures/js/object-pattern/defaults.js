@@ -0,0 +1,5 @@
+const {a=1,b   =   2} = obj;
+const { a = 1, b: { c=2 } = {} } = obj;
+function f({ a=1, b:{c=2}=d }, [x=1, y  =  2]) {}
+for (const { key = "default", value=0 } of entries) {}
+({ a=1, b=2 } = obj);
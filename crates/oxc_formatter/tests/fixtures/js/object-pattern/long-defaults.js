@@ -0,0 +1,4 @@
+const { firstValue = defaultFirstValue, secondValue = defaultSecondValue, thirdValue = defaultThirdValue } = someObject;
+const { veryLongPropertyName = someVeryLongDefaultValueExpression + anotherVeryLongOperand } = objectWithLongName;
+function configure({ timeout = DEFAULT_TIMEOUT, retries = DEFAULT_RETRIES, onError = () => {} }) {}
+({ firstValue = defaultFirstValue, secondValue = defaultSecondValue, thirdValue } = someObject);
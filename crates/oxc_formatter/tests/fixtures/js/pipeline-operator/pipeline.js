@@ -0,0 +1,13 @@
+const result = "hello"|>doubleSay|>capitalize|>exclaim;
+
+const searchResults$ = fromEvent(document.querySelector("input"), "input") |> map((event) => event.target.value) |> filter((searchText) => searchText.length > 2) |> debounce(300) |> distinctUntilChanged() |> switchMap((searchText) => queryApi(searchText) |> retry(3)) |> share();
+
+const grouped = (a ?? b) |> f;
+const nested = a ?? (b |> f);
+const withArrow = value |> ((x) => x + 1);
+
+function process(input) {
+  return input |> validateTheIncomingRequestPayload |> normalizeTheRequestPayload |> persistToDatabase;
+}
+
+foo(value |> transformTheValueWithALongFunctionName |> anotherTransformationWithALongName);
@@ -0,0 +1,33 @@
+function foo() {
+
+  const a = 1;
+  return a;
+
+}
+
+function bar() {
+
+
+  // comment
+  baz();
+}
+
+if (a) {
+
+  b();
+
+
+}
+
+class A {
+  method() {
+    "use strict";
+
+    c();
+
+  }
+}
+
+function noBlankLines() {
+  d();
+}
@@ -0,0 +1,10 @@
+/*
+   * Generated file,
+      * do not edit
+ */
+const a = 1;
+
+/*
+   * Not the first comment
+ */
+const b = 2;
@@ -0,0 +1,6 @@
+#!/usr/bin/env node
+/**
+    * @license MIT
+  * Copyright (c) 2024 Someone
+ */
+console.log("hello");
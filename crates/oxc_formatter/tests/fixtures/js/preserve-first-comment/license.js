@@ -0,0 +1,10 @@
+/*!
+ * my-lib v1.0.0
+ *   Copyright (c) 2024 Someone
+    * Released under the MIT License.
+ */
+
+/**
+  * Not a banner, the stars are aligned
+*/
+export function foo() {}
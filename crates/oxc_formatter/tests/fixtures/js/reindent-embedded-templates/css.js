@@ -0,0 +1,31 @@
+function Button() {
+  if (primary) {
+    const styles = css`
+            color: red;
+
+            &:hover {
+                color: blue;
+            }
+    `;
+  }
+}
+
+const Title = styled.h1`
+font-size: 1.5em;
+    text-align: center;
+`;
+
+// Content on the first line is kept as is
+const inline = css`color: red;
+  background: blue;
+`;
+
+// Not an embedded template
+const str = `
+        color: red;
+`;
+
+// Templates with substitutions are kept as is
+const withExpression = css`
+        color: ${color};
+`;
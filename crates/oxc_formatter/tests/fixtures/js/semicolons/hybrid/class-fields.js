@@ -0,0 +1,13 @@
+// Class fields always end with a semicolon, even when the next member is ASI-safe
+class A {
+  a = 1;
+  b;
+  static c = "c";
+  #d = () => {};
+  [e] = 2;
+  f() {}
+  accessor g = 3;
+  h
+  i
+  *j() {}
+}
@@ -0,0 +1,10 @@
+// Statements omit semicolons, a leading one is only added where ASI would fail
+const a = 1;
+let b;
+foo();
+(function () {})();
+[1, 2].forEach(bar);
+`template`.length;
++b;
+/regex/.test(a);
+export default a;
@@ -0,0 +1,28 @@
+const config = { zebra: 1, apple: 2, "mango": 3, 10: 4, banana() {}, get cherry() { return 5; } };
+
+const multiline = {
+  charlie: 3,
+  alpha: 1,
+  bravo: {
+    zulu: true,
+    yankee: false,
+  },
+};
+
+// Not sorted: spread element
+const withSpread = { zebra: 1, ...rest, apple: 2 };
+
+// Not sorted: computed key
+const withComputed = { zebra: 1, [key]: 2, apple: 3 };
+
+// Not sorted: comments would move
+const withComment = {
+  zebra: 1, // last
+  apple: 2,
+};
+
+// Not sorted: the calls would run in another order
+const withCalls = { zebra: f(), apple: g() };
+
+// Not sorted: `__proto__` sets the prototype
+const withProto = { zebra: 1, __proto__: null, apple: 2 };
@@ -0,0 +1,16 @@
+const message = "The quick brown fox " + "jumps over the lazy dog " + "and keeps on running";
+
+foo("The quick brown fox " + "jumps over the lazy dog " + "and keeps on running, far far away");
+
+foo(`The quick brown ${animal} ` + "jumps over the lazy dog " + "and keeps on running, far far away");
+
+function f() {
+  return "The quick brown fox " + "jumps over the lazy dog " + "and keeps on running" + name;
+}
+
+const x = {
+  key: cond ? "The quick brown fox " + "jumps over the lazy dog " + "and keeps on running" : null,
+};
+
+// Not a string concatenation
+foo(theQuickBrownFox + jumpsOverTheLazyDog + andKeepsOnRunning + farFarAway + untilTheEnd);
@@ -0,0 +1,3 @@
+a = "\x4A\x4a éé \u{1F600} \t\n\\ \d";
+b = 'café \'\" \xFF\\xFF';
+c = "\\\"";
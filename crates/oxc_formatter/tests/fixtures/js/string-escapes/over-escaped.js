@@ -0,0 +1,6 @@
+a = '\"hello\"';
+b = "\'hello\'";
+c = '\"it\'s\"';
+d = "\'\"\'";
+e = { "\'key\'": '\"value\"' };
+import x from '\"module\"' with { type: "\'json\'" };
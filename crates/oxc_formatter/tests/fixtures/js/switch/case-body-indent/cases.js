@@ -0,0 +1,29 @@
+switch (key) {
+  case fallthrough: // trailing comment
+  case normalBody:
+    someWork();
+    break;
+
+  case blockBody: {
+    const a = 1;
+    break;
+  }
+
+  case separateBlockBody:
+    {
+      breakIsNotInsideTheBlock();
+    }
+    break;
+
+  case nested:
+    switch (other) {
+      case 1:
+        return;
+    }
+
+  default:
+    break;
+}
+
+switch (empty) {
+}
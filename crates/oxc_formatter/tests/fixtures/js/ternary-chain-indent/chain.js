@@ -0,0 +1,7 @@
+const animal = isBird ? "bird" : isCat ? "cat" : isDog ? "dog" : "probably a fish or something else";
+
+const message = i % 3 === 0 && i % 5 === 0 ? "fizzbuzz" : i % 3 === 0 ? "fizz" : i % 5 === 0 ? "buzz" : String(i);
+
+const nested = a ? (bbbbbbbbbbbbbbbbbbbbbbb ? ccccccccccccccccccccccc : ddddddddddddddddddddd) : eeeeeeeeeeeeeeeeeeeeee ? fffffffffffffff : gggggggggg;
+
+const short = a ? b : c ? d : e;
@@ -0,0 +1,18 @@
+{
+  using   res = getResource();
+  using a = b, c = d
+  using veryLongResourceNameThatGoesOnAndOn = acquireTheResourceWithAVeryLongFunction(argumentOne);
+  for (using item of items) {
+    item.use();
+  }
+  for (using i = getResource(); ; ) {}
+}
+
+async function f() {
+  await   using conn = await connect();
+  await using x = y
+  for (await using item of items) {
+    item.use();
+  }
+  for await (await using item of items) {}
+}
@@ -0,0 +1,13 @@
+function* g() {
+  (yield x) + 1;
+  (yield x).y;
+  (yield)();
+  (yield* other()).value;
+  (yield x) ? 1 : 2;
+  const a = yield x;
+  const b = (yield x) || y;
+  f(yield x);
+  yield (a, b);
+  yield* (a, b);
+  yield (aVeryLongIdentifierNameNumberOne, aVeryLongIdentifierNameNumberTwo, aVeryLongIdentifierNameNumberThree);
+}
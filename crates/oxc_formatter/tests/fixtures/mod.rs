@@ -2,9 +2,12 @@ use std::{env::current_dir, fs, path::Path};
 
 use oxc_allocator::Allocator;
 use oxc_formatter::{
-    ArrowParentheses, BracketSameLine, BracketSpacing, FormatOptions, Formatter, IndentStyle,
-    IndentWidth, LineEnding, LineWidth, QuoteProperties, QuoteStyle, Semicolons, TrailingCommas,
-    get_parse_options,
+    AnonymousFunctionSpace, ArrowParentheses, BracketSameLine, BracketSpacing, EmptyBody,
+    EmptyBracketSpacing, ExpandArraysOfObjects, FormatOptions, Formatter, HugSoleArgument,
+    IndentStyle, IndentUnionTypes, IndentWidth, JsxAttributeQuotes, JsxTextWrap, LineEnding,
+    LineWidth, NumericLiterals, QuoteProperties, QuoteStyle, Semicolons, SortObjectKeys,
+    StringConcatenationIndent, SwitchCaseBodyIndent, TernaryChainIndent, TrailingCommas,
+    TypeOperatorPosition, get_parse_options_with,
 };
 use oxc_parser::Parser;
 use oxc_span::SourceType;
@@ -48,6 +51,8 @@ fn parse_format_options(json: &OptionSet) -> FormatOptions {
             "semi" => {
                 if let Some(b) = value.as_bool() {
                     options.semicolons = if b { Semicolons::Always } else { Semicolons::AsNeeded };
+                } else if value.as_str() == Some("hybrid") {
+                    options.semicolons = Semicolons::Hybrid;
                 }
             }
             "singleQuote" => {
@@ -119,6 +124,169 @@ fn parse_format_options(json: &OptionSet) -> FormatOptions {
                     };
                 }
             }
+            "switchCaseBodyIndent" => {
+                if let Some(s) = value.as_str() {
+                    options.switch_case_body_indent = match s {
+                        "single" => SwitchCaseBodyIndent::Single,
+                        "double" => SwitchCaseBodyIndent::Double,
+                        _ => SwitchCaseBodyIndent::default(),
+                    };
+                }
+            }
+            "blankLineBetweenClassMembers" => {
+                if let Some(b) = value.as_bool() {
+                    options.blank_line_between_class_members = b;
+                }
+            }
+            "preserveBlockBlankLines" => {
+                if let Some(b) = value.as_bool() {
+                    options.preserve_block_blank_lines = b;
+                }
+            }
+            "compactJsxConditionals" => {
+                if let Some(b) = value.as_bool() {
+                    options.compact_jsx_conditionals = b;
+                }
+            }
+            "collapseElseIf" => {
+                if let Some(b) = value.as_bool() {
+                    options.collapse_else_if = b;
+                }
+            }
+            "anonymousFunctionSpace" => {
+                if let Some(b) = value.as_bool() {
+                    options.anonymous_function_space = AnonymousFunctionSpace::from(b);
+                }
+            }
+            "hugSoleArgument" => {
+                if let Some(b) = value.as_bool() {
+                    options.hug_sole_argument = HugSoleArgument::from(b);
+                }
+            }
+            "methodChainBreakThreshold" => {
+                if let Some(n) = value.as_u64() {
+                    options.method_chain_break_threshold = u8::try_from(n).unwrap().into();
+                }
+            }
+            "alignObjectProperties" => {
+                if let Some(b) = value.as_bool() {
+                    options.align_object_properties = b;
+                }
+            }
+            "alignEnumMembers" => {
+                if let Some(b) = value.as_bool() {
+                    options.align_enum_members = b;
+                }
+            }
+            "preserveFirstComment" => {
+                if let Some(b) = value.as_bool() {
+                    options.preserve_first_comment = b;
+                }
+            }
+            "numericLiterals" => {
+                if let Some(s) = value.as_str() {
+                    options.numeric_literals = match s {
+                        "preserve" => NumericLiterals::Preserve,
+                        _ => NumericLiterals::default(),
+                    };
+                }
+            }
+            "normalizeEscapeSequences" => {
+                if let Some(b) = value.as_bool() {
+                    options.normalize_escape_sequences = b;
+                }
+            }
+            "jsxTextWrap" => {
+                if let Some(s) = value.as_str() {
+                    options.jsx_text_wrap = match s {
+                        "preserve" => JsxTextWrap::Preserve,
+                        _ => JsxTextWrap::default(),
+                    };
+                }
+            }
+            "sortObjectKeys" => {
+                if let Some(s) = value.as_str() {
+                    options.sort_object_keys = match s {
+                        "asc" => SortObjectKeys::Asc,
+                        "desc" => SortObjectKeys::Desc,
+                        _ => SortObjectKeys::default(),
+                    };
+                }
+            }
+            "expandArraysOfObjects" => {
+                if let Some(s) = value.as_str() {
+                    options.expand_arrays_of_objects = match s {
+                        "always" => ExpandArraysOfObjects::Always,
+                        "never" => ExpandArraysOfObjects::Never,
+                        _ => ExpandArraysOfObjects::default(),
+                    };
+                }
+            }
+            "stringConcatenationIndent" => {
+                if let Some(s) = value.as_str() {
+                    options.string_concatenation_indent = match s {
+                        "indent" => StringConcatenationIndent::Indent,
+                        "align" => StringConcatenationIndent::Align,
+                        _ => StringConcatenationIndent::default(),
+                    };
+                }
+            }
+            "ternaryChainIndent" => {
+                if let Some(s) = value.as_str() {
+                    options.ternary_chain_indent = match s {
+                        "indent" => TernaryChainIndent::Indent,
+                        "flat" => TernaryChainIndent::Flat,
+                        _ => TernaryChainIndent::default(),
+                    };
+                }
+            }
+            "typeOperatorPosition" => {
+                if let Some(s) = value.as_str() {
+                    options.type_operator_position = match s {
+                        "start" => TypeOperatorPosition::Start,
+                        "end" => TypeOperatorPosition::End,
+                        _ => TypeOperatorPosition::default(),
+                    };
+                }
+            }
+            "indentUnionTypes" => {
+                if let Some(b) = value.as_bool() {
+                    options.indent_union_types = IndentUnionTypes::from(b);
+                }
+            }
+            "experimentalPipelineOperator" => {
+                if let Some(b) = value.as_bool() {
+                    options.experimental_pipeline_operator = b;
+                }
+            }
+            "reindentEmbeddedTemplates" => {
+                if let Some(b) = value.as_bool() {
+                    options.reindent_embedded_templates = b;
+                }
+            }
+            "emptyBracketSpacing" => {
+                if let Some(b) = value.as_bool() {
+                    options.empty_bracket_spacing = EmptyBracketSpacing::from(b);
+                }
+            }
+            "emptyBody" => {
+                if let Some(s) = value.as_str() {
+                    options.empty_body = match s {
+                        "collapse" => EmptyBody::Collapse,
+                        "expand" => EmptyBody::Expand,
+                        _ => EmptyBody::default(),
+                    };
+                }
+            }
+            "jsxAttributeQuotes" => {
+                if let Some(s) = value.as_str() {
+                    options.jsx_attribute_quotes = match s {
+                        "as-needed" => JsxAttributeQuotes::AsNeeded,
+                        "consistent" => JsxAttributeQuotes::Consistent,
+                        _ => JsxAttributeQuotes::default(),
+                    };
+                }
+            }
             "quoteProps" => {
                 if let Some(s) = value.as_str() {
                     options.quote_properties = match s {
@@ -150,8 +318,9 @@ fn format_options_display(json: &OptionSet) -> String {
 /// Format a source file with given options
 fn format_source(source_text: &str, source_type: SourceType, options: FormatOptions) -> String {
     let allocator = Allocator::default();
-    let ret =
-        Parser::new(&allocator, source_text, source_type).with_options(get_parse_options()).parse();
+    let ret = Parser::new(&allocator, source_text, source_type)
+        .with_options(get_parse_options_with(&options))
+        .parse();
     assert!(ret.errors.is_empty());
 
     let formatter = Formatter::new(&allocator, options);
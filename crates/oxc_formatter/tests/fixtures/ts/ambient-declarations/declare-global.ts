@@ -0,0 +1,8 @@
+declare global {
+interface Window { myGlobal: string }
+  var __DEV__: boolean
+    function helper(x: number): void;
+  namespace NodeJS { interface ProcessEnv { NODE_ENV: "development" | "production" } }
+}
+
+export {};
@@ -0,0 +1,10 @@
+declare module "my-lib" {
+  export interface Options { verbose?: boolean; }
+  export function run(options?: Options): Promise<void>
+  export default run;
+  export type Callback = (error: Error | null) => void
+  namespace internal { export const version: string }
+}
+declare module "*.svg" { const content: string; export default content; }
+declare module "empty" {}
+declare module "shorthand";
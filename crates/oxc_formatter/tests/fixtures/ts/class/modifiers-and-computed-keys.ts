@@ -0,0 +1,17 @@
+class A {
+  static   accessor   count = 0;
+  accessor   name: string;
+  static accessor #secret = 1;
+  static get   [Symbol.species]() { return Array; }
+  *[Symbol.iterator]() { yield 1; }
+  async  *[Symbol.asyncIterator]() {}
+  [  computed  ] = 1;
+  static [key + "suffix"]() {}
+  get   value() { return 1; }
+  set   value(v) {}
+  static  {
+    init();
+  }
+  static {}
+  declare readonly [Symbol.toStringTag]: string;
+}
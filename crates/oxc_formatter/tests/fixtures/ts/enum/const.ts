@@ -0,0 +1,7 @@
+const enum Direction { Up, Down, Left, Right }
+
+export const enum Flags {
+  None = 0,
+  A = 1 << 0,
+  B = 1 << 1
+}
@@ -0,0 +1,12 @@
+enum Status {
+  Ok = 200,
+  NotFound = 404,
+  InternalServerError = 500,
+
+  Teapot = 418,
+  A,
+  'quoted-name' = 1,
+  [`template`] = 2,
+}
+
+enum Short { A = 1, BB = 2 }
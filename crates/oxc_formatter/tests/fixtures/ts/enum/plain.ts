@@ -0,0 +1,9 @@
+enum Direction { Up, Down, Left, Right }
+
+enum Empty {}
+
+declare enum Color {
+  Red,
+  Green, // comment
+  Blue
+}
@@ -0,0 +1,2 @@
+type Wrapped<T> = { readonly [Key in keyof SomeVeryLongTypeName<T>]?: AnotherVeryLongTypeName<T>[Key] };
+type WrappedAs<T> = { readonly [Key in keyof SomeVeryLongTypeName<T> as Exclude<Key, "kind" | "type">]?: SomeVeryLongTypeName<T>[Key] };
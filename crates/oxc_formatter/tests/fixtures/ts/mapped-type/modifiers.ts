@@ -0,0 +1,5 @@
+type Optional<T> = {readonly [K in keyof T]?: T[K]};
+type Added<T> = { +readonly [ K in keyof T ]+?: T[K] };
+type Removed<T> = { -readonly [K in keyof T]-?: T[K] };
+type Renamed<T> = { [K in keyof T as `get${Capitalize<K & string>}`]: () => T[K] };
+type NoValue = { [K in "a" | "b"] };
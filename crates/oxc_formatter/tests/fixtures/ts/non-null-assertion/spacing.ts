@@ -0,0 +1,9 @@
+foo !.bar;
+foo! ();
+arr! [0];
+foo!!.bar!.baz!();
+const x = obj!.a!.b! ;
+a!?.b;
+(foo as Bar)!.baz;
+new Foo!();
+this.veryLongPropertyName!.anotherVeryLongPropertyName!.yetAnotherLongProperty!.method!();
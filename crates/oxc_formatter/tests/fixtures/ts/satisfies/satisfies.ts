@@ -0,0 +1,11 @@
+const short = {x:1}   satisfies   Foo;
+const shortAs = {x:1} as   Foo;
+
+export const config = { someLongPropertyName: "value", anotherLongPropertyName: 42 } satisfies Config;
+const routes = someFunctionCall(argumentNumberOne, argumentNumberTwo) satisfies Record<string, RouteDefinition>;
+const member = veryLongIdentifierNameForTesting.someProperty.anotherProperty satisfies SomeVeryLongTypeName<WithGeneric>;
+const memberAs = veryLongIdentifierNameForTesting.someProperty.anotherProperty as SomeVeryLongTypeName<WithGeneric>;
+
+const colors = ["red", "green"]   as const   satisfies readonly string[];
+const palette = { primary: "red", secondary: "green", tertiary: "blue", quaternary: "white" } as const satisfies Record<string, Color>;
+const kind = { kind: "x" } satisfies Base as const;
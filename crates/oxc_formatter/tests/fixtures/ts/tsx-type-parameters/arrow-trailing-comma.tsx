@@ -0,0 +1,7 @@
+const identity = <T,>(value: T) => value;
+const withReturnType = <T,>(value: T): T => value;
+const constParameter = <const T,>(value: T) => value;
+const constrained = <T extends object>(value: T) => value;
+const multiple = <T, U>(first: T, second: U) => [first, second];
+function declaration<T>(value: T) {}
+const element = <div>{identity<string>("a")}</div>;
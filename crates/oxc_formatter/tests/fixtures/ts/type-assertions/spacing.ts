@@ -0,0 +1,15 @@
+const a = x   as    const;
+const b = [1, 2]as const;
+const c = { a: 1 } as
+  const;
+const d = y as  Foo;
+const e = (x as  unknown)   as   Bar<T>;
+const f = obj satisfies   Baz;
+foo(a as   any, <any>  b);
+
+const g = < Foo >  z;
+const h = <const>["a"];
+const i = <  Foo<T>  >(<unknown>w);
+const j = <
+  Foo
+>z;
@@ -0,0 +1,5 @@
+type Short = A & B;
+
+type LongIntersection = Aaaaaaaaaaaaaaaaaaaaaa & Bbbbbbbbbbbbbbbbbbbbbbbbbb & Cccccccccccccccccccccc;
+
+type WithObject = Aaaaaaaaaaaaaaaaaaaa & { bbbbbbbbbbbbbbbbbbbb: string; cccccccccccccccc: number };
@@ -0,0 +1,13 @@
+type Short = "a" | "b" | null;
+
+type LongUnion = "aaaaaaaaaaaaaaa" | "bbbbbbbbbbbbbbbbb" | "cccccccccccccccccc" | "dddddddddddddddddd";
+
+interface Props {
+  variant: "primaryvariant" | "secondaryvariant" | "tertiaryvariant" | "quaternary";
+}
+
+function foo(value: "aaaaaaaaaaaaaaaaaa" | "bbbbbbbbbbbbbbbbbbbb" | "cccccccccccccccccccccc") {}
+
+type InTuple = [first: "aaaaaaaaaaaaaaaaaaaa" | "bbbbbbbbbbbbbbbbbbbbbbb", second: "cccccccccccccccccc"];
+
+type Hugged = { aaaaaaaaaaaaaa: string; bbbbbbbbbbbbbbbbbbbbbbb: number; ccccccccccc: boolean } | null;
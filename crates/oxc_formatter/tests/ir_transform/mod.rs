@@ -69,9 +69,11 @@ struct TestSortImportsConfig {
     partition_by_newline: Option<bool>,
     partition_by_comment: Option<bool>,
     sort_side_effects: Option<bool>,
+    partition_by_side_effect: Option<bool>,
     order: Option<String>,
     ignore_case: Option<bool>,
     newlines_between: Option<bool>,
+    preserve_newlines_between: Option<bool>,
     internal_pattern: Option<Vec<String>>,
     #[serde(default, deserialize_with = "deserialize_groups")]
     groups: Option<Vec<Vec<String>>>,
@@ -134,6 +136,9 @@ fn parse_test_config(json: &str) -> FormatOptions {
         if let Some(v) = sort_config.sort_side_effects {
             sort_imports.sort_side_effects = v;
         }
+        if let Some(v) = sort_config.partition_by_side_effect {
+            sort_imports.partition_by_side_effect = v;
+        }
         if let Some(v) = sort_config.order {
             sort_imports.order = match v.as_str() {
                 "desc" => SortOrder::Desc,
@@ -146,6 +151,9 @@ fn parse_test_config(json: &str) -> FormatOptions {
         if let Some(v) = sort_config.newlines_between {
             sort_imports.newlines_between = v;
         }
+        if let Some(v) = sort_config.preserve_newlines_between {
+            sort_imports.preserve_newlines_between = v;
+        }
         if let Some(v) = sort_config.internal_pattern {
             sort_imports.internal_pattern = v;
         }
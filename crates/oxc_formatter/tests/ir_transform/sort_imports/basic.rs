@@ -141,6 +141,63 @@ import { x } from "a";
     );
 }
 
+#[test]
+fn should_keep_type_imports_separate_from_value_imports() {
+    assert_format(
+        r#"
+import { b } from "b";
+import type { A } from "a";
+import { a } from "a";
+import { type C, c } from "c";
+import type { B } from "b";
+"#,
+        r#"{ "experimentalSortImports": {} }"#,
+        r#"
+import type { A } from "a";
+import type { B } from "b";
+
+import { a } from "a";
+import { b } from "b";
+import { type C, c } from "c";
+"#,
+    );
+    // Imports of the same source keep their relative order when types are not grouped
+    assert_format(
+        r#"
+import { b } from "b";
+import type { A } from "a";
+import { a } from "a";
+import { type C, c } from "c";
+import type { B } from "b";
+"#,
+        r#"{ "experimentalSortImports": { "groups": ["external"] } }"#,
+        r#"
+import type { A } from "a";
+import { a } from "a";
+import { b } from "b";
+import type { B } from "b";
+import { type C, c } from "c";
+"#,
+    );
+    assert_format(
+        r#"
+import { b } from "b";
+import type { A } from "a";
+import { a } from "a";
+import { type C, c } from "c";
+import type { B } from "b";
+"#,
+        r#"{ "experimentalSortImports": { "groups": ["external"], "order": "desc" } }"#,
+        r#"
+import { type C, c } from "c";
+import { b } from "b";
+import type { B } from "b";
+import type { A } from "a";
+import { a } from "a";
+"#,
+    );
+}
+
 #[test]
 fn should_sort_regardless_of_quotes() {
     assert_format(
@@ -951,6 +1008,69 @@ import "./reset.css";
     );
 }
 
+#[test]
+fn should_partition_by_side_effect() {
+    // Imports are never moved across side-effect imports
+    assert_format(
+        r#"
+import c from "c";
+import b from "b";
+import "polyfill";
+import z from "z";
+import a from "a";
+"#,
+        r#"{ "experimentalSortImports": { "partitionBySideEffect": true } }"#,
+        r#"
+import b from "b";
+import c from "c";
+import "polyfill";
+import a from "a";
+import z from "z";
+"#,
+    );
+    // Side-effect imports are not regrouped, even if `groups` contain `side-effect`
+    assert_format(
+        r#"
+import { y } from "./y";
+import "./polyfill";
+import { x } from "x";
+import "./setup";
+import { b } from "./b";
+import { a } from "a";
+"#,
+        r#"{
+  "experimentalSortImports": {
+    "partitionBySideEffect": true,
+    "groups": ["side-effect", "external", "unknown"],
+    "newlinesBetween": false
+  }
+}"#,
+        r#"
+import { y } from "./y";
+import "./polyfill";
+import { x } from "x";
+import "./setup";
+import { a } from "a";
+import { b } from "./b";
+"#,
+    );
+    // No effect with `sortSideEffects: true`
+    assert_format(
+        r#"
+import b from "b";
+import "./polyfill";
+import a from "a";
+"#,
+        r#"{ "experimentalSortImports": { "sortSideEffects": true, "partitionBySideEffect": true } }"#,
+        r#"
+import a from "a";
+import b from "b";
+
+import "./polyfill";
+"#,
+    );
+}
+
 // ---
 
 #[test]
@@ -1267,6 +1387,61 @@ import z from "~/z";
     );
 }
 
+#[test]
+fn should_support_preserve_newlines_between_option() {
+    // Blank line between groups in the source survives sorting
+    assert_format(
+        r#"
+import { b } from "b";
+import { a } from "a";
+
+import y from "~/y";
+import x from "~/x";
+"#,
+        r#"{ "experimentalSortImports": { "newlinesBetween": false, "preserveNewlinesBetween": true } }"#,
+        r#"
+import { a } from "a";
+import { b } from "b";
+
+import x from "~/x";
+import y from "~/y";
+"#,
+    );
+
+    // Groups which were not separated in the source stay joined
+    assert_format(
+        r#"
+import y from "~/y";
+import { a } from "a";
+
+import d from ".";
+"#,
+        r#"{ "experimentalSortImports": { "newlinesBetween": false, "preserveNewlinesBetween": true } }"#,
+        r#"
+import { a } from "a";
+import y from "~/y";
+
+import d from ".";
+"#,
+    );
+
+    // Blank lines inside a group are still collapsed
+    assert_format(
+        r#"
+import { b } from "b";
+
+import { a } from "a";
+import x from "~/x";
+"#,
+        r#"{ "experimentalSortImports": { "newlinesBetween": false, "preserveNewlinesBetween": true } }"#,
+        r#"
+import { a } from "a";
+import { b } from "b";
+import x from "~/x";
+"#,
+    );
+}
+
 // ---
 
 #[test]
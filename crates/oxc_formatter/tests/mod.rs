@@ -1,2 +1,4 @@
+mod embedded;
 mod fixtures;
 mod ir_transform;
+mod nesting_depth;
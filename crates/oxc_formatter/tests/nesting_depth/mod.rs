@@ -0,0 +1,60 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{DEFAULT_MAX_NESTING_DEPTH, FormatOptions, Formatter, get_parse_options};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+/// Formats the source text and returns the error message if printing failed.
+fn format_with_max_nesting_depth(
+    source_text: &str,
+    max_nesting_depth: u32,
+) -> Result<String, String> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs())
+        .with_options(get_parse_options())
+        .parse();
+    assert!(ret.errors.is_empty());
+
+    Formatter::new(&allocator, FormatOptions::default())
+        .with_max_nesting_depth(max_nesting_depth)
+        .format(&ret.program)
+        .print()
+        .map(|printed| printed.as_code().to_string())
+        .map_err(|err| err.to_string())
+}
+
+fn nested_arrays(depth: usize) -> String {
+    format!("x = {}1{};\n", "[".repeat(depth), "]".repeat(depth))
+}
+
+#[test]
+fn pathologically_nested_input() {
+    let source_text = nested_arrays(2000);
+    // Debug builds use a lot more stack per level, run on a thread large enough to
+    // reach the default limit instead of testing the stack size of the test runner.
+    let result = std::thread::Builder::new()
+        .stack_size(64 * 1024 * 1024)
+        .spawn(move || format_with_max_nesting_depth(&source_text, DEFAULT_MAX_NESTING_DEPTH))
+        .unwrap()
+        .join()
+        .unwrap();
+
+    assert_eq!(
+        result,
+        Err(format!(
+            "The code is nested too deeply to be formatted (maximum nesting depth: {DEFAULT_MAX_NESTING_DEPTH})"
+        ))
+    );
+}
+
+#[test]
+fn custom_max_nesting_depth() {
+    let source_text = nested_arrays(10);
+    assert_eq!(
+        format_with_max_nesting_depth(&source_text, DEFAULT_MAX_NESTING_DEPTH),
+        Ok("x = [[[[[[[[[[1]]]]]]]]]];\n".to_string())
+    );
+    assert_eq!(
+        format_with_max_nesting_depth(&source_text, 16),
+        Err("The code is nested too deeply to be formatted (maximum nesting depth: 16)".to_string())
+    );
+}
@@ -1,30 +1,45 @@
-use std::{borrow::Cow, sync::Arc};
+use std::{
+    borrow::Cow,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::Arc,
+    time::Duration,
+};
 
 use futures::future::join_all;
-use rustc_hash::FxBuildHasher;
+use rustc_hash::{FxBuildHasher, FxHashMap};
 use serde_json::Value;
-use tokio::sync::{OnceCell, RwLock, SetError};
+use tokio::{
+    sync::{Mutex, OnceCell, RwLock, SetError},
+    task::JoinHandle,
+};
 use tower_lsp_server::{
     Client, LanguageServer,
     jsonrpc::{Error, ErrorCode, Result},
     ls_types::{
-        CodeActionParams, CodeActionResponse, ConfigurationItem, Diagnostic,
-        DidChangeConfigurationParams, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
-        DidChangeWorkspaceFoldersParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
-        DidSaveTextDocumentParams, DocumentDiagnosticParams, DocumentDiagnosticReport,
-        DocumentDiagnosticReportKind, DocumentDiagnosticReportResult, DocumentFormattingParams,
-        ExecuteCommandParams, FullDocumentDiagnosticReport, InitializeParams, InitializeResult,
-        InitializedParams, MessageType, RelatedFullDocumentDiagnosticReport, ServerInfo, TextEdit,
-        Uri,
+        CodeActionParams, CodeActionResponse, CompletionParams, CompletionResponse,
+        ConfigurationItem, Diagnostic, DidChangeConfigurationParams, DidChangeTextDocumentParams,
+        DidChangeWatchedFilesParams, DidChangeWorkspaceFoldersParams, DidCloseTextDocumentParams,
+        DidOpenTextDocumentParams, DidSaveTextDocumentParams, DocumentChanges,
+        DocumentDiagnosticParams, DocumentDiagnosticReport, DocumentDiagnosticReportKind,
+        DocumentDiagnosticReportResult, DocumentFormattingParams, DocumentSymbolParams,
+        DocumentSymbolResponse, ExecuteCommandParams, FoldingRange, FoldingRangeParams,
+        FullDocumentDiagnosticReport, InitializeParams, InitializeResult, InitializedParams,
+        InlayHint, InlayHintParams, MessageType, NumberOrString, OneOf, PrepareRenameResponse,
+        ProgressParams, ProgressParamsValue, RelatedFullDocumentDiagnosticReport,
+        RelatedUnchangedDocumentDiagnosticReport, RenameParams, SelectionRange,
+        SelectionRangeParams, ServerInfo, TextDocumentPositionParams, TextEdit,
+        UnchangedDocumentDiagnosticReport, Uri, WorkDoneProgress, WorkDoneProgressCreateParams,
+        WorkspaceEdit, WorkspaceSymbolParams, WorkspaceSymbolResponse, notification::Progress,
+        request::WorkDoneProgressCreate,
     },
 };
 use tracing::{debug, error, info, warn};
 
 use crate::{
-    ConcurrentHashMap, ToolBuilder,
+    CommandResult, ConcurrentHashMap, ToolBuilder,
     capabilities::{Capabilities, DiagnosticMode, server_capabilities},
     file_system::LSPFileSystem,
-    options::WorkspaceOption,
+    options::{Features, WorkspaceOption},
     worker::WorkspaceWorker,
 };
 
@@ -70,8 +85,19 @@ pub struct Backend {
     // The client will send the content of in-memory files on `textDocument/didOpen` and `textDocument/didChange`.
     // This is only needed when the client supports `textDocument/formatting` request.
     file_system: Arc<RwLock<LSPFileSystem>>,
+    // Diagnostics of saved files which are not published yet.
+    // Saving many files at once (e.g. when formatting the whole project) would flood the client
+    // with `textDocument/publishDiagnostics` notifications, so they are collected and published together,
+    // see [`Backend::queue_diagnostics`].
+    pending_diagnostics: Arc<Mutex<FxHashMap<Uri, Vec<Diagnostic>>>>,
+    // The running workspace scan of every workspace folder, see [`Backend::scan_workspace`].
+    // The scans run in the background, so they don't block other requests and notifications.
+    workspace_scans: Mutex<FxHashMap<Uri, JoinHandle<()>>>,
 }
 
+/// The time to wait for more saved files, before publishing the collected diagnostics.
+const PUBLISH_DIAGNOSTICS_DELAY: Duration = Duration::from_millis(50);
+
 impl LanguageServer for Backend {
     /// Initialize the language server with the given parameters.
     /// This method sets up workspace workers, capabilities, and starts the
@@ -80,10 +106,15 @@ impl LanguageServer for Backend {
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#initialize>
     #[expect(deprecated)] // `params.root_uri` is deprecated, we are only falling back to it if no workspace folder is provided
     async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        let features =
+            Features::from_initialization_options(params.initialization_options.as_ref());
         // initialization_options can be anything, so we are requesting `workspace/configuration` when no initialize options are provided
         let options = params.initialization_options.and_then(|value| {
-            // the client supports the new settings object
-            if let Ok(new_settings) = serde_json::from_value::<Vec<WorkspaceOption>>(value.clone())
+            // the client supports the new settings object,
+            // optionally next to the `features` in `{ features, workspaces }`
+            let workspaces = value.get("workspaces").unwrap_or(&value);
+            if let Ok(new_settings) =
+                serde_json::from_value::<Vec<WorkspaceOption>>(workspaces.clone())
             {
                 // ToDo: validate they have the same length as params.workspace_folders
                 return Some(new_settings);
@@ -109,6 +140,8 @@ impl LanguageServer for Backend {
         for tool_builder in self.tool_builders.iter() {
             tool_builder.server_capabilities(&mut server_capabilities, &mut capabilities);
         }
+        features.apply(&mut server_capabilities);
+        capabilities.features = features;
 
         info!("initialize: {options:?}");
         info!(
@@ -264,12 +297,23 @@ impl LanguageServer for Backend {
             }
         }
 
-        if registrations.is_empty() {
-            return;
-        }
-        if let Err(err) = self.client.register_capability(registrations).await {
+        if !registrations.is_empty()
+            && let Err(err) = self.client.register_capability(registrations).await
+        {
             warn!("sending registerCapability.didChangeWatchedFiles failed: {err}");
         }
+
+        // Pull clients request the diagnostics of the files they are interested in,
+        // only push clients get the diagnostics of the complete workspace.
+        if capabilities.diagnostic_mode == DiagnosticMode::Push {
+            for worker in workers {
+                self.scan_workspace(worker).await;
+            }
+        }
+
+        for worker in workers {
+            self.send_log_messages(worker).await;
+        }
     }
 
     /// This method clears all diagnostics and the in-memory file system.
@@ -278,6 +322,10 @@ impl LanguageServer for Backend {
     async fn shutdown(&self) -> Result<()> {
         let mut clearing_diagnostics = Vec::new();
 
+        for (_, scan) in self.workspace_scans.lock().await.drain() {
+            scan.abort();
+        }
+
         for worker in &*self.workspace_workers.read().await {
             // shutdown each worker and collect the URIs to clear diagnostics.
             // unregistering file watchers is not necessary, because the client will do it automatically on shutdown.
@@ -381,6 +429,7 @@ impl LanguageServer for Backend {
             let (diagnostics, registrations, unregistrations) = worker
                 .did_change_configuration(option.options, &mut needs_diagnostics_refresh, fs_ref)
                 .await;
+            self.send_log_messages(worker).await;
 
             if let Some(diagnostics) = diagnostics {
                 new_diagnostics.extend(diagnostics);
@@ -446,6 +495,7 @@ impl LanguageServer for Backend {
             let (diagnostics, registrations, unregistrations) = worker
                 .did_change_watched_files(file_event, &mut needs_diagnostics_refresh, fs_ref)
                 .await;
+            self.send_log_messages(worker).await;
 
             if let Some(diagnostics) = diagnostics {
                 new_diagnostics.extend(diagnostics);
@@ -481,6 +531,7 @@ impl LanguageServer for Backend {
     }
 
     /// The server will start new [WorkspaceWorker]s for added workspace folders
+    /// and scan them in push mode,
     /// and stop and remove [WorkspaceWorker]s for removed workspace folders including:
     /// - clearing diagnostics
     /// - unregistering file watchers
@@ -498,6 +549,10 @@ impl LanguageServer for Backend {
             else {
                 continue;
             };
+            let scan = self.workspace_scans.lock().await.remove(&folder.uri);
+            if let Some(scan) = scan {
+                scan.abort();
+            }
             let (uris, unregistrations) = worker.shutdown().await;
             cleared_diagnostics.extend(uris);
             removed_registrations.extend(unregistrations);
@@ -506,6 +561,7 @@ impl LanguageServer for Backend {
 
         let diagnostic_mode =
             self.capabilities.get().map(|cap| cap.diagnostic_mode.clone()).unwrap_or_default();
+        let added_uris: Vec<Uri> = params.event.added.iter().map(|w| w.uri.clone()).collect();
 
         if diagnostic_mode == DiagnosticMode::Push && !cleared_diagnostics.is_empty() {
             self.clear_diagnostics(cleared_diagnostics).await;
@@ -562,6 +618,14 @@ impl LanguageServer for Backend {
                 warn!("sending unregisterCapability.didChangeWatchedFiles failed: {err}");
             }
         }
+
+        // only push clients get the diagnostics of the complete workspace.
+        if diagnostic_mode == DiagnosticMode::Push {
+            for worker in workers.iter().filter(|worker| added_uris.contains(worker.get_root_uri()))
+            {
+                self.scan_workspace(worker).await;
+            }
+        }
     }
 
     /// It will remove the in-memory file content, because the file is saved to disk.
@@ -586,12 +650,13 @@ impl LanguageServer for Backend {
                 }
                 Ok(diagnostics) => {
                     if !diagnostics.is_empty() {
-                        self.publish_all_diagnostics(diagnostics, ConcurrentHashMap::default())
-                            .await;
+                        self.queue_diagnostics(diagnostics).await;
                     }
                 }
             }
         }
+
+        self.send_log_messages(worker).await;
     }
     /// It will update the in-memory file content if the client supports dynamic formatting.
     /// It will re-lint the file and send updated diagnostics, if necessary.
@@ -626,6 +691,8 @@ impl LanguageServer for Backend {
                 }
             }
         }
+
+        self.send_log_messages(worker).await;
     }
 
     /// It will add the in-memory file content if the client supports dynamic formatting.
@@ -660,6 +727,8 @@ impl LanguageServer for Backend {
                 }
             }
         }
+
+        self.send_log_messages(worker).await;
     }
 
     /// It will remove the in-memory file content if the client supports dynamic formatting.
@@ -678,7 +747,7 @@ impl LanguageServer for Backend {
     }
 
     /// It will return code actions or commands for the given range.
-    /// The client can send `context.only` to `source.fixAll.oxc` to fix all diagnostics of the file.
+    /// The client can send `context.only` to `source.fixAll.oxc` (or `source.fixAll`) to fix all diagnostics of the file.
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_codeAction>
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
@@ -688,8 +757,14 @@ impl LanguageServer for Backend {
             return Ok(None);
         };
 
-        let code_actions =
-            worker.get_code_actions_or_commands(uri, &params.range, params.context.only).await;
+        let code_actions = worker
+            .get_code_actions_or_commands(
+                uri,
+                self.file_system.read().await.get(uri).as_deref(),
+                &params.range,
+                params.context.only,
+            )
+            .await;
 
         if code_actions.is_empty() {
             return Ok(None);
@@ -706,26 +781,56 @@ impl LanguageServer for Backend {
         &self,
         params: ExecuteCommandParams,
     ) -> Result<Option<serde_json::Value>> {
-        for worker in self.workspace_workers.read().await.iter() {
-            match worker.execute_command(&params.command, params.arguments.clone()).await {
-                Ok(changes) => {
-                    let Some(edit) = changes else {
-                        continue;
-                    };
-
-                    if !self.capabilities.get().unwrap().workspace_apply_edit {
+        let workers = self.workspace_workers.read().await;
+        let mut values = Vec::new();
+        for worker in workers.iter() {
+            // The file system is not locked while the edit is applied, the client changes the document
+            let result = worker
+                .execute_command(
+                    &params.command,
+                    params.arguments.clone(),
+                    &*self.file_system.read().await,
+                )
+                .await;
+            self.send_log_messages(worker).await;
+            match result {
+                Ok(None) => {}
+                Ok(Some(CommandResult::WorkspaceEdit { edit, message })) => {
+                    let capabilities = self.capabilities.get().unwrap();
+                    if !capabilities.workspace_apply_edit {
                         return Err(Error::invalid_params(
                             "client does not support workspace apply edit",
                         ));
                     }
 
+                    let edit = if capabilities.change_annotations {
+                        edit
+                    } else {
+                        without_change_annotations(edit)
+                    };
                     self.client.apply_edit(edit).await?;
+
+                    if let Some(message) = message
+                        && capabilities.show_message
+                    {
+                        self.client.show_message(MessageType::INFO, message).await;
+                    }
+                }
+                Ok(Some(CommandResult::Diagnostics(diagnostics))) => {
+                    self.publish_all_diagnostics(diagnostics, ConcurrentHashMap::default()).await;
                 }
+                Ok(Some(CommandResult::Value(value))) => values.push(value),
                 Err(err) => return Err(Error::new(err)),
             }
         }
 
-        Ok(None)
+        // every workspace returning a value contributes to the answer
+        if values.len() > 1
+            && let Some(worker) = workers.first()
+        {
+            return Ok(Some(worker.merge_command_values(&params.command, values).await));
+        }
+        Ok(values.pop())
     }
 
     async fn diagnostic(
@@ -741,6 +846,7 @@ impl LanguageServer for Backend {
         };
         let diagnostics =
             worker.run_diagnostic(uri, self.file_system.read().await.get(uri).as_deref()).await;
+        self.send_log_messages(worker).await;
 
         let diagnostics = match diagnostics {
             Err(err) => {
@@ -762,33 +868,45 @@ impl LanguageServer for Backend {
 
         let related_diagnostics =
             diagnostics.into_iter().filter(|(diag_uri, _)| diag_uri != uri).collect::<Vec<_>>();
+        let related_documents = if related_diagnostics.is_empty() {
+            None
+        } else {
+            Some(
+                related_diagnostics
+                    .into_iter()
+                    .map(|(diag_uri, diags)| {
+                        (
+                            diag_uri,
+                            DocumentDiagnosticReportKind::Full(FullDocumentDiagnosticReport {
+                                items: diags,
+                                ..Default::default()
+                            }),
+                        )
+                    })
+                    .collect(),
+            )
+        };
+
+        // the client can skip updating the diagnostics, when they are the same as the previous report
+        let result_id = diagnostic_result_id(&uri_diagnostics);
+        if params.previous_result_id.as_ref() == Some(&result_id) {
+            return Ok(DocumentDiagnosticReportResult::Report(
+                DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+                    unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                        result_id,
+                    },
+                    related_documents,
+                }),
+            ));
+        }
 
         Ok(DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(
             RelatedFullDocumentDiagnosticReport {
                 full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                    result_id: Some(result_id),
                     items: uri_diagnostics,
-                    ..Default::default()
-                },
-                related_documents: if related_diagnostics.is_empty() {
-                    None
-                } else {
-                    Some(
-                        related_diagnostics
-                            .into_iter()
-                            .map(|(diag_uri, diags)| {
-                                (
-                                    diag_uri,
-                                    DocumentDiagnosticReportKind::Full(
-                                        FullDocumentDiagnosticReport {
-                                            items: diags,
-                                            ..Default::default()
-                                        },
-                                    ),
-                                )
-                            })
-                            .collect(),
-                    )
                 },
+                related_documents,
             },
         )))
     }
@@ -814,6 +932,235 @@ impl LanguageServer for Backend {
             }
         }
     }
+
+    /// It will return the folding ranges for the document, provided by the tools of the responsible workspace.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_foldingRange>
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        if !self.is_feature_enabled(|features| features.folding_range) {
+            return Ok(None);
+        }
+        let uri = &params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = Self::find_worker_for_uri(&workers, uri) else {
+            return Ok(None);
+        };
+
+        let ranges =
+            worker.get_folding_ranges(uri, self.file_system.read().await.get(uri).as_deref()).await;
+
+        if ranges.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(ranges))
+    }
+
+    /// It will return the hierarchical symbols of the document, provided by the tools of the responsible workspace.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_documentSymbol>
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        if !self.is_feature_enabled(|features| features.document_symbol) {
+            return Ok(None);
+        }
+        let uri = &params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = Self::find_worker_for_uri(&workers, uri) else {
+            return Ok(None);
+        };
+
+        let symbols = worker
+            .get_document_symbols(uri, self.file_system.read().await.get(uri).as_deref())
+            .await;
+
+        if symbols.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+    }
+
+    /// It will return the symbols of all open documents matching the query, provided by the tools of their workspaces.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#workspace_symbol>
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> Result<Option<WorkspaceSymbolResponse>> {
+        if !self.is_feature_enabled(|features| features.workspace_symbol) {
+            return Ok(None);
+        }
+        let workers = self.workspace_workers.read().await;
+        let file_system = self.file_system.read().await;
+        let mut open_uris = file_system.keys();
+        open_uris.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+
+        let mut symbols = Vec::new();
+        for worker in workers.iter() {
+            let documents = open_uris
+                .iter()
+                .filter(|uri| {
+                    Self::find_worker_for_uri(&workers, uri)
+                        .is_some_and(|responsible| std::ptr::eq(responsible, worker))
+                })
+                .filter_map(|uri| Some((uri.clone(), file_system.get(uri)?)))
+                .collect::<Vec<_>>();
+            if documents.is_empty() {
+                continue;
+            }
+            symbols.extend(worker.get_workspace_symbols(&params.query, &documents).await);
+        }
+
+        if symbols.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(WorkspaceSymbolResponse::Flat(symbols)))
+    }
+
+    /// It will return the selection ranges for the requested positions, provided by the tools of the responsible workspace.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_selectionRange>
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> Result<Option<Vec<SelectionRange>>> {
+        if !self.is_feature_enabled(|features| features.selection_range) {
+            return Ok(None);
+        }
+        let uri = &params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = Self::find_worker_for_uri(&workers, uri) else {
+            return Ok(None);
+        };
+
+        let ranges = worker
+            .get_selection_ranges(
+                uri,
+                self.file_system.read().await.get(uri).as_deref(),
+                &params.positions,
+            )
+            .await;
+
+        if ranges.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(ranges))
+    }
+
+    /// It will return the range of the symbol to rename at the requested position, provided by the tools of the responsible workspace.
+    /// `None` rejects the rename, e.g. for keywords or literals.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_prepareRename>
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        if !self.is_feature_enabled(|features| features.rename) {
+            return Ok(None);
+        }
+        let uri = &params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = Self::find_worker_for_uri(&workers, uri) else {
+            return Ok(None);
+        };
+
+        let range = worker
+            .prepare_rename(uri, self.file_system.read().await.get(uri).as_deref(), params.position)
+            .await;
+
+        Ok(range.map(PrepareRenameResponse::Range))
+    }
+
+    /// It will return the edits renaming the symbol at the requested position, provided by the tools of the responsible workspace.
+    /// `None` rejects the rename.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_rename>
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        if !self.is_feature_enabled(|features| features.rename) {
+            return Ok(None);
+        }
+        let uri = &params.text_document_position.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = Self::find_worker_for_uri(&workers, uri) else {
+            return Ok(None);
+        };
+
+        let edits = worker
+            .rename(
+                uri,
+                self.file_system.read().await.get(uri).as_deref(),
+                params.text_document_position.position,
+                &params.new_name,
+            )
+            .await;
+
+        if edits.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(WorkspaceEdit {
+            #[expect(clippy::disallowed_types)]
+            changes: Some(std::collections::HashMap::from([(uri.clone(), edits)])),
+            ..WorkspaceEdit::default()
+        }))
+    }
+
+    /// It will return the completion items at the requested position, provided by the tools of the responsible workspace.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_completion>
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        if !self.is_feature_enabled(|features| features.completion) {
+            return Ok(None);
+        }
+        let uri = &params.text_document_position.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = Self::find_worker_for_uri(&workers, uri) else {
+            return Ok(None);
+        };
+
+        let items = worker
+            .get_completions(
+                uri,
+                self.file_system.read().await.get(uri).as_deref(),
+                params.text_document_position.position,
+            )
+            .await;
+
+        if items.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(CompletionResponse::Array(items)))
+    }
+
+    /// It will return the inlay hints inside the requested range, provided by the tools of the responsible workspace.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_inlayHint>
+    async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        if !self.is_feature_enabled(|features| features.inlay_hint) {
+            return Ok(None);
+        }
+        let uri = &params.text_document.uri;
+        let workers = self.workspace_workers.read().await;
+        let Some(worker) = Self::find_worker_for_uri(&workers, uri) else {
+            return Ok(None);
+        };
+
+        let hints = worker
+            .get_inlay_hints(uri, self.file_system.read().await.get(uri).as_deref(), &params.range)
+            .await;
+
+        if hints.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(hints))
+    }
 }
 
 impl Backend {
@@ -829,9 +1176,16 @@ impl Backend {
             workspace_workers: Arc::new(RwLock::new(vec![])),
             capabilities: OnceCell::new(),
             file_system: Arc::new(RwLock::new(LSPFileSystem::default())),
+            pending_diagnostics: Arc::new(Mutex::new(FxHashMap::default())),
+            workspace_scans: Mutex::new(FxHashMap::default()),
         }
     }
 
+    /// Whether the client did not disable the feature with `initializationOptions.features`.
+    fn is_feature_enabled(&self, feature: impl FnOnce(&Features) -> bool) -> bool {
+        self.capabilities.get().is_none_or(|capabilities| feature(&capabilities.features))
+    }
+
     /// Request the workspace configuration from the client
     /// and return the options for each workspace folder.
     /// The check if the client support workspace configuration, should be done before.
@@ -859,6 +1213,90 @@ impl Backend {
         configs
     }
 
+    /// Run the diagnostics for every file in the workspace of the worker in the background and publish them.
+    /// The progress of the scan is reported with `$/progress` if the client supports it.
+    ///
+    /// The scan does not hold the workers lock, a new scan of the same workspace replaces the running one.
+    /// Open documents and saved files with queued diagnostics are not published,
+    /// their diagnostics were computed from newer content than the scan read from the disk.
+    async fn scan_workspace(&self, worker: &WorkspaceWorker) {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<WorkDoneProgress>();
+        let work_done_progress = self.capabilities.get().is_some_and(|cap| cap.work_done_progress);
+        let token = NumberOrString::String(format!("oxc/scan/{}", worker.get_root_uri().as_str()));
+        let client = self.client.clone();
+
+        // The tools report the progress synchronously,
+        // forward it to the client while the scan is still running.
+        let forward_progress = tokio::spawn(async move {
+            let mut created = false;
+            while let Some(progress) = receiver.recv().await {
+                if !work_done_progress {
+                    continue;
+                }
+                // The token must be created by the client before the first notification is sent
+                if matches!(progress, WorkDoneProgress::Begin(_)) && !created {
+                    let params = WorkDoneProgressCreateParams { token: token.clone() };
+                    if let Err(err) = client.send_request::<WorkDoneProgressCreate>(params).await {
+                        warn!("sending window/workDoneProgress/create failed: {err}");
+                        return;
+                    }
+                    created = true;
+                }
+                if created {
+                    let params = ProgressParams {
+                        token: token.clone(),
+                        value: ProgressParamsValue::WorkDone(progress),
+                    };
+                    client.send_notification::<Progress>(params).await;
+                }
+            }
+        });
+
+        // The sender is dropped together with the closure when the scan is finished,
+        // which ends the forwarding task.
+        let scan = worker
+            .run_workspace_diagnostic(move |progress| {
+                // the receiver is only dropped when the client rejected the progress token
+                let _ = sender.send(progress);
+            })
+            .await;
+
+        let client = self.client.clone();
+        let file_system = Arc::clone(&self.file_system);
+        let pending = Arc::clone(&self.pending_diagnostics);
+        let task = tokio::spawn(async move {
+            let diagnostics = scan.await;
+            if let Err(err) = forward_progress.await {
+                warn!("reporting the progress of the workspace scan failed: {err}");
+            }
+
+            let file_system = file_system.read().await;
+            let pending = pending.lock().await;
+            // the locks are released before the diagnostics are sent
+            let publish = join_all(
+                diagnostics
+                    .into_iter()
+                    .filter(|(uri, _)| !file_system.contains(uri) && !pending.contains_key(uri))
+                    .map(|(uri, diagnostics)| client.publish_diagnostics(uri, diagnostics, None)),
+            );
+            drop((file_system, pending));
+            publish.await;
+        });
+
+        let previous =
+            self.workspace_scans.lock().await.insert(worker.get_root_uri().clone(), task);
+        if let Some(previous) = previous {
+            previous.abort();
+        }
+    }
+
+    /// Send the trace messages of the worker's tools as `window/logMessage` notifications.
+    async fn send_log_messages(&self, worker: &WorkspaceWorker) {
+        for message in worker.take_log_messages().await {
+            self.client.log_message(MessageType::LOG, message).await;
+        }
+    }
+
     async fn clear_diagnostics(&self, uris: Vec<Uri>) {
         self.publish_all_diagnostics(
             uris.into_iter().map(|uri| (uri, vec![])).collect(),
@@ -867,12 +1305,49 @@ impl Backend {
         .await;
     }
 
+    /// Queue the diagnostics to be published after [`PUBLISH_DIAGNOSTICS_DELAY`].
+    /// All diagnostics queued in the meantime are published together,
+    /// only the latest diagnostics of a file are published.
+    async fn queue_diagnostics(&self, result: Vec<(Uri, Vec<Diagnostic>)>) {
+        let mut pending = self.pending_diagnostics.lock().await;
+        let is_scheduled = !pending.is_empty();
+        pending.extend(result);
+        drop(pending);
+
+        if is_scheduled {
+            return;
+        }
+
+        let client = self.client.clone();
+        let pending = Arc::clone(&self.pending_diagnostics);
+        tokio::spawn(async move {
+            tokio::time::sleep(PUBLISH_DIAGNOSTICS_DELAY).await;
+            let diagnostics = std::mem::take(&mut *pending.lock().await);
+            join_all(
+                diagnostics
+                    .into_iter()
+                    .map(|(uri, diagnostics)| client.publish_diagnostics(uri, diagnostics, None)),
+            )
+            .await;
+        });
+    }
+
     /// Publish diagnostics for all files.
+    /// Queued diagnostics of these files are dropped, so they will not override the published ones.
     async fn publish_all_diagnostics(
         &self,
         result: Vec<(Uri, Vec<Diagnostic>)>,
         version_map: ConcurrentHashMap<Uri, i32>,
     ) {
+        {
+            let mut pending = self.pending_diagnostics.lock().await;
+            if !pending.is_empty() {
+                for (uri, _) in &result {
+                    pending.remove(uri);
+                }
+            }
+        }
+
         join_all(result.into_iter().map(|(uri, diagnostics)| {
             let version = version_map.pin().get(&uri).copied();
             self.client.publish_diagnostics(uri, diagnostics, version)
@@ -923,15 +1398,81 @@ impl Backend {
     }
 }
 
+/// Convert the text document edits of `edit` into plain `changes`, dropping their change annotations.
+/// Used for clients which do not support `documentChanges` with change annotations.
+fn without_change_annotations(edit: WorkspaceEdit) -> WorkspaceEdit {
+    let Some(DocumentChanges::Edits(document_edits)) = edit.document_changes else {
+        return WorkspaceEdit { change_annotations: None, ..edit };
+    };
+
+    let mut changes = edit.changes.unwrap_or_default();
+    for document_edit in document_edits {
+        changes.entry(document_edit.text_document.uri).or_default().extend(
+            document_edit.edits.into_iter().map(|text_edit| match text_edit {
+                OneOf::Left(text_edit) => text_edit,
+                OneOf::Right(annotated) => annotated.text_edit,
+            }),
+        );
+    }
+
+    WorkspaceEdit { changes: Some(changes), document_changes: None, change_annotations: None }
+}
+
+/// The `resultId` of a pull diagnostic report, derived from the content of the diagnostics.
+/// Identical diagnostics always get the same id, so the client can ask for an `unchanged` report.
+fn diagnostic_result_id(diagnostics: &[Diagnostic]) -> String {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_string(diagnostics).unwrap_or_default().hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
-    use tower_lsp_server::ls_types::Uri;
+    use tower_lsp_server::ls_types::{
+        AnnotatedTextEdit, ChangeAnnotation, DocumentChanges, OneOf,
+        OptionalVersionedTextDocumentIdentifier, Position, Range, TextDocumentEdit, TextEdit, Uri,
+        WorkspaceEdit,
+    };
 
-    use super::Backend;
+    use super::{Backend, without_change_annotations};
     use crate::{DiagnosticMode, worker::WorkspaceWorker};
 
+    #[test]
+    fn test_without_change_annotations() {
+        let uri: Uri = "file:///path/to/file.js".parse().unwrap();
+        let text_edit =
+            TextEdit::new(Range::new(Position::new(0, 0), Position::new(0, 1)), "a".into());
+        let edit = WorkspaceEdit {
+            changes: None,
+            document_changes: Some(DocumentChanges::Edits(vec![TextDocumentEdit {
+                text_document: OptionalVersionedTextDocumentIdentifier {
+                    uri: uri.clone(),
+                    version: None,
+                },
+                edits: vec![OneOf::Right(AnnotatedTextEdit {
+                    text_edit: text_edit.clone(),
+                    annotation_id: "fix".into(),
+                })],
+            }])),
+            #[expect(clippy::disallowed_types)]
+            change_annotations: Some(std::collections::HashMap::from([(
+                "fix".into(),
+                ChangeAnnotation {
+                    label: "Fix".into(),
+                    needs_confirmation: None,
+                    description: None,
+                },
+            )])),
+        };
+
+        let edit = without_change_annotations(edit);
+        assert_eq!(edit.document_changes, None);
+        assert_eq!(edit.change_annotations, None);
+        assert_eq!(edit.changes.unwrap()[&uri], vec![text_edit]);
+    }
+
     #[test]
     fn test_find_worker_for_uri_nested_workspaces() {
         let workspace = WorkspaceWorker::new(
@@ -4,6 +4,8 @@ use tower_lsp_server::ls_types::{
     WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
 };
 
+use crate::options::Features;
+
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub enum DiagnosticMode {
     Pull,
@@ -25,6 +27,12 @@ pub struct Capabilities {
     pub pull_diagnostics: bool,
     /// Whether the client supports the `workspace/diagnostic/refresh` request.
     pub refresh_diagnostics: bool,
+    /// Whether the client supports server initiated progress with `window/workDoneProgress/create`.
+    pub work_done_progress: bool,
+    /// Whether the client supports `documentChanges` with change annotations in a `WorkspaceEdit`.
+    pub change_annotations: bool,
+    /// The optional features of the server which are not disabled by the client.
+    pub features: Features,
 }
 
 impl From<ClientCapabilities> for Capabilities {
@@ -57,6 +65,20 @@ impl From<ClientCapabilities> for Capabilities {
             })
         });
 
+        let work_done_progress = value
+            .window
+            .as_ref()
+            .is_some_and(|window| window.work_done_progress.is_some_and(|progress| progress));
+
+        let change_annotations = value
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.workspace_edit.as_ref())
+            .is_some_and(|workspace_edit| {
+                workspace_edit.document_changes.is_some_and(|document_changes| document_changes)
+                    && workspace_edit.change_annotation_support.is_some()
+            });
+
         Self {
             workspace_apply_edit,
             workspace_configuration,
@@ -64,7 +86,10 @@ impl From<ClientCapabilities> for Capabilities {
             show_message,
             pull_diagnostics,
             refresh_diagnostics,
+            work_done_progress,
+            change_annotations,
             diagnostic_mode: DiagnosticMode::None,
+            features: Features::default(),
         }
     }
 }
@@ -93,7 +118,9 @@ pub fn server_capabilities() -> ServerCapabilities {
 #[cfg(test)]
 mod test {
     use tower_lsp_server::ls_types::{
-        ClientCapabilities, DidChangeWatchedFilesClientCapabilities, WorkspaceClientCapabilities,
+        ChangeAnnotationWorkspaceEditClientCapabilities, ClientCapabilities,
+        DidChangeWatchedFilesClientCapabilities, WindowClientCapabilities,
+        WorkspaceClientCapabilities, WorkspaceEditClientCapabilities,
     };
 
     use super::Capabilities;
@@ -147,4 +174,42 @@ mod test {
         let capabilities = Capabilities::from(client_capabilities);
         assert!(capabilities.dynamic_watchers);
     }
+
+    #[test]
+    fn test_work_done_progress_vscode() {
+        let client_capabilities = ClientCapabilities {
+            window: Some(WindowClientCapabilities {
+                work_done_progress: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let capabilities = Capabilities::from(client_capabilities);
+        assert!(capabilities.work_done_progress);
+        assert!(!Capabilities::from(ClientCapabilities::default()).work_done_progress);
+    }
+
+    #[test]
+    fn test_change_annotations_vscode() {
+        let client_capabilities = ClientCapabilities {
+            workspace: Some(WorkspaceClientCapabilities {
+                workspace_edit: Some(WorkspaceEditClientCapabilities {
+                    document_changes: Some(true),
+                    change_annotation_support: Some(
+                        ChangeAnnotationWorkspaceEditClientCapabilities {
+                            groups_on_label: Some(true),
+                        },
+                    ),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let capabilities = Capabilities::from(client_capabilities);
+        assert!(capabilities.change_annotations);
+        assert!(!Capabilities::from(ClientCapabilities::default()).change_annotations);
+    }
 }
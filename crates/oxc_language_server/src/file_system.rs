@@ -20,6 +20,10 @@ impl LSPFileSystem {
         self.files.pin().get(uri).cloned()
     }
 
+    pub fn contains(&self, uri: &Uri) -> bool {
+        self.files.pin().contains_key(uri)
+    }
+
     pub fn remove(&self, uri: &Uri) {
         self.files.pin().remove(uri);
     }
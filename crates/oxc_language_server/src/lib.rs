@@ -11,7 +11,9 @@ mod tool;
 mod worker;
 
 pub use crate::capabilities::{Capabilities, DiagnosticMode};
-pub use crate::tool::{DiagnosticResult, Tool, ToolBuilder, ToolRestartChanges};
+pub use crate::file_system::LSPFileSystem;
+pub use crate::options::Features;
+pub use crate::tool::{CommandResult, DiagnosticResult, Tool, ToolBuilder, ToolRestartChanges};
 
 pub type ConcurrentHashMap<K, V> = papaya::HashMap<K, V, FxBuildHasher>;
 
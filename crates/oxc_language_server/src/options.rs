@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use tower_lsp_server::ls_types::Uri;
+use tower_lsp_server::ls_types::{ServerCapabilities, Uri};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8,11 +8,76 @@ pub struct WorkspaceOption {
     pub options: serde_json::Value,
 }
 
+/// The optional features of the server, the client can turn them off with `initializationOptions.features`.
+///
+/// Disabled features are not advertised in the server capabilities and their requests return `null`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Features {
+    pub folding_range: bool,
+    pub document_symbol: bool,
+    pub workspace_symbol: bool,
+    pub selection_range: bool,
+    pub inlay_hint: bool,
+    pub rename: bool,
+    pub completion: bool,
+}
+
+impl Default for Features {
+    fn default() -> Self {
+        Self {
+            folding_range: true,
+            document_symbol: true,
+            workspace_symbol: true,
+            selection_range: true,
+            inlay_hint: true,
+            rename: true,
+            completion: true,
+        }
+    }
+}
+
+impl Features {
+    /// Read the features from the `initializationOptions` of the `initialize` request.
+    /// Falls back to enabling every feature when they are missing or invalid.
+    pub fn from_initialization_options(value: Option<&serde_json::Value>) -> Self {
+        value
+            .and_then(|value| value.get("features"))
+            .and_then(|features| serde_json::from_value(features.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    /// Remove the providers of the disabled features from the server capabilities.
+    pub fn apply(self, capabilities: &mut ServerCapabilities) {
+        if !self.folding_range {
+            capabilities.folding_range_provider = None;
+        }
+        if !self.document_symbol {
+            capabilities.document_symbol_provider = None;
+        }
+        if !self.workspace_symbol {
+            capabilities.workspace_symbol_provider = None;
+        }
+        if !self.selection_range {
+            capabilities.selection_range_provider = None;
+        }
+        if !self.inlay_hint {
+            capabilities.inlay_hint_provider = None;
+        }
+        if !self.rename {
+            capabilities.rename_provider = None;
+        }
+        if !self.completion {
+            capabilities.completion_provider = None;
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use serde_json::json;
 
-    use super::WorkspaceOption;
+    use super::{Features, WorkspaceOption};
 
     #[test]
     fn test_workspace_options_json() {
@@ -33,4 +98,31 @@ mod test {
         let options = &workspace[0].options;
         assert_eq!(options["run"], true);
     }
+
+    #[test]
+    fn test_features_json() {
+        let features = Features::from_initialization_options(Some(&json!({
+            "features": { "foldingRange": false, "completion": false, "unknown": false }
+        })));
+
+        assert!(!features.folding_range);
+        assert!(!features.completion);
+        assert!(features.document_symbol);
+        assert!(features.rename);
+    }
+
+    #[test]
+    fn test_features_missing_or_invalid() {
+        assert_eq!(Features::from_initialization_options(None), Features::default());
+        assert_eq!(
+            Features::from_initialization_options(Some(
+                &json!([{ "workspaceUri": "file:///root/" }])
+            )),
+            Features::default()
+        );
+        assert_eq!(
+            Features::from_initialization_options(Some(&json!({ "features": true }))),
+            Features::default()
+        );
+    }
 }
@@ -9,7 +9,8 @@ use tower_lsp_server::{
 };
 
 use crate::{
-    DiagnosticMode, Tool, ToolBuilder, ToolRestartChanges, backend::Backend, tool::DiagnosticResult,
+    CommandResult, DiagnosticMode, LSPFileSystem, Tool, ToolBuilder, ToolRestartChanges,
+    backend::Backend, tool::DiagnosticResult,
 };
 
 #[derive(Default)]
@@ -24,8 +25,8 @@ impl FakeToolBuilder {
 }
 
 impl ToolBuilder for FakeToolBuilder {
-    fn build_boxed(&self, _root_uri: &Uri, _options: serde_json::Value) -> Box<dyn Tool> {
-        Box::new(FakeTool)
+    fn build_boxed(&self, root_uri: &Uri, _options: serde_json::Value) -> Box<dyn Tool> {
+        Box::new(FakeTool { root_uri: Some(root_uri.clone()), ..Default::default() })
     }
 
     fn server_capabilities(
@@ -42,10 +43,16 @@ impl ToolBuilder for FakeToolBuilder {
             } else {
                 None
             };
+        capabilities.folding_range_provider = Some(FoldingRangeProviderCapability::Simple(true));
+        capabilities.document_symbol_provider = Some(OneOf::Left(true));
     }
 }
 
-pub struct FakeTool;
+#[derive(Default)]
+pub struct FakeTool {
+    root_uri: Option<Uri>,
+    log_messages: std::sync::Mutex<Vec<String>>,
+}
 
 pub const FAKE_COMMAND: &str = "fake.command";
 
@@ -66,18 +73,44 @@ impl Tool for FakeTool {
         &self,
         command: &str,
         arguments: Vec<serde_json::Value>,
-    ) -> Result<Option<WorkspaceEdit>, ErrorCode> {
+        _file_system: &LSPFileSystem,
+    ) -> Result<Option<CommandResult>, ErrorCode> {
         if command != FAKE_COMMAND {
             return Err(ErrorCode::MethodNotFound);
         }
 
+        if arguments == [json!("value")] {
+            return Ok(Some(CommandResult::Value(json!({ "fake": true }))));
+        }
+
+        if arguments == [json!("diagnostics")] {
+            return Ok(Some(CommandResult::Diagnostics(vec![(
+                format!("{WORKSPACE}/file.js").parse().unwrap(),
+                vec![Diagnostic::default()],
+            )])));
+        }
+
+        if arguments == [json!("message")] {
+            return Ok(Some(CommandResult::WorkspaceEdit {
+                edit: WorkspaceEdit::default(),
+                message: Some("fake message".to_string()),
+            }));
+        }
+
         if !arguments.is_empty() {
-            return Ok(Some(WorkspaceEdit::default()));
+            return Ok(Some(CommandResult::WorkspaceEdit {
+                edit: WorkspaceEdit::default(),
+                message: None,
+            }));
         }
 
         Ok(None)
     }
 
+    fn merge_command_values(&self, _command: &str, values: Vec<Value>) -> Value {
+        json!({ "fake": true, "workspaces": values.len() })
+    }
+
     fn handle_configuration_change(
         &self,
         builder: &dyn ToolBuilder,
@@ -136,6 +169,7 @@ impl Tool for FakeTool {
     fn get_code_actions_or_commands(
         &self,
         uri: &Uri,
+        _content: Option<&str>,
         _range: &Range,
         _only_code_action_kinds: Option<&Vec<CodeActionKind>>,
     ) -> Vec<CodeActionOrCommand> {
@@ -169,18 +203,183 @@ impl Tool for FakeTool {
             return Err("Fake diagnostic error".to_string());
         }
 
+        if uri.as_str().ends_with("log.config") {
+            self.log_messages.lock().unwrap().push(format!("linted {}", uri.as_str()));
+        }
+
         Ok(Vec::new())
     }
 
+    fn run_workspace_diagnostic(
+        &self,
+        _progress: &(dyn Fn(WorkDoneProgress) + Sync),
+    ) -> DiagnosticResult {
+        let Some(root_uri) = &self.root_uri else {
+            return Ok(Vec::new());
+        };
+        if !root_uri.as_str().ends_with("scanned_folder") {
+            return Ok(Vec::new());
+        }
+
+        let uri: Uri = format!("{}/scanned.config", root_uri.as_str()).parse().unwrap();
+        Ok(vec![(
+            uri,
+            vec![Diagnostic {
+                message: "Fake workspace diagnostic".to_string(),
+                ..Default::default()
+            }],
+        )])
+    }
+
     fn run_diagnostic_on_change(&self, uri: &Uri, content: Option<&str>) -> DiagnosticResult {
         // For this fake tool, we use the same logic as run_diagnostic
         self.run_diagnostic(uri, content)
     }
 
+    fn take_log_messages(&self) -> Vec<String> {
+        std::mem::take(&mut *self.log_messages.lock().unwrap())
+    }
+
     fn run_diagnostic_on_save(&self, uri: &Uri, content: Option<&str>) -> DiagnosticResult {
         // For this fake tool, we use the same logic as run_diagnostic
         self.run_diagnostic(uri, content)
     }
+
+    fn get_folding_ranges(&self, uri: &Uri, _content: Option<&str>) -> Vec<FoldingRange> {
+        if uri.as_str().ends_with("folding_range.config") {
+            return vec![FoldingRange {
+                start_line: 0,
+                end_line: 2,
+                kind: Some(FoldingRangeKind::Region),
+                ..Default::default()
+            }];
+        }
+
+        vec![]
+    }
+
+    #[expect(deprecated)] // `DocumentSymbol::deprecated` is deprecated in favor of `tags`
+    fn get_document_symbols(&self, uri: &Uri, _content: Option<&str>) -> Vec<DocumentSymbol> {
+        if uri.as_str().ends_with("document_symbol.config") {
+            let range = Range::new(Position::new(0, 0), Position::new(2, 1));
+            let selection_range = Range::new(Position::new(0, 6), Position::new(0, 9));
+            return vec![DocumentSymbol {
+                name: "Foo".to_string(),
+                detail: None,
+                kind: SymbolKind::CLASS,
+                tags: None,
+                deprecated: None,
+                range,
+                selection_range,
+                children: Some(vec![]),
+            }];
+        }
+
+        vec![]
+    }
+
+    #[expect(deprecated)] // `SymbolInformation::deprecated` is deprecated in favor of `tags`
+    fn get_workspace_symbols(
+        &self,
+        query: &str,
+        documents: &[(Uri, String)],
+    ) -> Vec<SymbolInformation> {
+        documents
+            .iter()
+            .filter(|(_, content)| content.contains(query))
+            .map(|(uri, content)| SymbolInformation {
+                name: content.clone(),
+                kind: SymbolKind::VARIABLE,
+                tags: None,
+                deprecated: None,
+                location: Location::new(uri.clone(), Range::default()),
+                container_name: None,
+            })
+            .collect()
+    }
+
+    fn get_selection_ranges(
+        &self,
+        uri: &Uri,
+        _content: Option<&str>,
+        positions: &[Position],
+    ) -> Vec<SelectionRange> {
+        if uri.as_str().ends_with("selection_range.config") {
+            return positions
+                .iter()
+                .map(|position| SelectionRange {
+                    range: Range::new(*position, *position),
+                    parent: Some(Box::new(SelectionRange {
+                        range: Range::new(Position::new(0, 0), Position::new(2, 1)),
+                        parent: None,
+                    })),
+                })
+                .collect();
+        }
+
+        vec![]
+    }
+
+    fn get_completions(
+        &self,
+        uri: &Uri,
+        _content: Option<&str>,
+        _position: Position,
+    ) -> Vec<CompletionItem> {
+        if uri.as_str().ends_with("completion.config") {
+            return vec![CompletionItem::new_simple("fake".to_string(), "fake item".to_string())];
+        }
+
+        Vec::new()
+    }
+
+    fn prepare_rename(
+        &self,
+        uri: &Uri,
+        _content: Option<&str>,
+        position: Position,
+    ) -> Option<Range> {
+        // Only the first word of the first line can be renamed
+        if uri.as_str().ends_with("rename.config") && position.line == 0 && position.character <= 3
+        {
+            return Some(Range::new(Position::new(0, 0), Position::new(0, 3)));
+        }
+
+        None
+    }
+
+    fn rename(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        position: Position,
+        new_name: &str,
+    ) -> Vec<TextEdit> {
+        self.prepare_rename(uri, content, position)
+            .map(|range| vec![TextEdit::new(range, new_name.to_string())])
+            .unwrap_or_default()
+    }
+
+    fn get_inlay_hints(&self, uri: &Uri, _content: Option<&str>, range: &Range) -> Vec<InlayHint> {
+        if uri.as_str().ends_with("inlay_hint.config") {
+            return [Position::new(0, 10), Position::new(5, 10)]
+                .into_iter()
+                .filter(|position| range.start <= *position && *position <= range.end)
+                .map(|position| InlayHint {
+                    position,
+                    label: InlayHintLabel::String("unused".to_string()),
+                    kind: None,
+                    text_edits: None,
+                    tooltip: None,
+                    padding_left: Some(true),
+                    padding_right: None,
+                    data: None,
+                })
+                .collect();
+        }
+
+        vec![]
+    }
 }
 
 // A test server that can send requests and receive responses.
@@ -357,6 +556,7 @@ struct InitializeRequestOptions {
     workspace_configuration: bool,
     dynamic_watchers: bool,
     workspace_edit: bool,
+    show_message: bool,
     pull_mode: bool,
     initialization_options: Option<Value>,
     workspace_folders: Option<Vec<WorkspaceFolder>>,
@@ -387,6 +587,10 @@ fn initialize_request_workspace_folders(options: InitializeRequestOptions) -> Re
                 }),
                 ..Default::default()
             }),
+            window: options.show_message.then(|| WindowClientCapabilities {
+                show_message: Some(ShowMessageRequestClientCapabilities::default()),
+                ..Default::default()
+            }),
             ..Default::default()
         },
         initialization_options: options.initialization_options,
@@ -553,11 +757,11 @@ fn test_configuration_request(id: i64) -> Request {
     Request::build("test/configuration").id(id).params(json!(null)).finish()
 }
 
-fn diagnostic(id: i64, uri: &str) -> Request {
+fn diagnostic(id: i64, uri: &str, previous_result_id: Option<String>) -> Request {
     let params = DocumentDiagnosticParams {
         text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
         identifier: None,
-        previous_result_id: None,
+        previous_result_id,
         work_done_progress_params: WorkDoneProgressParams::default(),
         partial_result_params: PartialResultParams::default(),
     };
@@ -565,14 +769,102 @@ fn diagnostic(id: i64, uri: &str) -> Request {
     Request::build("textDocument/diagnostic").id(id).params(json!(params)).finish()
 }
 
+fn folding_range(id: i64, uri: &str) -> Request {
+    let params = FoldingRangeParams {
+        text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+
+    Request::build("textDocument/foldingRange").id(id).params(json!(params)).finish()
+}
+
+fn document_symbol(id: i64, uri: &str) -> Request {
+    let params = DocumentSymbolParams {
+        text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+
+    Request::build("textDocument/documentSymbol").id(id).params(json!(params)).finish()
+}
+
+fn workspace_symbol(id: i64, query: &str) -> Request {
+    let params = WorkspaceSymbolParams {
+        query: query.to_string(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+
+    Request::build("workspace/symbol").id(id).params(json!(params)).finish()
+}
+
+fn selection_range(id: i64, uri: &str, positions: Vec<Position>) -> Request {
+    let params = SelectionRangeParams {
+        text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
+        positions,
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+
+    Request::build("textDocument/selectionRange").id(id).params(json!(params)).finish()
+}
+
+fn completion(id: i64, uri: &str, position: Position) -> Request {
+    let params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
+            position,
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    Request::build("textDocument/completion").id(id).params(json!(params)).finish()
+}
+
+fn prepare_rename(id: i64, uri: &str, position: Position) -> Request {
+    let params = TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
+        position,
+    };
+
+    Request::build("textDocument/prepareRename").id(id).params(json!(params)).finish()
+}
+
+fn rename(id: i64, uri: &str, position: Position, new_name: &str) -> Request {
+    let params = RenameParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
+            position,
+        },
+        new_name: new_name.to_string(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+
+    Request::build("textDocument/rename").id(id).params(json!(params)).finish()
+}
+
+fn inlay_hint(id: i64, uri: &str, range: Range) -> Request {
+    let params = InlayHintParams {
+        text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
+        range,
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+
+    Request::build("textDocument/inlayHint").id(id).params(json!(params)).finish()
+}
+
 #[cfg(test)]
 mod test_suite {
     use serde_json::{Value, json};
     use tower_lsp_server::{
         jsonrpc::{Error, ErrorCode, Id, Response},
         ls_types::{
-            ApplyWorkspaceEditResponse, InitializeResult, PublishDiagnosticsParams, ServerInfo,
-            WorkspaceEdit, WorkspaceFolder,
+            ApplyWorkspaceEditResponse, InitializeResult, LogMessageParams, MessageType, Position,
+            PublishDiagnosticsParams, Range, ServerInfo, ShowMessageParams, WorkspaceEdit,
+            WorkspaceFolder,
         },
     };
 
@@ -582,11 +874,12 @@ mod test_suite {
         tests::{
             FAKE_COMMAND, FakeToolBuilder, InitializeRequestOptions, TestServer, WORKSPACE,
             WORKSPACE_2, acknowledge_diagnostic_refresh, acknowledge_registrations,
-            acknowledge_unregistrations, code_action, diagnostic, did_change,
+            acknowledge_unregistrations, code_action, completion, diagnostic, did_change,
             did_change_configuration, did_change_watched_files, did_close, did_open, did_save,
-            execute_command_request, initialize_request, initialize_request_workspace_folders,
-            initialized_notification, response_to_configuration, shutdown_request,
-            test_configuration_request, workspace_folders_changed,
+            document_symbol, execute_command_request, folding_range, initialize_request,
+            initialize_request_workspace_folders, initialized_notification, inlay_hint,
+            prepare_rename, rename, response_to_configuration, selection_range, shutdown_request,
+            test_configuration_request, workspace_folders_changed, workspace_symbol,
         },
     };
 
@@ -873,6 +1166,85 @@ mod test_suite {
         server.shutdown(4).await;
     }
 
+    #[tokio::test]
+    async fn test_execute_workspace_command_with_message() {
+        let init_options = InitializeRequestOptions {
+            workspace_edit: true,
+            show_message: true,
+            ..Default::default()
+        };
+
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(init_options),
+        )
+        .await;
+
+        let execute_command_request = execute_command_request(FAKE_COMMAND, &[json!("message")], 3);
+        server.send_request(execute_command_request).await;
+
+        let apply_edit_request = server.recv_notification().await;
+        assert_eq!(apply_edit_request.method(), "workspace/applyEdit");
+        server
+            .send_response(Response::from_ok(
+                apply_edit_request.id().unwrap().clone(),
+                json!(ApplyWorkspaceEditResponse {
+                    applied: true,
+                    failure_reason: None,
+                    failed_change: None
+                }),
+            ))
+            .await;
+
+        // the message is shown after the edit was applied
+        let show_message = server.recv_notification().await;
+        assert_eq!(show_message.method(), "window/showMessage");
+        assert_eq!(
+            show_message.params(),
+            Some(&json!(ShowMessageParams {
+                typ: MessageType::INFO,
+                message: "fake message".to_string()
+            }))
+        );
+
+        let execute_command_response = server.recv_response().await;
+        assert!(execute_command_response.is_ok());
+        assert_eq!(execute_command_response.id(), &Id::Number(3));
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_execute_workspace_command_with_diagnostics() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let execute_command_request =
+            execute_command_request(FAKE_COMMAND, &[json!("diagnostics")], 3);
+        server.send_request(execute_command_request).await;
+
+        let publish_diagnostics = server.recv_notification().await;
+        assert_eq!(publish_diagnostics.method(), "textDocument/publishDiagnostics");
+        let params: PublishDiagnosticsParams =
+            serde_json::from_value(publish_diagnostics.params().unwrap().clone()).unwrap();
+        assert_eq!(params.uri.as_str(), format!("{WORKSPACE}/file.js"));
+        assert_eq!(params.diagnostics.len(), 1);
+
+        let execute_command_response = server.recv_response().await;
+        assert!(execute_command_response.is_ok());
+        assert_eq!(execute_command_response.id(), &Id::Number(3));
+        assert_eq!(execute_command_response.result().unwrap(), &json!(null));
+
+        server.shutdown(4).await;
+    }
+
     #[tokio::test]
     async fn test_initialize_with_options_and_multiple_workspace_folders() {
         let init_options = InitializeRequestOptions {
@@ -960,6 +1332,62 @@ mod test_suite {
         server.shutdown(4).await;
     }
 
+    #[tokio::test]
+    async fn test_execute_workspace_command_with_value() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let execute_command_request = execute_command_request(FAKE_COMMAND, &[json!("value")], 3);
+        server.send_request(execute_command_request).await;
+
+        // Should get a direct response with the value, no workspace edit is applied
+        let execute_command_response = server.recv_response().await;
+        assert!(execute_command_response.is_ok());
+        assert_eq!(execute_command_response.id(), &Id::Number(3));
+        assert_eq!(execute_command_response.result().unwrap(), &json!({ "fake": true }));
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_execute_workspace_command_with_values_of_multiple_workspaces() {
+        let init_options = InitializeRequestOptions {
+            workspace_folders: Some(vec![
+                WorkspaceFolder { uri: WORKSPACE.parse().unwrap(), name: "workspace".to_string() },
+                WorkspaceFolder {
+                    uri: WORKSPACE_2.parse().unwrap(),
+                    name: "workspace_2".to_string(),
+                },
+            ]),
+            ..Default::default()
+        };
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request_workspace_folders(init_options),
+        )
+        .await;
+
+        let execute_command_request = execute_command_request(FAKE_COMMAND, &[json!("value")], 3);
+        server.send_request(execute_command_request).await;
+
+        // The values of both workspaces are merged by the tool
+        let execute_command_response = server.recv_response().await;
+        assert!(execute_command_response.is_ok());
+        assert_eq!(
+            execute_command_response.result().unwrap(),
+            &json!({ "fake": true, "workspaces": 2 })
+        );
+
+        server.shutdown(4).await;
+    }
+
     #[tokio::test]
     async fn test_execute_workspace_command_with_invalid_command() {
         let mut server = TestServer::new_initialized(
@@ -1008,38 +1436,146 @@ mod test_suite {
     }
 
     #[tokio::test]
-    async fn test_workspace_added_watchers() {
+    async fn test_workspace_added_scanned() {
         // workspace/didChangeWorkspaceFolders notification
         let folders_changed_notification = workspace_folders_changed(
             vec![WorkspaceFolder {
-                uri: "file:///path/to/new_folder".parse().unwrap(),
-                name: "new_folder".to_string(),
+                uri: "file:///path/to/scanned_folder".parse().unwrap(),
+                name: "scanned_folder".to_string(),
             }],
             vec![],
         );
 
-        let init_options =
-            InitializeRequestOptions { dynamic_watchers: true, ..Default::default() };
-
         let mut server = TestServer::new_initialized(
             |client| {
-                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+                Backend::new(
+                    client,
+                    server_info(),
+                    vec![Box::new(FakeToolBuilder::new(DiagnosticMode::Push))],
+                )
             },
-            initialize_request(init_options),
+            initialize_request(InitializeRequestOptions::default()),
         )
         .await;
-        acknowledge_registrations(&mut server).await;
         server.send_request(folders_changed_notification).await;
 
-        // new watcher registration expected
-        acknowledge_registrations(&mut server).await;
-        server.shutdown(4).await;
+        // the new workspace folder is scanned and its diagnostics are published
+        let uri = "file:///path/to/scanned_folder/scanned.config";
+        let diagnostic_response = server.recv_notification().await;
+        assert_eq!(diagnostic_response.method(), "textDocument/publishDiagnostics");
+        let params: PublishDiagnosticsParams =
+            serde_json::from_value(diagnostic_response.params().unwrap().clone()).unwrap();
+        assert_eq!(params.uri, uri.parse().unwrap());
+        assert_eq!(params.diagnostics.len(), 1);
+        assert_eq!(params.diagnostics[0].message, "Fake workspace diagnostic");
+
+        server.shutdown_with_diagnostic_clear(3, vec![uri.parse().unwrap()]).await;
     }
 
     #[tokio::test]
-    async fn test_workspace_added_configuration() {
-        // workspace/didChangeWorkspaceFolders notification
-        let folders_changed_notification = workspace_folders_changed(
+    async fn test_workspace_added_scanned_skips_open_documents() {
+        let init_options = InitializeRequestOptions {
+            workspace_folders: Some(vec![WorkspaceFolder {
+                uri: "file:///path/to".parse().unwrap(),
+                name: "to".to_string(),
+            }]),
+            ..Default::default()
+        };
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(
+                    client,
+                    server_info(),
+                    vec![Box::new(FakeToolBuilder::new(DiagnosticMode::Push))],
+                )
+            },
+            initialize_request_workspace_folders(init_options),
+        )
+        .await;
+
+        // the open document has no diagnostics, so nothing is published for it
+        let uri = "file:///path/to/scanned_folder/scanned.config";
+        server.send_request(did_open(uri, "unsaved content")).await;
+
+        server
+            .send_request(workspace_folders_changed(
+                vec![WorkspaceFolder {
+                    uri: "file:///path/to/scanned_folder".parse().unwrap(),
+                    name: "scanned_folder".to_string(),
+                }],
+                vec![],
+            ))
+            .await;
+
+        server
+            .send_request(workspace_folders_changed(
+                vec![WorkspaceFolder {
+                    uri: "file:///path/to/other/scanned_folder".parse().unwrap(),
+                    name: "scanned_folder".to_string(),
+                }],
+                vec![],
+            ))
+            .await;
+
+        // the scan result of the open document must not overwrite its diagnostics,
+        // only the closed document of the other folder is published
+        let other_uri = "file:///path/to/other/scanned_folder/scanned.config";
+        let diagnostic_response = server.recv_notification().await;
+        assert_eq!(diagnostic_response.method(), "textDocument/publishDiagnostics");
+        let params: PublishDiagnosticsParams =
+            serde_json::from_value(diagnostic_response.params().unwrap().clone()).unwrap();
+        assert_eq!(params.uri, other_uri.parse().unwrap());
+
+        // both scanned files are cleared on shutdown, in no particular order
+        server.send_request(shutdown_request(3)).await;
+        let mut cleared = Vec::new();
+        for _ in 0..2 {
+            let publish_diagnostics = server.recv_notification().await;
+            assert_eq!(publish_diagnostics.method(), "textDocument/publishDiagnostics");
+            let params: PublishDiagnosticsParams =
+                serde_json::from_value(publish_diagnostics.params().unwrap().clone()).unwrap();
+            assert!(params.diagnostics.is_empty());
+            cleared.push(params.uri.as_str().to_string());
+        }
+        cleared.sort();
+        assert_eq!(cleared, vec![other_uri, uri]);
+        let shutdown_result = server.recv_response().await;
+        assert!(shutdown_result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_workspace_added_watchers() {
+        // workspace/didChangeWorkspaceFolders notification
+        let folders_changed_notification = workspace_folders_changed(
+            vec![WorkspaceFolder {
+                uri: "file:///path/to/new_folder".parse().unwrap(),
+                name: "new_folder".to_string(),
+            }],
+            vec![],
+        );
+
+        let init_options =
+            InitializeRequestOptions { dynamic_watchers: true, ..Default::default() };
+
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(init_options),
+        )
+        .await;
+        acknowledge_registrations(&mut server).await;
+        server.send_request(folders_changed_notification).await;
+
+        // new watcher registration expected
+        acknowledge_registrations(&mut server).await;
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_workspace_added_configuration() {
+        // workspace/didChangeWorkspaceFolders notification
+        let folders_changed_notification = workspace_folders_changed(
             vec![WorkspaceFolder {
                 uri: "file:///path/to/new_folder".parse().unwrap(),
                 name: "new_folder".to_string(),
@@ -1541,6 +2077,33 @@ mod test_suite {
         server.shutdown_with_diagnostic_clear(4, vec![file.parse().unwrap()]).await;
     }
 
+    #[tokio::test]
+    async fn test_log_message_on_open() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(
+                    client,
+                    server_info(),
+                    vec![Box::new(FakeToolBuilder::new(DiagnosticMode::Push))],
+                )
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/log.config");
+        server.send_request(did_open(&file, "some text")).await;
+
+        let log_notification = server.recv_notification().await;
+        assert_eq!(log_notification.method(), "window/logMessage");
+        let params: LogMessageParams =
+            serde_json::from_value(log_notification.params().unwrap().clone()).unwrap();
+        assert_eq!(params.typ, MessageType::LOG);
+        assert_eq!(params.message, format!("linted {file}"));
+
+        server.shutdown(4).await;
+    }
+
     /// This test verifies that the tool is not requested to provide diagnostics,
     /// when ALL tools doe not change diagnostics mode.
     #[tokio::test]
@@ -1641,6 +2204,63 @@ mod test_suite {
         server.shutdown_with_diagnostic_clear(4, vec![file.parse().unwrap()]).await;
     }
 
+    #[tokio::test]
+    async fn test_diagnostics_on_save_are_batched() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(
+                    client,
+                    server_info(),
+                    vec![Box::new(FakeToolBuilder::new(DiagnosticMode::Push))],
+                )
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/diagnostics.config");
+        let other_file = format!("{WORKSPACE}/other/diagnostics.config");
+        for file in [&file, &other_file] {
+            server.send_request(did_open(file, "old text")).await;
+            let diagnostic_response = server.recv_notification().await;
+            assert_eq!(diagnostic_response.method(), "textDocument/publishDiagnostics");
+        }
+
+        // saving the same file multiple times in a short period only publishes the latest diagnostics
+        server.send_request(did_save(&file, "first save")).await;
+        server.send_request(did_save(&other_file, "other save")).await;
+        server.send_request(did_save(&file, "second save")).await;
+
+        let mut messages = Vec::new();
+        for _ in 0..2 {
+            let diagnostic_response = server.recv_notification().await;
+            assert_eq!(diagnostic_response.method(), "textDocument/publishDiagnostics");
+            let params: PublishDiagnosticsParams =
+                serde_json::from_value(diagnostic_response.params().unwrap().clone()).unwrap();
+            assert_eq!(params.diagnostics.len(), 1);
+            messages.push((params.uri, params.diagnostics[0].message.clone()));
+        }
+        messages.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+        assert_eq!(
+            messages,
+            vec![
+                (file.parse().unwrap(), "Fake diagnostic for content: second save".to_string()),
+                (
+                    other_file.parse().unwrap(),
+                    "Fake diagnostic for content: other save".to_string()
+                ),
+            ]
+        );
+
+        // no other diagnostics are published before the ones of the shutdown
+        server
+            .shutdown_with_diagnostic_clear(
+                4,
+                vec![file.parse().unwrap(), other_file.parse().unwrap()],
+            )
+            .await;
+    }
+
     #[tokio::test]
     async fn test_no_diagnostics_on_pull_mode_on_save() {
         let init_options = InitializeRequestOptions { pull_mode: true, ..Default::default() };
@@ -1685,7 +2305,7 @@ mod test_suite {
         let content = "pull mode text";
         server.send_request(did_open(&file, content)).await;
 
-        server.send_request(diagnostic(3, &file)).await;
+        server.send_request(diagnostic(3, &file, None)).await;
 
         let diagnostic_response = server.recv_response().await;
         assert!(diagnostic_response.is_ok());
@@ -1703,4 +2323,475 @@ mod test_suite {
 
         server.shutdown(4).await;
     }
+
+    #[tokio::test]
+    async fn test_diagnostics_pull_mode_unchanged() {
+        let init_options = InitializeRequestOptions { pull_mode: true, ..Default::default() };
+
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(
+                    client,
+                    server_info(),
+                    vec![Box::new(FakeToolBuilder::new(DiagnosticMode::Pull))],
+                )
+            },
+            initialize_request(init_options),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/diagnostics.config");
+        server.send_request(did_open(&file, "pull mode text")).await;
+
+        // the same content is linted twice, resulting in the same id
+        server.send_request(diagnostic(3, &file, None)).await;
+        let first = server.recv_response().await.result().unwrap().clone();
+        server.send_request(diagnostic(4, &file, None)).await;
+        let second = server.recv_response().await.result().unwrap().clone();
+        assert_eq!(first["kind"], "full");
+        let result_id = first["resultId"].as_str().unwrap().to_string();
+        assert_eq!(second["resultId"], result_id.as_str());
+
+        // the client already has the diagnostics of this id
+        server.send_request(diagnostic(5, &file, Some(result_id.clone()))).await;
+        let unchanged = server.recv_response().await.result().unwrap().clone();
+        assert_eq!(unchanged["kind"], "unchanged");
+        assert_eq!(unchanged["resultId"], result_id.as_str());
+        assert!(unchanged.get("items").is_none());
+
+        // different content results in a new full report
+        server.send_request(did_change(&file, "changed text")).await;
+        server.send_request(diagnostic(6, &file, Some(result_id.clone()))).await;
+        let changed = server.recv_response().await.result().unwrap().clone();
+        assert_eq!(changed["kind"], "full");
+        assert_ne!(changed["resultId"], result_id.as_str());
+
+        server.shutdown(7).await;
+    }
+
+    #[tokio::test]
+    async fn test_folding_range_no_ranges() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/file.txt");
+        server.send_request(did_open(&file, "some text")).await;
+
+        server.send_request(folding_range(3, &file)).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        assert!(response.result().is_some_and(|result| *result == Value::Null));
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_folding_range_with_ranges() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/folding_range.config");
+        server.send_request(did_open(&file, "some text")).await;
+
+        server.send_request(folding_range(3, &file)).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        let ranges: Vec<serde_json::Value> =
+            serde_json::from_value(response.result().unwrap().clone()).unwrap();
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0]["startLine"], 0);
+        assert_eq!(ranges[0]["endLine"], 2);
+        assert_eq!(ranges[0]["kind"], "region");
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_disabled_feature() {
+        let mut server = TestServer::new(|client| {
+            Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+        });
+        server
+            .send_request(initialize_request(InitializeRequestOptions {
+                initialization_options: Some(json!({ "features": { "foldingRange": false } })),
+                ..Default::default()
+            }))
+            .await;
+        let initialize_response = server.recv_response().await;
+        assert!(initialize_response.is_ok());
+        let capabilities = &initialize_response.result().unwrap()["capabilities"];
+        assert!(capabilities.get("foldingRangeProvider").is_none());
+        assert_eq!(capabilities["documentSymbolProvider"], true);
+        server.send_request(initialized_notification()).await;
+
+        let file = format!("{WORKSPACE}/folding_range.config");
+        server.send_request(did_open(&file, "some text")).await;
+
+        server.send_request(folding_range(3, &file)).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        assert!(response.result().is_some_and(|result| *result == Value::Null));
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_disabled_feature_with_workspace_options() {
+        let init_options = InitializeRequestOptions {
+            initialization_options: Some(json!({
+                "features": { "foldingRange": false },
+                "workspaces": [{
+                    "workspaceUri": WORKSPACE,
+                    "options": { "run": true }
+                }]
+            })),
+            ..Default::default()
+        };
+        let mut server = TestServer::new(|client| {
+            Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+        });
+        server.send_request(initialize_request(init_options)).await;
+        let initialize_response = server.recv_response().await;
+        assert!(initialize_response.is_ok());
+        let capabilities = &initialize_response.result().unwrap()["capabilities"];
+        assert!(capabilities.get("foldingRangeProvider").is_none());
+        server.send_request(initialized_notification()).await;
+
+        server.send_request(test_configuration_request(2)).await;
+        let config_response = server.recv_response().await;
+        assert!(config_response.is_ok());
+        assert_eq!(*config_response.result().unwrap(), json!([{ "run": true }]));
+
+        server.shutdown(3).await;
+    }
+
+    #[tokio::test]
+    async fn test_document_symbol_no_symbols() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/file.txt");
+        server.send_request(did_open(&file, "some text")).await;
+
+        server.send_request(document_symbol(3, &file)).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        assert!(response.result().is_some_and(|result| *result == Value::Null));
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_document_symbol_with_symbols() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/document_symbol.config");
+        server.send_request(did_open(&file, "some text")).await;
+
+        server.send_request(document_symbol(3, &file)).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        let symbols: Vec<serde_json::Value> =
+            serde_json::from_value(response.result().unwrap().clone()).unwrap();
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0]["name"], "Foo");
+        assert_eq!(symbols[0]["kind"], 5); // SymbolKind::CLASS
+        assert_eq!(symbols[0]["selectionRange"]["start"]["character"], 6);
+        assert_eq!(symbols[0]["children"], json!([]));
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_workspace_symbol_no_symbols() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/file.txt");
+        server.send_request(did_open(&file, "some text")).await;
+
+        server.send_request(workspace_symbol(3, "unknown")).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        assert!(response.result().is_some_and(|result| *result == Value::Null));
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_workspace_symbol_across_open_documents() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let first = format!("{WORKSPACE}/first.txt");
+        let second = format!("{WORKSPACE}/second.txt");
+        let closed = format!("{WORKSPACE}/closed.txt");
+        server.send_request(did_open(&first, "symbol one")).await;
+        server.send_request(did_open(&second, "symbol two")).await;
+        server.send_request(did_open(&closed, "symbol three")).await;
+        server.send_request(did_close(&closed)).await;
+
+        server.send_request(workspace_symbol(3, "symbol")).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        let symbols: Vec<serde_json::Value> =
+            serde_json::from_value(response.result().unwrap().clone()).unwrap();
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols[0]["name"], "symbol one");
+        assert_eq!(symbols[0]["location"]["uri"], first);
+        assert_eq!(symbols[1]["name"], "symbol two");
+        assert_eq!(symbols[1]["location"]["uri"], second);
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_selection_range_no_ranges() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/file.txt");
+        server.send_request(did_open(&file, "some text")).await;
+
+        server.send_request(selection_range(3, &file, vec![Position::new(0, 1)])).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        assert!(response.result().is_some_and(|result| *result == Value::Null));
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_selection_range_with_ranges() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/selection_range.config");
+        server.send_request(did_open(&file, "some text")).await;
+
+        let positions = vec![Position::new(0, 1), Position::new(1, 4)];
+        server.send_request(selection_range(3, &file, positions)).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        let ranges: Vec<serde_json::Value> =
+            serde_json::from_value(response.result().unwrap().clone()).unwrap();
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[1]["range"]["start"], json!({ "line": 1, "character": 4 }));
+        assert_eq!(ranges[1]["parent"]["range"]["end"], json!({ "line": 2, "character": 1 }));
+        assert!(ranges[1]["parent"].get("parent").is_none());
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_completion_no_items() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/file.js");
+        server.send_request(completion(3, &file, Position::new(0, 0))).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        assert!(response.result().is_some_and(|result| *result == Value::Null));
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_completion_with_items() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/completion.config");
+        server.send_request(completion(3, &file, Position::new(0, 0))).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        assert_eq!(
+            response.result().unwrap().clone(),
+            json!([{ "label": "fake", "detail": "fake item" }])
+        );
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_prepare_rename() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/rename.config");
+        server.send_request(did_open(&file, "foo bar")).await;
+
+        server.send_request(prepare_rename(3, &file, Position::new(0, 1))).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        assert_eq!(
+            response.result().unwrap().clone(),
+            json!({ "start": { "line": 0, "character": 0 }, "end": { "line": 0, "character": 3 } })
+        );
+
+        server.send_request(prepare_rename(4, &file, Position::new(0, 5))).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(4));
+        assert!(response.result().is_some_and(|result| *result == Value::Null));
+
+        server.shutdown(5).await;
+    }
+
+    #[tokio::test]
+    async fn test_rename() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/rename.config");
+        server.send_request(did_open(&file, "foo bar")).await;
+
+        server.send_request(rename(3, &file, Position::new(0, 1), "baz")).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        assert_eq!(
+            response.result().unwrap().clone(),
+            json!({
+                "changes": {
+                    file.clone(): [{
+                        "range": { "start": { "line": 0, "character": 0 }, "end": { "line": 0, "character": 3 } },
+                        "newText": "baz"
+                    }]
+                }
+            })
+        );
+
+        server.send_request(rename(4, &file, Position::new(0, 5), "baz")).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(4));
+        assert!(response.result().is_some_and(|result| *result == Value::Null));
+
+        server.shutdown(5).await;
+    }
+
+    #[tokio::test]
+    async fn test_inlay_hint_no_hints() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/file.txt");
+        server.send_request(did_open(&file, "some text")).await;
+
+        let range = Range::new(Position::new(0, 0), Position::new(10, 0));
+        server.send_request(inlay_hint(3, &file, range)).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        assert!(response.result().is_some_and(|result| *result == Value::Null));
+
+        server.shutdown(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_inlay_hint_with_hints() {
+        let mut server = TestServer::new_initialized(
+            |client| {
+                Backend::new(client, server_info(), vec![Box::new(FakeToolBuilder::default())])
+            },
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/inlay_hint.config");
+        server.send_request(did_open(&file, "some text")).await;
+
+        let range = Range::new(Position::new(0, 0), Position::new(2, 0));
+        server.send_request(inlay_hint(3, &file, range)).await;
+        let response = server.recv_response().await;
+        assert!(response.is_ok());
+        assert_eq!(response.id(), &Id::Number(3));
+        let hints: Vec<serde_json::Value> =
+            serde_json::from_value(response.result().unwrap().clone()).unwrap();
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0]["position"], json!({ "line": 0, "character": 10 }));
+        assert_eq!(hints[0]["label"], "unused");
+
+        server.shutdown(4).await;
+    }
 }
@@ -1,12 +1,13 @@
 use tower_lsp_server::{
     jsonrpc::ErrorCode,
     ls_types::{
-        CodeActionKind, CodeActionOrCommand, Diagnostic, Pattern, Range, ServerCapabilities,
-        TextEdit, Uri, WorkspaceEdit,
+        CodeActionKind, CodeActionOrCommand, CompletionItem, Diagnostic, DocumentSymbol,
+        FoldingRange, InlayHint, Pattern, Position, Range, SelectionRange, ServerCapabilities,
+        SymbolInformation, TextEdit, Uri, WorkDoneProgress, WorkspaceEdit,
     },
 };
 
-use crate::capabilities::Capabilities;
+use crate::{capabilities::Capabilities, file_system::LSPFileSystem};
 
 pub trait ToolBuilder: Send + Sync {
     /// Modify the server capabilities to include capabilities provided by this tool.
@@ -28,6 +29,18 @@ pub trait ToolBuilder: Send + Sync {
 
 pub type DiagnosticResult = Result<Vec<(Uri, Vec<Diagnostic>)>, String>;
 
+/// The outcome of a command which was executed successfully.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CommandResult {
+    /// Changes which the client is asked to apply with `workspace/applyEdit`.
+    /// The optional message is shown to the user with `window/showMessage` afterwards.
+    WorkspaceEdit { edit: WorkspaceEdit, message: Option<String> },
+    /// Data which is returned to the client as the response of `workspace/executeCommand`.
+    Value(serde_json::Value),
+    /// Diagnostics which are published to the client with `textDocument/publishDiagnostics`.
+    Diagnostics(Vec<(Uri, Vec<Diagnostic>)>),
+}
+
 pub trait Tool: Send + Sync {
     /// Get the name of the tool.
     fn name(&self) -> &'static str;
@@ -63,10 +76,11 @@ pub trait Tool: Send + Sync {
     }
 
     /// Tries to execute the given command with the provided arguments.
+    /// The content of open documents can be read from `file_system`, other files are read from disk.
     /// If the command is not recognized, returns `Ok(None)`.
     /// If the command is recognized and executed it can return:
-    /// - `Ok(Some(WorkspaceEdit))` if the command was executed successfully and produced a workspace edit.
-    /// - `Ok(None)` if the command was executed successfully but did not produce any workspace edit.
+    /// - `Ok(Some(CommandResult))` if the command was executed successfully and produced a workspace edit or a value.
+    /// - `Ok(None)` if the command was executed successfully but did not produce any result.
     ///
     /// # Errors
     /// If there was an error executing the command, returns an `Err(ErrorCode)`.
@@ -74,15 +88,29 @@ pub trait Tool: Send + Sync {
         &self,
         _command: &str,
         _arguments: Vec<serde_json::Value>,
-    ) -> Result<Option<WorkspaceEdit>, ErrorCode> {
+        _file_system: &LSPFileSystem,
+    ) -> Result<Option<CommandResult>, ErrorCode> {
         Ok(None)
     }
 
+    /// Merge the [`CommandResult::Value`]s returned by the tools of every workspace for the same command,
+    /// e.g. when each workspace reports its own statistics.
+    /// The default implementation answers with the value of the first workspace.
+    fn merge_command_values(
+        &self,
+        _command: &str,
+        values: Vec<serde_json::Value>,
+    ) -> serde_json::Value {
+        values.into_iter().next().unwrap_or_default()
+    }
+
     /// Get code actions or commands provided by this tool for the given URI and range.
+    /// If `content` is `None`, the tool should read the content from the file system.
     /// The `only_code_action_kinds` parameter can be used to filter the results based on specific code action kinds.
     fn get_code_actions_or_commands(
         &self,
         _uri: &Uri,
+        _content: Option<&str>,
         _range: &Range,
         _only_code_action_kinds: Option<&Vec<CodeActionKind>>,
     ) -> Vec<CodeActionOrCommand> {
@@ -133,10 +161,120 @@ pub trait Tool: Send + Sync {
         Ok(Vec::new())
     }
 
+    /// Run diagnostics on every file of the workspace, when the workspace is opened.
+    /// `progress` is called to report the progress of the scan to the client.
+    /// Returns a vector of a Uri-Diagnostic tuple, files without diagnostics can be omitted.
+    /// Not all tools will implement a workspace scan, so the default implementation returns [`Ok`] with an empty vector.
+    ///
+    /// # Errors
+    /// Return [`Err`] when an error occurs, ignoring diagnostics should return [`Ok`] with an empty vector.
+    fn run_workspace_diagnostic(
+        &self,
+        _progress: &(dyn Fn(WorkDoneProgress) + Sync),
+    ) -> DiagnosticResult {
+        Ok(Vec::new())
+    }
+
+    /// Get the folding ranges for the content of the given URI.
+    /// If `content` is `None`, the tool should read the content from the file system.
+    /// Not all tools will implement folding ranges, so the default implementation returns an empty vector.
+    fn get_folding_ranges(&self, _uri: &Uri, _content: Option<&str>) -> Vec<FoldingRange> {
+        Vec::new()
+    }
+
+    /// Get the hierarchical document symbols for the content of the given URI.
+    /// If `content` is `None`, the tool should read the content from the file system.
+    /// Not all tools will implement document symbols, so the default implementation returns an empty vector.
+    fn get_document_symbols(&self, _uri: &Uri, _content: Option<&str>) -> Vec<DocumentSymbol> {
+        Vec::new()
+    }
+
+    /// Get the symbols of the given open documents, whose name matches the query of a `workspace/symbol` request.
+    /// Each document is a tuple of its URI and its content.
+    /// Not all tools will implement workspace symbols, so the default implementation returns an empty vector.
+    fn get_workspace_symbols(
+        &self,
+        _query: &str,
+        _documents: &[(Uri, String)],
+    ) -> Vec<SymbolInformation> {
+        Vec::new()
+    }
+
+    /// Get the selection ranges for the given positions in the content of the given URI.
+    /// If `content` is `None`, the tool should read the content from the file system.
+    /// The returned vector must contain one selection range per position, in the same order.
+    /// Not all tools will implement selection ranges, so the default implementation returns an empty vector.
+    fn get_selection_ranges(
+        &self,
+        _uri: &Uri,
+        _content: Option<&str>,
+        _positions: &[Position],
+    ) -> Vec<SelectionRange> {
+        Vec::new()
+    }
+
+    /// Get the range of the symbol at the given position which can be renamed.
+    /// If `content` is `None`, the tool should read the content from the file system.
+    /// Returns `None` if the position can not be renamed.
+    /// Not all tools will implement renaming, so the default implementation returns `None`.
+    fn prepare_rename(
+        &self,
+        _uri: &Uri,
+        _content: Option<&str>,
+        _position: Position,
+    ) -> Option<Range> {
+        None
+    }
+
+    /// Get the text edits renaming the symbol at the given position to `new_name`.
+    /// If `content` is `None`, the tool should read the content from the file system.
+    /// Returns an empty vector if the position can not be renamed.
+    /// Not all tools will implement renaming, so the default implementation returns an empty vector.
+    fn rename(
+        &self,
+        _uri: &Uri,
+        _content: Option<&str>,
+        _position: Position,
+        _new_name: &str,
+    ) -> Vec<TextEdit> {
+        Vec::new()
+    }
+
+    /// Get the completion items at the given position of the URI.
+    /// If `content` is `None`, the tool should read the content from the file system.
+    /// Not all tools will implement completions, so the default implementation returns an empty vector.
+    fn get_completions(
+        &self,
+        _uri: &Uri,
+        _content: Option<&str>,
+        _position: Position,
+    ) -> Vec<CompletionItem> {
+        Vec::new()
+    }
+
+    /// Get the inlay hints inside the given range of the URI.
+    /// If `content` is `None`, the tool should read the content from the file system.
+    /// Not all tools will implement inlay hints, so the default implementation returns an empty vector.
+    fn get_inlay_hints(
+        &self,
+        _uri: &Uri,
+        _content: Option<&str>,
+        _range: &Range,
+    ) -> Vec<InlayHint> {
+        Vec::new()
+    }
+
     /// Remove internal cache for the given URI, if any.
     fn remove_uri_cache(&self, _uri: &Uri) {
         // Default implementation does nothing.
     }
+
+    /// Take the trace messages collected since the last call, they are sent to the client as `window/logMessage`.
+    /// Tools should only collect messages when the user asked for verbose output, to avoid noise in the client.
+    /// Not all tools will emit trace messages, so the default implementation returns an empty vector.
+    fn take_log_messages(&self) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 pub struct ToolRestartChanges {
@@ -5,18 +5,20 @@ use tokio::sync::{Mutex, RwLock};
 use tower_lsp_server::{
     jsonrpc::ErrorCode,
     ls_types::{
-        CodeActionKind, CodeActionOrCommand, Diagnostic, DidChangeWatchedFilesRegistrationOptions,
-        FileEvent, FileSystemWatcher, GlobPattern, OneOf, Range, Registration, RelativePattern,
-        TextEdit, Unregistration, Uri, WatchKind, WorkspaceEdit,
+        CodeActionKind, CodeActionOrCommand, CompletionItem, Diagnostic,
+        DidChangeWatchedFilesRegistrationOptions, DocumentSymbol, FileEvent, FileSystemWatcher,
+        FoldingRange, GlobPattern, InlayHint, OneOf, Position, Range, Registration,
+        RelativePattern, SelectionRange, SymbolInformation, TextEdit, Unregistration, Uri,
+        WatchKind, WorkDoneProgress,
     },
 };
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::{
     ToolRestartChanges,
     capabilities::DiagnosticMode,
     file_system::LSPFileSystem,
-    tool::{DiagnosticResult, Tool, ToolBuilder},
+    tool::{CommandResult, DiagnosticResult, Tool, ToolBuilder},
 };
 
 /// A worker that manages the individual tools for a specific workspace
@@ -26,7 +28,7 @@ use crate::{
 /// The [`Backend`](crate::backend::Backend) is responsible to target the correct worker for a given file URI.
 pub struct WorkspaceWorker {
     root_uri: Uri,
-    tools: RwLock<Vec<Box<dyn Tool>>>,
+    tools: RwLock<Vec<Arc<dyn Tool>>>,
     builders: Arc<[Box<dyn ToolBuilder>]>,
     // Initialized options from the client
     // If None, the worker has not been initialized yet
@@ -35,7 +37,7 @@ pub struct WorkspaceWorker {
     // Whether the client is in diagnostic pull mode / push mode, or not supporting diagnostics at all
     diagnostic_mode: DiagnosticMode,
     // Keep track of published diagnostics to clear them on shutdown (only in push mode)
-    published_diagnostics: Mutex<FxHashSet<Uri>>,
+    published_diagnostics: Arc<Mutex<FxHashSet<Uri>>>,
 }
 
 impl WorkspaceWorker {
@@ -53,7 +55,7 @@ impl WorkspaceWorker {
             builders,
             options: Mutex::new(None),
             diagnostic_mode,
-            published_diagnostics: Mutex::new(FxHashSet::default()),
+            published_diagnostics: Arc::new(Mutex::new(FxHashSet::default())),
         }
     }
 
@@ -68,7 +70,7 @@ impl WorkspaceWorker {
         *self.tools.write().await = self
             .builders
             .iter()
-            .map(|builder| builder.build_boxed(&self.root_uri, options.clone()))
+            .map(|builder| Arc::from(builder.build_boxed(&self.root_uri, options.clone())))
             .collect();
 
         *self.options.lock().await = Some(options);
@@ -108,6 +110,11 @@ impl WorkspaceWorker {
         });
     }
 
+    /// Take the trace messages the tools collected since the last call.
+    pub async fn take_log_messages(&self) -> Vec<String> {
+        self.tools.read().await.iter().flat_map(|tool| tool.take_log_messages()).collect()
+    }
+
     /// Common aggregator for tool-provided diagnostics.
     async fn collect_diagnostics_with<F>(
         &self,
@@ -116,12 +123,12 @@ impl WorkspaceWorker {
         run: F,
     ) -> Result<Vec<(Uri, Vec<Diagnostic>)>, String>
     where
-        F: Fn(&Box<dyn Tool>, &Uri, Option<&str>) -> DiagnosticResult,
+        F: Fn(&dyn Tool, &Uri, Option<&str>) -> DiagnosticResult,
     {
         let mut aggregated: FxHashMap<Uri, Vec<Diagnostic>> = FxHashMap::default();
 
         for tool in self.tools.read().await.iter() {
-            let tool_diagnostics = run(tool, uri, content);
+            let tool_diagnostics = run(tool.as_ref(), uri, content);
 
             match tool_diagnostics {
                 Ok(diags) => {
@@ -160,6 +167,60 @@ impl WorkspaceWorker {
         .await
     }
 
+    /// Run different tools to collect diagnostics of every file in the workspace.
+    /// A failing tool does not prevent the other tools from reporting their diagnostics.
+    ///
+    /// The returned scan does not borrow the worker, so it can run without holding the workers lock.
+    /// It runs on a blocking thread and does not hold the tools lock,
+    /// so requests for single files can still be answered while the workspace is scanned.
+    pub async fn run_workspace_diagnostic<P>(
+        &self,
+        progress: P,
+    ) -> impl Future<Output = Vec<(Uri, Vec<Diagnostic>)>> + Send + 'static
+    where
+        P: Fn(WorkDoneProgress) + Send + Sync + 'static,
+    {
+        let tools = self.tools.read().await.clone();
+        let root_uri = self.root_uri.clone();
+        let published_diagnostics = (self.diagnostic_mode == DiagnosticMode::Push)
+            .then(|| Arc::clone(&self.published_diagnostics));
+
+        async move {
+            let scan = tokio::task::spawn_blocking(move || {
+                let mut aggregated: FxHashMap<Uri, Vec<Diagnostic>> = FxHashMap::default();
+                for tool in &tools {
+                    match tool.run_workspace_diagnostic(&progress) {
+                        Ok(diags) => {
+                            for (entry_uri, mut diags) in diags {
+                                aggregated.entry(entry_uri).or_default().append(&mut diags);
+                            }
+                        }
+                        Err(err) => {
+                            warn!("scanning the workspace with {} failed: {err}", tool.name());
+                        }
+                    }
+                }
+                aggregated
+            });
+
+            let aggregated = match scan.await {
+                Ok(aggregated) => aggregated,
+                Err(err) => {
+                    warn!("scanning the workspace {} failed: {err}", root_uri.as_str());
+                    return Vec::new();
+                }
+            };
+
+            // In push mode, keep track of published diagnostics to clear them on shutdown
+            if let Some(published_diagnostics) = published_diagnostics {
+                let new_published_uris: FxHashSet<Uri> = aggregated.keys().cloned().collect();
+                published_diagnostics.lock().await.extend(new_published_uris);
+            }
+
+            aggregated.into_iter().collect()
+        }
+    }
+
     /// Run different tools to collect diagnostics on change.
     pub async fn run_diagnostic_on_change(
         &self,
@@ -233,6 +294,7 @@ impl WorkspaceWorker {
     pub async fn get_code_actions_or_commands(
         &self,
         uri: &Uri,
+        content: Option<&str>,
         range: &Range,
         only_code_action_kinds: Option<Vec<CodeActionKind>>,
     ) -> Vec<CodeActionOrCommand> {
@@ -240,6 +302,7 @@ impl WorkspaceWorker {
         for tool in self.tools.read().await.iter() {
             actions.extend(tool.get_code_actions_or_commands(
                 uri,
+                content,
                 range,
                 only_code_action_kinds.as_ref(),
             ));
@@ -247,6 +310,125 @@ impl WorkspaceWorker {
         actions
     }
 
+    /// Get the folding ranges for the given URI.
+    /// It calls all tools and collects their folding ranges.
+    pub async fn get_folding_ranges(&self, uri: &Uri, content: Option<&str>) -> Vec<FoldingRange> {
+        let mut ranges = Vec::new();
+        for tool in self.tools.read().await.iter() {
+            ranges.extend(tool.get_folding_ranges(uri, content));
+        }
+        ranges
+    }
+
+    /// Get the document symbols for the given URI.
+    /// It calls all tools and collects their document symbols.
+    pub async fn get_document_symbols(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+    ) -> Vec<DocumentSymbol> {
+        let mut symbols = Vec::new();
+        for tool in self.tools.read().await.iter() {
+            symbols.extend(tool.get_document_symbols(uri, content));
+        }
+        symbols
+    }
+
+    /// Get the symbols of the open documents matching the query.
+    /// It calls all tools and collects their workspace symbols.
+    pub async fn get_workspace_symbols(
+        &self,
+        query: &str,
+        documents: &[(Uri, String)],
+    ) -> Vec<SymbolInformation> {
+        let mut symbols = Vec::new();
+        for tool in self.tools.read().await.iter() {
+            symbols.extend(tool.get_workspace_symbols(query, documents));
+        }
+        symbols
+    }
+
+    /// Get the selection ranges for the given positions of the URI.
+    /// Selection ranges can not be merged, so the ranges of the first tool providing them are returned.
+    pub async fn get_selection_ranges(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        positions: &[Position],
+    ) -> Vec<SelectionRange> {
+        for tool in self.tools.read().await.iter() {
+            let ranges = tool.get_selection_ranges(uri, content, positions);
+            if !ranges.is_empty() {
+                return ranges;
+            }
+        }
+        Vec::new()
+    }
+
+    /// Get the range of the symbol at the given position of the URI which can be renamed.
+    /// The range of the first tool allowing the rename is returned.
+    pub async fn prepare_rename(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        position: Position,
+    ) -> Option<Range> {
+        for tool in self.tools.read().await.iter() {
+            if let Some(range) = tool.prepare_rename(uri, content, position) {
+                return Some(range);
+            }
+        }
+        None
+    }
+
+    /// Get the text edits renaming the symbol at the given position of the URI.
+    /// The edits of the first tool allowing the rename are returned.
+    pub async fn rename(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        position: Position,
+        new_name: &str,
+    ) -> Vec<TextEdit> {
+        for tool in self.tools.read().await.iter() {
+            let edits = tool.rename(uri, content, position, new_name);
+            if !edits.is_empty() {
+                return edits;
+            }
+        }
+        Vec::new()
+    }
+
+    /// Get the completion items at the given position of the URI.
+    /// It calls all tools and collects their completion items.
+    pub async fn get_completions(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        position: Position,
+    ) -> Vec<CompletionItem> {
+        let mut items = Vec::new();
+        for tool in self.tools.read().await.iter() {
+            items.extend(tool.get_completions(uri, content, position));
+        }
+        items
+    }
+
+    /// Get the inlay hints inside the given range of the URI.
+    /// It calls all tools and collects their inlay hints.
+    pub async fn get_inlay_hints(
+        &self,
+        uri: &Uri,
+        content: Option<&str>,
+        range: &Range,
+    ) -> Vec<InlayHint> {
+        let mut hints = Vec::new();
+        for tool in self.tools.read().await.iter() {
+            hints.extend(tool.get_inlay_hints(uri, content, range));
+        }
+        hints
+    }
+
     /// Handle file changes that are watched by the client
     /// At the moment, this only handles changes to lint configuration files
     /// When a change is detected, the linter is refreshed and all diagnostics are revalidated
@@ -338,7 +520,7 @@ impl WorkspaceWorker {
         change_handler: F,
     ) -> (Option<Vec<(Uri, Vec<Diagnostic>)>>, Vec<Registration>, Vec<Unregistration>)
     where
-        F: Fn(&mut Box<dyn Tool>, &dyn ToolBuilder) -> ToolRestartChanges,
+        F: Fn(&dyn Tool, &dyn ToolBuilder) -> ToolRestartChanges,
     {
         let mut registrations = vec![];
         let mut unregistrations = vec![];
@@ -352,7 +534,7 @@ impl WorkspaceWorker {
         );
         for (tool, builder) in tools.iter_mut().zip(self.builders.iter()) {
             let builder: &dyn ToolBuilder = builder.as_ref();
-            let change = change_handler(tool, builder);
+            let change = change_handler(tool.as_ref(), builder);
 
             if let Some(patterns) = change.watch_patterns {
                 unregistrations.push(unregistration_tool_watcher_id(tool.name(), &self.root_uri));
@@ -365,7 +547,7 @@ impl WorkspaceWorker {
                 }
             }
             if let Some(replaced_tool) = change.tool {
-                *tool = replaced_tool;
+                *tool = Arc::from(replaced_tool);
                 *needs_diagnostic_refresh = true;
 
                 let Some(file_system) = file_system else {
@@ -395,7 +577,7 @@ impl WorkspaceWorker {
     }
 
     /// Execute a command for the workspace.
-    /// Currently, only the `oxc.fixAll` command is supported.
+    /// The first tool responsible for the command executes it.
     ///
     /// # Errors
     /// Returns `ErrorCode` when the command is found but could not be executed.
@@ -403,14 +585,29 @@ impl WorkspaceWorker {
         &self,
         command: &str,
         arguments: Vec<serde_json::Value>,
-    ) -> Result<Option<WorkspaceEdit>, ErrorCode> {
+        file_system: &LSPFileSystem,
+    ) -> Result<Option<CommandResult>, ErrorCode> {
         for tool in self.tools.read().await.iter() {
             if tool.is_responsible_for_command(command) {
-                return tool.execute_command(command, arguments);
+                return tool.execute_command(command, arguments, file_system);
             }
         }
         Ok(None)
     }
+
+    /// Merge the values returned by the workers for the same command.
+    /// The tool responsible for the command merges them.
+    pub async fn merge_command_values(
+        &self,
+        command: &str,
+        values: Vec<serde_json::Value>,
+    ) -> serde_json::Value {
+        let tools = self.tools.read().await;
+        match tools.iter().find(|tool| tool.is_responsible_for_command(command)) {
+            Some(tool) => tool.merge_command_values(command, values),
+            None => values.into_iter().next().unwrap_or_default(),
+        }
+    }
 }
 
 /// Create an unregistration for a file system watcher for the given tool
@@ -515,19 +712,20 @@ mod tests {
             DiagnosticMode::None,
         );
         worker.start_worker(serde_json::Value::Null).await;
+        let fs = LSPFileSystem::default();
 
         // Test command not found
-        let result = worker.execute_command("unknown.command", vec![]).await;
+        let result = worker.execute_command("unknown.command", vec![], &fs).await;
         assert!(result.is_ok());
         assert!(result.ok().unwrap().is_none());
 
         // Test command found but no arguments
-        let result = worker.execute_command(FAKE_COMMAND, vec![]).await;
+        let result = worker.execute_command(FAKE_COMMAND, vec![], &fs).await;
         assert!(result.is_ok());
         assert!(result.ok().unwrap().is_none());
 
         // Test command found with arguments
-        let result = worker.execute_command(FAKE_COMMAND, vec![serde_json::Value::Null]).await;
+        let result = worker.execute_command(FAKE_COMMAND, vec![serde_json::Value::Null], &fs).await;
         assert!(result.is_ok());
         assert!(result.ok().unwrap().is_some());
     }
@@ -695,6 +893,7 @@ mod tests {
         let actions = worker
             .get_code_actions_or_commands(
                 &Uri::from_str("file:///root/file.js").unwrap(),
+                None,
                 &Range::default(),
                 None,
             )
@@ -705,6 +904,7 @@ mod tests {
         let actions = worker
             .get_code_actions_or_commands(
                 &Uri::from_str("file:///root/code_action.config").unwrap(),
+                None,
                 &Range::default(),
                 None,
             )
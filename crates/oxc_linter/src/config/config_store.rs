@@ -119,22 +119,24 @@ impl Config {
         self.base.rules.len()
     }
 
-    pub fn apply_overrides(&self, path: &Path) -> ResolvedLinterState {
-        if self.overrides.is_empty() {
-            return self.base.clone();
-        }
-
-        let relative_path = self
-            .base
+    /// The path of the file relative to the directory of the config file, as matched by `overrides`.
+    fn relative_path<'a>(&self, path: &'a Path) -> &'a Path {
+        self.base
             .config
             .path
             .as_ref()
             .and_then(|config_path| {
                 config_path.parent().map(|parent| path.strip_prefix(parent).unwrap_or(path))
             })
-            .unwrap_or(path);
+            .unwrap_or(path)
+    }
+
+    pub fn apply_overrides(&self, path: &Path) -> ResolvedLinterState {
+        if self.overrides.is_empty() {
+            return self.base.clone();
+        }
 
-        let path = relative_path.to_string_lossy();
+        let path = self.relative_path(path).to_string_lossy();
         let overrides_to_apply =
             self.overrides.iter().filter(|config| config.files.is_match(path.as_ref()));
 
@@ -303,13 +305,39 @@ impl ConfigStore {
             return None;
         }
 
+        Some(Self::count_rules(&self.base, type_aware_enabled))
+    }
+
+    /// Returns the number of rules of the config which applies to `path`, like [`Self::number_of_rules`],
+    /// but also when there are nested configs. The rules of `overrides` are not included.
+    pub fn number_of_rules_for_path(&self, path: &Path, type_aware_enabled: bool) -> usize {
+        Self::count_rules(self.get_related_config(path), type_aware_enabled)
+    }
+
+    fn count_rules(config: &Config, type_aware_enabled: bool) -> usize {
         let builtin_count = if type_aware_enabled {
-            self.base.base.rules.len()
+            config.base.rules.len()
         } else {
-            self.base.base.rules.iter().filter(|(rule, _)| !rule.is_tsgolint_rule()).count()
+            config.base.rules.iter().filter(|(rule, _)| !rule.is_tsgolint_rule()).count()
         };
 
-        Some(builtin_count + self.base.base.external_rules.len())
+        builtin_count + config.base.external_rules.len()
+    }
+
+    /// Returns whether the builtin rule is configured by the `overrides` which apply to `path`.
+    /// If it is, the top level `rules` of the config do not determine the severity of the rule for this file.
+    pub fn is_rule_overridden(&self, path: &Path, plugin_name: &str, rule_name: &str) -> bool {
+        let config = self.get_related_config(path);
+        let path = config.relative_path(path).to_string_lossy();
+        config.overrides.iter().filter(|config| config.files.is_match(path.as_ref())).any(
+            |override_config| {
+                override_config
+                    .rules
+                    .builtin_rules
+                    .iter()
+                    .any(|(rule, _)| rule.name() == rule_name && rule.plugin_name() == plugin_name)
+            },
+        )
     }
 
     pub fn rules(&self) -> &Arc<[(RuleEnum, AllowWarnDeny)]> {
@@ -363,7 +391,10 @@ impl ConfigStore {
 
 #[cfg(test)]
 mod test {
-    use std::{path::PathBuf, str::FromStr};
+    use std::{
+        path::{Path, PathBuf},
+        str::FromStr,
+    };
 
     use rustc_hash::FxHashMap;
     use serde_json::Value;
@@ -1064,6 +1095,41 @@ mod test {
         // Should return None when there are nested configs.
         assert_eq!(store_with_nested_configs.number_of_rules(false), None);
         assert_eq!(store_with_nested_configs.number_of_rules(true), None);
+        // The count of the config of a file is known with nested configs too.
+        let path = Path::new("nested/file.ts");
+        assert_eq!(store_with_nested_configs.number_of_rules_for_path(path, false), 1);
+        assert_eq!(store_with_nested_configs.number_of_rules_for_path(path, true), 2);
+    }
+
+    #[test]
+    fn test_is_rule_overridden() {
+        let overrides = ResolvedOxlintOverrides::new(vec![ResolvedOxlintOverride {
+            env: None,
+            files: GlobSet::new(vec!["*.test.{ts,tsx}"]),
+            plugins: None,
+            globals: None,
+            rules: ResolvedOxlintOverrideRules {
+                builtin_rules: vec![no_explicit_any()],
+                external_rules: vec![],
+            },
+        }]);
+        let store = ConfigStore::new(
+            Config::new(
+                vec![no_explicit_any()],
+                vec![],
+                OxlintCategories::default(),
+                LintConfig::default(),
+                overrides,
+            ),
+            FxHashMap::default(),
+            ExternalPluginStore::default(),
+        );
+
+        let test_file = Path::new("App.test.tsx");
+        assert!(store.is_rule_overridden(test_file, "typescript", "no-explicit-any"));
+        assert!(!store.is_rule_overridden(test_file, "eslint", "curly"));
+        // The override does not apply to the file
+        assert!(!store.is_rule_overridden(Path::new("App.tsx"), "typescript", "no-explicit-any"));
     }
 
     #[test]
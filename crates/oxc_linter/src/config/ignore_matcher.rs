@@ -60,9 +60,11 @@ impl LintIgnoreMatcher {
                     .is_some_and(|gi| gi.matched_path_or_any_parents(path, false).is_ignore());
             }
         }
-        self.base
-            .as_ref()
-            .is_some_and(|base| base.matched_path_or_any_parents(path, false).is_ignore())
+        // The base patterns are relative to their root, absolute paths outside of it are never ignored by them
+        self.base.as_ref().is_some_and(|base| {
+            (path.is_relative() || path.starts_with(base.path()))
+                && base.matched_path_or_any_parents(path, false).is_ignore()
+        })
     }
 }
 
@@ -100,4 +102,12 @@ mod tests {
         assert!(matcher.should_ignore(Path::new("/repo/file.js")));
         assert!(!matcher.should_ignore(Path::new("/repo/file.ts")));
     }
+
+    #[test]
+    fn test_path_outside_base_root() {
+        let matcher = LintIgnoreMatcher::new(&["*.js".to_string()], Path::new("/repo/app"), vec![]);
+
+        assert!(matcher.should_ignore(Path::new("/repo/app/file.js")));
+        assert!(!matcher.should_ignore(Path::new("/repo/file.js")));
+    }
 }
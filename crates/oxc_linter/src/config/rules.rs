@@ -74,6 +74,20 @@ impl OxlintRules {
     pub fn is_empty(&self) -> bool {
         self.rules.is_empty()
     }
+
+    /// Returns the configured rules of built-in plugins which do not exist.
+    ///
+    /// Rules of other plugins (e.g. JS plugins) are never returned,
+    /// because their rules can only be known by loading the plugin.
+    pub fn unknown_rules(&self) -> impl Iterator<Item = &ESLintRule> {
+        self.rules.iter().filter(|rule_config| {
+            let (rule_name, plugin_name) =
+                transform_rule_and_plugin_name(&rule_config.rule_name, &rule_config.plugin_name);
+
+            LintPlugins::try_from(plugin_name).is_ok()
+                && !RULES.iter().any(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
+        })
+    }
 }
 
 /// A fully qualified rule name.
@@ -498,6 +512,23 @@ mod test {
         assert!(r7.config.is_empty());
     }
 
+    #[test]
+    fn test_unknown_rules() {
+        let rules = OxlintRules::deserialize(&json!({
+            "no-console": "error",
+            "no-consol": "error",
+            "typescript/no-explicit-any": "warn",
+            "typescript/no-explicit-anyy": "warn",
+            "@typescript-eslint/no-unused-vars": "warn",
+            "vitest/no-focused-tests": "warn",
+            "my-js-plugin/some-rule": "error",
+        }))
+        .unwrap();
+
+        let unknown = rules.unknown_rules().map(|r| r.full_name()).collect::<Vec<_>>();
+        assert_eq!(unknown, vec!["no-consol", "typescript/no-explicit-anyy"]);
+    }
+
     #[test]
     fn test_parse_rules_default() {
         let rules = OxlintRules::default();
@@ -2,12 +2,15 @@ use lazy_regex::Regex;
 
 use oxc_ast::{
     AstKind,
-    ast::{Argument, TSModuleReference},
+    ast::{
+        Argument, BindingPattern, CallExpression, PropertyKey, TSModuleReference,
+        VariableDeclarationKind,
+    },
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::IsGlobalReference;
-use oxc_span::{CompactStr, Span};
+use oxc_span::{CompactStr, GetSpan, Span};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
@@ -109,10 +112,13 @@ declare_oxc_lint!(
     /// import { lib2 } from 'lib2';
     /// import * as lib3 from 'lib3';
     /// ```
+    ///
+    /// In ES modules, a top-level `const` declaration of a `require` call can be converted into an `import` declaration,
+    /// e.g. `const { a, b: c } = require('lib')` into `import { a, b as c } from 'lib'`.
     NoRequireImports,
     typescript,
     restriction,
-    pending,  // TODO: fixer (change require to import)
+    conditional_suggestion,
     config = NoRequireImportsConfig,
 );
 
@@ -174,7 +180,13 @@ impl Rule for NoRequireImports {
                     return;
                 }
 
-                ctx.diagnostic(no_require_imports_diagnostic(call_expr.span));
+                match require_to_import(node, call_expr, ctx) {
+                    Some((span, import)) => ctx.diagnostic_with_suggestion(
+                        no_require_imports_diagnostic(call_expr.span),
+                        |fixer| fixer.replace(span, import).with_message("Convert to `import`"),
+                    ),
+                    None => ctx.diagnostic(no_require_imports_diagnostic(call_expr.span)),
+                }
             }
             AstKind::TSImportEqualsDeclaration(decl) => match &decl.module_reference {
                 TSModuleReference::ExternalModuleReference(mod_ref) => {
@@ -200,6 +212,73 @@ impl Rule for NoRequireImports {
     }
 }
 
+/// Build the `import` declaration replacing `const x = require("y")` or `const { a, b: c } = require("y")`.
+///
+/// Returns the span of the `const` declaration and the `import` declaration replacing it,
+/// or `None` when the `require` call can not be converted, e.g. in scripts or when it is not at the top level.
+fn require_to_import(
+    node: &AstNode,
+    call_expr: &CallExpression,
+    ctx: &LintContext,
+) -> Option<(Span, String)> {
+    if !ctx.source_type().is_module() || call_expr.optional || call_expr.arguments.len() != 1 {
+        return None;
+    }
+    let Argument::StringLiteral(source) = &call_expr.arguments[0] else {
+        return None;
+    };
+
+    let AstKind::VariableDeclarator(declarator) = ctx.nodes().parent_kind(node.id()) else {
+        return None;
+    };
+    let declarator_node = ctx.nodes().parent_node(node.id());
+    let AstKind::VariableDeclaration(declaration) = ctx.nodes().parent_kind(declarator_node.id())
+    else {
+        return None;
+    };
+    let declaration_node = ctx.nodes().parent_node(declarator_node.id());
+    if declaration.kind != VariableDeclarationKind::Const
+        || declaration.declarations.len() != 1
+        || declarator.type_annotation.is_some()
+        || !matches!(ctx.nodes().parent_kind(declaration_node.id()), AstKind::Program(_))
+    {
+        return None;
+    }
+
+    let specifiers = match &declarator.id {
+        BindingPattern::BindingIdentifier(ident) => ident.name.to_string(),
+        BindingPattern::ObjectPattern(pattern) => {
+            if pattern.rest.is_some() {
+                return None;
+            }
+            let mut names = Vec::with_capacity(pattern.properties.len());
+            for property in &pattern.properties {
+                let BindingPattern::BindingIdentifier(local) = &property.value else {
+                    return None;
+                };
+                let imported = match &property.key {
+                    PropertyKey::StaticIdentifier(key) if !property.computed => key.name.as_str(),
+                    PropertyKey::StringLiteral(key) if !property.computed => {
+                        ctx.source_range(key.span)
+                    }
+                    _ => return None,
+                };
+                if imported == local.name.as_str() {
+                    names.push(imported.to_string());
+                } else {
+                    names.push(format!("{imported} as {}", local.name));
+                }
+            }
+            format!("{{ {} }}", names.join(", "))
+        }
+        _ => return None,
+    };
+
+    let semicolon = if ctx.source_range(declaration.span).ends_with(';') { ";" } else { "" };
+    let import = format!("import {specifiers} from {}{semicolon}", ctx.source_range(source.span()));
+    Some((declaration.span, import))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -383,7 +462,48 @@ fn test() {
         ),
     ];
 
+    let fix = vec![
+        ("import 'a';\nconst lib = require('lib');", "import 'a';\nimport lib from 'lib';"),
+        ("import 'a'\nconst lib = require(\"lib\")", "import 'a'\nimport lib from \"lib\""),
+        (
+            "export {};\nconst { a, b: c } = require('lib');",
+            "export {};\nimport { a, b as c } from 'lib';",
+        ),
+        (
+            "export {};\nconst { 'a-b': c } = require('lib');",
+            "export {};\nimport { 'a-b' as c } from 'lib';",
+        ),
+        // not converted
+        ("const lib = require('lib');", "const lib = require('lib');"),
+        ("export {};\nlet lib = require('lib');", "export {};\nlet lib = require('lib');"),
+        (
+            "export {};\nconst lib = require('lib'), other = 1;",
+            "export {};\nconst lib = require('lib'), other = 1;",
+        ),
+        (
+            "export {};\nconst { a, ...rest } = require('lib');",
+            "export {};\nconst { a, ...rest } = require('lib');",
+        ),
+        (
+            "export {};\nconst { a: { b } } = require('lib');",
+            "export {};\nconst { a: { b } } = require('lib');",
+        ),
+        (
+            "export {};\nconst lib = require('lib').default;",
+            "export {};\nconst lib = require('lib').default;",
+        ),
+        (
+            "export {};\nconst lib: Lib = require('lib');",
+            "export {};\nconst lib: Lib = require('lib');",
+        ),
+        (
+            "export {};\nfunction foo() { const lib = require('lib'); }",
+            "export {};\nfunction foo() { const lib = require('lib'); }",
+        ),
+    ];
+
     Tester::new(NoRequireImports::NAME, NoRequireImports::PLUGIN, pass, fail)
         .change_rule_path_extension("ts")
+        .expect_fix(fix)
         .test_and_snapshot();
 }
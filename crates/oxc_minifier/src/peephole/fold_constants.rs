@@ -327,7 +327,7 @@ impl<'a> PeepholeOptimizations {
                         .then(|| ctx.value_to_expr(span, ConstantValue::Number(result)))
                 })
             }
-            BinaryOperator::In => None,
+            BinaryOperator::In | BinaryOperator::Pipeline => None,
         };
         if let Some(changed) = changed {
             *expr = changed;
@@ -686,6 +686,13 @@ pub fn v8_intrinsic_spread_elem(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+#[cold]
+pub fn pipeline_operator_not_allowed(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("The pipeline operator is an experimental proposal and is not enabled")
+        .with_help("Enable the `allow_pipeline_operator` parse option to parse it")
+        .with_label(span)
+}
+
 #[cold]
 pub fn a_set_accessor_cannot_have_a_return_type_annotation(span: Span) -> OxcDiagnostic {
     ts_error("1095", "A 'set' accessor cannot have a return type annotation.").with_label(span)
@@ -1227,6 +1227,11 @@ impl<'a> ParserImpl<'a> {
                 break;
             }
 
+            // The pipeline operator `|>` is an experimental proposal, only allowed when enabled.
+            if kind == Kind::PipeGt && !self.options.allow_pipeline_operator {
+                self.error(diagnostics::pipeline_operator_not_allowed(self.cur_token().span()));
+            }
+
             if matches!(kind, Kind::As | Kind::Satisfies) {
                 if self.cur_token().is_on_new_line() {
                     break;
@@ -9,6 +9,7 @@ use crate::lexer::Kind;
 
 pub fn kind_to_precedence(kind: Kind) -> Option<Precedence> {
     match kind {
+        Kind::PipeGt => Some(Precedence::Pipeline),
         Kind::Question2 => Some(Precedence::NullishCoalescing),
         Kind::Pipe2 => Some(Precedence::LogicalOr),
         Kind::Amp2 => Some(Precedence::LogicalAnd),
@@ -52,6 +53,7 @@ pub fn map_binary_operator(kind: Kind) -> BinaryOperator {
         Kind::In => BinaryOperator::In,
         Kind::Instanceof => BinaryOperator::Instanceof,
         Kind::Star2 => BinaryOperator::Exponential,
+        Kind::PipeGt => BinaryOperator::Pipeline,
         _ => unreachable!("Binary Operator: {kind:?}"),
     }
 }
@@ -462,6 +462,10 @@ ascii_byte_handler!(PIP(lexer) {
             lexer.consume_char();
             Kind::PipeEq
         }
+        Some(b'>') => {
+            lexer.consume_char();
+            Kind::PipeGt
+        }
         _ => Kind::Pipe
     }
 });
@@ -143,6 +143,7 @@ pub enum Kind {
     Pipe2,
     Pipe2Eq,
     PipeEq,
+    PipeGt, // |>
     Plus,
     Plus2,
     PlusEq,
@@ -335,6 +336,7 @@ impl Kind {
             self,
             Eq2 | Neq | Eq3 | Neq2 | LAngle | LtEq | RAngle | GtEq | ShiftLeft | ShiftRight | ShiftRight3
             | Plus | Minus | Star | Slash | Percent | Pipe | Caret | Amp | In | Instanceof | Star2
+            | PipeGt
         )
     }
 
@@ -629,6 +631,7 @@ impl Kind {
             Pipe2 => "||",
             Pipe2Eq => "||=",
             PipeEq => "|=",
+            PipeGt => "|>",
             Plus => "+",
             Plus2 => "++",
             PlusEq => "+=",
@@ -221,6 +221,17 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Allow the [pipeline operator](https://github.com/tc39/proposal-pipeline-operator) proposal.
+    ///
+    /// Only the minimal proposal `x |> f` is supported,
+    /// it is parsed as a [`BinaryExpression`] with [`BinaryOperator::Pipeline`].
+    ///
+    /// Default: `false`
+    ///
+    /// [`BinaryExpression`]: oxc_ast::ast::BinaryExpression
+    /// [`BinaryOperator::Pipeline`]: oxc_syntax::operator::BinaryOperator::Pipeline
+    pub allow_pipeline_operator: bool,
 }
 
 impl Default for ParseOptions {
@@ -231,6 +242,7 @@ impl Default for ParseOptions {
             allow_return_outside_function: false,
             preserve_parens: true,
             allow_v8_intrinsics: false,
+            allow_pipeline_operator: false,
         }
     }
 }
@@ -640,7 +652,7 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression, Statement};
+    use oxc_ast::ast::{BinaryOperator, CommentKind, Expression, Statement};
     use oxc_span::GetSpan;
 
     use super::*;
@@ -761,6 +773,42 @@ mod test {
         }
     }
 
+    #[test]
+    fn pipeline_operator() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let opts = ParseOptions { allow_pipeline_operator: true, ..ParseOptions::default() };
+        {
+            // `|>` has a lower precedence than `??`, and is left associative
+            let source = "a ?? b |> f |> g";
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty());
+
+            let Some(Statement::ExpressionStatement(expr_stmt)) = ret.program.body.first() else {
+                panic!("Expected ExpressionStatement");
+            };
+            let Expression::BinaryExpression(outer) = &expr_stmt.expression else {
+                panic!("Expected BinaryExpression");
+            };
+            assert_eq!(outer.operator, BinaryOperator::Pipeline);
+            assert_eq!(outer.right.span().source_text(source), "g");
+            let Expression::BinaryExpression(inner) = &outer.left else {
+                panic!("Expected BinaryExpression");
+            };
+            assert_eq!(inner.operator, BinaryOperator::Pipeline);
+            assert_eq!(inner.left.span().source_text(source), "a ?? b");
+        }
+        {
+            let source = "x = value |> f";
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert_eq!(ret.errors.len(), 1);
+            assert_eq!(
+                ret.errors[0].to_string(),
+                "The pipeline operator is an experimental proposal and is not enabled"
+            );
+        }
+    }
+
     #[test]
     fn comments() {
         let allocator = Allocator::default();
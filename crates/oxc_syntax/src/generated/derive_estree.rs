@@ -148,6 +148,7 @@ impl ESTree for BinaryOperator {
             Self::BitwiseAnd => JsonSafeString("&").serialize(serializer),
             Self::In => JsonSafeString("in").serialize(serializer),
             Self::Instanceof => JsonSafeString("instanceof").serialize(serializer),
+            Self::Pipeline => JsonSafeString("|>").serialize(serializer),
         }
     }
 }
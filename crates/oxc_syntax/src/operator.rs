@@ -227,6 +227,9 @@ pub enum BinaryOperator {
     /// `instanceof`
     #[estree(rename = "instanceof")]
     Instanceof = 21,
+    /// `|>`
+    #[estree(rename = "|>")]
+    Pipeline = 22,
 }
 
 impl BinaryOperator {
@@ -362,6 +365,7 @@ impl BinaryOperator {
             Self::BitwiseAnd => "&",
             Self::In => "in",
             Self::Instanceof => "instanceof",
+            Self::Pipeline => "|>",
         }
     }
 
@@ -386,6 +390,7 @@ impl BinaryOperator {
             Self::Addition | Self::Subtraction => Precedence::Shift,
             Self::Multiplication | Self::Remainder | Self::Division => Precedence::Add,
             Self::Exponential => Precedence::Multiply,
+            Self::Pipeline => Precedence::Conditional,
         }
     }
 }
@@ -409,6 +414,7 @@ impl GetPrecedence for BinaryOperator {
             Self::Subtraction | Self::Addition => Precedence::Add,
             Self::Multiplication | Self::Remainder | Self::Division => Precedence::Multiply,
             Self::Exponential => Precedence::Exponentiation,
+            Self::Pipeline => Precedence::Pipeline,
         }
     }
 }
@@ -23,23 +23,24 @@ pub enum Precedence {
     Yield = 3,
     Assign = 4,
     Conditional = 5,
-    NullishCoalescing = 6,
-    LogicalOr = 7,
-    LogicalAnd = 8,
-    BitwiseOr = 9,
-    BitwiseXor = 10,
-    BitwiseAnd = 11,
-    Equals = 12,
-    Compare = 13,
-    Shift = 14,
-    Add = 15,
-    Multiply = 16,
-    Exponentiation = 17,
-    Prefix = 18,
-    Postfix = 19,
-    New = 20,
-    Call = 21,
-    Member = 22,
+    Pipeline = 6,
+    NullishCoalescing = 7,
+    LogicalOr = 8,
+    LogicalAnd = 9,
+    BitwiseOr = 10,
+    BitwiseXor = 11,
+    BitwiseAnd = 12,
+    Equals = 13,
+    Compare = 14,
+    Shift = 15,
+    Add = 16,
+    Multiply = 17,
+    Exponentiation = 18,
+    Prefix = 19,
+    Postfix = 20,
+    New = 21,
+    Call = 22,
+    Member = 23,
 }
 
 impl Precedence {
@@ -54,6 +55,7 @@ impl Precedence {
                 | Self::Comma
                 | Self::Spread
                 | Self::Yield
+                | Self::Pipeline
                 | Self::NullishCoalescing
                 | Self::LogicalOr
                 | Self::LogicalAnd
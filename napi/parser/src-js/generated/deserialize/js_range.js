@@ -4913,6 +4913,8 @@ function deserializeBinaryOperator(pos) {
       return "in";
     case 21:
       return "instanceof";
+    case 22:
+      return "|>";
     default:
       throw Error(`Unexpected discriminant ${uint8[pos]} for BinaryOperator`);
   }
@@ -12119,6 +12119,8 @@ function constructBinaryOperator(pos, ast) {
       return "in";
     case 21:
       return "instanceof";
+    case 22:
+      return "|>";
     default:
       throw new Error(`Unexpected discriminant ${ast.buffer[pos]} for BinaryOperator`);
   }
@@ -160,6 +160,8 @@ export interface OxcSortImportsOptions {
   partitionByNewline?: boolean
   /** Partition imports by comments (default: false) */
   partitionByComment?: boolean
+  /** Partition imports by side-effect imports (default: false) */
+  partitionBySideEffect?: boolean
   /** Sort side effects imports (default: false) */
   sortSideEffects?: boolean
   /** Sort order: "asc" | "desc" (default: "asc") */
@@ -168,6 +170,8 @@ export interface OxcSortImportsOptions {
   ignoreCase?: boolean
   /** Add newlines between import groups (default: true) */
   newlinesBetween?: boolean
+  /** Keep existing blank lines between import groups (default: false) */
+  preserveNewlinesBetween?: boolean
   /** Pattern prefixes for internal imports */
   internalPattern?: Array<string>
   /** Custom groups of imports */
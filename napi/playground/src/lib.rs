@@ -221,6 +221,7 @@ impl Oxc {
             allow_return_outside_function: parser_options.allow_return_outside_function,
             preserve_parens: parser_options.preserve_parens,
             allow_v8_intrinsics: parser_options.allow_v8_intrinsics,
+            allow_pipeline_operator: false,
         };
         let ParserReturn { program, errors, module_record, .. } =
             Parser::new(allocator, source_text, source_type).with_options(parser_options).parse();
@@ -519,10 +520,16 @@ impl Oxc {
             format_options.experimental_sort_imports = Some(SortImportsOptions {
                 partition_by_newline: sort_imports_config.partition_by_newline.unwrap_or(false),
                 partition_by_comment: sort_imports_config.partition_by_comment.unwrap_or(false),
+                partition_by_side_effect: sort_imports_config
+                    .partition_by_side_effect
+                    .unwrap_or(false),
                 sort_side_effects: sort_imports_config.sort_side_effects.unwrap_or(false),
                 order,
                 ignore_case: sort_imports_config.ignore_case.unwrap_or(true),
                 newlines_between: sort_imports_config.newlines_between.unwrap_or(true),
+                preserve_newlines_between: sort_imports_config
+                    .preserve_newlines_between
+                    .unwrap_or(false),
                 internal_pattern: sort_imports_config
                     .internal_pattern
                     .clone()
@@ -155,6 +155,8 @@ pub struct OxcSortImportsOptions {
     pub partition_by_newline: Option<bool>,
     /// Partition imports by comments (default: false)
     pub partition_by_comment: Option<bool>,
+    /// Partition imports by side-effect imports (default: false)
+    pub partition_by_side_effect: Option<bool>,
     /// Sort side effects imports (default: false)
     pub sort_side_effects: Option<bool>,
     /// Sort order: "asc" | "desc" (default: "asc")
@@ -163,6 +165,8 @@ pub struct OxcSortImportsOptions {
     pub ignore_case: Option<bool>,
     /// Add newlines between import groups (default: true)
     pub newlines_between: Option<bool>,
+    /// Keep existing blank lines between import groups (default: false)
+    pub preserve_newlines_between: Option<bool>,
     /// Pattern prefixes for internal imports
     pub internal_pattern: Option<Vec<String>>,
     /// Custom groups of imports
@@ -1710,7 +1710,8 @@ export type BinaryOperator =
   | "^"
   | "&"
   | "in"
-  | "instanceof";
+  | "instanceof"
+  | "|>";
 
 export type LogicalOperator = "||" | "&&" | "??";
 